@@ -0,0 +1,181 @@
+//! System clipboard for the `y` yank keys.
+//!
+//! Copying shells out to the platform's clipboard tool (`wl-copy`,
+//! `xclip`, `xsel` or `pbcopy`), the same way attachments open through
+//! `xdg-open`, rather than linking a clipboard crate such as `arboard`.
+//! On X11 and Wayland the selection is served by the process that
+//! copied it, so text held by Vulthor itself would vanish when it quits.
+//! The tools fork to keep serving it. When none works —
+//! a headless box, an SSH session without forwarding — the text lands
+//! in `~/.local/share/vulthor/clipboard.txt` instead, so a yank is
+//! never silently lost.
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use crate::components::html_viewer::binary_on_path;
+
+/// Where yanked text goes. Boxed in `AppRoot` so tests can swap in a
+/// recorder instead of touching the real clipboard.
+pub trait Clipboard: Send {
+    fn copy(&mut self, text: &str) -> io::Result<()>;
+}
+
+/// Clipboard tools in preference order, with the arguments that make
+/// them read the text from stdin into the clipboard selection.
+const TOOLS: &[(&str, &[&str])] = &[
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("pbcopy", &[]),
+];
+
+/// The desktop clipboard, through the first tool of [`TOOLS`] on
+/// `PATH`. `wl-copy` is only tried under Wayland.
+pub struct SystemClipboard;
+
+impl Clipboard for SystemClipboard {
+    fn copy(&mut self, text: &str) -> io::Result<()> {
+        let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+        let (program, args) = TOOLS
+            .iter()
+            .find(|(name, _)| (*name != "wl-copy" || wayland) && binary_on_path(name))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no clipboard tool on PATH"))?;
+        let mut child = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(text.as_bytes())?;
+        let status = child.wait()?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(io::Error::other(format!(
+                "{} exited with {}",
+                program, status
+            )))
+        }
+    }
+}
+
+/// Where a yank ended up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Yanked {
+    Clipboard,
+    /// The clipboard failed; the text was written to this file.
+    File(PathBuf),
+}
+
+/// `~/.local/share/vulthor/clipboard.txt` (the platform data dir on
+/// other systems), or `./vulthor-clipboard.txt` when none resolves.
+pub fn fallback_path() -> PathBuf {
+    dirs::data_dir()
+        .map(|d| d.join("vulthor").join("clipboard.txt"))
+        .unwrap_or_else(|| PathBuf::from("./vulthor-clipboard.txt"))
+}
+
+/// Copy `text` with `clipboard`, falling back to writing it to
+/// `fallback`. Errors only when both fail.
+pub fn yank(
+    clipboard: &mut dyn Clipboard,
+    text: &str,
+    fallback: PathBuf,
+) -> crate::error::Result<Yanked> {
+    match clipboard.copy(text) {
+        Ok(()) => Ok(Yanked::Clipboard),
+        Err(e) => {
+            tracing::warn!(error = %e, path = %fallback.display(), "clipboard unavailable; writing yank to file");
+            if let Some(dir) = fallback.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(&fallback, text)?;
+            Ok(Yanked::File(fallback))
+        }
+    }
+}
+
+/// Every distinct `http(s)://` URL in `text`, in order of first
+/// appearance. A URL ends at whitespace, a quote or an angle bracket;
+/// trailing sentence punctuation is dropped.
+pub fn extract_urls(text: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(at) = ["http://", "https://"]
+        .iter()
+        .filter_map(|scheme| rest.find(scheme))
+        .min()
+    {
+        let tail = &rest[at..];
+        let end = tail
+            .find(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '<' | '>'))
+            .unwrap_or(tail.len());
+        let url = tail[..end].trim_end_matches(['.', ',', ';', ':', '!', '?', ')', ']']);
+        if url.len() > "https://".len() && !urls.iter().any(|u| u == url) {
+            urls.push(url.to_string());
+        }
+        rest = &tail[end..];
+    }
+    urls
+}
+
+/// The bare address of a `From:` value: what's inside `<…>`, or the
+/// whole trimmed value when there are no brackets.
+pub fn bare_address(from: &str) -> &str {
+    match (from.rfind('<'), from.rfind('>')) {
+        (Some(open), Some(close)) if open < close => &from[open + 1..close],
+        _ => from.trim(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Broken;
+
+    impl Clipboard for Broken {
+        fn copy(&mut self, _text: &str) -> io::Result<()> {
+            Err(io::Error::other("no display"))
+        }
+    }
+
+    #[test]
+    fn yank_falls_back_to_a_file_when_the_clipboard_fails() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("vulthor/clipboard.txt");
+        let yanked = yank(&mut Broken, "hello", path.clone()).unwrap();
+        assert_eq!(yanked, Yanked::File(path.clone()));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "hello");
+    }
+
+    #[test]
+    fn extract_urls_finds_each_link_once_without_trailing_punctuation() {
+        let text = "See https://a.test/x. Or <a href=\"http://b.test/?q=1&r=2\">b</a>\n\
+                    (https://a.test/x) and https://c.test, done";
+        assert_eq!(
+            extract_urls(text),
+            [
+                "https://a.test/x",
+                "http://b.test/?q=1&r=2",
+                "https://c.test"
+            ]
+        );
+        assert!(extract_urls("no links, just https:// here").is_empty());
+    }
+
+    #[test]
+    fn bare_address_strips_the_display_name() {
+        assert_eq!(
+            bare_address("John Doe <john@example.com>"),
+            "john@example.com"
+        );
+        assert_eq!(bare_address(" jane@example.com "), "jane@example.com");
+    }
+}
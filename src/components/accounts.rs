@@ -147,15 +147,13 @@ impl AccountsComponent {
 impl Component for AccountsComponent {
     fn handle_msg(&mut self, msg: &Msg, _ctx: &Ctx) -> Vec<Msg> {
         match msg {
-            Msg::AccountMove(Dir::Down) => {
-                if !self.accounts.is_empty() && self.selected_index + 1 < self.accounts.len() {
-                    self.selected_index += 1;
-                }
+            Msg::AccountMove(Dir::Down)
+                if !self.accounts.is_empty() && self.selected_index + 1 < self.accounts.len() =>
+            {
+                self.selected_index += 1;
             }
-            Msg::AccountMove(Dir::Up) => {
-                if self.selected_index > 0 {
-                    self.selected_index -= 1;
-                }
+            Msg::AccountMove(Dir::Up) if self.selected_index > 0 => {
+                self.selected_index -= 1;
             }
             // `AccountSelect` is observed by AppRoot (which rebuilds
             // the store); the component itself only needs to mirror
@@ -171,11 +169,7 @@ impl Component for AccountsComponent {
     }
 
     fn render(&self, f: &mut Frame, area: Rect, focused: bool, ctx: &Ctx) {
-        let border_style = if focused {
-            Style::default().fg(ctx.theme.cyan_light)
-        } else {
-            Style::default()
-        };
+        let border_style = ctx.theme.focus(focused, ctx.theme.cyan_light);
         let block = Block::default()
             .borders(Borders::ALL)
             .style(border_style)
@@ -195,12 +189,9 @@ impl Component for AccountsComponent {
             .iter()
             .map(|row| ListItem::new(self.highlight_row(row)))
             .collect();
-        let list = List::new(items).block(block).highlight_style(
-            Style::default()
-                .bg(ctx.theme.primary)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        );
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(ctx.theme.selection());
         let mut state = self.list_state.borrow_mut();
         state.select(Some(self.selected_index));
         f.render_stateful_widget(list, area, &mut *state);
@@ -0,0 +1,218 @@
+// `AttachPicker` — the `Ctrl+A` file chooser of the Draft pane.
+//
+// A plain directory listing in a popup over the Draft pane: `..`
+// first (unless at `/`), then subdirectories, then files, each group
+// sorted by name. Dotfiles are left out so `~/` isn't buried under
+// configuration directories.
+//
+// Keys (routed here by AppRoot while `visible`):
+//   - j / k / ↓ / ↑      move the cursor
+//   - Enter              descend into a directory (`..` goes up)
+//   - Backspace / h      go up one directory
+//   - Space              attach the file under the cursor
+//   - Esc / q            close without attaching
+//
+// The picker stays open after Space so several files from one
+// directory can be attached in a row; each emits `Msg::DraftAttach`.
+
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+use crate::theme::Theme;
+
+use super::Msg;
+
+/// One row of the listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PickerEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub is_dir: bool,
+}
+
+/// Directory-listing popup state.
+#[derive(Default)]
+pub struct AttachPicker {
+    pub visible: bool,
+    /// Directory being listed.
+    pub dir: PathBuf,
+    pub entries: Vec<PickerEntry>,
+    pub selected_index: usize,
+    list_state: RefCell<ListState>,
+}
+
+impl AttachPicker {
+    /// Show the picker listing `dir`.
+    pub fn open(&mut self, dir: PathBuf) {
+        self.visible = true;
+        self.change_dir(dir);
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.entries.clear();
+    }
+
+    /// List `dir` and put the cursor on its first row. An unreadable
+    /// directory lists as just `..`, so the user can back out of it.
+    fn change_dir(&mut self, dir: PathBuf) {
+        self.entries = list_dir(&dir);
+        self.dir = dir;
+        self.selected_index = 0;
+    }
+
+    pub fn selected(&self) -> Option<&PickerEntry> {
+        self.entries.get(self.selected_index)
+    }
+
+    /// Handle one key while visible. Returns the attach message when a
+    /// file is chosen.
+    pub fn on_key(&mut self, key: KeyEvent) -> Option<Msg> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.close(),
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.selected_index =
+                    (self.selected_index + 1).min(self.entries.len().saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.selected_index = self.selected_index.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                if let Some(entry) = self.selected().filter(|e| e.is_dir) {
+                    let dir = entry.path.clone();
+                    self.change_dir(dir);
+                }
+            }
+            KeyCode::Backspace | KeyCode::Char('h') => {
+                if let Some(parent) = self.dir.parent() {
+                    let parent = parent.to_path_buf();
+                    self.change_dir(parent);
+                }
+            }
+            KeyCode::Char(' ') => {
+                if let Some(entry) = self.selected().filter(|e| !e.is_dir) {
+                    return Some(Msg::DraftAttach(entry.path.clone()));
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Paint the popup centred in `area` (the Draft pane).
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let width = (area.width * 4 / 5).max(20).min(area.width);
+        let height = (area.height * 4 / 5).max(6).min(area.height);
+        let rect = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .map(|e| {
+                if e.is_dir {
+                    ListItem::new(format!("{}/", e.name))
+                } else {
+                    ListItem::new(e.name.clone())
+                }
+            })
+            .collect();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.focus(true, theme.accent))
+            .title(format!(
+                "Attach - {} [Enter open · Space attach · Esc]",
+                self.dir.display()
+            ));
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.selection());
+        let mut state = self.list_state.borrow_mut();
+        state.select(Some(self.selected_index));
+        f.render_widget(Clear, rect);
+        f.render_stateful_widget(list, rect, &mut *state);
+    }
+}
+
+/// `..`, then the visible subdirectories, then the visible files of
+/// `dir`, each group sorted by name.
+fn list_dir(dir: &Path) -> Vec<PickerEntry> {
+    let mut dirs = Vec::new();
+    let mut files = Vec::new();
+    if let Ok(read) = std::fs::read_dir(dir) {
+        for entry in read.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.starts_with('.') {
+                continue;
+            }
+            let path = entry.path();
+            // Follow symlinks so a linked directory can be entered.
+            let is_dir = path.is_dir();
+            let row = PickerEntry { name, path, is_dir };
+            if is_dir {
+                dirs.push(row);
+            } else {
+                files.push(row);
+            }
+        }
+    }
+    dirs.sort_by(|a, b| a.name.cmp(&b.name));
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    let parent = dir.parent().map(|p| PickerEntry {
+        name: "..".to_string(),
+        path: p.to_path_buf(),
+        is_dir: true,
+    });
+    parent.into_iter().chain(dirs).chain(files).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn the_listing_descends_and_space_attaches_a_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(temp.path().join("docs")).unwrap();
+        std::fs::write(temp.path().join("docs/report.pdf"), b"%PDF").unwrap();
+        std::fs::write(temp.path().join("a.txt"), b"a").unwrap();
+        std::fs::write(temp.path().join(".hidden"), b"h").unwrap();
+
+        let mut picker = AttachPicker::default();
+        picker.open(temp.path().to_path_buf());
+        let names: Vec<&str> = picker.entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["..", "docs", "a.txt"]);
+
+        // Space on a directory does nothing.
+        picker.on_key(key(KeyCode::Char('j')));
+        assert_eq!(picker.on_key(key(KeyCode::Char(' '))), None);
+        picker.on_key(key(KeyCode::Enter));
+        assert_eq!(picker.dir, temp.path().join("docs"));
+        picker.on_key(key(KeyCode::Char('j')));
+        assert_eq!(
+            picker.on_key(key(KeyCode::Char(' '))),
+            Some(Msg::DraftAttach(temp.path().join("docs/report.pdf")))
+        );
+        assert!(picker.visible, "stays open for the next file");
+
+        picker.on_key(key(KeyCode::Backspace));
+        assert_eq!(picker.dir, temp.path());
+        picker.on_key(key(KeyCode::Esc));
+        assert!(!picker.visible);
+    }
+}
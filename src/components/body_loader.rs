@@ -62,6 +62,7 @@ impl BodyLoader {
         thread::spawn(move || {
             while let Ok(path) = req_rx.recv() {
                 let mut email = Email::new(path.clone());
+                tracing::debug!(path = %path.display(), "loading email body");
                 let parsed = match email.parse_from_file() {
                     Ok(()) => Some(ParsedBody {
                         body_plain: email.body_plain.take(),
@@ -69,7 +70,10 @@ impl BodyLoader {
                         attachments: std::mem::take(&mut email.attachments),
                         inline_images: std::mem::take(&mut email.inline_images),
                     }),
-                    Err(_) => None,
+                    Err(e) => {
+                        tracing::warn!(path = %path.display(), error = %e, "email body failed to parse");
+                        None
+                    }
                 };
                 if res_tx.send(LoadedBody { path, parsed }).is_err() {
                     break;
@@ -0,0 +1,744 @@
+// `CommandLineComponent` — vim-style `:` command line — and the
+// command registry behind it.
+//
+// `:` opens a one-line prompt over the status bar. While `visible ==
+// true`, AppRoot routes every key event here first, like the search
+// modal. Enter emits `Msg::CommandRun(line)`; AppRoot splits the line
+// into a name and an argument string and calls the matching
+// `CommandSpec::handler` from its `CommandRegistry`.
+//
+// Handlers are plain `fn(&mut AppRoot, &str)` pointers so any module
+// can contribute commands through `AppRoot::commands_mut().register`.
+// The built-ins mostly enqueue the same `Msg` a key binding would.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Position, Rect},
+    style::Style,
+    widgets::{Clear, Paragraph},
+};
+
+use crate::email::{Folder, SortOrder};
+use crate::theme::Theme;
+
+use super::{AppRoot, Component, Ctx, Msg};
+
+/// Runs one command. Receives the text after the command name
+/// (trimmed; empty when none was given). An `Err` is shown in the
+/// status bar as an error.
+pub type CommandHandler = fn(&mut AppRoot, &str) -> Result<(), String>;
+
+/// One entry in the [`CommandRegistry`].
+#[derive(Debug, Clone, Copy)]
+pub struct CommandSpec {
+    /// Canonical name, typed after `:` and offered by Tab completion.
+    pub name: &'static str,
+    /// Alternative names (`q` for `quit`). Not offered by completion.
+    pub aliases: &'static [&'static str],
+    /// One-line summary.
+    pub description: &'static str,
+    pub handler: CommandHandler,
+}
+
+/// Name → handler table for the command line.
+#[derive(Debug, Clone, Default)]
+pub struct CommandRegistry {
+    commands: Vec<CommandSpec>,
+}
+
+impl CommandRegistry {
+    /// Empty registry; see [`Self::builtin`] for the default set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `quit`, `help`, `search`, `move`, `open`, `untag-all`,
+    /// `mark-read`, `mark-unread`, `mark-all-read`, `goto`, `undo`, `refresh`,
+    /// `sort`, `filter`, `export`, `charset` and `empty-trash`.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        for spec in BUILTIN_COMMANDS {
+            registry.register(*spec);
+        }
+        registry
+    }
+
+    /// Add `spec`, replacing any command already registered under the
+    /// same name.
+    pub fn register(&mut self, spec: CommandSpec) {
+        match self.commands.iter_mut().find(|c| c.name == spec.name) {
+            Some(existing) => *existing = spec,
+            None => self.commands.push(spec),
+        }
+    }
+
+    /// Command registered as `name` or with `name` as an alias.
+    pub fn lookup(&self, name: &str) -> Option<&CommandSpec> {
+        self.commands
+            .iter()
+            .find(|c| c.name == name)
+            .or_else(|| self.commands.iter().find(|c| c.aliases.contains(&name)))
+    }
+
+    /// Canonical names starting with `prefix`, alphabetically.
+    pub fn complete(&self, prefix: &str) -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = self
+            .commands
+            .iter()
+            .map(|c| c.name)
+            .filter(|name| name.starts_with(prefix))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Every registered command, in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = &CommandSpec> {
+        self.commands.iter()
+    }
+}
+
+/// Split a command line into its name and trimmed argument string.
+/// `None` for a blank line.
+pub fn parse_command_line(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+    Some(match line.split_once(char::is_whitespace) {
+        Some((name, args)) => (name, args.trim()),
+        None => (line, ""),
+    })
+}
+
+const BUILTIN_COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "quit",
+        aliases: &["q"],
+        description: "Quit Vulthor",
+        handler: cmd_quit,
+    },
+    CommandSpec {
+        name: "help",
+        aliases: &[],
+        description: "Show the key bindings",
+        handler: cmd_help,
+    },
+    CommandSpec {
+        name: "search",
+        aliases: &[],
+        description: "Run a notmuch query (no argument: open the search prompt)",
+        handler: cmd_search,
+    },
+    CommandSpec {
+        name: "move",
+        aliases: &[],
+        description: "Move the email to a folder (no argument: open the picker)",
+        handler: cmd_move,
+    },
+    CommandSpec {
+        name: "open",
+        aliases: &[],
+        description: "Enter a folder (inexact name: open the jump picker filtered by it)",
+        handler: cmd_open,
+    },
+    CommandSpec {
+        name: "untag-all",
+        aliases: &[],
+        description: "Untag every tagged email",
+        handler: cmd_untag_all,
+    },
+    CommandSpec {
+        name: "mark-read",
+        aliases: &[],
+        description: "Mark the email read",
+        handler: cmd_mark_read,
+    },
+    CommandSpec {
+        name: "mark-unread",
+        aliases: &[],
+        description: "Mark the email unread",
+        handler: cmd_mark_unread,
+    },
+    CommandSpec {
+        name: "mark-all-read",
+        aliases: &[],
+        description: "Mark every email in the folder read",
+        handler: cmd_mark_all_read,
+    },
+    CommandSpec {
+        name: "goto",
+        aliases: &[],
+        description: "Go to the Nth email of the folder",
+        handler: cmd_goto,
+    },
+    CommandSpec {
+        name: "undo",
+        aliases: &[],
+        description: "Undo the last action",
+        handler: cmd_undo,
+    },
+    CommandSpec {
+        name: "refresh",
+        aliases: &[],
+        description: "Rescan the current folder from disk",
+        handler: cmd_refresh,
+    },
+    CommandSpec {
+        name: "sort",
+        aliases: &[],
+        description: "Sort by date, from, subject or size; add `reverse` to flip (no argument: load order)",
+        handler: cmd_sort,
+    },
+    CommandSpec {
+        name: "filter",
+        aliases: &[],
+        description: "Show only the folder's emails matching the text",
+        handler: cmd_filter,
+    },
+    CommandSpec {
+        name: "export",
+        aliases: &[],
+        description: "Write the email (or the tagged ones) to an mbox file",
+        handler: cmd_export,
+    },
+    CommandSpec {
+        name: "charset",
+        aliases: &[],
+        description: "Decode the email's body as another charset (auto: as declared)",
+        handler: cmd_charset,
+    },
+    CommandSpec {
+        name: "empty-trash",
+        aliases: &[],
+        description: "Permanently delete every email in the Trash folder (asks first)",
+        handler: cmd_empty_trash,
+    },
+];
+
+fn cmd_quit(root: &mut AppRoot, _args: &str) -> Result<(), String> {
+    root.enqueue(Msg::Quit);
+    Ok(())
+}
+
+fn cmd_help(root: &mut AppRoot, _args: &str) -> Result<(), String> {
+    if !root.help_visible() {
+        root.enqueue(Msg::ToggleHelp);
+    }
+    Ok(())
+}
+
+fn cmd_search(root: &mut AppRoot, args: &str) -> Result<(), String> {
+    root.enqueue(if args.is_empty() {
+        Msg::OpenSearchInput
+    } else {
+        Msg::SearchExecute(args.to_string())
+    });
+    Ok(())
+}
+
+fn cmd_move(root: &mut AppRoot, args: &str) -> Result<(), String> {
+    if args.is_empty() {
+        root.enqueue(Msg::OpenFolderPicker);
+        return Ok(());
+    }
+    let target = {
+        let store = root.email_store_handle();
+        let store = store.lock().unwrap();
+        find_folder(&store.root_folder, args).map(|f| f.path.clone())
+    };
+    match target {
+        Some(path) => {
+            root.enqueue(Msg::MoveTo(String::new(), path));
+            Ok(())
+        }
+        None => Err(format!("no folder named {}", args)),
+    }
+}
+
+fn cmd_open(root: &mut AppRoot, args: &str) -> Result<(), String> {
+    let target = {
+        let store = root.email_store_handle();
+        let store = store.lock().unwrap();
+        find_folder(&store.root_folder, args).map(|f| f.path.clone())
+    };
+    root.enqueue(match target {
+        Some(path) if !args.is_empty() => Msg::JumpToFolder(path),
+        _ => Msg::OpenFolderJump(args.to_string()),
+    });
+    Ok(())
+}
+
+fn cmd_untag_all(root: &mut AppRoot, _args: &str) -> Result<(), String> {
+    root.enqueue(Msg::MessageUntagAll);
+    Ok(())
+}
+
+fn cmd_mark_read(root: &mut AppRoot, _args: &str) -> Result<(), String> {
+    root.enqueue(Msg::MarkRead(String::new()));
+    Ok(())
+}
+
+fn cmd_mark_unread(root: &mut AppRoot, _args: &str) -> Result<(), String> {
+    root.enqueue(Msg::MarkUnread(String::new()));
+    Ok(())
+}
+
+fn cmd_mark_all_read(root: &mut AppRoot, _args: &str) -> Result<(), String> {
+    root.enqueue(Msg::MarkAllRead);
+    Ok(())
+}
+
+fn cmd_goto(root: &mut AppRoot, args: &str) -> Result<(), String> {
+    match args.parse::<usize>() {
+        Ok(n) if n > 0 => {
+            root.enqueue(Msg::MessageGoto(n - 1));
+            Ok(())
+        }
+        _ => Err(format!("not a message number: {}", args)),
+    }
+}
+
+fn cmd_undo(root: &mut AppRoot, _args: &str) -> Result<(), String> {
+    root.enqueue(Msg::Undo);
+    Ok(())
+}
+
+fn cmd_refresh(root: &mut AppRoot, _args: &str) -> Result<(), String> {
+    root.enqueue(Msg::FolderRefresh);
+    Ok(())
+}
+
+fn cmd_empty_trash(root: &mut AppRoot, _args: &str) -> Result<(), String> {
+    root.enqueue(Msg::EmptyTrashAsk);
+    Ok(())
+}
+
+fn cmd_sort(root: &mut AppRoot, args: &str) -> Result<(), String> {
+    let msg = parse_sort_args(args)?;
+    root.enqueue(msg);
+    Ok(())
+}
+
+/// `:sort` arguments: an order name and an optional `reverse` (or
+/// `rev`); nothing at all for load order.
+fn parse_sort_args(args: &str) -> Result<Msg, String> {
+    let mut words = args.split_whitespace();
+    let Some(name) = words.next() else {
+        return Ok(Msg::SortBy(None, false));
+    };
+    let order = SortOrder::from_name(name).ok_or_else(|| {
+        let names: Vec<&str> = SortOrder::ALL.iter().map(|o| o.name()).collect();
+        format!("unknown sort order: {} (use {})", name, names.join(", "))
+    })?;
+    let reversed = match words.next() {
+        None => false,
+        Some("reverse" | "rev") => true,
+        Some(other) => return Err(format!("unexpected sort argument: {}", other)),
+    };
+    Ok(Msg::SortBy(Some(order), reversed))
+}
+
+fn cmd_filter(root: &mut AppRoot, args: &str) -> Result<(), String> {
+    if args.is_empty() {
+        return Err("filter needs some text to match".to_string());
+    }
+    root.enqueue(Msg::MessageFilter(args.to_string()));
+    Ok(())
+}
+
+fn cmd_charset(root: &mut AppRoot, args: &str) -> Result<(), String> {
+    let charset = match args {
+        "" => return Err("charset needs an encoding name, or auto".to_string()),
+        "auto" => None,
+        name => Some(name.to_string()),
+    };
+    root.enqueue(Msg::CharsetOverride(charset));
+    Ok(())
+}
+
+fn cmd_export(root: &mut AppRoot, args: &str) -> Result<(), String> {
+    if args.is_empty() {
+        return Err("export needs a file name".to_string());
+    }
+    let path = match args.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map_or_else(|| args.into(), |home| home.join(rest)),
+        None => std::path::PathBuf::from(args),
+    };
+    root.enqueue(Msg::Export(path));
+    Ok(())
+}
+
+/// Folder under `root` whose path relative to it is `query`
+/// (`Work/Projects`), else the first whose name matches
+/// case-insensitively.
+fn find_folder<'a>(root: &'a Folder, query: &str) -> Option<&'a Folder> {
+    let query = query.trim_matches('/');
+    fn walk<'a>(folder: &'a Folder, out: &mut Vec<&'a Folder>) {
+        for sub in &folder.subfolders {
+            out.push(sub);
+            walk(sub, out);
+        }
+    }
+    let mut all = Vec::new();
+    walk(root, &mut all);
+    all.iter()
+        .find(|f| {
+            f.path
+                .strip_prefix(&root.path)
+                .is_ok_and(|rel| rel == std::path::Path::new(query))
+        })
+        .or_else(|| all.iter().find(|f| f.name.eq_ignore_ascii_case(query)))
+        .copied()
+}
+
+/// Command-line prompt state. Absorbs every key event while
+/// `visible == true`.
+#[derive(Default)]
+pub struct CommandLineComponent {
+    /// True while the prompt is shown.
+    pub visible: bool,
+    /// Text after the `:`.
+    pub input: String,
+    /// Cursor position in `input`, in chars.
+    cursor: usize,
+    /// Submitted lines, oldest first. Consecutive repeats are stored
+    /// once.
+    history: Vec<String>,
+    /// Index into `history` while Up / Down is browsing it.
+    history_pos: Option<usize>,
+    /// What was typed before history browsing started, restored when
+    /// Down walks past the newest entry.
+    draft: String,
+    /// Candidates and position of an in-progress Tab cycle.
+    completion: Option<(Vec<&'static str>, usize)>,
+}
+
+impl CommandLineComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show an empty prompt.
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.set_input(String::new());
+        self.history_pos = None;
+    }
+
+    /// Hide the prompt and drop the typed text. History is kept.
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.set_input(String::new());
+        self.history_pos = None;
+    }
+
+    /// Past command lines, oldest first.
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Cursor position in `input`, in chars.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    fn set_input(&mut self, input: String) {
+        self.cursor = input.chars().count();
+        self.input = input;
+        self.completion = None;
+    }
+
+    fn byte_at(&self, cursor: usize) -> usize {
+        self.input
+            .char_indices()
+            .nth(cursor)
+            .map_or(self.input.len(), |(i, _)| i)
+    }
+
+    /// Tab: complete the command name under the cursor from
+    /// `registry`. Repeated presses cycle through the candidates.
+    /// Arguments are left alone.
+    pub fn complete(&mut self, registry: &CommandRegistry) {
+        if let Some((candidates, idx)) = self.completion.take() {
+            let idx = (idx + 1) % candidates.len();
+            self.input = candidates[idx].to_string();
+            self.cursor = self.input.chars().count();
+            self.completion = Some((candidates, idx));
+            return;
+        }
+        if self.input.contains(char::is_whitespace) {
+            return;
+        }
+        let candidates = registry.complete(&self.input);
+        match candidates.as_slice() {
+            [] => {}
+            [only] => self.set_input(format!("{} ", only)),
+            [first, ..] => {
+                self.input = first.to_string();
+                self.cursor = self.input.chars().count();
+                self.completion = Some((candidates, 0));
+            }
+        }
+    }
+
+    fn history_step(&mut self, older: bool) {
+        if self.history.is_empty() {
+            return;
+        }
+        let next = match (self.history_pos, older) {
+            (None, true) => {
+                self.draft = self.input.clone();
+                Some(self.history.len() - 1)
+            }
+            (None, false) => return,
+            (Some(pos), true) => Some(pos.saturating_sub(1)),
+            (Some(pos), false) if pos + 1 < self.history.len() => Some(pos + 1),
+            (Some(_), false) => None,
+        };
+        let text = match next {
+            Some(pos) => self.history[pos].clone(),
+            None => std::mem::take(&mut self.draft),
+        };
+        self.set_input(text);
+        self.history_pos = next;
+    }
+
+    /// Draw the prompt over the status bar and place the terminal
+    /// cursor in it. No-op when `!self.visible`.
+    pub fn render_modal(&self, f: &mut Frame, screen: Rect, theme: &Theme) {
+        if !self.visible || screen.height == 0 {
+            return;
+        }
+        let area = Rect {
+            x: screen.x,
+            y: screen.bottom() - 1,
+            width: screen.width,
+            height: 1,
+        };
+        f.render_widget(Clear, area);
+        let para =
+            Paragraph::new(format!(":{}", self.input)).style(Style::default().fg(theme.cyan));
+        f.render_widget(para, area);
+        let x = area.x.saturating_add(1 + self.cursor as u16);
+        f.set_cursor_position(Position::new(x.min(area.right().saturating_sub(1)), area.y));
+    }
+}
+
+impl Component for CommandLineComponent {
+    fn handle_msg(&mut self, msg: &Msg, _ctx: &Ctx) -> Vec<Msg> {
+        match msg {
+            Msg::CommandOpen => self.open(),
+            Msg::CommandRun(_) | Msg::CommandCancel => self.close(),
+            _ => {}
+        }
+        Vec::new()
+    }
+
+    fn render(&self, _f: &mut Frame, _area: Rect, _focused: bool, _ctx: &Ctx) {
+        // Drawn via `render_modal` from `ui::UI::draw`.
+    }
+
+    fn on_key(&mut self, key: KeyEvent, _ctx: &Ctx) -> Option<Msg> {
+        if !self.visible {
+            return None;
+        }
+        if key.code != KeyCode::Tab {
+            self.completion = None;
+        }
+        match (key.code, key.modifiers) {
+            (KeyCode::Esc, _) => return Some(Msg::CommandCancel),
+            (KeyCode::Enter, _) => {
+                let line = self.input.trim().to_string();
+                if line.is_empty() {
+                    return Some(Msg::CommandCancel);
+                }
+                if self.history.last() != Some(&line) {
+                    self.history.push(line.clone());
+                }
+                return Some(Msg::CommandRun(line));
+            }
+            // Backspace on an empty line leaves command mode, as in vim.
+            (KeyCode::Backspace, _) if self.input.is_empty() => return Some(Msg::CommandCancel),
+            (KeyCode::Backspace, _) if self.cursor > 0 => {
+                self.cursor -= 1;
+                let at = self.byte_at(self.cursor);
+                self.input.remove(at);
+            }
+            (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
+                let at = self.byte_at(self.cursor);
+                self.input.drain(..at);
+                self.cursor = 0;
+            }
+            (KeyCode::Left, _) => self.cursor = self.cursor.saturating_sub(1),
+            (KeyCode::Right, _) => {
+                self.cursor = (self.cursor + 1).min(self.input.chars().count());
+            }
+            (KeyCode::Home, _) => self.cursor = 0,
+            (KeyCode::End, _) => self.cursor = self.input.chars().count(),
+            (KeyCode::Up, _) => self.history_step(true),
+            (KeyCode::Down, _) => self.history_step(false),
+            (KeyCode::Char(c), m) if m.is_empty() || m == KeyModifiers::SHIFT => {
+                let at = self.byte_at(self.cursor);
+                self.input.insert(at, c);
+                self.cursor += 1;
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::email::EmailStore;
+    use std::path::PathBuf;
+
+    fn key(c: &mut CommandLineComponent, key: KeyEvent) -> Option<Msg> {
+        let (config, store, theme) = (
+            Config::default(),
+            EmailStore::new(PathBuf::from("/tmp")),
+            Theme::default(),
+        );
+        let ctx = Ctx {
+            theme: &theme,
+            config: &config,
+            store: &store,
+        };
+        c.on_key(key, &ctx)
+    }
+
+    fn press(c: &mut CommandLineComponent, code: KeyCode) -> Option<Msg> {
+        key(c, KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn type_str(c: &mut CommandLineComponent, s: &str) {
+        for ch in s.chars() {
+            press(c, KeyCode::Char(ch));
+        }
+    }
+
+    #[test]
+    fn parse_splits_name_from_trimmed_arguments() {
+        assert_eq!(
+            parse_command_line("  move  Work/Projects "),
+            Some(("move", "Work/Projects"))
+        );
+        assert_eq!(parse_command_line("q"), Some(("q", "")));
+        assert_eq!(parse_command_line("   "), None);
+    }
+
+    #[test]
+    fn editing_keys_insert_at_the_cursor_and_ctrl_u_kills_to_the_start() {
+        let mut c = CommandLineComponent::new();
+        c.open();
+        type_str(&mut c, "mve");
+        press(&mut c, KeyCode::Left);
+        press(&mut c, KeyCode::Left);
+        type_str(&mut c, "o");
+        assert_eq!(c.input, "move");
+        press(&mut c, KeyCode::End);
+        press(&mut c, KeyCode::Backspace);
+        assert_eq!(c.input, "mov");
+        press(&mut c, KeyCode::Left);
+        key(
+            &mut c,
+            KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL),
+        );
+        assert_eq!(c.input, "v");
+        assert_eq!(c.cursor(), 0);
+    }
+
+    #[test]
+    fn up_and_down_walk_history_and_restore_the_draft() {
+        let mut c = CommandLineComponent::new();
+        for line in ["help", "search tag:inbox"] {
+            c.open();
+            type_str(&mut c, line);
+            assert_eq!(
+                press(&mut c, KeyCode::Enter),
+                Some(Msg::CommandRun(line.into()))
+            );
+            c.close();
+        }
+        c.open();
+        type_str(&mut c, "mo");
+        press(&mut c, KeyCode::Up);
+        assert_eq!(c.input, "search tag:inbox");
+        press(&mut c, KeyCode::Up);
+        press(&mut c, KeyCode::Up);
+        assert_eq!(c.input, "help");
+        press(&mut c, KeyCode::Down);
+        assert_eq!(c.input, "search tag:inbox");
+        press(&mut c, KeyCode::Down);
+        assert_eq!(c.input, "mo");
+    }
+
+    #[test]
+    fn tab_completes_a_unique_name_and_cycles_ambiguous_ones() {
+        let registry = CommandRegistry::builtin();
+        let mut c = CommandLineComponent::new();
+        c.open();
+        type_str(&mut c, "he");
+        c.complete(&registry);
+        assert_eq!(c.input, "help ");
+
+        c.open();
+        type_str(&mut c, "mark");
+        c.complete(&registry);
+        assert_eq!(c.input, "mark-all-read");
+        c.complete(&registry);
+        assert_eq!(c.input, "mark-read");
+        c.complete(&registry);
+        assert_eq!(c.input, "mark-unread");
+        c.complete(&registry);
+        assert_eq!(c.input, "mark-all-read");
+    }
+
+    #[test]
+    fn register_adds_and_replaces_commands_by_name() {
+        fn noop(_: &mut AppRoot, _: &str) -> Result<(), String> {
+            Ok(())
+        }
+        let mut registry = CommandRegistry::builtin();
+        registry.register(CommandSpec {
+            name: "archive",
+            aliases: &["x"],
+            description: "Archive",
+            handler: noop,
+        });
+        assert_eq!(registry.lookup("x").map(|c| c.name), Some("archive"));
+        assert_eq!(registry.lookup("q").map(|c| c.name), Some("quit"));
+        let before = registry.iter().count();
+        registry.register(CommandSpec {
+            name: "help",
+            aliases: &[],
+            description: "Replaced",
+            handler: noop,
+        });
+        assert_eq!(registry.iter().count(), before);
+        assert_eq!(registry.lookup("help").unwrap().description, "Replaced");
+    }
+
+    #[test]
+    fn sort_arguments_name_an_order_and_an_optional_reverse() {
+        assert_eq!(parse_sort_args(""), Ok(Msg::SortBy(None, false)));
+        assert_eq!(
+            parse_sort_args("Subject"),
+            Ok(Msg::SortBy(Some(SortOrder::Subject), false))
+        );
+        assert_eq!(
+            parse_sort_args("size reverse"),
+            Ok(Msg::SortBy(Some(SortOrder::Size), true))
+        );
+        assert!(
+            parse_sort_args("colour")
+                .unwrap_err()
+                .contains("date, from")
+        );
+        assert!(parse_sort_args("date sideways").is_err());
+    }
+}
@@ -1,39 +1,60 @@
 // `ContentComponent` — content pane.
 //
-// Owns the content-pane scroll position (`scroll_offset`). Translates
-// Content-pane keys (j/k/Up/Down/PageUp/PageDown) into messages, and
-// renders the headers + body + scrollbar against the selected email.
+// Owns the content-pane scroll position (`scroll_offset`, plus
+// `h_scroll_offset` while word wrap is off). Translates Content-pane keys
+// (j/k/Up/Down, PageUp/PageDown, Ctrl-d/Ctrl-u, Left/Right when
+// unwrapped) into messages, and renders the headers + body + scrollbar
+// against the selected email.
 //
 // **`RefCell<ScrollbarState>`.** Ratatui's `render_stateful_widget`
 // needs `&mut state`, but `Component::render` takes `&self`. The
 // component owns the state in a cell, mirroring what `FoldersComponent`
 // does for `ListState`.
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Margin, Rect},
-    style::{Modifier, Style},
-    text::{Line, Span},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
     widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
 };
 
-use crate::email::{Attachment, EmailLoadState};
+use crate::email::{Attachment, BodyPart, EmailLoadState, strip_control_chars};
+use crate::ical::{CalendarEvent, EventTime};
 use crate::theme::Theme;
+use crate::util::format_file_size;
 
-use super::{Component, Ctx, Dir, Msg};
+use super::part_picker::PartPicker;
+use super::{Component, Ctx, Dir, Jump, Msg};
 
-/// How many lines PageUp/PageDown moves through the body. Matches the
-/// legacy `input::handle_main_view_input` constant of 10.
+/// How many lines PageUp/PageDown moves through the body before the
+/// pane has rendered and knows its height. Matches the legacy
+/// `input::handle_main_view_input` constant of 10.
 pub(crate) const PAGE_SCROLL_STEP: usize = 10;
 
+/// How many columns Left/Right moves the body while word wrap is off.
+pub(crate) const H_SCROLL_STEP: usize = 4;
+
+/// Largest attachment the pane will preview. Bigger ones are refused
+/// with a status-bar warning; `o` still opens them externally.
+pub(crate) const PREVIEW_MAX_BYTES: usize = 1024 * 1024;
+
+/// Bytes shown in the hex dump of a non-UTF-8 attachment preview.
+const PREVIEW_HEX_BYTES: usize = 256;
+
+/// Rows the folded header box grows to before it cuts the summary off
+/// and marks the overflow; `H` shows everything.
+const HEADER_MAX_ROWS: usize = 10;
+
 /// Content pane state. Holds the scroll offset for the body and the
 /// scrollbar's ratatui state.
 pub struct ContentComponent {
     /// Lines scrolled past the top of the body. `j`/`k` and arrows
-    /// step by 1; PageUp/PageDown step by `PAGE_SCROLL_STEP`.
+    /// step by 1; PageUp/PageDown and `Ctrl-d`/`Ctrl-u` by a full or
+    /// half body height.
     pub scroll_offset: usize,
     /// Index of the focused row in the per-email attachment list shown
     /// below the body. AppRoot reads this when resolving the
@@ -46,6 +67,63 @@ pub struct ContentComponent {
     /// verbatim, or the literal `"(no plain part)"` marker if missing.
     /// Default `false`; flipped by `Msg::TogglePlaintext` (Shift+P).
     pub prefer_plaintext: bool,
+    /// Show the HTML part (converted to text) of messages that carry
+    /// both parts. Seeded from `[render].prefer_html`; flipped by
+    /// `Msg::ToggleBodyPart` (`v` in this pane). The pane title tags
+    /// the part on display as `[text]` / `[html]`.
+    pub prefer_html: bool,
+    /// Wrap long body lines to the pane width. Default `true`; flipped
+    /// by `Msg::ToggleWordWrap` (`w`). When off, long lines run past the
+    /// right edge and Left/Right scroll horizontally.
+    pub word_wrap: bool,
+    /// Columns scrolled past the left edge of the body. Only meaningful
+    /// while `word_wrap` is off; reset to 0 when wrap comes back on.
+    pub h_scroll_offset: usize,
+    /// Index of the attachment shown in place of the body (`p` in the
+    /// Attachments pane or on the attachment strip). `None` shows the
+    /// email body. Backspace / Esc close it; any change of selected
+    /// email drops it.
+    pub attachment_preview: Option<usize>,
+    /// Inner body rect while an image attachment preview is on screen,
+    /// set by `render`. The image itself is painted over it by
+    /// `AppRoot::render` through [`crate::image_preview`] after the
+    /// frame lands; AppRoot resets it to `None` before every frame.
+    pub image_area: Cell<Option<Rect>>,
+    /// `(rendered rows, visible rows)` of the body as last drawn, wrap
+    /// included. Bounds `G` ([`Msg::ContentJump`]) to the last line.
+    body_extent: Cell<(usize, usize)>,
+    /// Inner body width as last drawn; maps body lines to wrapped rows
+    /// when scrolling to a search match.
+    body_width: Cell<u16>,
+    /// Show every header instead of the From / To / Subject / Date
+    /// summary. Flipped by `Msg::ToggleHeaders` (`H`); stays on across
+    /// emails until folded back.
+    pub headers_expanded: bool,
+    /// Rows scrolled past the top of the expanded header box. While the
+    /// headers overflow their box, `j` / `k` scroll them instead of the
+    /// body.
+    pub header_scroll: usize,
+    /// The selected message file as it sits on disk, shown in place of
+    /// the body while the source view is on (`gs`). Read afresh on each
+    /// toggle; dropped when toggled off or another email is selected.
+    pub raw_source: Option<String>,
+    /// `Alt+m` popup listing the selected message's MIME parts.
+    pub part_picker: PartPicker,
+    /// `(content type, text)` of the MIME part chosen in the popup,
+    /// shown in place of the body until Backspace / Esc or another
+    /// email is selected.
+    pub part_preview: Option<(String, String)>,
+    /// `(rendered rows, visible rows)` of the header box as last drawn.
+    header_extent: Cell<(usize, usize)>,
+    /// Text typed into the in-body search prompt; `Some` while the
+    /// prompt is open (`/` … `Enter`).
+    pub find_input: Option<String>,
+    /// Committed in-body search term, highlighted in the body.
+    pub find_query: Option<String>,
+    /// Body lines containing `find_query`, ascending.
+    find_matches: Vec<usize>,
+    /// Index into `find_matches` of the match `n` / `N` last landed on.
+    find_current: usize,
     scrollbar_state: RefCell<ScrollbarState>,
 }
 
@@ -60,13 +138,144 @@ impl ContentComponent {
     /// `AppRoot::with_config` passes `config.render.prefer_plaintext`
     /// so a static opt-in takes effect on the first frame.
     pub fn with_prefer_plaintext(initial: bool) -> Self {
+        Self::with_prefs(initial, false)
+    }
+
+    /// Build a content pane seeding both body preferences.
+    /// `AppRoot::with_config` passes `config.render.prefer_html`.
+    pub fn with_prefs(prefer_plaintext: bool, prefer_html: bool) -> Self {
         Self {
             scroll_offset: 0,
             attachment_focus: 0,
-            prefer_plaintext: initial,
+            prefer_plaintext,
+            prefer_html,
+            word_wrap: true,
+            h_scroll_offset: 0,
+            attachment_preview: None,
+            image_area: Cell::new(None),
+            body_extent: Cell::new((0, 0)),
+            body_width: Cell::new(0),
+            headers_expanded: false,
+            header_scroll: 0,
+            raw_source: None,
+            part_picker: PartPicker::default(),
+            part_preview: None,
+            header_extent: Cell::new((0, 0)),
+            find_input: None,
+            find_query: None,
+            find_matches: Vec::new(),
+            find_current: 0,
             scrollbar_state: RefCell::new(ScrollbarState::default()),
         }
     }
+
+    /// Show attachment `idx` of the selected email in place of the
+    /// body, scrolled to the top.
+    pub fn open_preview(&mut self, idx: usize) {
+        self.attachment_preview = Some(idx);
+        self.scroll_offset = 0;
+        self.h_scroll_offset = 0;
+    }
+
+    /// `(vertical, horizontal)` offset handed to `Paragraph::scroll`.
+    /// The horizontal half is pinned to 0 while wrapping, since a
+    /// wrapped paragraph has nothing to the right to scroll to.
+    pub(crate) fn body_scroll(&self) -> (u16, u16) {
+        let h = if self.word_wrap {
+            0
+        } else {
+            self.h_scroll_offset
+        };
+        (
+            self.body_top().min(u16::MAX as usize) as u16,
+            h.min(u16::MAX as usize) as u16,
+        )
+    }
+
+    /// `scroll_offset` held to the last full page of the body as last
+    /// drawn. A resize or a shorter body can leave the stored offset
+    /// past the end; this is where the pane actually starts. Unclamped
+    /// before the first render.
+    fn body_top(&self) -> usize {
+        let (rows, visible) = self.body_extent.get();
+        if visible == 0 {
+            self.scroll_offset
+        } else {
+            self.scroll_offset.min(rows.saturating_sub(visible))
+        }
+    }
+
+    /// Where the visible body sits: `All` when it fits the pane, `Top`
+    /// / `Bot` at either end, otherwise how far down it is scrolled.
+    fn body_position(&self) -> String {
+        let (rows, visible) = self.body_extent.get();
+        let last_top = rows.saturating_sub(visible);
+        match self.body_top() {
+            _ if last_top == 0 => "All".to_string(),
+            0 => "Top".to_string(),
+            top if top >= last_top => "Bot".to_string(),
+            top => format!("{}%", top * 100 / last_top),
+        }
+    }
+
+    /// Wrap mode for the body `Paragraph`. `None` leaves lines
+    /// unwrapped (truncated at the pane edge, horizontally scrollable).
+    pub(crate) fn body_wrap(&self) -> Option<Wrap> {
+        self.word_wrap.then_some(Wrap { trim: true })
+    }
+
+    /// True while the expanded headers don't fit their box, so `j` /
+    /// `k` scroll them rather than the body.
+    fn headers_scrollable(&self) -> bool {
+        let (rows, visible) = self.header_extent.get();
+        self.headers_expanded && rows > visible
+    }
+
+    /// The text the body box shows: the raw source while that view is
+    /// on, the rendered body otherwise. In-body search runs over it.
+    fn shown_body(&self, ctx: &Ctx) -> String {
+        if let Some((_, text)) = &self.part_preview {
+            return text.clone();
+        }
+        match &self.raw_source {
+            Some(source) => source.clone(),
+            None => ctx
+                .store
+                .get_selected_email_markdown_with_prefs(self.prefer_plaintext, self.prefer_html)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Drop the search prompt and any active in-body search.
+    fn clear_find(&mut self) {
+        self.find_input = None;
+        self.find_query = None;
+        self.find_matches.clear();
+        self.find_current = 0;
+    }
+
+    /// Scroll so the current search match is the top line, as far as
+    /// the end of the body allows. Body lines become wrapped rows by
+    /// measuring the lines above the match at the last drawn width.
+    fn scroll_to_current_match(&mut self, body: &str) {
+        let Some(&line) = self.find_matches.get(self.find_current) else {
+            return;
+        };
+        let width = self.body_width.get();
+        let row = match self.body_wrap() {
+            Some(wrap) if width > 0 && line > 0 => {
+                let above: Vec<Line> = body.lines().take(line).map(Line::raw).collect();
+                Paragraph::new(above).wrap(wrap).line_count(width)
+            }
+            _ => line,
+        };
+        let (rows, visible) = self.body_extent.get();
+        self.scroll_offset = if visible == 0 {
+            row
+        } else {
+            row.min(rows.saturating_sub(visible))
+        };
+    }
 }
 
 impl Default for ContentComponent {
@@ -76,19 +285,109 @@ impl Default for ContentComponent {
 }
 
 impl Component for ContentComponent {
-    fn handle_msg(&mut self, msg: &Msg, _ctx: &Ctx) -> Vec<Msg> {
+    fn handle_msg(&mut self, msg: &Msg, ctx: &Ctx) -> Vec<Msg> {
         match msg {
+            Msg::ContentFindOpen => {
+                self.find_input = Some(String::new());
+            }
+            Msg::ContentFind(query) => {
+                let body = self.shown_body(ctx);
+                self.find_input = None;
+                self.find_matches = find_match_lines(&body, query);
+                self.find_current = 0;
+                if self.find_matches.is_empty() {
+                    self.find_query = None;
+                    return vec![Msg::StatusSet(format!("Not found: {}", query))];
+                }
+                self.find_query = Some(query.clone());
+                self.scroll_to_current_match(&body);
+            }
+            Msg::ContentFindStep(dir) if !self.find_matches.is_empty() => {
+                let n = self.find_matches.len();
+                self.find_current = match dir {
+                    Dir::Up => (self.find_current + n - 1) % n,
+                    _ => (self.find_current + 1) % n,
+                };
+                let body = self.shown_body(ctx);
+                self.scroll_to_current_match(&body);
+            }
+            Msg::ContentFindClear => self.clear_find(),
+            Msg::ToggleHeaders => {
+                self.headers_expanded = !self.headers_expanded;
+                self.header_scroll = 0;
+            }
+            Msg::ToggleRawSource => {
+                self.clear_find();
+                self.scroll_offset = 0;
+                self.h_scroll_offset = 0;
+                self.attachment_preview = None;
+                self.part_preview = None;
+                if self.raw_source.take().is_none()
+                    && let Some(email) = ctx.store.get_selected_email()
+                {
+                    match crate::mbox::read_message(&email.file_path) {
+                        Ok(bytes) => {
+                            // Sender-controlled bytes: escape sequences
+                            // must not reach the terminal.
+                            let text = String::from_utf8_lossy(&bytes);
+                            self.raw_source = Some(strip_control_chars(&text).into_owned());
+                        }
+                        Err(e) => {
+                            return vec![Msg::StatusSet(format!("Could not read source: {}", e))];
+                        }
+                    }
+                }
+            }
+            Msg::ContentScroll(Dir::Down, n) if self.headers_scrollable() => {
+                let (rows, visible) = self.header_extent.get();
+                self.header_scroll = (self.header_scroll + n).min(rows - visible);
+            }
+            Msg::ContentScroll(Dir::Up, n) if self.headers_scrollable() => {
+                self.header_scroll = self.header_scroll.saturating_sub(*n);
+            }
             Msg::ContentScroll(Dir::Down, n) => {
-                // Saturating add mirrors the legacy `App::scroll`'s TODO
-                // for bounds checking — no upper clamp yet because the
-                // body line count isn't easily available off the render
-                // path. Ratatui's `Paragraph::scroll` clamps the visible
-                // offset on its own, so over-scrolling is a cosmetic
-                // no-op rather than a panic.
+                // Stop with the last line at the bottom of the pane,
+                // like `ContentPage`. Before the first render the
+                // extent is unknown and the add goes unclamped.
+                let (rows, visible) = self.body_extent.get();
                 self.scroll_offset = self.scroll_offset.saturating_add(*n);
+                if visible > 0 {
+                    self.scroll_offset = self.scroll_offset.min(rows.saturating_sub(visible));
+                }
             }
             Msg::ContentScroll(Dir::Up, n) => {
-                self.scroll_offset = self.scroll_offset.saturating_sub(*n);
+                self.scroll_offset = self.body_top().saturating_sub(*n);
+            }
+            Msg::ContentPage(dir, halves) => {
+                // Before the first render the extent is unknown; page
+                // by the fixed step and let ratatui clamp.
+                let (rows, visible) = self.body_extent.get();
+                if visible == 0 {
+                    self.scroll_offset = dir.paged(self.scroll_offset, *halves, PAGE_SCROLL_STEP);
+                } else {
+                    let last_top = rows.saturating_sub(visible);
+                    self.scroll_offset = dir.paged(self.body_top(), *halves, visible).min(last_top);
+                }
+            }
+            Msg::ContentJump(jump) => {
+                let (rows, visible) = self.body_extent.get();
+                let last_top = rows.saturating_sub(visible.max(1));
+                self.scroll_offset = match jump {
+                    Jump::To(line) => (*line).min(last_top),
+                    Jump::Last => last_top,
+                };
+            }
+            Msg::ContentScroll(Dir::Right, n) if !self.word_wrap => {
+                self.h_scroll_offset = self.h_scroll_offset.saturating_add(*n);
+            }
+            Msg::ContentScroll(Dir::Left, n) => {
+                self.h_scroll_offset = self.h_scroll_offset.saturating_sub(*n);
+            }
+            Msg::ToggleWordWrap => {
+                self.word_wrap = !self.word_wrap;
+                if self.word_wrap {
+                    self.h_scroll_offset = 0;
+                }
             }
             // Any folder-level navigation invalidates the current scroll
             // position. Matches the resets in
@@ -96,14 +395,55 @@ impl Component for ContentComponent {
             // `apply_root(FolderExitParent)`, and the legacy
             // `input::handle_back_navigation` /
             // `handle_folder_selection_and_switch_view` paths.
-            Msg::FolderEnter | Msg::FolderExitParent | Msg::FolderMove(_) => {
+            Msg::FolderEnter | Msg::FolderExitParent | Msg::FolderMove(_) | Msg::FolderJump(_) => {
+                self.clear_find();
                 self.scroll_offset = 0;
+                self.h_scroll_offset = 0;
                 self.attachment_focus = 0;
+                self.attachment_preview = None;
             }
             // Selecting a different email invalidates the focused
-            // attachment row from the prior email.
-            Msg::MessageMove(_) | Msg::MessageOpen(_) => {
+            // attachment row (and any preview) from the prior email.
+            Msg::MessageMove(_) | Msg::MessageJump(_) | Msg::MessageOpen(_) => {
+                self.clear_find();
+                self.header_scroll = 0;
                 self.attachment_focus = 0;
+                self.attachment_preview = None;
+            }
+            Msg::AttachmentPreviewClose => {
+                self.attachment_preview = None;
+                self.part_preview = None;
+                self.scroll_offset = 0;
+                self.h_scroll_offset = 0;
+            }
+            Msg::MimeTreeOpen => {
+                let Some(email) = ctx.store.get_selected_email() else {
+                    return Vec::new();
+                };
+                match crate::mime_tree::mime_tree(&email.file_path) {
+                    Ok(parts) => self.part_picker.open(parts),
+                    Err(e) => {
+                        return vec![Msg::StatusSet(format!("Could not read MIME parts: {}", e))];
+                    }
+                }
+            }
+            Msg::MimePartPreview(index) => {
+                let Some(email) = ctx.store.get_selected_email() else {
+                    return Vec::new();
+                };
+                match crate::mime_tree::part_preview(&email.file_path, *index) {
+                    Ok(shown) => {
+                        self.clear_find();
+                        self.attachment_preview = None;
+                        self.raw_source = None;
+                        self.part_preview = Some(shown);
+                        self.scroll_offset = 0;
+                        self.h_scroll_offset = 0;
+                    }
+                    Err(e) => {
+                        return vec![Msg::StatusSet(format!("Could not show MIME part: {}", e))];
+                    }
+                }
             }
             _ => {}
         }
@@ -111,15 +451,29 @@ impl Component for ContentComponent {
     }
 
     fn render(&self, f: &mut Frame, area: Rect, focused: bool, ctx: &Ctx) {
-        let border_style = if focused {
-            Style::default().fg(ctx.theme.cyan_light)
-        } else {
-            Style::default()
-        };
+        let border_style = ctx.theme.focus(focused, ctx.theme.cyan_light);
 
         let email_info = ctx.store.get_selected_email_headers();
 
-        if let Some(email) = email_info {
+        if let Some(email) = email_info
+            && let Some(error) = &email.parse_error
+        {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .style(border_style)
+                .title("Content (unreadable)");
+            let text = format!(
+                "Could not parse this message: {}\n\n{}",
+                error,
+                email.file_path.display()
+            );
+            let paragraph = Paragraph::new(text)
+                .block(block)
+                .style(Style::default().fg(ctx.theme.error()))
+                .wrap(Wrap { trim: false });
+            self.body_extent.set((0, 0));
+            f.render_widget(paragraph, area);
+        } else if let Some(email) = email_info {
             // Attachment strip sits below the body when the email has
             // any. Its height = list rows + 2 for the bordered block;
             // we cap at 8 rows to keep the body usable for messages
@@ -130,10 +484,59 @@ impl Component for ContentComponent {
             } else {
                 (attachment_rows.min(6) as u16) + 2
             };
+            let header_fields = if self.headers_expanded {
+                email.get_full_header_lines()
+            } else {
+                email.get_header_lines()
+            };
+            let label_style = Style::default().fg(ctx.theme.accent);
+            let mut header_lines: Text = header_fields
+                .into_iter()
+                .map(|field| match field.label {
+                    Some(label) => Line::from(vec![
+                        Span::styled(format!("{}:", label), label_style),
+                        Span::raw(format!(" {}", field.value)),
+                    ]),
+                    None => Line::from(field.value),
+                })
+                .collect::<Vec<_>>()
+                .into();
+            // Folded, the From line carries a badge per authentication
+            // verdict the receiving server recorded.
+            if !self.headers_expanded
+                && let Some(from_line) = header_lines.lines.first_mut()
+            {
+                for (label, passed) in email.auth_status.badges() {
+                    let (mark, color) = if passed {
+                        ("✓", ctx.theme.cyan)
+                    } else {
+                        ("✗", ctx.theme.error())
+                    };
+                    from_line.push_span(Span::styled(
+                        format!(" {} {}", mark, label),
+                        Style::default().fg(color),
+                    ));
+                }
+            }
+            // The box is as tall as the headers wrap to at this width,
+            // plus the borders. Folded it stops at HEADER_MAX_ROWS and
+            // marks what is cut off; expanded it grows up to half the
+            // pane and scrolls past that.
+            let header_rows = Paragraph::new(header_lines.clone())
+                .wrap(Wrap { trim: true })
+                .line_count(area.width.saturating_sub(2));
+            let header_height = if self.headers_expanded {
+                (header_rows as u16 + 2).min((area.height / 2).max(6))
+            } else {
+                header_rows.min(HEADER_MAX_ROWS) as u16 + 2
+            };
+            let headers_cut = !self.headers_expanded && header_rows > HEADER_MAX_ROWS;
+            self.header_extent
+                .set((header_rows, header_height.saturating_sub(2) as usize));
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
-                    Constraint::Length(6),
+                    Constraint::Length(header_height),
                     Constraint::Min(0),
                     Constraint::Length(attachment_strip),
                 ])
@@ -142,44 +545,131 @@ impl Component for ContentComponent {
             let header_block = Block::default()
                 .borders(Borders::ALL)
                 .style(border_style)
-                .title("Headers");
-            let header_text = email.get_header_display();
-            let header_paragraph = Paragraph::new(header_text.as_str())
+                .title(match (self.headers_expanded, email.size_bytes) {
+                    (false, 0) => "Headers".to_string(),
+                    (false, size) => format!("Headers ({})", format_file_size(size)),
+                    (true, _) => format!("All headers ({}) [H to fold]", email.raw_headers.len()),
+                });
+            let header_block = if headers_cut {
+                header_block.title_bottom(Line::from("… [H for all]").right_aligned())
+            } else {
+                header_block
+            };
+            let header_paragraph = Paragraph::new(header_lines)
                 .block(header_block)
-                .wrap(Wrap { trim: true });
+                .wrap(Wrap { trim: true })
+                .scroll((self.header_scroll.min(u16::MAX as usize) as u16, 0));
             f.render_widget(header_paragraph, chunks[0]);
 
-            let body_title = if email.has_attachments() {
+            let preview = self
+                .attachment_preview
+                .and_then(|idx| email.attachments.get(idx));
+
+            let mut body_title = if let Some(attachment) = preview {
+                format!("{} [attachment preview]", attachment.filename)
+            } else if let Some((content_type, _)) = &self.part_preview {
+                format!("{} [MIME part]", content_type)
+            } else if self.raw_source.is_some() {
+                "Source [raw]".to_string()
+            } else if email.has_attachments() {
                 format!("Content ({} attachments)", email.attachment_count())
             } else {
                 "Content".to_string()
             };
-
-            let body_block = Block::default()
-                .borders(Borders::ALL)
-                .style(border_style)
-                .title(body_title);
+            if preview.is_none()
+                && self.raw_source.is_none()
+                && self.part_preview.is_none()
+                && matches!(email.load_state, EmailLoadState::FullyLoaded)
+                && let Some(part) = email.displayed_part(self.prefer_html)
+            {
+                let part = if self.prefer_plaintext {
+                    BodyPart::Text
+                } else {
+                    part
+                };
+                body_title = format!("{} {}", body_title, part.label());
+            }
+            if let Some(input) = &self.find_input {
+                body_title = format!("{} /{}", body_title, input);
+            } else if let Some(query) = &self.find_query {
+                body_title = format!(
+                    "{} [{}/{} \"{}\"]",
+                    body_title,
+                    self.find_current + 1,
+                    self.find_matches.len(),
+                    query
+                );
+            }
 
             // Non-blocking read: the body loader (`BodyLoader`) parses
             // bodies off-thread. Until it lands a result, `body_text`
             // is empty and `load_state` is `HeadersOnly`. Show a
             // placeholder so the user knows selection succeeded.
-            let body_text = match email.load_state {
-                EmailLoadState::HeadersOnly => "Loading body…".to_string(),
-                EmailLoadState::FullyLoaded => {
-                    ctx.store
-                        .get_selected_email_markdown_with_pref(self.prefer_plaintext)
-                        .unwrap_or_default()
+            let body_text = match (preview, &email.load_state) {
+                (Some(_), _) => String::new(),
+                (None, _) if self.raw_source.is_some() || self.part_preview.is_some() => {
+                    self.shown_body(ctx)
                 }
+                (None, EmailLoadState::HeadersOnly) => "Loading body…".to_string(),
+                (None, EmailLoadState::FullyLoaded) => self.shown_body(ctx),
             };
 
-            let body_paragraph = Paragraph::new(body_text.as_str())
-                .block(body_block)
-                .wrap(Wrap { trim: true })
-                .scroll((self.scroll_offset as u16, 0));
-            f.render_widget(body_paragraph, chunks[1]);
+            let image_preview = preview.is_some_and(Attachment::is_image);
+            let body = match preview {
+                // The image is painted over the empty body afterwards.
+                Some(_) if image_preview => Text::default(),
+                Some(attachment) => preview_lines(attachment, ctx.theme),
+                None => {
+                    let dim_from = (ctx.config.render.dim_signatures
+                        && self.raw_source.is_none()
+                        && self.part_preview.is_none()
+                        && matches!(email.load_state, EmailLoadState::FullyLoaded))
+                    .then(|| crate::signature::trailer_start(&body_text))
+                    .flatten();
+                    let body = body_lines(&body_text, dim_from, ctx.theme);
+                    match &self.find_query {
+                        Some(query) => highlight_matches(
+                            body,
+                            query,
+                            self.find_matches.get(self.find_current).copied(),
+                            ctx.theme,
+                        ),
+                        None => body,
+                    }
+                }
+            };
+            let body_len = body.lines.len();
+            let mut body_paragraph = Paragraph::new(body);
+            if let Some(wrap) = self.body_wrap() {
+                body_paragraph = body_paragraph.wrap(wrap);
+            }
+            let inner = chunks[1].inner(Margin {
+                vertical: 1,
+                horizontal: 1,
+            });
+            // Measure at this frame's width first, so a resize clamps
+            // the scroll and the position indicator right away.
+            self.body_extent.set((
+                body_paragraph.line_count(inner.width),
+                inner.height as usize,
+            ));
+            self.body_width.set(inner.width);
+            if !image_preview && inner.height > 0 {
+                body_title = format!("{} {}", body_title, self.body_position());
+            }
+            let body_block = Block::default()
+                .borders(Borders::ALL)
+                .style(border_style)
+                .title(body_title);
+            f.render_widget(
+                body_paragraph.scroll(self.body_scroll()).block(body_block),
+                chunks[1],
+            );
+            if image_preview {
+                self.image_area.set(Some(inner));
+            }
 
-            if focused {
+            if focused && !image_preview {
                 let scrollbar = Scrollbar::default()
                     .orientation(ScrollbarOrientation::VerticalRight)
                     .begin_symbol(Some("↑"))
@@ -187,17 +677,10 @@ impl Component for ContentComponent {
 
                 let mut state = self.scrollbar_state.borrow_mut();
                 *state = ScrollbarState::default()
-                    .content_length(body_text.lines().count())
-                    .position(self.scroll_offset);
-
-                f.render_stateful_widget(
-                    scrollbar,
-                    chunks[1].inner(Margin {
-                        vertical: 1,
-                        horizontal: 1,
-                    }),
-                    &mut *state,
-                );
+                    .content_length(body_len)
+                    .position(self.body_top());
+
+                f.render_stateful_widget(scrollbar, inner, &mut *state);
             }
 
             if attachment_strip > 0 {
@@ -210,6 +693,9 @@ impl Component for ContentComponent {
                     ctx.theme,
                 );
             }
+            if self.part_picker.visible {
+                self.part_picker.render(f, area, ctx.theme);
+            }
         } else {
             let block = Block::default()
                 .borders(Borders::ALL)
@@ -230,26 +716,288 @@ impl Component for ContentComponent {
         }
     }
 
-    fn on_key(&mut self, _key: KeyEvent, _ctx: &Ctx) -> Option<Msg> {
-        // Every Content-pane key (`j`/`k`/arrows/PageUp/PageDown)
-        // resolves through the central `AppRoot::action_to_msg` keymap
-        // dispatch. Keeping this trait method as a no-op satisfies the
-        // Component contract and leaves a seam for future
-        // Content-local sequence keys.
-        None
+    fn on_key(&mut self, key: KeyEvent, _ctx: &Ctx) -> Option<Msg> {
+        // Every remappable Content-pane key (`j`/`k`/Up/Down/PageUp/
+        // PageDown/`w`) resolves through the central
+        // `AppRoot::action_to_msg` keymap dispatch. Left/Right are
+        // pane-local: they only mean something while word wrap is off,
+        // so they stay out of the keymap and resolve here. Backspace /
+        // Esc close an open attachment preview before AppRoot's
+        // back-navigation sees them.
+        // The MIME part popup and the search prompt, while open, take
+        // every key.
+        if self.part_picker.visible {
+            return self.part_picker.on_key(key);
+        }
+        if let Some(input) = self.find_input.as_mut() {
+            return match key.code {
+                KeyCode::Esc => Some(Msg::ContentFindClear),
+                KeyCode::Enter => match input.trim() {
+                    "" => Some(Msg::ContentFindClear),
+                    query => Some(Msg::ContentFind(query.to_string())),
+                },
+                KeyCode::Backspace => {
+                    input.pop();
+                    None
+                }
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    input.push(c);
+                    None
+                }
+                _ => None,
+            };
+        }
+        if (self.attachment_preview.is_some() || self.part_preview.is_some())
+            && key.modifiers.is_empty()
+            && matches!(key.code, KeyCode::Backspace | KeyCode::Esc)
+        {
+            return Some(Msg::AttachmentPreviewClose);
+        }
+        if self.find_query.is_some() && key.modifiers.is_empty() && key.code == KeyCode::Esc {
+            return Some(Msg::ContentFindClear);
+        }
+        if self.word_wrap || !key.modifiers.is_empty() {
+            return None;
+        }
+        match key.code {
+            KeyCode::Left => Some(Msg::ContentScroll(Dir::Left, H_SCROLL_STEP)),
+            KeyCode::Right => Some(Msg::ContentScroll(Dir::Right, H_SCROLL_STEP)),
+            _ => None,
+        }
     }
 }
 
-/// Format a byte count as `"123 B"`, `"4.5 KB"`, or `"12.3 MB"`.
-/// Standalone so tests can exercise it directly.
-pub(crate) fn format_attachment_size(bytes: usize) -> String {
-    if bytes < 1024 {
-        format!("{} B", bytes)
-    } else if bytes < 1024 * 1024 {
-        format!("{:.1} KB", bytes as f64 / 1024.0)
-    } else {
-        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+/// Split `body` into paragraph lines, styling every line from
+/// `dim_from` onward (the detected signature / list footer) in
+/// `gray_dark`.
+fn body_lines<'a>(body: &'a str, dim_from: Option<usize>, theme: &Theme) -> Text<'a> {
+    let Some(start) = dim_from else {
+        return Text::raw(body);
+    };
+    let dim = Style::default().fg(theme.gray_dark);
+    body.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i >= start {
+                Line::styled(line, dim)
+            } else {
+                Line::raw(line)
+            }
+        })
+        .collect()
+}
+
+/// Byte ranges of `needle` in `haystack`, ignoring ASCII case. ASCII
+/// lowercasing keeps byte offsets, so the ranges index `haystack`.
+fn match_ranges(haystack: &str, needle: &str) -> Vec<(usize, usize)> {
+    if needle.is_empty() {
+        return Vec::new();
     }
+    let needle = needle.to_ascii_lowercase();
+    haystack
+        .to_ascii_lowercase()
+        .match_indices(&needle)
+        .map(|(start, m)| (start, start + m.len()))
+        .collect()
+}
+
+/// Indices of the body lines containing `query` (ASCII
+/// case-insensitive), in order.
+pub(crate) fn find_match_lines(body: &str, query: &str) -> Vec<usize> {
+    body.lines()
+        .enumerate()
+        .filter(|(_, line)| !match_ranges(line, query).is_empty())
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Restyle every occurrence of `query` in `text`; the match on line
+/// `current` stands out from the rest. Occurrences split across spans
+/// go unmarked.
+fn highlight_matches<'a>(
+    text: Text<'a>,
+    query: &str,
+    current: Option<usize>,
+    theme: &Theme,
+) -> Text<'a> {
+    let hit = Style::default().fg(theme.dark).bg(theme.accent_light);
+    let current_hit = Style::default()
+        .fg(theme.dark)
+        .bg(theme.accent)
+        .add_modifier(Modifier::BOLD);
+    let lines: Vec<Line<'a>> = text
+        .lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let style = if Some(i) == current { current_hit } else { hit };
+            let mut spans = Vec::with_capacity(line.spans.len());
+            for span in line.spans {
+                let ranges = match_ranges(&span.content, query);
+                if ranges.is_empty() {
+                    spans.push(span);
+                    continue;
+                }
+                let mut at = 0;
+                for (start, end) in ranges {
+                    if start > at {
+                        spans.push(Span::styled(
+                            span.content[at..start].to_string(),
+                            span.style,
+                        ));
+                    }
+                    spans.push(Span::styled(
+                        span.content[start..end].to_string(),
+                        span.style.patch(style),
+                    ));
+                    at = end;
+                }
+                if at < span.content.len() {
+                    spans.push(Span::styled(span.content[at..].to_string(), span.style));
+                }
+            }
+            Line::from(spans).style(line.style)
+        })
+        .collect();
+    Text::from(lines)
+}
+
+/// Lines for an attachment preview. Calendar invites get an event
+/// summary, other UTF-8 text is shown as-is (with +/- coloring for
+/// diffs); anything else gets a "binary file" notice and a hex dump of
+/// the first [`PREVIEW_HEX_BYTES`] bytes.
+fn preview_lines(attachment: &Attachment, theme: &Theme) -> Text<'static> {
+    // Attachment text is sender-controlled: drop escape sequences and
+    // other control characters before they reach the terminal.
+    let text = std::str::from_utf8(&attachment.raw_bytes)
+        .ok()
+        .map(strip_control_chars);
+    if attachment.is_calendar()
+        && let Some(event) = text.as_deref().and_then(CalendarEvent::parse)
+    {
+        return calendar_lines(&event, theme, chrono::Local::now());
+    }
+    let Some(text) = text else {
+        let mut lines = vec![
+            Line::styled(
+                format!(
+                    "Binary file ({}), not valid UTF-8. Press o to open it.",
+                    format_file_size(attachment.raw_bytes.len())
+                ),
+                Style::default().fg(theme.accent),
+            ),
+            Line::raw(""),
+        ];
+        let shown = &attachment.raw_bytes[..attachment.raw_bytes.len().min(PREVIEW_HEX_BYTES)];
+        lines.extend(shown.chunks(16).enumerate().map(|(row, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| if b.is_ascii_graphic() { b as char } else { '.' })
+                .collect();
+            Line::raw(format!(
+                "{:08x}  {:<47}  |{}|",
+                row * 16,
+                hex.join(" "),
+                ascii
+            ))
+        }));
+        return Text::from(lines);
+    };
+    if !attachment.is_patch() {
+        return Text::from(text.to_string());
+    }
+    text.lines()
+        .map(|line| {
+            let style = if line.starts_with("+++") || line.starts_with("---") {
+                Style::default().add_modifier(Modifier::BOLD)
+            } else if line.starts_with('+') {
+                Style::default().fg(theme.cyan)
+            } else if line.starts_with('-') {
+                Style::default().fg(theme.accent)
+            } else if line.starts_with("@@") {
+                Style::default().fg(theme.cyan_light)
+            } else {
+                Style::default()
+            };
+            Line::styled(line.to_string(), style)
+        })
+        .collect()
+}
+
+/// Event summary for a calendar invite: title, a labelled field per
+/// known property, the countdown to the start, then the description.
+fn calendar_lines(
+    event: &CalendarEvent,
+    theme: &Theme,
+    now: chrono::DateTime<chrono::Local>,
+) -> Text<'static> {
+    let label = |name: &str| {
+        Span::styled(
+            format!("{:<11}", name),
+            Style::default().fg(theme.gray_light),
+        )
+    };
+    let mut lines = vec![
+        Line::styled(
+            event
+                .summary
+                .clone()
+                .unwrap_or_else(|| "(untitled event)".to_string()),
+            Style::default()
+                .fg(theme.cyan_light)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Line::raw(""),
+    ];
+    if let Some(start) = &event.start {
+        let mut when = format_event_time(start);
+        if let Some(end) = &event.end {
+            let same_day = end.at.date_naive() == start.at.date_naive();
+            when.push_str(" – ");
+            when.push_str(&if same_day && !end.all_day {
+                end.at.format("%H:%M").to_string()
+            } else {
+                format_event_time(end)
+            });
+        }
+        lines.push(Line::from(vec![label("When:"), Span::raw(when)]));
+        lines.push(Line::from(vec![
+            label(""),
+            Span::styled(
+                crate::ical::countdown(start, now),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+    for (name, value) in [
+        ("Where:", &event.location),
+        ("Organizer:", &event.organizer),
+    ] {
+        if let Some(value) = value {
+            lines.push(Line::from(vec![label(name), Span::raw(value.clone())]));
+        }
+    }
+    if let Some(description) = &event.description {
+        lines.push(Line::raw(""));
+        lines.extend(description.lines().map(|l| Line::raw(l.to_string())));
+    }
+    Text::from(lines)
+}
+
+/// `Fri 15 Mar 2024 14:00`, without the time for all-day events and
+/// with the `TZID` zone name when the invite named one.
+fn format_event_time(time: &EventTime) -> String {
+    if time.all_day {
+        return time.at.format("%a %-d %b %Y").to_string();
+    }
+    let mut out = time.at.format("%a %-d %b %Y %H:%M").to_string();
+    if let Some(tz) = &time.tzid {
+        out.push_str(&format!(" ({})", tz));
+    }
+    out
 }
 
 /// Render the attachment strip block at `area` with `focus_index`
@@ -273,7 +1021,7 @@ fn render_attachment_strip(
         .iter()
         .enumerate()
         .map(|(i, a)| {
-            let label = format!(" {} ({})", a.filename, format_attachment_size(a.size));
+            let label = format!(" {}. {} ({})", i + 1, a.filename, format_file_size(a.size));
             if i == focus_index {
                 Line::from(Span::styled(
                     format!("▸{}", label),
@@ -328,6 +1076,18 @@ mod tests {
         assert_eq!(c.scroll_offset, 11);
     }
 
+    #[test]
+    fn content_scroll_down_stops_at_the_last_page_once_rendered() {
+        let (theme, config, store) = fixtures();
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+        c.body_extent.set((30, 10));
+        c.handle_msg(&Msg::ContentScroll(Dir::Down, 15), &ctx);
+        assert_eq!(c.scroll_offset, 15);
+        c.handle_msg(&Msg::ContentScroll(Dir::Down, 15), &ctx);
+        assert_eq!(c.scroll_offset, 20);
+    }
+
     #[test]
     fn content_scroll_up_saturates_at_zero() {
         let (theme, config, store) = fixtures();
@@ -350,6 +1110,407 @@ mod tests {
         assert_eq!(c.scroll_offset, 4);
     }
 
+    #[test]
+    fn content_jump_clamps_to_last_rendered_line() {
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), PathBuf::from("/tmp/INBOX"));
+        let mut email = crate::email::Email::new(PathBuf::from("/tmp/INBOX/m0"));
+        email.body_plain = Some((1..=50).map(|i| format!("line {i}\n")).collect());
+        email.load_state = EmailLoadState::FullyLoaded;
+        inbox.add_email(email);
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+
+        let backend = ratatui::backend::TestBackend::new(40, 20);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| c.render(f, f.area(), true, &ctx))
+            .unwrap();
+        let (rows, visible) = c.body_extent.get();
+        assert!(
+            rows >= 50 && visible > 0 && visible < rows,
+            "{rows} / {visible}"
+        );
+
+        c.handle_msg(&Msg::ContentJump(Jump::Last), &ctx);
+        assert_eq!(c.scroll_offset, rows - visible);
+        c.handle_msg(&Msg::ContentJump(Jump::To(5)), &ctx);
+        assert_eq!(c.scroll_offset, 5);
+        c.handle_msg(&Msg::ContentJump(Jump::To(10_000)), &ctx);
+        assert_eq!(c.scroll_offset, rows - visible);
+        c.handle_msg(&Msg::ContentJump(Jump::To(0)), &ctx);
+        assert_eq!(c.scroll_offset, 0);
+    }
+
+    /// Title row of the body box (the header box takes rows 0..6).
+    fn body_title_row(terminal: &ratatui::Terminal<ratatui::backend::TestBackend>) -> String {
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, 6)].symbol())
+            .collect()
+    }
+
+    #[test]
+    fn resizing_reclamps_the_scroll_and_the_position_indicator() {
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), PathBuf::from("/tmp/INBOX"));
+        let mut email = crate::email::Email::new(PathBuf::from("/tmp/INBOX/m0"));
+        let long_line = "word ".repeat(12);
+        email.body_plain = Some((0..20).map(|_| format!("{long_line}\n")).collect());
+        email.load_state = EmailLoadState::FullyLoaded;
+        inbox.add_email(email);
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 20)).unwrap();
+        terminal
+            .draw(|f| c.render(f, f.area(), true, &ctx))
+            .unwrap();
+        assert!(body_title_row(&terminal).contains("Content [text] Top"));
+        let (wide_rows, visible) = c.body_extent.get();
+        c.handle_msg(&Msg::ContentScroll(Dir::Down, 1_000), &ctx);
+        assert_eq!(c.scroll_offset, wide_rows - visible, "j stops at the end");
+
+        // Narrower: each line wraps onto more rows, so there is more
+        // to scroll through and the old bottom is now mid-body.
+        terminal.backend_mut().resize(30, 20);
+        terminal
+            .draw(|f| c.render(f, f.area(), true, &ctx))
+            .unwrap();
+        let (narrow_rows, _) = c.body_extent.get();
+        assert!(narrow_rows > wide_rows, "{narrow_rows} vs {wide_rows}");
+        let title = body_title_row(&terminal);
+        assert!(title.contains('%'), "title was {:?}", title);
+        c.handle_msg(&Msg::ContentJump(Jump::Last), &ctx);
+        let narrow_bottom = c.scroll_offset;
+        assert_eq!(narrow_bottom, narrow_rows - visible);
+
+        // Wider again: the stored offset is past the end; the pane
+        // shows the last page and `k` moves up from there at once.
+        terminal.backend_mut().resize(80, 20);
+        terminal
+            .draw(|f| c.render(f, f.area(), true, &ctx))
+            .unwrap();
+        assert_eq!(c.body_scroll().0 as usize, wide_rows - visible);
+        assert!(body_title_row(&terminal).contains("Content [text] Bot"));
+        c.handle_msg(&Msg::ContentScroll(Dir::Up, 1), &ctx);
+        assert_eq!(c.scroll_offset, wide_rows - visible - 1);
+    }
+
+    #[test]
+    fn raw_source_view_shows_the_file_with_its_transport_headers() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("m0");
+        std::fs::write(
+            &path,
+            "Return-Path: <bounce@lists.example>\r\n\
+             Received: from mx.example by host; Mon, 1 Jan 2024 00:00:00 +0000\r\n\
+             From: a@b.c\r\nSubject: Hi\r\n\r\nHello there\r\n\x1b]0;pwned\x07\x1b[2J\tbye\r\n",
+        )
+        .unwrap();
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), temp.path().to_path_buf());
+        let mut email = crate::email::Email::new(path);
+        email.parse_from_file().unwrap();
+        inbox.add_email(email);
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+        let screen = |c: &ContentComponent| {
+            let mut terminal =
+                ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 20)).unwrap();
+            terminal
+                .draw(|f| c.render(f, f.area(), true, &ctx))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (0..20)
+                .map(|y| (0..80).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let formatted = screen(&c);
+        assert!(formatted.contains("Hello there"));
+        assert!(!formatted.contains("Return-Path:") && !formatted.contains("Received:"));
+
+        c.scroll_offset = 3;
+        c.handle_msg(&Msg::ToggleRawSource, &ctx);
+        assert_eq!(c.scroll_offset, 0);
+        let raw = screen(&c);
+        assert!(raw.contains("┌Source [raw]"), "{raw}");
+        assert!(raw.contains("Return-Path: <bounce@lists.example>"));
+        assert!(raw.contains("Received: from mx.example by host"));
+        assert!(raw.contains("Hello there"));
+        let source = c.raw_source.as_deref().unwrap();
+        assert!(!source.contains(['\x1b', '\x07']), "{source:?}");
+        assert!(source.contains("Hello there\r\n]0;pwned[2J\tbye\r\n"));
+
+        c.handle_msg(&Msg::ToggleRawSource, &ctx);
+        assert!(
+            c.raw_source.is_none(),
+            "the source is dropped on the way out"
+        );
+        assert!(!screen(&c).contains("Return-Path:"));
+    }
+
+    #[test]
+    fn mime_popup_lists_the_attachment_email_and_enter_shows_a_part() {
+        let maildir = crate::test_fixtures::TestMailDir::new();
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), maildir.get_folder_path("INBOX"));
+        let mut email = crate::email::Email::new(
+            maildir
+                .get_folder_path("INBOX")
+                .join("cur/1234567894.email5"),
+        );
+        email.parse_from_file().unwrap();
+        inbox.add_email(email);
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+        let screen = |c: &ContentComponent| {
+            let mut terminal =
+                ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+            terminal
+                .draw(|f| c.render(f, f.area(), true, &ctx))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (0..24)
+                .map(|y| (0..80).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+                .collect::<Vec<_>>()
+        };
+
+        c.handle_msg(&Msg::MimeTreeOpen, &ctx);
+        assert!(c.part_picker.visible);
+        let rows = screen(&c);
+        let row_of = |needle: &str| rows.iter().position(|r| r.contains(needle));
+        let mixed = row_of("│multipart/mixed").expect("root part listed");
+        assert!(rows[mixed + 1].contains("│  text/plain ("), "{rows:#?}");
+        assert!(rows[mixed + 2].contains("│  application/pdf \"project_specs.pdf\""));
+        assert!(rows[mixed + 3].contains("│  image/jpeg \"prototype_photo.jpg\""));
+
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert_eq!(c.on_key(key(KeyCode::Char('j')), &ctx), None);
+        let msg = c.on_key(key(KeyCode::Enter), &ctx).unwrap();
+        assert_eq!(msg, Msg::MimePartPreview(1));
+        c.handle_msg(&msg, &ctx);
+        assert!(!c.part_picker.visible);
+        let rows = screen(&c);
+        assert!(rows.iter().any(|r| r.contains("text/plain [MIME part]")));
+        assert!(rows.iter().any(|r| r.contains("Please find the attached")));
+
+        let close = c.on_key(key(KeyCode::Esc), &ctx).unwrap();
+        assert_eq!(close, Msg::AttachmentPreviewClose);
+        c.handle_msg(&close, &ctx);
+        assert!(c.part_preview.is_none());
+    }
+
+    #[test]
+    fn a_shorter_email_is_never_drawn_scrolled_past_its_end() {
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), PathBuf::from("/tmp/INBOX"));
+        for (name, lines) in [("long", 60), ("short", 3)] {
+            let mut email = crate::email::Email::new(PathBuf::from("/tmp/INBOX").join(name));
+            email.body_plain = Some((1..=lines).map(|i| format!("line {i}\n")).collect());
+            email.load_state = EmailLoadState::FullyLoaded;
+            inbox.add_email(email);
+        }
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let mut c = ContentComponent::new();
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 20)).unwrap();
+        {
+            let ctx = ctx(&theme, &config, &store);
+            terminal
+                .draw(|f| c.render(f, f.area(), true, &ctx))
+                .unwrap();
+            c.handle_msg(&Msg::ContentJump(Jump::Last), &ctx);
+            terminal
+                .draw(|f| c.render(f, f.area(), true, &ctx))
+                .unwrap();
+            assert!(body_title_row(&terminal).contains("Bot"));
+        }
+
+        store.select_email(1);
+        let ctx = ctx(&theme, &config, &store);
+        terminal
+            .draw(|f| c.render(f, f.area(), true, &ctx))
+            .unwrap();
+        assert_eq!(c.body_scroll().0, 0);
+        assert!(body_title_row(&terminal).contains("Content [text] All"));
+        let buffer = terminal.backend().buffer();
+        let first: String = (1..7).map(|x| buffer[(x, 7)].symbol()).collect();
+        assert_eq!(first, "line 1");
+    }
+
+    #[test]
+    fn content_page_stops_at_last_rendered_line() {
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), PathBuf::from("/tmp/INBOX"));
+        let mut email = crate::email::Email::new(PathBuf::from("/tmp/INBOX/m0"));
+        email.body_plain = Some((1..=30).map(|i| format!("line {i}\n")).collect());
+        email.load_state = EmailLoadState::FullyLoaded;
+        inbox.add_email(email);
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+
+        let backend = ratatui::backend::TestBackend::new(40, 20);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| c.render(f, f.area(), true, &ctx))
+            .unwrap();
+        let (rows, visible) = c.body_extent.get();
+        let last_top = rows - visible;
+
+        c.handle_msg(&Msg::ContentPage(Dir::Down, 1), &ctx);
+        assert_eq!(c.scroll_offset, visible / 2);
+        for _ in 0..10 {
+            c.handle_msg(&Msg::ContentPage(Dir::Down, 2), &ctx);
+        }
+        assert_eq!(c.scroll_offset, last_top);
+        c.handle_msg(&Msg::ContentPage(Dir::Up, 1), &ctx);
+        assert_eq!(c.scroll_offset, last_top - visible / 2);
+        c.handle_msg(&Msg::ContentPage(Dir::Up, 10), &ctx);
+        assert_eq!(c.scroll_offset, 0);
+    }
+
+    #[test]
+    fn find_match_lines_reports_lines_containing_the_term() {
+        let body = "Dear team,\nthe Budget is attached.\n\nbudget review friday\n-- \nAda";
+        assert_eq!(find_match_lines(body, "budget"), vec![1, 3]);
+        assert_eq!(find_match_lines(body, "BUDGET"), vec![1, 3]);
+        assert_eq!(find_match_lines(body, "team"), vec![0]);
+        assert!(find_match_lines(body, "invoice").is_empty());
+        assert!(find_match_lines(body, "").is_empty());
+    }
+
+    #[test]
+    fn highlight_matches_splits_spans_and_marks_the_current_line() {
+        let theme = Theme::default();
+        let text = highlight_matches(Text::raw("a cat\ncatalog cat"), "CAT", Some(1), &theme);
+        let contents: Vec<Vec<&str>> = text
+            .lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(
+            contents,
+            vec![vec!["a ", "cat"], vec!["cat", "alog ", "cat"]]
+        );
+        assert_eq!(text.lines[0].spans[1].style.bg, Some(theme.accent_light));
+        assert_eq!(text.lines[1].spans[0].style.bg, Some(theme.accent));
+        assert_eq!(text.lines[1].spans[1].style.bg, None);
+    }
+
+    #[test]
+    fn content_find_scrolls_to_matches_and_cycles() {
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), PathBuf::from("/tmp/INBOX"));
+        let mut email = crate::email::Email::new(PathBuf::from("/tmp/INBOX/m0"));
+        let mut body: Vec<String> = (0..60).map(|i| format!("line {i}")).collect();
+        body[12] = "needle one".into();
+        body[40] = "another Needle".into();
+        email.body_plain = Some(body.join("\n"));
+        email.load_state = EmailLoadState::FullyLoaded;
+        inbox.add_email(email);
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+
+        let backend = ratatui::backend::TestBackend::new(40, 20);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| c.render(f, f.area(), true, &ctx))
+            .unwrap();
+
+        c.handle_msg(&Msg::ContentFind("needle".into()), &ctx);
+        assert_eq!(c.scroll_offset, 12);
+        c.handle_msg(&Msg::ContentFindStep(Dir::Down), &ctx);
+        assert_eq!(c.scroll_offset, 40);
+        c.handle_msg(&Msg::ContentFindStep(Dir::Down), &ctx);
+        assert_eq!(c.scroll_offset, 12, "wraps to the first match");
+        c.handle_msg(&Msg::ContentFindStep(Dir::Up), &ctx);
+        assert_eq!(c.scroll_offset, 40);
+
+        let followups = c.handle_msg(&Msg::ContentFind("haystack".into()), &ctx);
+        assert!(matches!(followups.as_slice(), [Msg::StatusSet(_)]));
+        assert_eq!(c.find_query, None);
+        assert_eq!(c.scroll_offset, 40, "a miss leaves the view alone");
+    }
+
+    #[test]
+    fn corrupt_email_shows_parse_error_in_red() {
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), PathBuf::from("/tmp/INBOX"));
+        let mut email = crate::email::Email::new(PathBuf::from("/tmp/INBOX/bad"));
+        email.parse_error = Some("Mail parser error".into());
+        inbox.add_email(email);
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let ctx = ctx(&theme, &config, &store);
+        let c = ContentComponent::new();
+
+        let backend = ratatui::backend::TestBackend::new(60, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| c.render(f, f.area(), false, &ctx))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..60).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert!(row.contains("Could not parse"), "row was {:?}", row);
+        assert_eq!(buffer[(1, 1)].fg, Color::Red);
+        assert!(!row.contains("Loading body"));
+    }
+
+    #[test]
+    fn auth_badges_follow_the_from_line() {
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), PathBuf::from("/tmp/INBOX"));
+        let mut email = crate::email::Email::new(PathBuf::from("/tmp/INBOX/signed"));
+        email.headers.from = "a@b.c".into();
+        email.auth_status.dkim = Some(true);
+        email.auth_status.spf = Some(false);
+        inbox.add_email(email);
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let ctx = ctx(&theme, &config, &store);
+        let c = ContentComponent::new();
+
+        let backend = ratatui::backend::TestBackend::new(60, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| c.render(f, f.area(), false, &ctx))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..60).map(|x| buffer[(x, 1)].symbol()).collect();
+        let from = row.find("From: a@b.c ✓ DKIM ✗ SPF");
+        assert!(from.is_some(), "row was {:?}", row);
+        let tick = row.chars().position(|ch| ch == '✓').unwrap() as u16;
+        let cross = row.chars().position(|ch| ch == '✗').unwrap() as u16;
+        assert_eq!(buffer[(tick, 1)].fg, theme.cyan);
+        assert_eq!(buffer[(cross, 1)].fg, Color::Red);
+    }
+
     #[test]
     fn folder_enter_resets_scroll_offset() {
         let (theme, config, store) = fixtures();
@@ -392,9 +1553,292 @@ mod tests {
         assert_eq!(c.scroll_offset, 5);
     }
 
+    #[test]
+    fn wrap_mode_pins_horizontal_scroll_to_zero() {
+        let mut c = ContentComponent::new();
+        c.scroll_offset = 7;
+        c.h_scroll_offset = 12;
+        assert!(c.word_wrap, "wrap defaults on");
+        assert_eq!(c.body_scroll(), (7, 0));
+        assert!(c.body_wrap().is_some());
+    }
+
+    #[test]
+    fn no_wrap_mode_scrolls_horizontally_by_step() {
+        let (theme, config, store) = fixtures();
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+        c.scroll_offset = 3;
+        c.handle_msg(&Msg::ToggleWordWrap, &ctx);
+        assert!(!c.word_wrap);
+        assert!(c.body_wrap().is_none());
+
+        let right = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+        let msg = c.on_key(right, &ctx).expect("Right scrolls when unwrapped");
+        assert_eq!(msg, Msg::ContentScroll(Dir::Right, H_SCROLL_STEP));
+        c.handle_msg(&msg, &ctx);
+        c.handle_msg(&msg, &ctx);
+        assert_eq!(c.body_scroll(), (3, 8));
+
+        let left = KeyEvent::new(KeyCode::Left, KeyModifiers::NONE);
+        let msg = c.on_key(left, &ctx).unwrap();
+        c.handle_msg(&msg, &ctx);
+        assert_eq!(c.body_scroll(), (3, 4));
+    }
+
+    #[test]
+    fn toggling_wrap_back_on_resets_horizontal_offset() {
+        let (theme, config, store) = fixtures();
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+        c.handle_msg(&Msg::ToggleWordWrap, &ctx);
+        c.handle_msg(&Msg::ContentScroll(Dir::Right, 8), &ctx);
+        assert_eq!(c.h_scroll_offset, 8);
+        c.handle_msg(&Msg::ToggleWordWrap, &ctx);
+        assert!(c.word_wrap);
+        assert_eq!(c.h_scroll_offset, 0);
+    }
+
+    #[test]
+    fn arrows_are_ignored_while_wrapping() {
+        let (theme, config, store) = fixtures();
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+        let right = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+        assert_eq!(c.on_key(right, &ctx), None);
+        c.handle_msg(&Msg::ContentScroll(Dir::Right, 4), &ctx);
+        assert_eq!(c.h_scroll_offset, 0);
+    }
+
+    #[test]
+    fn body_lines_dims_from_trailer_start() {
+        let theme = Theme::default();
+        let body = "Hello\n-- \nAda";
+        let text = body_lines(body, crate::signature::trailer_start(body), &theme);
+        assert_eq!(text.lines.len(), 3);
+        assert_eq!(text.lines[0].style.fg, None);
+        assert_eq!(text.lines[1].style.fg, Some(theme.gray_dark));
+        assert_eq!(text.lines[2].style.fg, Some(theme.gray_dark));
+
+        let plain = body_lines(body, None, &theme);
+        assert!(plain.lines.iter().all(|l| l.style.fg.is_none()));
+    }
+
+    fn attachment(name: &str, content_type: &str, bytes: &[u8]) -> Attachment {
+        Attachment {
+            filename: name.into(),
+            content_type: content_type.into(),
+            size: bytes.len(),
+            raw_bytes: bytes.to_vec(),
+        }
+    }
+
+    #[test]
+    fn preview_lines_colors_patch_additions_and_removals() {
+        let theme = Theme::default();
+        let patch = attachment(
+            "fix.patch",
+            "application/octet-stream",
+            b"--- a/x\n+++ b/x\n@@ -1 +1 @@\n-old\n+new\n ctx",
+        );
+        let text = preview_lines(&patch, &theme);
+        let fg: Vec<_> = text.lines.iter().map(|l| l.style.fg).collect();
+        assert_eq!(
+            fg,
+            vec![
+                None,
+                None,
+                Some(theme.cyan_light),
+                Some(theme.accent),
+                Some(theme.cyan),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn preview_lines_shows_plain_text_unstyled() {
+        let theme = Theme::default();
+        let text = preview_lines(&attachment("a.txt", "text/plain", b"-a\n+b"), &theme);
+        assert!(text.lines.iter().all(|l| l.style.fg.is_none()));
+        assert_eq!(text.lines.len(), 2);
+    }
+
+    #[test]
+    fn preview_lines_strips_terminal_escapes() {
+        let theme = Theme::default();
+        for name in ["a.txt", "fix.patch"] {
+            let text = preview_lines(
+                &attachment(name, "text/plain", b"\x1b[2Jsafe\n+\x1b]0;pwned\x07x"),
+                &theme,
+            );
+            let rendered: Vec<String> = text.lines.iter().map(|l| l.to_string()).collect();
+            assert_eq!(rendered, ["[2Jsafe", "+]0;pwnedx"], "{name}");
+        }
+    }
+
+    #[test]
+    fn calendar_invites_preview_as_an_event_summary() {
+        use chrono::TimeZone;
+        let theme = Theme::default();
+        let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nSUMMARY:Design review\r\n\
+DTSTART:20240315T140000\r\nDTEND:20240315T153000\r\nLOCATION:Room 4\r\n\
+ORGANIZER;CN=Jane Doe:mailto:jane@example.com\r\nDESCRIPTION:Bring the mockups.\r\n\
+END:VEVENT\r\nEND:VCALENDAR\r\n";
+        let invite = attachment("invite.ics", "application/ics", ics.as_bytes());
+        assert!(invite.is_previewable());
+
+        let event = CalendarEvent::parse(ics).unwrap();
+        let now = chrono::Local
+            .with_ymd_and_hms(2024, 3, 12, 14, 0, 0)
+            .unwrap();
+        let text: Vec<String> = calendar_lines(&event, &theme, now)
+            .lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(
+            text,
+            [
+                "Design review",
+                "",
+                "When:      Fri 15 Mar 2024 14:00 – 15:30",
+                "           In 3 days",
+                "Where:     Room 4",
+                "Organizer: Jane Doe <jane@example.com>",
+                "",
+                "Bring the mockups.",
+            ]
+        );
+    }
+
+    #[test]
+    fn preview_lines_falls_back_to_hex_for_non_utf8() {
+        let theme = Theme::default();
+        let text = preview_lines(
+            &attachment("a.txt", "text/plain", &[0xff, 0x41, 0x00]),
+            &theme,
+        );
+        let rendered: Vec<String> = text.lines.iter().map(|l| l.to_string()).collect();
+        assert!(rendered[0].contains("Binary file"), "{rendered:?}");
+        assert!(
+            rendered[2].starts_with("00000000  ff 41 00"),
+            "{rendered:?}"
+        );
+        assert!(rendered[2].ends_with("|.A.|"), "{rendered:?}");
+    }
+
+    #[test]
+    fn backspace_and_esc_close_preview_and_selection_change_drops_it() {
+        let (theme, config, store) = fixtures();
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+        let backspace = KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE);
+        assert_eq!(c.on_key(backspace, &ctx), None, "no preview, no claim");
+
+        c.open_preview(1);
+        assert_eq!(c.on_key(backspace, &ctx), Some(Msg::AttachmentPreviewClose));
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(c.on_key(esc, &ctx), Some(Msg::AttachmentPreviewClose));
+        c.handle_msg(&Msg::AttachmentPreviewClose, &ctx);
+        assert_eq!(c.attachment_preview, None);
+
+        c.open_preview(0);
+        c.handle_msg(&Msg::MessageMove(Dir::Down), &ctx);
+        assert_eq!(c.attachment_preview, None);
+    }
+
     // `j`/`k`, arrow `Up`/`Down`, and `PageUp`/`PageDown` all resolve
     // via `AppRoot::action_to_msg` (centralised keymap dispatch). This
     // component's `on_key` is a no-op; the dispatch test
     // `components::root::tests::key_pagedown_in_content_pane_scrolls_by_ten`
     // exercises the full process_event → keymap → ContentScroll path.
+
+    #[test]
+    fn folded_headers_grow_with_their_wrapped_text_up_to_the_cap() {
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), PathBuf::from("/tmp/INBOX"));
+        let mut email = crate::email::Email::new(PathBuf::from("/tmp/INBOX/m0"));
+        email.headers.subject = "word ".repeat(20).trim_end().to_string();
+        inbox.add_email(email);
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let c = ContentComponent::new();
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 40)).unwrap();
+        let mut draw = |store: &EmailStore| {
+            let ctx = ctx(&theme, &config, store);
+            terminal
+                .draw(|f| c.render(f, f.area(), true, &ctx))
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            let bottom: String = (0..buffer.area.width)
+                .map(|x| buffer[(x, c.header_extent.get().1 as u16 + 1)].symbol())
+                .collect();
+            (c.header_extent.get(), bottom)
+        };
+
+        // The Subject line wraps to three rows of a 38-wide box: six
+        // rows with From / To / Date, all shown.
+        let ((rows, visible), bottom) = draw(&store);
+        assert_eq!((rows, visible), (6, 6));
+        assert!(!bottom.contains('…'));
+
+        let email = store.get_selected_email_mut().unwrap();
+        email.headers.subject = "word ".repeat(60).trim_end().to_string();
+        let ((rows, visible), bottom) = draw(&store);
+        assert!(rows > HEADER_MAX_ROWS);
+        assert_eq!(visible, HEADER_MAX_ROWS);
+        assert!(bottom.contains("… [H for all]"), "{}", bottom);
+    }
+
+    #[test]
+    fn h_expands_the_header_box_to_every_header_and_j_scrolls_it() {
+        let (theme, config, mut store) = fixtures();
+        let mut inbox = crate::email::Folder::new("INBOX".into(), PathBuf::from("/tmp/INBOX"));
+        let mut email = crate::email::Email::new(PathBuf::from("/tmp/INBOX/m0"));
+        email.headers.subject = "Hello".into();
+        email.raw_headers = (0..20)
+            .map(|i| (format!("X-Header-{i}"), "value".to_string()))
+            .chain([("Message-ID".to_string(), "<abc@example.com>".to_string())])
+            .collect();
+        inbox.add_email(email);
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+        store.select_email(0);
+        let ctx = ctx(&theme, &config, &store);
+        let mut c = ContentComponent::new();
+        let backend = ratatui::backend::TestBackend::new(60, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let screen = |terminal: &mut ratatui::Terminal<ratatui::backend::TestBackend>,
+                      c: &ContentComponent| {
+            terminal
+                .draw(|f| c.render(f, f.area(), true, &ctx))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            buffer
+                .content
+                .chunks(buffer.area.width as usize)
+                .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        assert!(!screen(&mut terminal, &c).contains("X-Header-0"));
+        c.handle_msg(&Msg::ToggleHeaders, &ctx);
+        let expanded = screen(&mut terminal, &c);
+        assert!(expanded.contains("X-Header-0"));
+        assert!(!expanded.contains("Message-ID"), "past the half-pane cap");
+        assert_eq!(c.header_extent.get(), (21, 13));
+
+        c.handle_msg(&Msg::ContentScroll(Dir::Down, 100), &ctx);
+        assert_eq!(c.header_scroll, 8, "j scrolls the headers, not the body");
+        assert_eq!(c.scroll_offset, 0);
+        assert!(screen(&mut terminal, &c).contains("Message-ID: <abc@example.com>"));
+
+        c.handle_msg(&Msg::ToggleHeaders, &ctx);
+        assert!(!screen(&mut terminal, &c).contains("Message-ID"));
+        assert_eq!(c.header_scroll, 0);
+    }
 }
@@ -8,15 +8,19 @@
 // Scope (minimal, iteration 1):
 //   - State: original_message_id + Compose + DraftStatus
 //   - Messages: DraftStart (begin), DraftEditorExited (parsed back),
-//     DraftSend (handed to msmtp)
-//   - Render: To / Cc / Subject header strip, scrollable body
+//     DraftSend (handed to msmtp), DraftAttachOpen / DraftAttach
+//   - Render: To / Cc / Subject header strip, scrollable body, the
+//     attached files below it, and the `Ctrl+A` file picker on top
 //
 // Out of scope (follow-ups tracked as separate beads):
 //   - Per-pane key handling in AppRoot (e/a/t/c/b/S/D/q binding)
 //   - Editor relaunch + msmtp pipeline execution from this pane
-//   - Attachment list rendering and add/remove
+//   - Removing an attachment again
 //   - Side-by-side original-message preview when width allows
 
+use std::path::PathBuf;
+
+use crossterm::event::KeyEvent;
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout as RatatuiLayout, Rect},
@@ -27,7 +31,9 @@ use ratatui::{
 
 use crate::compose::Compose;
 use crate::theme::Theme;
+use crate::util::format_file_size;
 
+use super::attach_picker::AttachPicker;
 use super::{Component, Ctx, MessageId, Msg, ReplyKind};
 
 /// Pane title — tests grep for this exact string.
@@ -64,6 +70,13 @@ pub struct DraftState {
     pub reply_kind: ReplyKind,
     pub compose: Compose,
     pub status: DraftStatus,
+    /// The `Drafts/` file this draft was reopened from. Edits are
+    /// saved back over it; `None` for replies started from a message.
+    pub path: Option<PathBuf>,
+    /// `(typed, replacement)` for each short name the address book
+    /// expanded when the editor last exited, listed under the headers
+    /// so the user sees who a name became before sending.
+    pub expansions: Vec<(String, String)>,
 }
 
 /// Draft pane. Stateless when no draft is in flight; otherwise owns
@@ -71,6 +84,8 @@ pub struct DraftState {
 #[derive(Default)]
 pub struct DraftComponent {
     state: Option<DraftState>,
+    /// `Ctrl+A` file picker. AppRoot routes keys to it while visible.
+    pub(crate) picker: AttachPicker,
 }
 
 impl DraftComponent {
@@ -84,6 +99,11 @@ impl DraftComponent {
         self.state.is_some()
     }
 
+    /// Whether the attach picker is open and takes every key.
+    pub fn picking(&self) -> bool {
+        self.picker.visible
+    }
+
     /// Borrow the current draft state. `None` between sessions or
     /// after discard.
     pub fn state(&self) -> Option<&DraftState> {
@@ -101,6 +121,23 @@ impl DraftComponent {
         }
     }
 
+    /// Record the recipient expansions from the last editor exit,
+    /// replacing the previous list. No-op when there is no draft in
+    /// flight.
+    pub fn set_expansions(&mut self, expansions: Vec<(String, String)>) {
+        if let Some(state) = self.state.as_mut() {
+            state.expansions = expansions;
+        }
+    }
+
+    /// Tie the live draft to the `Drafts/` file it was reopened from.
+    /// No-op when there is no draft in flight.
+    pub fn set_path(&mut self, path: PathBuf) {
+        if let Some(state) = self.state.as_mut() {
+            state.path = Some(path);
+        }
+    }
+
     /// Force a particular status on the current draft. AppRoot uses
     /// this to flip a ReplyLater draft straight to `ReadyToSend` (it
     /// skips the editor) and to surface send failures via
@@ -116,6 +153,7 @@ impl DraftComponent {
     /// state would lock the user out of starting a new reply.
     pub fn clear(&mut self) {
         self.state = None;
+        self.picker.close();
     }
 }
 
@@ -133,6 +171,21 @@ impl Component for DraftComponent {
                     reply_kind: *kind,
                     compose: Compose::new(),
                     status: DraftStatus::Editing,
+                    path: None,
+                    expansions: Vec::new(),
+                });
+                Vec::new()
+            }
+            Msg::DraftResume => {
+                // Same empty shell as DraftStart; AppRoot fills in the
+                // compose and file path once it has read the draft.
+                self.state = Some(DraftState {
+                    original_message_id: MessageId::new(),
+                    reply_kind: ReplyKind::Reply,
+                    compose: Compose::new(),
+                    status: DraftStatus::Editing,
+                    path: None,
+                    expansions: Vec::new(),
                 });
                 Vec::new()
             }
@@ -160,6 +213,20 @@ impl Component for DraftComponent {
                 // a new one. View progression back to MessagesContent
                 // is AppRoot's job.
                 self.state = None;
+                self.picker.close();
+                Vec::new()
+            }
+            Msg::DraftAttachOpen if self.state.is_some() => {
+                let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+                self.picker.open(home);
+                Vec::new()
+            }
+            Msg::DraftAttach(path) => {
+                if let Some(state) = self.state.as_mut()
+                    && !state.compose.attachments.contains(path)
+                {
+                    state.compose.attachments.push(path.clone());
+                }
                 Vec::new()
             }
             Msg::DraftEditRelaunch => {
@@ -175,12 +242,16 @@ impl Component for DraftComponent {
         }
     }
 
-    fn render(&self, f: &mut Frame, area: Rect, focused: bool, ctx: &Ctx) {
-        let border_style = if focused {
-            Style::default().fg(ctx.theme.cyan_light)
+    fn on_key(&mut self, key: KeyEvent, _ctx: &Ctx) -> Option<Msg> {
+        if self.picker.visible {
+            self.picker.on_key(key)
         } else {
-            Style::default()
-        };
+            None
+        }
+    }
+
+    fn render(&self, f: &mut Frame, area: Rect, focused: bool, ctx: &Ctx) {
+        let border_style = ctx.theme.focus(focused, ctx.theme.cyan_light);
 
         let block = Block::default()
             .borders(Borders::ALL)
@@ -205,21 +276,60 @@ impl Component for DraftComponent {
         let chunks = RatatuiLayout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(header_lines(&state.compose) as u16),
+                Constraint::Length((header_lines(&state.compose) + state.expansions.len()) as u16),
                 Constraint::Min(1),
+                Constraint::Length(attachment_lines(&state.compose) as u16),
                 Constraint::Length(1),
             ])
             .split(inner);
 
-        f.render_widget(header_paragraph(&state.compose, ctx.theme), chunks[0]);
+        f.render_widget(
+            header_paragraph(&state.compose, &state.expansions, ctx.theme),
+            chunks[0],
+        );
         f.render_widget(
             Paragraph::new(state.compose.body.clone()).wrap(Wrap { trim: false }),
             chunks[1],
         );
-        f.render_widget(status_paragraph(&state.status, ctx.theme), chunks[2]);
+        f.render_widget(attachment_paragraph(&state.compose, ctx.theme), chunks[2]);
+        f.render_widget(status_paragraph(&state.status, ctx.theme), chunks[3]);
+
+        if self.picker.visible {
+            self.picker.render(f, area, ctx.theme);
+        }
     }
 }
 
+/// Rows of the attachment list: a label row plus one per file, or
+/// nothing when no file is attached.
+fn attachment_lines(c: &Compose) -> usize {
+    match c.attachments.len() {
+        0 => 0,
+        n => n + 1,
+    }
+}
+
+fn attachment_paragraph<'a>(c: &'a Compose, theme: &Theme) -> Paragraph<'a> {
+    let label_style = Style::default()
+        .fg(theme.cyan_light)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = Vec::with_capacity(attachment_lines(c));
+    if !c.attachments.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Attachments ({}):", c.attachments.len()),
+            label_style,
+        )));
+    }
+    for path in &c.attachments {
+        // A file that has gone missing still lists; send reports it.
+        let size = std::fs::metadata(path)
+            .map(|m| format_file_size(m.len() as usize))
+            .unwrap_or_else(|_| "missing".to_string());
+        lines.push(Line::from(format!("  📎 {} ({})", path.display(), size)));
+    }
+    Paragraph::new(lines)
+}
+
 /// Number of header rows the strip will paint. Cc/Bcc are conditional
 /// so the strip stays compact for typical drafts.
 fn header_lines(c: &Compose) -> usize {
@@ -234,12 +344,18 @@ fn header_lines(c: &Compose) -> usize {
     n
 }
 
-fn header_paragraph<'a>(c: &'a Compose, theme: &Theme) -> Paragraph<'a> {
+/// Header strip: To / Cc / Bcc / Subject, then one `Expanded:` row per
+/// short name the address book filled in.
+fn header_paragraph<'a>(
+    c: &'a Compose,
+    expansions: &[(String, String)],
+    theme: &Theme,
+) -> Paragraph<'a> {
     let label_style = Style::default()
         .fg(theme.cyan_light)
         .add_modifier(Modifier::BOLD);
 
-    let mut lines = Vec::with_capacity(4);
+    let mut lines = Vec::with_capacity(4 + expansions.len());
     lines.push(Line::from(vec![
         Span::styled("To:      ", label_style),
         Span::raw(c.to.clone()),
@@ -260,6 +376,12 @@ fn header_paragraph<'a>(c: &'a Compose, theme: &Theme) -> Paragraph<'a> {
         Span::styled("Subject: ", label_style),
         Span::raw(c.subject.clone()),
     ]));
+    for (typed, replacement) in expansions {
+        lines.push(Line::from(vec![
+            Span::styled("Expanded: ", Style::default().fg(theme.gray_dark)),
+            Span::raw(format!("{} → {}", typed, replacement)),
+        ]));
+    }
     Paragraph::new(lines).wrap(Wrap { trim: false })
 }
 
@@ -486,6 +608,34 @@ mod tests {
         assert!(rendered.contains("bob@example.com"));
     }
 
+    /// Each short name the address book expanded is listed under the
+    /// headers, so the user sees who it became before sending.
+    #[test]
+    fn render_lists_recipient_expansions() {
+        let mut c = DraftComponent::new();
+        let (theme, config, store) = fixtures();
+        let ctx = ctx(&theme, &config, &store);
+        c.handle_msg(
+            &Msg::DraftStart(ReplyKind::Reply, MessageId::from("m")),
+            &ctx,
+        );
+        c.set_compose(Compose {
+            to: "Alice <alice@example.com>".into(),
+            subject: "hi".into(),
+            ..Compose::new()
+        });
+        c.set_expansions(vec![(
+            "ali".to_string(),
+            "Alice <alice@example.com>".to_string(),
+        )]);
+        let rendered = render_to_string(&c, true, 60, 10);
+        assert!(
+            rendered.contains("Expanded: ali → Alice <alice@example.com>"),
+            "{}",
+            rendered
+        );
+    }
+
     #[test]
     fn render_without_cc_omits_cc_row() {
         let mut c = DraftComponent::new();
@@ -534,4 +684,26 @@ mod tests {
         assert!(rendered.contains("send failed"));
         assert!(rendered.contains("smtp 550"));
     }
+
+    #[test]
+    fn attached_files_are_kept_once_each_and_listed_under_the_body() {
+        let mut c = DraftComponent::new();
+        let (theme, config, store) = fixtures();
+        let ctx = ctx(&theme, &config, &store);
+        c.handle_msg(&Msg::DraftAttach(PathBuf::from("/tmp/ignored")), &ctx);
+        c.handle_msg(
+            &Msg::DraftStart(ReplyKind::Reply, MessageId::from("m")),
+            &ctx,
+        );
+        for path in ["/tmp/a.pdf", "/tmp/b.png", "/tmp/a.pdf"] {
+            c.handle_msg(&Msg::DraftAttach(PathBuf::from(path)), &ctx);
+        }
+        assert_eq!(
+            c.state().unwrap().compose.attachments,
+            [PathBuf::from("/tmp/a.pdf"), PathBuf::from("/tmp/b.png")]
+        );
+        let rendered = render_to_string(&c, true, 60, 12);
+        assert!(rendered.contains("Attachments (2):"), "{}", rendered);
+        assert!(rendered.contains("/tmp/b.png"));
+    }
 }
@@ -1,12 +1,16 @@
-// `FolderPickerComponent` — modal "move to folder" picker.
+// `FolderPickerComponent` — modal "move to folder" picker, also used as
+// the "jump to folder" quick-switcher.
 //
 // Owns its own visibility flag. When `visible == true`, AppRoot routes
 // every key event here first; the picker absorbs all keys until it
-// returns `Msg::MoveTo` (Enter) or hides itself (Esc).
+// returns `Msg::MoveTo` / `Msg::JumpToFolder` (Enter) or hides itself
+// (Esc).
 //
 // State:
 //   - `visible`            modal currently shown
-//   - `filter_text`        substring filter applied to the flat folder list
+//   - `mode`               what Enter does with the picked folder
+//   - `filter_text`        filter applied to the flat folder list:
+//                          substring when moving, fuzzy when jumping
 //   - `folder_list`        full flattened list of (display, fs_path)
 //   - `selected_index`     cursor over the *filtered* view
 //
@@ -38,12 +42,24 @@ use crate::theme::Theme;
 
 use super::{Component, Ctx, Msg};
 
+/// What the picker does with the folder chosen on Enter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PickerMode {
+    /// Move the selected email(s) there (`m`).
+    #[default]
+    Move,
+    /// Enter the folder (`gf`, `:open`). Filters fuzzily.
+    Jump,
+}
+
 /// Modal folder picker state. The picker absorbs every key event
 /// while `visible == true`; AppRoot routes input here first and
 /// resumes normal dispatch once the modal closes.
 pub struct FolderPickerComponent {
     /// True while the modal is shown.
     pub visible: bool,
+    /// Set by [`Self::open`]; decides the Enter message and the filter.
+    pub mode: PickerMode,
     /// Substring filter typed by the user. Matched case-insensitively
     /// against folder display labels.
     pub filter_text: String,
@@ -61,6 +77,7 @@ impl FolderPickerComponent {
     pub fn new() -> Self {
         Self {
             visible: false,
+            mode: PickerMode::Move,
             filter_text: String::new(),
             selected_index: 0,
             folder_list: Vec::new(),
@@ -69,8 +86,9 @@ impl FolderPickerComponent {
     }
 
     /// Show the modal and rebuild the folder list from the live store.
-    pub fn open(&mut self, root: &Folder) {
+    pub fn open(&mut self, root: &Folder, mode: PickerMode) {
         self.visible = true;
+        self.mode = mode;
         self.filter_text.clear();
         self.selected_index = 0;
         self.folder_list = Self::build_folder_list(root);
@@ -84,12 +102,27 @@ impl FolderPickerComponent {
         self.folder_list.clear();
     }
 
-    /// Filtered view (case-insensitive substring match on the display
-    /// label). Returns indices into `folder_list` so the caller can
-    /// re-resolve the picked path even after the filter changes.
+    /// Filtered view: case-insensitive substring match on the display
+    /// label when moving, [`fuzzy_score`] ranking when jumping. Returns
+    /// indices into `folder_list` so the caller can re-resolve the
+    /// picked path even after the filter changes.
     pub fn filtered_indices(&self) -> Vec<usize> {
         if self.filter_text.is_empty() {
             (0..self.folder_list.len()).collect()
+        } else if self.mode == PickerMode::Jump {
+            let mut scored: Vec<(i64, usize)> = self
+                .folder_list
+                .iter()
+                .enumerate()
+                .filter_map(|(i, (label, _))| {
+                    fuzzy_score(&self.filter_text, label).map(|score| (score, i))
+                })
+                .collect();
+            // Best score first; shorter labels win ties, then tree order.
+            scored.sort_by_key(|&(score, i)| {
+                (std::cmp::Reverse(score), self.folder_list[i].0.len(), i)
+            });
+            scored.into_iter().map(|(_, i)| i).collect()
         } else {
             let q = self.filter_text.to_lowercase();
             self.folder_list
@@ -128,7 +161,10 @@ impl FolderPickerComponent {
         let outer = Block::default()
             .borders(Borders::ALL)
             .style(Style::default().fg(theme.cyan))
-            .title("Move to folder (Esc to cancel)");
+            .title(match self.mode {
+                PickerMode::Move => "Move to folder (Esc to cancel)",
+                PickerMode::Jump => "Jump to folder (Esc to cancel)",
+            });
         let inner = outer.inner(modal);
         f.render_widget(outer, modal);
 
@@ -149,12 +185,7 @@ impl FolderPickerComponent {
 
         let list = List::new(items)
             .block(Block::default().borders(Borders::ALL))
-            .highlight_style(
-                Style::default()
-                    .bg(theme.primary)
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            );
+            .highlight_style(theme.selection());
 
         let mut state = self.list_state.borrow_mut();
         let sel = if filtered.is_empty() {
@@ -194,6 +225,43 @@ fn collect_folders(folder: &Folder, prefix: &str, out: &mut Vec<(String, PathBuf
     }
 }
 
+/// Score `candidate` against `query` as a case-insensitive subsequence
+/// match, or `None` when some query character is missing. Consecutive
+/// characters and matches at the start of a path segment score extra;
+/// every skipped character between two matches costs a point. Each
+/// occurrence of the first query character is tried as the anchor, so
+/// "proj" finds the `Projects` in `Personal/Projects` rather than the
+/// scattered letters of `Personal`.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    let cand: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+    let Some(&first) = query.first() else {
+        return Some(0);
+    };
+    let segment_start = |i: usize| i == 0 || matches!(cand[i - 1], '/' | '.' | '-' | '_' | ' ');
+    cand.iter()
+        .enumerate()
+        .filter(|&(_, &c)| c == first)
+        .filter_map(|(start, _)| {
+            let mut score = if segment_start(start) { 10 } else { 0 };
+            let mut prev = start;
+            for &q in &query[1..] {
+                let i = prev + 1 + cand[prev + 1..].iter().position(|&c| c == q)?;
+                score += if i == prev + 1 {
+                    5
+                } else {
+                    -((i - prev - 1) as i64)
+                };
+                if segment_start(i) {
+                    score += 10;
+                }
+                prev = i;
+            }
+            Some(score)
+        })
+        .max()
+}
+
 /// Center an inner rect inside `outer`, sized as the given % of width/height.
 fn centered_rect(outer: Rect, pct_x: u16, pct_y: u16) -> Rect {
     let v = RLayout::default()
@@ -216,8 +284,13 @@ fn centered_rect(outer: Rect, pct_x: u16, pct_y: u16) -> Rect {
 
 impl Component for FolderPickerComponent {
     fn handle_msg(&mut self, msg: &Msg, ctx: &Ctx) -> Vec<Msg> {
-        if matches!(msg, Msg::OpenFolderPicker) {
-            self.open(&ctx.store.root_folder);
+        match msg {
+            Msg::OpenFolderPicker => self.open(&ctx.store.root_folder, PickerMode::Move),
+            Msg::OpenFolderJump(filter) => {
+                self.open(&ctx.store.root_folder, PickerMode::Jump);
+                self.filter_text = filter.clone();
+            }
+            _ => {}
         }
         Vec::new()
     }
@@ -240,8 +313,12 @@ impl Component for FolderPickerComponent {
             }
             (KeyCode::Enter, _) => {
                 let target = self.selected_path();
+                let mode = self.mode;
                 self.close();
-                target.map(|p| Msg::MoveTo(String::new(), p))
+                target.map(|p| match mode {
+                    PickerMode::Move => Msg::MoveTo(String::new(), p),
+                    PickerMode::Jump => Msg::JumpToFolder(p),
+                })
             }
             (KeyCode::Up, _)
             | (KeyCode::Char('p'), KeyModifiers::CONTROL)
@@ -452,6 +529,47 @@ mod tests {
         assert_eq!(p.selected_index, n - 1);
     }
 
+    #[test]
+    fn fuzzy_score_prefers_segment_starts_and_runs() {
+        assert!(fuzzy_score("xyz", "Work/Projects").is_none());
+        let projects = fuzzy_score("proj", "Work/Projects").unwrap();
+        let scattered = fuzzy_score("prj", "Work/Projects").unwrap();
+        assert!(projects > scattered);
+        assert_eq!(
+            fuzzy_score("proj", "Personal/Projects"),
+            Some(projects),
+            "the anchor on the later segment start wins"
+        );
+    }
+
+    #[test]
+    fn jump_mode_ranks_fuzzy_matches_and_emits_jump_to_folder() {
+        let mut store = store_with_folders();
+        let mut work = Folder::new("Work".to_string(), PathBuf::from("/tmp/Work"));
+        work.add_subfolder(Folder::new(
+            "Projects".to_string(),
+            PathBuf::from("/tmp/Work/Projects"),
+        ));
+        store.root_folder.add_subfolder(work);
+        let (theme, config) = (Theme::default(), Config::default());
+        let ctx = ctx(&theme, &config, &store);
+        let mut p = FolderPickerComponent::new();
+        p.handle_msg(&Msg::OpenFolderJump("wpro".into()), &ctx);
+        assert_eq!(p.mode, PickerMode::Jump);
+
+        let labels: Vec<&str> = p
+            .filtered_indices()
+            .iter()
+            .map(|&i| p.folder_list[i].0.as_str())
+            .collect();
+        assert_eq!(labels, ["Work/Projects"]);
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        match p.on_key(enter, &ctx) {
+            Some(Msg::JumpToFolder(path)) => assert_eq!(path, PathBuf::from("/tmp/Work/Projects")),
+            other => panic!("expected JumpToFolder, got {:?}", other),
+        }
+    }
+
     #[test]
     fn on_key_returns_none_when_invisible() {
         let store = store_with_folders();
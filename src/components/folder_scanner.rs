@@ -12,7 +12,6 @@
 // shows a "Scanning folders…" splash via `EmailStore::scanning_folders`.
 
 use std::collections::HashMap;
-use std::path::PathBuf;
 use std::sync::mpsc::{self, Receiver, TryRecvError};
 use std::thread;
 
@@ -41,15 +40,14 @@ pub struct FolderScannerHandle {
 
 impl FolderScannerHandle {
     /// Spawn the worker thread and return a handle. The thread runs to
-    /// completion exactly once: it scans `maildir_path`, builds the
+    /// completion exactly once: it runs `scanner` over its root, builds the
     /// drafts index, and sends the combined result. Dropping the handle
     /// drops the receiver; if the send happens after that, it fails
     /// silently — fine, because we only care about reaping the result
     /// when someone is listening.
-    pub fn spawn(maildir_path: PathBuf) -> Self {
+    pub fn spawn(scanner: MaildirScanner) -> Self {
         let (tx, rx) = mpsc::channel::<Result<ScannedMaildir>>();
         thread::spawn(move || {
-            let scanner = MaildirScanner::new(maildir_path);
             let scanned = scanner.scan().map(|root| {
                 let drafts = scanner.build_drafts_index();
                 ScannedMaildir { root, drafts }
@@ -72,6 +70,7 @@ impl FolderScannerHandle {
 mod tests {
     use super::*;
     use std::fs;
+    use std::path::PathBuf;
     use std::time::{Duration, Instant};
     use tempfile::TempDir;
 
@@ -126,7 +125,7 @@ mod tests {
     #[test]
     fn scanner_returns_deep_hierarchy_off_thread() {
         let temp = build_deep_maildir(3, 3);
-        let handle = FolderScannerHandle::spawn(temp.path().to_path_buf());
+        let handle = FolderScannerHandle::spawn(MaildirScanner::new(temp.path().to_path_buf()));
 
         let result = wait_for(|| handle.try_recv(), Duration::from_secs(5));
         let scanned = result.expect("scan must succeed on a valid maildir");
@@ -145,7 +144,7 @@ mod tests {
     #[test]
     fn scanner_propagates_missing_path_error() {
         let missing = PathBuf::from("/definitely/does/not/exist/folder-scanner");
-        let handle = FolderScannerHandle::spawn(missing);
+        let handle = FolderScannerHandle::spawn(MaildirScanner::new(missing));
         let result = wait_for(|| handle.try_recv(), Duration::from_secs(2));
         assert!(result.is_err(), "missing path must yield Err, got Ok");
     }
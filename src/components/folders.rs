@@ -1,8 +1,14 @@
 // `FoldersComponent` — folder pane.
 //
-// Owns the folder-pane selection (`folder_index`) and the ratatui
-// `ListState` used to highlight it. Renders the folder tree from
-// `Ctx::store.root_folder`. Translates Folders-pane keys into messages.
+// Owns the folder-pane selection (`folder_index`), the set of collapsed
+// folders, and the ratatui `ListState` used to highlight it. Renders
+// the folder tree from `Ctx::store.root_folder`. Translates Folders-pane
+// keys into messages.
+//
+// **Collapse state** is keyed by each folder's filesystem path rather
+// than its display index, so it survives rescans and sibling inserts.
+// `folder_index` always counts *visible* rows; every display-index ↔
+// store-path translation goes through `layout::*` with `collapsed`.
 //
 // **`RefCell<ListState>`** — ratatui's `render_stateful_widget`
 // requires `&mut ListState`, but `Component::render` takes `&self`.
@@ -10,7 +16,9 @@
 // § "Risks & open questions". It costs a borrow-check at render time
 // and nothing else.
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+use std::path::PathBuf;
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -24,7 +32,7 @@ use ratatui::{
 use crate::email::Folder;
 use crate::theme::Theme;
 
-use super::{Component, Ctx, Dir, Msg};
+use super::{Component, Ctx, Dir, Jump, Msg, viewport};
 
 /// Folder pane state. Tracks the cursor index and the ratatui list
 /// state used to render the highlight. The folder tree itself is read
@@ -33,6 +41,14 @@ pub struct FoldersComponent {
     /// Flat display-order index of the highlighted folder (subfolders
     /// listed depth-first, INBOX first).
     pub folder_index: usize,
+    /// Filesystem paths of folders whose children are hidden. Absence
+    /// means expanded; [`Self::collapse_nested`] seeds the default of
+    /// an open top level over folded deeper levels.
+    pub collapsed: HashSet<PathBuf>,
+    /// Folder rows the pane could display at its last render; sizes
+    /// `Ctrl-d` / `PageDown` jumps. Seeded to 20 like
+    /// `MessagesComponent::visible_rows`.
+    pub visible_rows: Cell<usize>,
     list_state: RefCell<ListState>,
 }
 
@@ -44,6 +60,8 @@ impl FoldersComponent {
         state.select(Some(folder_index));
         Self {
             folder_index,
+            collapsed: HashSet::new(),
+            visible_rows: Cell::new(20),
             list_state: RefCell::new(state),
         }
     }
@@ -61,66 +79,146 @@ impl FoldersComponent {
         0
     }
 
-    fn build_folder_list(folder: &Folder, depth: usize) -> Vec<ListItem<'static>> {
-        let mut items = Vec::new();
-        if depth > 0 {
-            let indent = "  ".repeat(depth - 1);
-            let display_name = folder.get_display_name();
-            items.push(ListItem::new(format!("{}{}", indent, display_name)));
+    /// First folder row drawn in the last frame; recorded for mouse
+    /// hit-testing.
+    pub fn list_offset(&self) -> usize {
+        self.list_state.borrow().offset()
+    }
+
+    /// Render rows for every visible folder. Folders with children get
+    /// a `▾` (expanded) or `▸` (collapsed) marker; leaves get a blank
+    /// slot of the same width so names stay aligned.
+    fn build_folder_list(folder: &Folder, collapsed: &HashSet<PathBuf>) -> Vec<ListItem<'static>> {
+        crate::layout::build_flat_folder_list(folder, 0, collapsed)
+            .into_iter()
+            .map(|(folder, depth)| {
+                let indent = "  ".repeat(depth - 1);
+                let marker = if folder.subfolders.is_empty() {
+                    "  "
+                } else if collapsed.contains(&folder.path) {
+                    "▸ "
+                } else {
+                    "▾ "
+                };
+                ListItem::new(format!("{}{}{}", indent, marker, folder.get_display_name()))
+            })
+            .collect()
+    }
+
+    /// Collapse every folder below the top level that has children,
+    /// leaving the top level open: the default shape of a freshly
+    /// scanned tree, so deep archives start as one row per project.
+    pub fn collapse_nested(&mut self, root: &Folder) {
+        fn walk(folder: &Folder, depth: usize, collapsed: &mut HashSet<PathBuf>) {
+            for sub in &folder.subfolders {
+                if depth >= 1 && !sub.subfolders.is_empty() {
+                    collapsed.insert(sub.path.clone());
+                }
+                walk(sub, depth + 1, collapsed);
+            }
         }
-        for subfolder in folder.get_sorted_subfolders() {
-            items.extend(Self::build_folder_list(subfolder, depth + 1));
+        walk(root, 0, &mut self.collapsed);
+    }
+
+    /// Rows in the pane: the visible folders, then one per
+    /// `[[saved_searches]]` entry.
+    fn row_count(&self, ctx: &Ctx) -> usize {
+        crate::layout::count_visible_folders(&ctx.store.root_folder, &self.collapsed)
+            + ctx.config.saved_searches.len()
+    }
+
+    /// Index into `[[saved_searches]]` of the highlighted row, when it
+    /// is one of the saved searches listed after the folders.
+    pub fn saved_search_at_cursor(&self, root: &Folder) -> Option<usize> {
+        self.folder_index
+            .checked_sub(crate::layout::count_visible_folders(root, &self.collapsed))
+    }
+
+    /// Path of the highlighted folder when it has children to fold.
+    pub(crate) fn parent_at_cursor(&self, root: &Folder) -> Option<PathBuf> {
+        crate::layout::build_flat_folder_list(root, 0, &self.collapsed)
+            .get(self.folder_index)
+            .filter(|(folder, _)| !folder.subfolders.is_empty())
+            .map(|(folder, _)| folder.path.clone())
+    }
+
+    /// Flip the collapse state of the highlighted folder. Folders
+    /// without children are left alone. The highlighted row keeps its
+    /// display index either way — only rows *below* it move.
+    fn toggle_collapse_at_cursor(&mut self, root: &Folder) {
+        if let Some(path) = self.parent_at_cursor(root)
+            && !self.collapsed.remove(&path)
+        {
+            self.collapsed.insert(path);
         }
-        items
     }
-}
 
-/// Count every folder rendered in the pane (root excluded). Same shape as
-/// `input::count_visible_folders` — kept private here to make the bound
-/// check self-contained inside the component.
-fn count_visible_folders(folder: &Folder) -> usize {
-    let mut count = 0;
-    for sub in &folder.subfolders {
-        count += 1 + count_visible_folders(sub);
+    fn set_collapsed_at_cursor(&mut self, root: &Folder, collapse: bool) {
+        if let Some(path) = self.parent_at_cursor(root) {
+            if collapse {
+                self.collapsed.insert(path);
+            } else {
+                self.collapsed.remove(&path);
+            }
+        }
     }
-    count
 }
 
 impl Component for FoldersComponent {
     fn handle_msg(&mut self, msg: &Msg, ctx: &Ctx) -> Vec<Msg> {
         match msg {
-            Msg::FolderMove(Dir::Down) => {
-                let total = count_visible_folders(&ctx.store.root_folder);
-                if self.folder_index + 1 < total {
-                    self.folder_index += 1;
-                }
+            Msg::FolderMove(Dir::Down) if self.folder_index + 1 < self.row_count(ctx) => {
+                self.folder_index += 1;
             }
-            Msg::FolderMove(Dir::Up) => {
-                if self.folder_index > 0 {
-                    self.folder_index -= 1;
-                }
+            Msg::FolderMove(Dir::Up) if self.folder_index > 0 => {
+                self.folder_index -= 1;
+            }
+            Msg::FolderJump(jump) => {
+                let last = self.row_count(ctx).saturating_sub(1);
+                self.folder_index = match jump {
+                    Jump::To(row) => (*row).min(last),
+                    Jump::Last => last,
+                };
+            }
+            Msg::FolderPage(dir, halves) => {
+                let row = dir.paged(self.folder_index, *halves, self.visible_rows.get());
+                return vec![Msg::FolderJump(Jump::To(row))];
             }
             Msg::FolderExitParent => {
                 // Back-navigation collapses the selection to the top of
                 // the (now parent) folder pane.
                 self.folder_index = 0;
             }
+            Msg::FolderToggleCollapse => {
+                self.toggle_collapse_at_cursor(&ctx.store.root_folder);
+            }
+            Msg::FolderSetCollapsed(collapse) => {
+                self.set_collapsed_at_cursor(&ctx.store.root_folder, *collapse);
+            }
             _ => {}
         }
         Vec::new()
     }
 
     fn render(&self, f: &mut Frame, area: Rect, focused: bool, ctx: &Ctx) {
-        let style = if focused {
-            Style::default().fg(ctx.theme.accent)
-        } else {
-            Style::default()
-        };
+        self.visible_rows
+            .set(area.height.saturating_sub(2) as usize);
+        let style = ctx.theme.focus(focused, ctx.theme.accent);
 
+        // With `[accounts.*]` configured, name the account whose tree
+        // is on display.
+        let mut title = match ctx.config.account_for_maildir(&ctx.store.root_folder.path) {
+            Some((_, account)) => format!("Folders - {}", account.name),
+            None => "Folders".to_string(),
+        };
+        match ctx.store.root_folder.total_unread_count() {
+            0 => {}
+            unread => title.push_str(&format!(" ({} unread)", unread)),
+        }
         let block = Block::default()
             .borders(Borders::ALL)
             .style(style)
-            .title("Folders");
+            .title(title);
 
         // The initial folder-structure scan runs off-thread. Until it
         // lands, the store carries no folders; render a splash instead
@@ -138,18 +236,23 @@ impl Component for FoldersComponent {
             return;
         }
 
-        let folder_items = Self::build_folder_list(&ctx.store.root_folder, 0);
+        let mut folder_items = Self::build_folder_list(&ctx.store.root_folder, &self.collapsed);
+        folder_items.extend(
+            ctx.config
+                .saved_searches
+                .iter()
+                .map(|s| ListItem::new(format!("  {} {}", s.icon, s.name))),
+        );
+        let len = folder_items.len();
         let list = List::new(folder_items)
             .block(block)
             .style(style)
-            .highlight_style(
-                Style::default()
-                    .bg(ctx.theme.primary)
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            );
+            .highlight_style(ctx.theme.selection());
 
         let mut state = self.list_state.borrow_mut();
+        let visible = area.height.saturating_sub(2) as usize;
+        let offset = viewport::list_offset(state.offset(), self.folder_index, len, visible);
+        *state.offset_mut() = offset;
         state.select(Some(self.folder_index));
         f.render_stateful_widget(list, area, &mut *state);
     }
@@ -157,9 +260,10 @@ impl Component for FoldersComponent {
     fn on_key(&mut self, key: KeyEvent, ctx: &Ctx) -> Option<Msg> {
         // Action keys (including arrow `Up`/`Down`) resolve through the
         // central `AppRoot::action_to_msg` keymap dispatch. This handler
-        // owns only context-dependent `l` (select-into vs. view-advance)
-        // — the keymap maps `Action::ViewNext` to `None` in the Folders
-        // pane so this arm gets to make the call.
+        // owns context-dependent `l` (select-into vs. view-advance) —
+        // the keymap maps `Action::ViewNext` to `None` in the Folders
+        // pane so this arm gets to make the call — and the unbound
+        // `Left`/`Right` arrows, which fold the tree like a file browser.
         if !key.modifiers.is_empty() {
             return None;
         }
@@ -168,12 +272,15 @@ impl Component for FoldersComponent {
                 let path = crate::layout::get_folder_path_from_display_index(
                     &ctx.store.root_folder,
                     self.folder_index,
+                    &self.collapsed,
                 );
                 match path {
                     Some(p) if p == ctx.store.current_folder => Some(Msg::ViewNext),
                     _ => Some(Msg::FolderEnter),
                 }
             }
+            KeyCode::Left => Some(Msg::FolderSetCollapsed(true)),
+            KeyCode::Right => Some(Msg::FolderSetCollapsed(false)),
             _ => None,
         }
     }
@@ -206,6 +313,47 @@ mod tests {
         }
     }
 
+    fn title_row(store: &EmailStore, config: &Config) -> String {
+        let theme = Theme::default();
+        let backend = ratatui::backend::TestBackend::new(40, 6);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let comp = FoldersComponent::with_index(0);
+        terminal
+            .draw(|f| comp.render(f, f.area(), false, &ctx(&theme, config, store)))
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        (0..buf.area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn title_names_the_account_on_display() {
+        let store = store_with_folders(&["INBOX"]);
+        let mut config = Config::default();
+        assert!(title_row(&store, &config).contains("Folders─"));
+
+        config.accounts.insert(
+            "work".to_string(),
+            crate::config::AccountConfig {
+                name: "Work".to_string(),
+                email: "me@work.test".to_string(),
+                maildir_path: PathBuf::from("/tmp"),
+                smtp_command: None,
+                signature: None,
+            },
+        );
+        assert!(title_row(&store, &config).contains("Folders - Work"));
+    }
+
+    #[test]
+    fn title_counts_unread_mail_across_the_tree() {
+        let mut store = store_with_nested_folder();
+        store.root_folder.subfolders[0].subfolders[0].unread_count = 4;
+        store.root_folder.subfolders[1].unread_count = 8;
+        assert!(title_row(&store, &Config::default()).contains("Folders (12 unread)"));
+    }
+
     #[test]
     fn auto_select_finds_inbox_in_sorted_order() {
         // Inserted in non-alpha order; sort order should still surface INBOX.
@@ -280,6 +428,61 @@ mod tests {
         assert_eq!(comp.on_key(l, &ctx), Some(Msg::ViewNext));
     }
 
+    /// `A` (with child `A/x`) above leaf `B`: display order A, A/x, B.
+    fn store_with_nested_folder() -> EmailStore {
+        let mut store = EmailStore::new(PathBuf::from("/tmp"));
+        let mut a = Folder::new("A".to_string(), PathBuf::from("/tmp/A"));
+        a.add_subfolder(Folder::new("x".to_string(), PathBuf::from("/tmp/A/x")));
+        store.root_folder.add_subfolder(a);
+        store
+            .root_folder
+            .add_subfolder(Folder::new("B".to_string(), PathBuf::from("/tmp/B")));
+        store
+    }
+
+    #[test]
+    fn toggle_collapse_hides_children_from_navigation() {
+        let store = store_with_nested_folder();
+        let (theme, config) = (Theme::default(), Config::default());
+        let ctx = ctx(&theme, &config, &store);
+        let mut comp = FoldersComponent::with_index(0);
+
+        comp.handle_msg(&Msg::FolderToggleCollapse, &ctx);
+        assert!(comp.collapsed.contains(&PathBuf::from("/tmp/A")));
+        // Collapsed: A, B — one Down lands on B, a second is clamped.
+        comp.handle_msg(&Msg::FolderMove(Dir::Down), &ctx);
+        comp.handle_msg(&Msg::FolderMove(Dir::Down), &ctx);
+        assert_eq!(comp.folder_index, 1);
+        let path = crate::layout::get_folder_path_from_display_index(
+            &store.root_folder,
+            comp.folder_index,
+            &comp.collapsed,
+        );
+        assert_eq!(path, Some(vec![1]), "index 1 must map to B, not A/x");
+    }
+
+    #[test]
+    fn toggle_collapse_twice_expands_again() {
+        let store = store_with_nested_folder();
+        let (theme, config) = (Theme::default(), Config::default());
+        let ctx = ctx(&theme, &config, &store);
+        let mut comp = FoldersComponent::with_index(0);
+        comp.handle_msg(&Msg::FolderToggleCollapse, &ctx);
+        comp.handle_msg(&Msg::FolderToggleCollapse, &ctx);
+        assert!(comp.collapsed.is_empty());
+    }
+
+    #[test]
+    fn toggle_collapse_on_leaf_is_noop() {
+        let store = store_with_nested_folder();
+        let (theme, config) = (Theme::default(), Config::default());
+        let ctx = ctx(&theme, &config, &store);
+        // Index 2 is the leaf `B`.
+        let mut comp = FoldersComponent::with_index(2);
+        comp.handle_msg(&Msg::FolderToggleCollapse, &ctx);
+        assert!(comp.collapsed.is_empty());
+    }
+
     #[test]
     fn on_key_ignores_modified_keys() {
         let store = store_with_folders(&["A"]);
@@ -289,4 +492,91 @@ mod tests {
         let alt_j = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::ALT);
         assert_eq!(comp.on_key(alt_j, &ctx), None);
     }
+
+    #[test]
+    fn collapse_nested_folds_deep_parents_and_arrows_unfold_them() {
+        // Archive/2023/{proj-a, proj-b}: Archive stays open, 2023 folds.
+        let mut store = EmailStore::new(PathBuf::from("/tmp"));
+        let mut archive = Folder::new("Archive".to_string(), PathBuf::from("/tmp/Archive"));
+        let mut year = Folder::new("2023".to_string(), PathBuf::from("/tmp/Archive/2023"));
+        for p in ["proj-a", "proj-b"] {
+            year.add_subfolder(Folder::new(
+                p.to_string(),
+                PathBuf::from(format!("/tmp/Archive/2023/{}", p)),
+            ));
+        }
+        archive.add_subfolder(year);
+        store.root_folder.add_subfolder(archive);
+        let (theme, config) = (Theme::default(), Config::default());
+        let ctx = ctx(&theme, &config, &store);
+
+        let mut comp = FoldersComponent::with_index(1);
+        comp.collapse_nested(&store.root_folder);
+        assert_eq!(
+            comp.collapsed,
+            HashSet::from([PathBuf::from("/tmp/Archive/2023")])
+        );
+        let count = |c: &FoldersComponent| {
+            crate::layout::count_visible_folders(&store.root_folder, &c.collapsed)
+        };
+        assert_eq!(count(&comp), 2);
+
+        let right = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+        let msg = comp.on_key(right, &ctx).unwrap();
+        comp.handle_msg(&msg, &ctx);
+        assert_eq!(count(&comp), 4);
+        comp.handle_msg(&msg, &ctx);
+        assert_eq!(count(&comp), 4, "Right on an open folder leaves it open");
+
+        let left = KeyEvent::new(KeyCode::Left, KeyModifiers::NONE);
+        let msg = comp.on_key(left, &ctx).unwrap();
+        comp.handle_msg(&msg, &ctx);
+        assert_eq!(count(&comp), 2);
+        assert_eq!(
+            crate::layout::get_folder_path_from_display_index(
+                &store.root_folder,
+                1,
+                &comp.collapsed
+            ),
+            Some(vec![0, 0])
+        );
+    }
+
+    #[test]
+    fn left_folds_work_and_right_unfolds_it_in_the_fixture_tree() {
+        let fixture = crate::test_fixtures::TestMailDir::new();
+        let mut store = EmailStore::new(fixture.root_path.clone());
+        store.root_folder = crate::maildir::MaildirScanner::new(fixture.root_path.clone())
+            .scan()
+            .unwrap();
+        let (theme, config) = (Theme::default(), Config::default());
+        let ctx = ctx(&theme, &config, &store);
+        let count = |c: &FoldersComponent| {
+            crate::layout::count_visible_folders(&store.root_folder, &c.collapsed)
+        };
+
+        let work = (0..crate::layout::count_visible_folders(&store.root_folder, &HashSet::new()))
+            .find(|&i| {
+                crate::layout::get_folder_path_from_display_index(
+                    &store.root_folder,
+                    i,
+                    &HashSet::new(),
+                )
+                .and_then(|p| store.get_folder_at_path(&p).map(|f| f.name.clone()))
+                    == Some("Work".to_string())
+            })
+            .expect("fixture has Work");
+        let mut comp = FoldersComponent::with_index(work);
+        let expanded = count(&comp);
+
+        let left = KeyEvent::new(KeyCode::Left, KeyModifiers::NONE);
+        let msg = comp.on_key(left, &ctx).unwrap();
+        comp.handle_msg(&msg, &ctx);
+        assert_eq!(count(&comp), expanded - 2, "Projects and Meetings hidden");
+
+        let right = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+        let msg = comp.on_key(right, &ctx).unwrap();
+        comp.handle_msg(&msg, &ctx);
+        assert_eq!(count(&comp), expanded);
+    }
 }
@@ -1,21 +1,24 @@
 // `?` help overlay (vu-dzm).
 //
-// Renders a centered, sectioned cheatsheet of every resolved keymap
-// binding. Bindings are grouped by [`PaneScope`] (Global, Folders,
-// Messages, Content, Compose) so the user can scan for the pane they're
-// in. Each row is `<key>  <description>`, with the key column padded to
-// a uniform width so the descriptions line up.
+// Renders a centered table of every resolved keymap binding. Bindings
+// are grouped by [`PaneScope`] (Global, Folders, Messages, Content,
+// Compose) so the user can scan for the pane they're in; each row is
+// `<key> | <description>`. `j` / `k` / `PageUp` / `PageDown` scroll the
+// table on short terminals, and `/` starts a filter that narrows it to
+// rows whose key or description contains the typed text. Any other key
+// closes the overlay.
 //
 // The data comes from [`Keymap::bindings`] + [`Action::scope`] +
 // [`Action::description`] — there is no hand-maintained string list, so
 // the overlay can never drift from the live keymap.
 
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
 };
 
 use crate::keymap::{Action, Keymap, PaneScope};
@@ -39,77 +42,227 @@ pub fn centered_overlay_rect(area: Rect) -> Rect {
     }
 }
 
-/// Build the lines rendered inside the help overlay. Public for testing
-/// (snapshot + unit tests assert the line layout directly without
-/// needing a `Frame`).
-pub fn help_lines(keymap: &Keymap, theme: &Theme) -> Vec<Line<'static>> {
-    let bindings: Vec<(Action, String)> = keymap
-        .bindings()
-        .map(|(a, k)| (a, k.to_string()))
-        .collect();
+/// One binding as listed in the help overlay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpRow {
+    /// Pane the binding is active in.
+    pub scope: PaneScope,
+    /// Key string exactly as configured (`a`, `gr`, `Alt+c`, `Down`).
+    pub key: String,
+    /// [`Action::description`] of the bound action.
+    pub description: &'static str,
+}
 
-    let key_width = bindings
+/// Bindings shown in the help overlay, in [`PaneScope::all`] order,
+/// keeping only rows whose key or description contains `filter`
+/// (case-insensitive). An empty filter keeps everything. Public for
+/// testing without a `Frame`.
+pub fn help_rows(keymap: &Keymap, filter: &str) -> Vec<HelpRow> {
+    let needle = filter.to_lowercase();
+    let bindings: Vec<(Action, &str)> = keymap.bindings().collect();
+    PaneScope::all()
         .iter()
-        .map(|(_, k)| k.chars().count())
-        .max()
-        .unwrap_or(6)
-        .max(6);
-
-    let mut lines: Vec<Line<'static>> = Vec::new();
-    lines.push(Line::from(Span::styled(
-        "Vulthor — keyboard bindings  (press ?, Esc, or q to close)",
-        Style::default()
-            .fg(theme.cyan)
-            .add_modifier(Modifier::BOLD),
-    )));
-
-    for scope in PaneScope::all() {
-        let rows: Vec<&(Action, String)> = bindings
-            .iter()
-            .filter(|(a, _)| a.scope() == *scope)
-            .collect();
-        lines.push(Line::from(""));
-        lines.push(Line::from(Span::styled(
-            scope.title().to_string(),
-            Style::default()
-                .fg(theme.accent)
-                .add_modifier(Modifier::BOLD),
-        )));
-        if rows.is_empty() {
-            lines.push(Line::from(Span::raw("  (no scope-specific bindings)")));
+        .flat_map(|scope| {
+            bindings
+                .iter()
+                .filter(move |(a, _)| a.scope() == *scope)
+                .map(move |(action, key)| HelpRow {
+                    scope: *scope,
+                    key: key.to_string(),
+                    description: action.description(),
+                })
+        })
+        .filter(|row| {
+            needle.is_empty()
+                || row.key.to_lowercase().contains(&needle)
+                || row.description.to_lowercase().contains(&needle)
+        })
+        .collect()
+}
+
+/// Scroll and filter state of the open help overlay.
+#[derive(Debug)]
+pub struct HelpView {
+    /// Narrows the table; see [`help_rows`].
+    pub filter: String,
+    /// Set by `/`: keys edit [`Self::filter`] until Enter or Esc.
+    pub editing: bool,
+    /// First table row shown.
+    pub scroll: usize,
+    /// Table rows that fit at the last render; sizes `PageUp` /
+    /// `PageDown` and bounds the scroll.
+    pub visible_rows: std::cell::Cell<usize>,
+}
+
+impl Default for HelpView {
+    fn default() -> Self {
+        Self {
+            filter: String::new(),
+            editing: false,
+            scroll: 0,
+            visible_rows: std::cell::Cell::new(20),
         }
-        for (action, key) in rows {
-            let padded_key = format!("{:<width$}", key, width = key_width);
-            lines.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled(
-                    padded_key,
-                    Style::default()
-                        .fg(theme.cyan)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::raw("  "),
-                Span::raw(action.description().to_string()),
-            ]));
+    }
+}
+
+impl HelpView {
+    /// Apply a key pressed while the overlay is open. Returns `false`
+    /// when the key closes it.
+    pub fn handle_key(&mut self, key: KeyEvent, keymap: &Keymap) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        if self.editing {
+            match key.code {
+                KeyCode::Esc => {
+                    self.editing = false;
+                    self.filter.clear();
+                }
+                KeyCode::Enter => self.editing = false,
+                KeyCode::Backspace => {
+                    self.filter.pop();
+                }
+                KeyCode::Char(c) if !ctrl => self.filter.push(c),
+                _ => return true,
+            }
+            self.scroll = 0;
+            return true;
         }
+
+        let page = self.visible_rows.get().max(1);
+        let last = table_len(&help_rows(keymap, &self.filter)).saturating_sub(page);
+        self.scroll = match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.scroll + 1,
+            KeyCode::Char('k') | KeyCode::Up => self.scroll.saturating_sub(1),
+            KeyCode::PageDown => self.scroll + page,
+            KeyCode::Char('d') if ctrl => self.scroll + page / 2,
+            KeyCode::PageUp => self.scroll.saturating_sub(page),
+            KeyCode::Char('u') if ctrl => self.scroll.saturating_sub(page / 2),
+            KeyCode::Char('g') | KeyCode::Home => 0,
+            KeyCode::Char('G') | KeyCode::End => last,
+            KeyCode::Char('/') => {
+                self.editing = true;
+                return true;
+            }
+            _ => return false,
+        }
+        .min(last);
+        true
     }
+}
 
-    lines
+/// Number of table lines `rows` renders as, section rows included.
+fn table_len(rows: &[HelpRow]) -> usize {
+    let mut sections = rows.iter().map(|r| r.scope).collect::<Vec<_>>();
+    sections.dedup();
+    (rows.len() + sections.len()).max(1)
 }
 
-/// Draw the help overlay into `area`. `Clear` wipes whatever was
-/// painted under the overlay so the cheatsheet renders cleanly on top
-/// of the normal pane layout.
-pub fn render_help_overlay(f: &mut Frame, area: Rect, keymap: &Keymap, theme: &Theme) {
+/// Table rows for `rows`: a bold section row per scope followed by its
+/// `key | description` rows.
+fn table_rows(rows: &[HelpRow], theme: &Theme) -> Vec<Row<'static>> {
+    let mut out = Vec::new();
+    let mut scope = None;
+    for row in rows {
+        if scope != Some(row.scope) {
+            scope = Some(row.scope);
+            out.push(Row::new(vec![Cell::from(Span::styled(
+                row.scope.title(),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ))]));
+        }
+        out.push(Row::new(vec![
+            Cell::from(Span::styled(
+                format!("  {}", row.key),
+                Style::default().fg(theme.cyan).add_modifier(Modifier::BOLD),
+            )),
+            Cell::from(row.description),
+        ]));
+    }
+    if out.is_empty() {
+        out.push(Row::new(vec![Cell::from("  (no bindings match)")]));
+    }
+    out
+}
+
+/// Draw the help overlay into `area` as `view` has it scrolled and
+/// filtered. `Clear` wipes whatever was painted under the overlay so
+/// the table renders cleanly on top of the normal pane layout. The
+/// bottom line echoes the filter and shows which rows are in view.
+pub fn render_help_overlay(
+    f: &mut Frame,
+    area: Rect,
+    keymap: &Keymap,
+    view: &HelpView,
+    theme: &Theme,
+) {
     let rect = centered_overlay_rect(area);
     let block = Block::default()
         .borders(Borders::ALL)
         .style(Style::default().fg(theme.cyan))
-        .title(" Help ");
-    let lines = help_lines(keymap, theme);
-    let paragraph = Paragraph::new(lines).block(block);
+        .title(" Help — j/k scroll, / filter, any other key closes ");
+    let inner = block.inner(rect);
     f.render_widget(Clear, rect);
-    f.render_widget(paragraph, rect);
+    f.render_widget(block, rect);
+
+    let [table_area, filter_area] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .areas(inner);
+
+    let rows = help_rows(keymap, &view.filter);
+    let key_width = rows
+        .iter()
+        .map(|r| r.key.chars().count() + 2)
+        .chain(PaneScope::all().iter().map(|s| s.title().len()))
+        .max()
+        .unwrap_or(8) as u16;
+    // One line goes to the header row.
+    let page = table_area.height.saturating_sub(1) as usize;
+    view.visible_rows.set(page);
+    let total = table_len(&rows);
+    let first = view.scroll.min(total.saturating_sub(page));
+    let table = Table::new(
+        table_rows(&rows, theme).into_iter().skip(first).take(page),
+        [Constraint::Length(key_width), Constraint::Min(0)],
+    )
+    .header(
+        Row::new(vec!["Key", "Description"]).style(
+            Style::default()
+                .fg(theme.gray_light)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .column_spacing(2);
+    f.render_widget(table, table_area);
+
+    let mut prompt = if view.editing || !view.filter.is_empty() {
+        vec![
+            Span::styled("Filter: ", Style::default().fg(theme.gray_light)),
+            Span::styled(view.filter.clone(), Style::default().fg(theme.accent)),
+        ]
+    } else {
+        vec![Span::styled(
+            "/ to filter",
+            Style::default().fg(theme.gray_light),
+        )]
+    };
+    if view.editing {
+        prompt.push(Span::styled("▏", Style::default().fg(theme.accent)));
+    }
+    let position = format!(
+        "{}-{}/{}",
+        (first + 1).min(total),
+        (first + page).min(total),
+        total
+    );
+    f.render_widget(Paragraph::new(Line::from(prompt)), filter_area);
+    f.render_widget(
+        Paragraph::new(position)
+            .style(Style::default().fg(theme.gray_light))
+            .alignment(ratatui::layout::Alignment::Right),
+        filter_area,
+    );
 }
 
 #[cfg(test)]
@@ -122,35 +275,25 @@ mod tests {
         resolve_keymap(&BTreeMap::new()).expect("defaults resolve")
     }
 
-    fn rendered_text(keymap: &Keymap) -> String {
-        help_lines(keymap, &Theme::default())
-            .iter()
-            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect::<String>())
-            .collect::<Vec<_>>()
-            .join("\n")
-    }
-
     #[test]
-    fn help_lines_include_every_pane_scope_header_with_bindings() {
-        let keymap = defaults();
-        let text = rendered_text(&keymap);
+    fn help_rows_cover_every_pane_scope() {
+        let rows = help_rows(&defaults(), "");
         for scope in PaneScope::all() {
             assert!(
-                text.contains(scope.title()),
-                "missing section header `{}` in:\n{text}",
+                rows.iter().any(|r| r.scope == *scope),
+                "no rows for scope `{}`",
                 scope.title()
             );
         }
     }
 
     #[test]
-    fn help_lines_include_every_resolved_action_description() {
-        let keymap = defaults();
-        let text = rendered_text(&keymap);
+    fn help_rows_include_every_resolved_action_description() {
+        let rows = help_rows(&defaults(), "");
         for action in Action::all() {
             assert!(
-                text.contains(action.description()),
-                "missing description `{}` for {:?} in:\n{text}",
+                rows.iter().any(|r| r.description == action.description()),
+                "missing description `{}` for {:?}",
                 action.description(),
                 action
             );
@@ -158,29 +301,213 @@ mod tests {
     }
 
     #[test]
-    fn help_lines_show_user_override_keystring() {
+    fn help_rows_include_every_legacy_cheatsheet_key() {
+        // Keys the old hard-coded help screen listed; the generated
+        // table must still carry each of them.
+        let rows = help_rows(&defaults(), "");
+        for key in [
+            "j",
+            "k",
+            "Down",
+            "Up",
+            "PageDown",
+            "PageUp",
+            "Tab",
+            "BackTab",
+            "h",
+            "l",
+            "Enter",
+            "Backspace",
+            "a",
+            "d",
+            "s",
+            "F",
+            "m",
+            "U",
+            "u",
+            "r",
+            "gr",
+            "f",
+            "R",
+            "/",
+            "Alt+c",
+            "?",
+            "q",
+        ] {
+            assert!(
+                rows.iter().any(|r| r.key == key),
+                "help table missing key `{}`",
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn help_rows_filter_by_key_or_description() {
+        let keymap = defaults();
+        let archive = help_rows(&keymap, "ARCHIVE");
+        assert!(!archive.is_empty());
+        assert!(
+            archive
+                .iter()
+                .all(|r| r.description.to_lowercase().contains("archive"))
+        );
+        let alt = help_rows(&keymap, "alt+");
+        assert!(alt.iter().any(|r| r.key == "Alt+c"));
+        assert!(alt.iter().all(|r| r.key.to_lowercase().contains("alt+")));
+        assert!(help_rows(&keymap, "no such binding").is_empty());
+    }
+
+    #[test]
+    fn help_rows_show_user_override_keystring() {
         // Rebinding archive → e must surface `e` (not the default `a`)
         // in the help overlay. The overlay is driven entirely off the
         // resolved keymap, so this is a regression test for the
         // bindings()-passthrough.
         let mut overrides = BTreeMap::new();
-        overrides.insert("archive".to_string(), "e".to_string());
-        overrides.insert("draft_edit".to_string(), "E".to_string());
+        overrides.insert("archive".to_string(), "e".into());
+        overrides.insert("draft_edit".to_string(), "E".into());
         let keymap = resolve_keymap(&overrides).unwrap();
-        let text = rendered_text(&keymap);
-        // The Archive row prints the user-chosen key.
+        let archive: Vec<HelpRow> = help_rows(&keymap, "")
+            .into_iter()
+            .filter(|r| r.description == Action::Archive.description())
+            .collect();
         assert!(
-            text.lines()
-                .any(|l| l.contains("Archive email") && l.contains('e')),
-            "Archive row should show user-chosen `e`:\n{text}"
+            archive.iter().any(|r| r.key == "e"),
+            "Archive row should show user-chosen `e`: {archive:?}"
         );
-        // The pre-override default `a` no longer carries Archive.
         assert!(
-            !text
-                .lines()
-                .any(|l| l.contains("Archive email") && l.contains(" a ")),
-            "Archive row must not still print the retired `a`:\n{text}"
+            archive.iter().all(|r| r.key != "a"),
+            "Archive row must not still print the retired `a`: {archive:?}"
+        );
+    }
+
+    #[test]
+    fn overlay_renders_table_and_filter_prompt() {
+        let keymap = defaults();
+        let view = HelpView {
+            filter: "arch".to_string(),
+            ..HelpView::default()
+        };
+        let backend = ratatui::backend::TestBackend::new(80, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_help_overlay(f, f.area(), &keymap, &view, &Theme::default()))
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        let text: String = (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Description"), "{text}");
+        assert!(text.contains(Action::Archive.description()), "{text}");
+        assert!(!text.contains(Action::Quit.description()), "{text}");
+        assert!(text.contains("Filter: arch"), "{text}");
+    }
+
+    #[test]
+    fn overlay_lists_the_key_a_config_remaps_quit_to() {
+        let config: crate::config::Config =
+            toml::from_str("maildir_path = \"/tmp\"\n[keybindings]\nquit = \"x\"\n").unwrap();
+        let keymap = resolve_keymap(&config.keybindings.inner).unwrap();
+        let view = HelpView {
+            filter: "quit vulthor".to_string(),
+            ..HelpView::default()
+        };
+        let backend = ratatui::backend::TestBackend::new(80, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_help_overlay(f, f.area(), &keymap, &view, &Theme::default()))
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        let quit_rows: Vec<String> = (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .filter(|line| line.contains(Action::Quit.description()))
+            .collect();
+        assert_eq!(quit_rows.len(), 1, "{quit_rows:?}");
+        let key = quit_rows[0]
+            .trim_matches(|c: char| c == '│' || c.is_whitespace())
+            .split_whitespace()
+            .next();
+        assert_eq!(key, Some("x"), "{quit_rows:?}");
+    }
+
+    #[test]
+    fn help_view_scrolls_within_the_table_and_slash_edits_the_filter() {
+        let keymap = defaults();
+        let total = table_len(&help_rows(&keymap, ""));
+        let mut view = HelpView::default();
+        view.visible_rows.set(10);
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        assert!(view.handle_key(key(KeyCode::Char('k')), &keymap));
+        assert_eq!(view.scroll, 0);
+        view.handle_key(key(KeyCode::Char('j')), &keymap);
+        view.handle_key(key(KeyCode::PageDown), &keymap);
+        assert_eq!(view.scroll, 11);
+        view.handle_key(key(KeyCode::End), &keymap);
+        assert_eq!(view.scroll, total - 10);
+        view.handle_key(key(KeyCode::Down), &keymap);
+        assert_eq!(view.scroll, total - 10, "never past the last page");
+        view.handle_key(key(KeyCode::PageUp), &keymap);
+        assert_eq!(view.scroll, total - 20);
+
+        view.handle_key(key(KeyCode::Char('/')), &keymap);
+        for c in "qj".chars() {
+            assert!(view.handle_key(key(KeyCode::Char(c)), &keymap));
+        }
+        assert_eq!(view.filter, "qj");
+        assert_eq!(view.scroll, 0);
+        view.handle_key(key(KeyCode::Backspace), &keymap);
+        view.handle_key(key(KeyCode::Enter), &keymap);
+        assert!(!view.editing);
+        assert_eq!(view.filter, "q");
+        assert!(
+            !view.handle_key(key(KeyCode::Char('x')), &keymap),
+            "any other key closes"
         );
+
+        view.handle_key(key(KeyCode::Char('/')), &keymap);
+        view.handle_key(key(KeyCode::Esc), &keymap);
+        assert!(view.filter.is_empty() && !view.editing);
+    }
+
+    #[test]
+    fn overlay_scrolls_to_later_scopes_on_a_short_terminal() {
+        let keymap = defaults();
+        let mut view = HelpView::default();
+        let draw = |view: &HelpView| {
+            let backend = ratatui::backend::TestBackend::new(80, 16);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|f| render_help_overlay(f, f.area(), &keymap, view, &Theme::default()))
+                .unwrap();
+            let buf = terminal.backend().buffer().clone();
+            (0..buf.area.height)
+                .map(|y| {
+                    (0..buf.area.width)
+                        .map(|x| buf[(x, y)].symbol())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let last_scope = PaneScope::all().last().unwrap().title();
+        let top = draw(&view);
+        assert!(!top.contains(last_scope), "{top}");
+        assert!(top.contains("/ to filter"), "{top}");
+
+        view.handle_key(KeyEvent::new(KeyCode::End, KeyModifiers::NONE), &keymap);
+        let bottom = draw(&view);
+        assert!(bottom.contains(last_scope), "{bottom}");
     }
 
     #[test]
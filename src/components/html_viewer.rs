@@ -8,6 +8,9 @@
 //! Browser detection prefers Chromium-class browsers (`--app=<URL>`)
 //! over Firefox (`--kiosk <URL>`), falling back to `xdg-open <URL>`.
 //! Detection is split from launch so tests can stub `PATH` lookups.
+//!
+//! `O` skips all of that and hands the URL to the platform's default
+//! browser ([`open_in_default_browser`]); that window is not tracked.
 
 use std::process::{Child, Command, Stdio};
 use std::thread;
@@ -81,6 +84,48 @@ pub fn launch(browser: Browser, url: &str) -> std::io::Result<Child> {
         .spawn()
 }
 
+/// URL of the embedded web server for a `[web].bind` / port pair. An
+/// unspecified bind (`0.0.0.0`, `::`) is not browsable, so it maps to
+/// loopback; IPv6 literals get brackets.
+pub fn server_url(bind: &str, port: u16) -> String {
+    let host = match bind {
+        "" | "0.0.0.0" => "127.0.0.1".to_string(),
+        "::" | "[::]" => "[::1]".to_string(),
+        ip if ip.contains(':') && !ip.starts_with('[') => format!("[{}]", ip),
+        host => host.to_string(),
+    };
+    format!("http://{}:{}/", host, port)
+}
+
+/// The platform's "open with the default application" command and
+/// the arguments that precede the URL.
+fn system_opener() -> (&'static str, &'static [&'static str]) {
+    if cfg!(target_os = "macos") {
+        ("open", &[])
+    } else if cfg!(windows) {
+        // `start`'s first quoted argument is the window title.
+        ("cmd", &["/C", "start", ""])
+    } else {
+        ("xdg-open", &[])
+    }
+}
+
+/// Open `url` in the user's default browser. The opener usually exits
+/// as soon as it has handed the URL off; a detached thread reaps it so
+/// it does not linger as a zombie.
+pub fn open_in_default_browser(url: &str) -> std::io::Result<()> {
+    let (program, args) = system_opener();
+    let mut child = Command::new(program)
+        .args(args)
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    thread::spawn(move || child.wait());
+    Ok(())
+}
+
 /// Ask `child` to exit gracefully, escalate to `kill -9` if it does
 /// not within `timeout`. Sends SIGTERM via `kill(1)` to avoid pulling
 /// in `libc` for one signal. Always reaps the child so it does not
@@ -169,6 +214,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn server_url_maps_unspecified_binds_to_loopback() {
+        assert_eq!(server_url("127.0.0.1", 8080), "http://127.0.0.1:8080/");
+        assert_eq!(server_url("0.0.0.0", 9090), "http://127.0.0.1:9090/");
+        assert_eq!(server_url("::", 9090), "http://[::1]:9090/");
+        assert_eq!(server_url("fe80::1", 80), "http://[fe80::1]:80/");
+        assert_eq!(server_url("mail.local", 80), "http://mail.local:80/");
+    }
+
     /// `terminate` must reap a long-running child within the timeout.
     /// We spawn `sleep 60` as a stand-in for a real browser process,
     /// then call terminate and check the second `try_wait` reports
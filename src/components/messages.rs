@@ -14,10 +14,12 @@
 // is written exclusively by `render` from the live pane area.
 
 use std::cell::{Cell, RefCell};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, TimeZone};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     Frame,
     layout::Rect,
@@ -28,17 +30,47 @@ use ratatui::{
 use unicode_width::UnicodeWidthStr;
 
 use crate::classifier::{Classifier, NoopClassifier, suggestion_glyph};
-use crate::config::AiConfig;
-use crate::email::{DraftInfo, Email, Folder};
+use crate::config::{AiConfig, ColumnConfig, ColumnWidth, Config, DateStyle, SenderDisplay};
+use crate::email::{DraftInfo, Email, Folder, FolderRole, Priority};
 use crate::theme::{Theme, VulthorTheme};
+use crate::threading;
+use crate::util::parse_flexible_date;
 
-use super::{Component, Ctx, Dir, Msg};
+use super::{Component, Ctx, Dir, Jump, Msg, viewport};
 
 /// How many rows past the visible tail we look ahead before asking the
 /// store for more headers. Matches the legacy `index + 5 >= len` test
 /// in `EmailStore::load_more_messages_if_needed`.
 const SCROLL_LOOKAHEAD: usize = 5;
 
+/// Deepest reply level that still indents further, so a long thread
+/// doesn't push its subjects off the row.
+const MAX_THREAD_INDENT: usize = 6;
+
+// Message-list column widths. The `unread` gutter is the dot and `*`
+// tag mark, the spam / corrupt mark, then the draft (`✏`/`⏰`) and AI
+// chip slots, each reserved even when empty so the columns after it
+// stay vertically aligned.
+const SPAM_WIDTH: usize = 2;
+const CHIP_WIDTH: usize = 2;
+const AI_CHIP_WIDTH: usize = 2;
+const GUTTER_WIDTH: usize = 2 + SPAM_WIDTH + CHIP_WIDTH + AI_CHIP_WIDTH;
+/// Star and high-priority `!`.
+const FLAGS_WIDTH: usize = 2;
+/// Paperclip plus a space.
+const ATTACHMENT_WIDTH: usize = 3;
+/// `date_style = "relative"` labels are right-aligned to this width
+/// (`11mo`).
+const RELATIVE_DATE_WIDTH: usize = 4;
+/// Fits `1023.9 KB`.
+const SIZE_WIDTH: usize = 9;
+/// Spaces after each text column but the last.
+const TEXT_COLUMN_GAP: usize = 2;
+/// Cells left free at the right edge of a row.
+const RIGHT_MARGIN: usize = 4;
+/// Ahead of a draft's subject, taken out of the subject's width.
+const DRAFT_BADGE: &str = "[D] ";
+
 /// Messages pane state. Owns the email cursor, the
 /// remembered-cursor handoff slot used across pane focus changes,
 /// and a `Cell` mirroring the last-rendered row count so handle_msg
@@ -51,6 +83,24 @@ pub struct MessagesComponent {
     /// Restored on `FoldersBlur`; `None` if focus has never settled
     /// here yet.
     pub remembered_email_index: Option<usize>,
+    /// Messages tagged for a bulk action (`Space` / `V`), by file path
+    /// so the tags stay on the right rows when the folder is re-sorted
+    /// or reloaded. Cleared on folder change, by `Esc` / `:untag-all`,
+    /// and after a bulk action runs. Empty means actions target the
+    /// cursor row.
+    pub tagged: HashSet<PathBuf>,
+    /// Row of the last `Space` toggle — the fixed end of a `V` range.
+    select_anchor: Option<usize>,
+    /// Text typed into the in-folder search prompt; `Some` while the
+    /// prompt is open (`/` … `Enter`).
+    pub find_input: Option<String>,
+    /// Committed search term; `n` / `N` step through its matches.
+    pub find_query: Option<String>,
+    /// Rows matching the prompt text or the committed term, ascending.
+    /// Highlighted in the list.
+    find_matches: Vec<usize>,
+    /// Cursor when the prompt opened; `Esc` returns here.
+    find_origin: usize,
     /// Number of email rows the pane could display at its last render.
     /// Set by `render_with_folder` from the pane area; read by AppRoot
     /// to size the headers-load chunk. Seeded to 20 (same default as
@@ -79,6 +129,12 @@ impl MessagesComponent {
         Self {
             email_index: 0,
             remembered_email_index: None,
+            tagged: HashSet::new(),
+            select_anchor: None,
+            find_input: None,
+            find_query: None,
+            find_matches: Vec::new(),
+            find_origin: 0,
             visible_rows: Cell::new(20),
             list_state: RefCell::new(ListState::default()),
             classifier: Arc::new(NoopClassifier),
@@ -95,6 +151,16 @@ impl MessagesComponent {
         self.confidence_threshold = threshold;
     }
 
+    /// Rows of `emails` whose messages are tagged, ascending.
+    pub fn tagged_rows(&self, emails: &[Email]) -> Vec<usize> {
+        emails
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| self.tagged.contains(&e.file_path))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
     /// Read-only access to the installed classifier — AppRoot calls
     /// `self.messages.classifier()` when resolving `Action::AcceptSuggestion`
     /// so both the chip and the accept-key dispatch use the same instance.
@@ -109,6 +175,35 @@ impl MessagesComponent {
         self.confidence_threshold
     }
 
+    /// Close the search prompt and drop the committed term.
+    fn clear_find(&mut self) {
+        self.find_input = None;
+        self.find_query = None;
+        self.find_matches.clear();
+    }
+
+    /// Move the cursor through `MessageJump` so AppRoot syncs the
+    /// store's selection. Empty when already on `row`.
+    fn jump_to(&self, row: usize) -> Vec<Msg> {
+        if row == self.email_index {
+            Vec::new()
+        } else {
+            vec![Msg::MessageJump(Jump::To(row))]
+        }
+    }
+
+    /// Screen lines per list entry: two under `list_preview` (the
+    /// preview row), one otherwise.
+    pub fn row_height(config: &Config) -> usize {
+        if config.list_preview { 2 } else { 1 }
+    }
+
+    /// First email row drawn in the last frame; recorded for mouse
+    /// hit-testing.
+    pub fn list_offset(&self) -> usize {
+        self.list_state.borrow().offset()
+    }
+
     /// Render the pane against an explicit folder reference. `AppRoot`
     /// (and tests) pick the right folder based on the active view —
     /// see `MessagesRenderContext`. We render against the resolved
@@ -124,29 +219,43 @@ impl MessagesComponent {
         folder_path: &str,
         drafts: &HashMap<String, DraftInfo>,
         theme: &Theme,
+        config: &Config,
     ) {
         // Track the actual visible row count so `handle_msg(MessageMove)`
         // can emit `StoreLoadMore` ahead of the user reaching the tail.
-        let rows = (area.height.saturating_sub(2)) as usize;
+        // Under `list_preview` each entry takes two lines.
+        let rows = (area.height.saturating_sub(2)) as usize / Self::row_height(config);
         self.visible_rows.set(rows);
 
-        let is_sent_folder = folder_to_display.name == "Sent"
-            || folder_to_display.name.to_lowercase().contains("sent");
+        let columns = config.columns_for(&folder_to_display.path, folder_to_display.role);
 
+        let shown: Vec<usize> = threading::shown(&folder_to_display.emails).collect();
         let email_items = Self::build_email_list_with_truncation(
-            &folder_to_display.emails,
+            shown.iter().map(|&i| &folder_to_display.emails[i]),
             area.width.saturating_sub(2) as usize,
-            is_sent_folder,
+            &columns,
             drafts,
+            &self.tagged,
             self.classifier.as_ref(),
             self.confidence_threshold,
+            config,
+            theme,
         );
+        // Search hits stand out in the accent colour; the cursor row
+        // keeps its own highlight on top.
+        let email_items: Vec<ListItem> = email_items
+            .into_iter()
+            .zip(&shown)
+            .map(|(item, &i)| {
+                if self.find_matches.binary_search(&i).is_ok() {
+                    item.style(Style::default().fg(theme.accent))
+                } else {
+                    item
+                }
+            })
+            .collect();
 
-        let style = if focused {
-            Style::default().fg(theme.cyan)
-        } else {
-            Style::default()
-        };
+        let style = theme.focus(focused, theme.cyan);
 
         let title = if folder_to_display.is_loaded {
             format!(
@@ -163,6 +272,22 @@ impl MessagesComponent {
                 folder_to_display.emails.len()
             )
         };
+        let title = if let Some(input) = &self.find_input {
+            format!("{} /{}", title, input)
+        } else if let Some(query) = &self.find_query {
+            match self.find_matches.binary_search(&self.email_index) {
+                Ok(i) => format!(
+                    "{} [{}/{} \"{}\"]",
+                    title,
+                    i + 1,
+                    self.find_matches.len(),
+                    query
+                ),
+                Err(_) => format!("{} [{} \"{}\"]", title, self.find_matches.len(), query),
+            }
+        } else {
+            title
+        };
 
         let block = Block::default()
             .borders(Borders::ALL)
@@ -172,32 +297,85 @@ impl MessagesComponent {
         let list = List::new(email_items)
             .block(block)
             .style(style)
-            .highlight_style(
-                Style::default()
-                    .bg(theme.primary)
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            );
+            .highlight_style(theme.selection());
 
         let mut state = self.list_state.borrow_mut();
-        state.select(Some(self.email_index));
+        // Folded replies take no row, so the cursor's row is its
+        // position among the shown ones.
+        let row = shown
+            .iter()
+            .position(|&i| i >= self.email_index)
+            .unwrap_or(self.email_index);
+        let offset = viewport::list_offset(state.offset(), row, shown.len(), rows);
+        *state.offset_mut() = offset;
+        state.select(Some(row));
         f.render_stateful_widget(list, area, &mut *state);
     }
 
     // --- Email-row helpers (extracted from the pre-refactor ui.rs) ---
 
-    fn format_email_date(date_str: &str) -> String {
-        if let Ok(date_time) = DateTime::parse_from_rfc3339(date_str) {
-            let local_time = date_time.with_timezone(&Local);
-            let today = Local::now().date_naive();
-            if local_time.date_naive() == today {
-                local_time.format("%H:%M").to_string()
-            } else {
-                local_time.format("%Y-%m-%d").to_string()
+    /// Width of the `date` column: the widest label the configured
+    /// `date_style` can produce, measured on a date whose names are
+    /// the longest (a Wednesday in September, just before midnight).
+    fn date_column_width(config: &Config) -> usize {
+        let widest = NaiveDate::from_ymd_opt(2000, 9, 27)
+            .and_then(|d| d.and_hms_opt(23, 59, 59))
+            .and_then(|t| Local.from_local_datetime(&t).earliest());
+        let width = |format: &str| widest.map_or(0, |t| t.format(format).to_string().width());
+        match config.date_style {
+            DateStyle::Relative => RELATIVE_DATE_WIDTH,
+            DateStyle::Absolute => width(&config.date_format),
+            DateStyle::Smart => [
+                &config.date_format_today,
+                &config.date_format_this_week,
+                &config.date_format_this_year,
+                &config.date_format_other,
+            ]
+            .into_iter()
+            .map(|format| width(format))
+            .max()
+            .unwrap_or(0),
+        }
+    }
+
+    fn format_email_date(date_str: &str, config: &Config) -> String {
+        Self::format_email_date_at(date_str, config, Local::now())
+    }
+
+    /// Format `date_str` (anything [`parse_flexible_date`] reads;
+    /// otherwise its first ten characters) in the configured
+    /// `date_style`. Smart picks the `date_format_*` string for how far
+    /// it lies before `now`: today, the past six days, earlier this
+    /// year, or anything else (including future dates from another
+    /// year).
+    fn format_email_date_at(date_str: &str, config: &Config, now: DateTime<Local>) -> String {
+        let Some(date_time) = parse_flexible_date(date_str) else {
+            return date_str.chars().take(10).collect();
+        };
+        let local_time = date_time.with_timezone(&Local);
+        match config.date_style {
+            DateStyle::Relative => {
+                return format!(
+                    "{:>width$}",
+                    relative_age(now.signed_duration_since(local_time)),
+                    width = RELATIVE_DATE_WIDTH
+                );
             }
-        } else {
-            date_str.chars().take(10).collect()
+            DateStyle::Absolute => return local_time.format(&config.date_format).to_string(),
+            DateStyle::Smart => {}
         }
+        let today = now.date_naive();
+        let days_ago = (today - local_time.date_naive()).num_days();
+        let format = if days_ago == 0 {
+            &config.date_format_today
+        } else if (1..7).contains(&days_ago) {
+            &config.date_format_this_week
+        } else if local_time.year() == today.year() {
+            &config.date_format_this_year
+        } else {
+            &config.date_format_other
+        };
+        local_time.format(format).to_string()
     }
 
     fn truncate_with_ellipsis(text: &str, max_width: usize) -> String {
@@ -243,6 +421,25 @@ impl MessagesComponent {
         }
     }
 
+    /// `from_field` as `[sender_display]` asks for it. `Name` is
+    /// [`Self::extract_email_address`]; a field with no address in it
+    /// is shown as is in every mode.
+    fn sender_label(from_field: &str, mode: SenderDisplay) -> String {
+        let address = match (from_field.rfind('<'), from_field.rfind('>')) {
+            (Some(open), Some(close)) if open < close => Some(&from_field[open + 1..close]),
+            _ if from_field.contains('@') => Some(from_field.trim()),
+            _ => None,
+        };
+        match (mode, address) {
+            (SenderDisplay::Name, _) | (_, None) => Self::extract_email_address(from_field),
+            (SenderDisplay::Address, Some(address)) => address.to_string(),
+            (SenderDisplay::Both, Some(address)) => match from_field.find(" <") {
+                Some(name_end) => format!("{} <{}>", &from_field[..name_end], address),
+                None => address.to_string(),
+            },
+        }
+    }
+
     fn extract_email_address(from_field: &str) -> String {
         if let Some(name_end) = from_field.find(" <") {
             from_field[..name_end].to_string()
@@ -290,74 +487,246 @@ impl MessagesComponent {
         suggestion_glyph(s.action)
     }
 
-    fn build_email_list_with_truncation(
-        emails: &[Email],
+    #[allow(clippy::too_many_arguments)]
+    fn build_email_list_with_truncation<'a>(
+        emails: impl IntoIterator<Item = &'a Email>,
         available_width: usize,
-        is_sent_folder: bool,
+        columns: &[ColumnConfig],
         drafts: &HashMap<String, DraftInfo>,
+        tagged: &HashSet<PathBuf>,
         classifier: &dyn Classifier,
         threshold: f32,
+        config: &Config,
+        theme: &Theme,
     ) -> Vec<ListItem<'static>> {
         emails
-            .iter()
+            .into_iter()
             .map(|email| {
-                ListItem::new(Line::from(Self::build_email_row_spans(
+                let row = Line::from(Self::build_email_row_spans(
                     email,
                     available_width,
-                    is_sent_folder,
+                    columns,
                     drafts,
+                    tagged.contains(&email.file_path),
                     classifier,
                     threshold,
-                )))
+                    config,
+                    theme,
+                ));
+                if config.list_preview {
+                    ListItem::new(vec![row, Self::preview_line(email, available_width, theme)])
+                } else {
+                    ListItem::new(row)
+                }
             })
             .collect()
     }
 
+    /// Second row of an entry under `list_preview`: the body preview,
+    /// dimmed and indented past the status gutter.
+    fn preview_line(email: &Email, available_width: usize, theme: &Theme) -> Line<'static> {
+        let indent = " ".repeat(GUTTER_WIDTH);
+        let text = Self::truncate_with_ellipsis(
+            email.preview.as_deref().unwrap_or(""),
+            available_width.saturating_sub(GUTTER_WIDTH + RIGHT_MARGIN),
+        );
+        Line::from(Span::styled(
+            format!("{}{}", indent, text),
+            Style::default().fg(theme.gray_dark),
+        ))
+    }
+
     /// Build the row's spans for one email. Extracted from
     /// `build_email_list_with_truncation` so the test suite can inspect
     /// the rendered glyphs and column widths without going through the
-    /// private `ListItem.content` field.
+    /// private `ListItem.content` field. `columns` is the folder's set
+    /// from [`Config::columns_for`].
+    #[allow(clippy::too_many_arguments)]
     fn build_email_row_spans(
         email: &Email,
         available_width: usize,
-        is_sent_folder: bool,
+        columns: &[ColumnConfig],
         drafts: &HashMap<String, DraftInfo>,
+        tagged: bool,
         classifier: &dyn Classifier,
         threshold: f32,
+        config: &Config,
+        theme: &Theme,
     ) -> Vec<Span<'static>> {
-        const UNREAD_WIDTH: usize = 2;
-        // `✏`/`⏰` plus trailing space — reserved even when no chip
-        // present so the From column stays vertically aligned.
-        const CHIP_WIDTH: usize = 2;
-        // AI suggestion chip — reserved beside the draft chip so an
-        // email with a classifier suggestion and an in-flight draft
-        // can show both without nudging the From column.
-        const AI_CHIP_WIDTH: usize = 2;
-        const DATE_WIDTH: usize = 10;
-        const ATTACHMENT_WIDTH: usize = 3;
-        const SEPARATORS: usize = 8;
-
-        let min_from_width = 15;
-        let max_from_width = (available_width * 30) / 100;
-        let from_width = min_from_width.max(max_from_width).min(25);
-
         let mut style = Style::default();
         if email.is_unread {
             style = style.add_modifier(Modifier::BOLD);
         }
 
-        let subject_width = available_width
-            .saturating_sub(UNREAD_WIDTH)
-            .saturating_sub(CHIP_WIDTH)
-            .saturating_sub(AI_CHIP_WIDTH)
-            .saturating_sub(from_width)
-            .saturating_sub(DATE_WIDTH)
-            .saturating_sub(ATTACHMENT_WIDTH)
-            .saturating_sub(SEPARATORS);
+        let columns: Vec<&ColumnConfig> = columns
+            .iter()
+            .filter(|c| c.enabled && ColumnConfig::NAMES.contains(&c.name.as_str()))
+            .collect();
+        let widths =
+            Self::column_widths(&columns, available_width, Self::date_column_width(config));
+
+        let mut spans = vec![];
+        for (i, (column, width)) in columns.iter().zip(widths).enumerate() {
+            let text = match column.name.as_str() {
+                "unread" => {
+                    spans.extend(Self::status_gutter_spans(
+                        email, drafts, tagged, classifier, threshold, style, theme,
+                    ));
+                    continue;
+                }
+                "flags" => {
+                    spans.extend(Self::flag_spans(email, style, theme));
+                    continue;
+                }
+                "attachments" => {
+                    spans.push(Span::styled(
+                        if email.has_attachments() {
+                            "📎"
+                        } else {
+                            "  "
+                        },
+                        style,
+                    ));
+                    spans.push(Span::raw(" "));
+                    continue;
+                }
+                "from" => Self::sender_label(&email.headers.from, config.sender_display),
+                "to" => Self::sender_label(&email.headers.to, config.sender_display),
+                "subject" if email.is_draft => {
+                    spans.push(Span::styled(DRAFT_BADGE, style.fg(theme.accent)));
+                    let width = width.saturating_sub(DRAFT_BADGE.len());
+                    let text = Self::truncate_with_ellipsis(&Self::subject_label(email), width);
+                    spans.push(Span::styled(Self::pad_to_width(&text, width), style));
+                    if i + 1 < columns.len() {
+                        spans.push(Span::raw(" ".repeat(TEXT_COLUMN_GAP)));
+                    }
+                    continue;
+                }
+                "subject" => Self::subject_label(email),
+                "folder" => folder_label(&email.file_path),
+                "size" => format!(
+                    "{:>width$}",
+                    crate::util::format_file_size(email.size_bytes),
+                    width = width
+                ),
+                _ => Self::format_email_date(&email.headers.date, config),
+            };
+            let text = Self::truncate_with_ellipsis(&text, width);
+            spans.push(Span::styled(Self::pad_to_width(&text, width), style));
+            if i + 1 < columns.len() {
+                spans.push(Span::raw(" ".repeat(TEXT_COLUMN_GAP)));
+            }
+        }
+        spans
+    }
+
+    /// Cells given to each of `columns` in a row `available_width`
+    /// wide. Glyph columns (`unread`, `flags`, `attachments`) always
+    /// take their own width; text columns take their `width`, or
+    /// their default when unset. Flexible columns split what is left
+    /// evenly, the last one taking any odd cell.
+    /// `date_width` is the `date` column's default, from
+    /// [`Self::date_column_width`].
+    fn column_widths(
+        columns: &[&ColumnConfig],
+        available_width: usize,
+        date_width: usize,
+    ) -> Vec<usize> {
+        let default_width = |name: &str| match name {
+            "from" | "to" | "folder" => {
+                let min_from_width = 15;
+                let max_from_width = (available_width * 30) / 100;
+                ColumnWidth::Fixed(min_from_width.max(max_from_width).min(25))
+            }
+            "date" => ColumnWidth::Fixed(date_width),
+            "size" => ColumnWidth::Fixed(SIZE_WIDTH),
+            _ => ColumnWidth::Flexible,
+        };
+        let widths: Vec<ColumnWidth> = columns
+            .iter()
+            .map(|c| match c.name.as_str() {
+                "unread" => ColumnWidth::Fixed(GUTTER_WIDTH),
+                "flags" => ColumnWidth::Fixed(FLAGS_WIDTH),
+                "attachments" => ColumnWidth::Fixed(ATTACHMENT_WIDTH),
+                name => c.width.unwrap_or_else(|| default_width(name)),
+            })
+            .collect();
+
+        let gaps: usize = columns
+            .iter()
+            .take(columns.len().saturating_sub(1))
+            .filter(|c| !matches!(c.name.as_str(), "unread" | "flags" | "attachments"))
+            .count()
+            * TEXT_COLUMN_GAP;
+        let fixed: usize = widths
+            .iter()
+            .map(|w| match w {
+                ColumnWidth::Fixed(n) => *n,
+                ColumnWidth::Flexible => 0,
+            })
+            .sum();
+        let flexible = widths
+            .iter()
+            .filter(|w| **w == ColumnWidth::Flexible)
+            .count();
+        let left = available_width.saturating_sub(fixed + gaps + RIGHT_MARGIN);
+        let share = left.checked_div(flexible).unwrap_or(0);
+        let mut flex_seen = 0;
+        widths
+            .into_iter()
+            .map(|w| match w {
+                ColumnWidth::Fixed(n) => n,
+                ColumnWidth::Flexible => {
+                    flex_seen += 1;
+                    if flex_seen == flexible {
+                        left - share * (flexible - 1)
+                    } else {
+                        share
+                    }
+                }
+            })
+            .collect()
+    }
 
+    /// The `unread` column: unread dot, `*` tag mark, spam / corrupt
+    /// mark, then the draft and AI chip slots. Every slot keeps its
+    /// width when empty so the columns after it stay aligned.
+    fn status_gutter_spans(
+        email: &Email,
+        drafts: &HashMap<String, DraftInfo>,
+        tagged: bool,
+        classifier: &dyn Classifier,
+        threshold: f32,
+        style: Style,
+        theme: &Theme,
+    ) -> Vec<Span<'static>> {
         let mut spans = vec![];
         spans.push(Span::styled(if email.is_unread { "•" } else { " " }, style));
-        spans.push(Span::raw(" "));
+        if tagged {
+            spans.push(Span::styled(
+                "*",
+                Style::default().fg(theme.cyan).add_modifier(Modifier::BOLD),
+            ));
+        } else {
+            spans.push(Span::raw(" "));
+        }
+        if email.parse_error.is_some() {
+            spans.push(Span::styled(
+                "! ",
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        } else if email.is_spam {
+            spans.push(Span::styled(
+                "S ",
+                Style::default()
+                    .fg(theme.error())
+                    .add_modifier(Modifier::BOLD),
+            ));
+        } else {
+            spans.push(Span::raw(" ".repeat(SPAM_WIDTH)));
+        }
 
         // Draft chip slot. Always emits CHIP_WIDTH wide so absent chips
         // don't shift the From column off by one.
@@ -376,42 +745,47 @@ impl MessagesComponent {
             Some(c) => Self::pad_to_width(&c.to_string(), AI_CHIP_WIDTH),
             None => " ".repeat(AI_CHIP_WIDTH),
         };
-        spans.push(Span::styled(ai_chip_text, style.fg(VulthorTheme::CYAN)));
+        spans.push(Span::styled(ai_chip_text, style.fg(theme.cyan)));
+        spans
+    }
 
-        let sender = if is_sent_folder {
-            Self::extract_email_address(&email.headers.to)
+    /// The `flags` column: star (Maildir `F` flag), then the
+    /// high-priority `!`.
+    fn flag_spans(email: &Email, style: Style, theme: &Theme) -> Vec<Span<'static>> {
+        let star = if email.is_flagged {
+            Span::styled("★", style.fg(theme.accent))
         } else {
-            Self::extract_email_address(&email.headers.from)
+            Span::raw(" ")
         };
-        let truncated_sender = Self::truncate_with_ellipsis(&sender, from_width);
-        let padded_sender = Self::pad_to_width(&truncated_sender, from_width);
-        spans.push(Span::styled(padded_sender, style));
-        spans.push(Span::raw("  "));
+        let priority = if email.headers.priority == Priority::High {
+            Span::styled("!", style.fg(theme.accent).add_modifier(Modifier::BOLD))
+        } else {
+            Span::raw(" ")
+        };
+        vec![star, priority]
+    }
 
+    /// Subject as the list shows it. Threaded view: replies indent
+    /// under their parent, and a folded thread shows how many replies
+    /// it hides.
+    fn subject_label(email: &Email) -> String {
         let subject = if email.headers.subject.is_empty() {
             "(No Subject)"
         } else {
             &email.headers.subject
         };
-        let truncated_subject = Self::truncate_with_ellipsis(subject, subject_width);
-        let padded_subject = Self::pad_to_width(&truncated_subject, subject_width);
-        spans.push(Span::styled(padded_subject, style));
-        spans.push(Span::raw("  "));
-
-        spans.push(Span::styled(
-            if email.has_attachments() {
-                "📎"
-            } else {
-                "  "
-            },
-            style,
-        ));
-        spans.push(Span::raw(" "));
-
-        let date_str = Self::format_email_date(&email.headers.date);
-        spans.push(Span::styled(date_str, style));
-
-        spans
+        let thread = &email.thread;
+        if thread.depth > 0 {
+            format!(
+                "{}└ {}",
+                "  ".repeat((thread.depth - 1).min(MAX_THREAD_INDENT)),
+                subject
+            )
+        } else if thread.collapsed {
+            format!("[+{}] {}", thread.replies, subject)
+        } else {
+            subject.to_string()
+        }
     }
 }
 
@@ -425,9 +799,12 @@ impl Component for MessagesComponent {
     fn handle_msg(&mut self, msg: &Msg, ctx: &Ctx) -> Vec<Msg> {
         match msg {
             Msg::MessageMove(Dir::Down) => {
-                let total = ctx.store.get_current_folder().emails.len();
-                if self.email_index + 1 < total {
-                    self.email_index += 1;
+                let emails = &ctx.store.get_current_folder().emails;
+                let total = emails.len();
+                // Skip replies folded away under a collapsed thread.
+                let next = threading::shown(emails).find(|&i| i > self.email_index);
+                if let Some(next) = next {
+                    self.email_index = next;
                     // Look ahead: if the user is scrolling into the
                     // unloaded tail, ask the store for more headers.
                     if self.email_index + SCROLL_LOOKAHEAD >= total {
@@ -435,18 +812,152 @@ impl Component for MessagesComponent {
                     }
                 }
             }
-            Msg::MessageMove(Dir::Up) => {
-                if self.email_index > 0 {
-                    self.email_index -= 1;
+            Msg::MessageMove(Dir::Up) if self.email_index > 0 => {
+                let emails = &ctx.store.get_current_folder().emails;
+                self.email_index = threading::visible_row(emails, self.email_index - 1);
+            }
+            Msg::MessagePage(dir, halves) => {
+                let row = dir.paged(self.email_index, *halves, self.visible_rows.get());
+                return vec![Msg::MessageJump(Jump::To(row))];
+            }
+            Msg::MessageJump(jump) => {
+                let emails = &ctx.store.get_current_folder().emails;
+                let total = emails.len();
+                let last = total.saturating_sub(1);
+                self.email_index = threading::visible_row(
+                    emails,
+                    match jump {
+                        Jump::To(row) => (*row).min(last),
+                        Jump::Last => last,
+                    },
+                );
+                // Same look-ahead as `MessageMove(Down)`: `G` on a
+                // partially loaded folder pulls in the next chunk, so a
+                // second `G` reaches further.
+                if total > 0 && self.email_index + SCROLL_LOOKAHEAD >= total {
+                    return vec![Msg::StoreLoadMore(self.email_index)];
                 }
             }
-            Msg::FolderMove(_) | Msg::FolderEnter | Msg::FolderExitParent => {
+            Msg::FolderMove(_) | Msg::FolderJump(_) | Msg::FolderEnter | Msg::FolderExitParent => {
                 // New folder context: drop the cursor to the top and
                 // clear the cross-pane remembered position. The legacy
                 // `App::load_selected_folder_messages` and
                 // `handle_back_navigation` paths did exactly this.
                 self.email_index = 0;
                 self.remembered_email_index = None;
+                self.tagged.clear();
+                self.select_anchor = None;
+                self.clear_find();
+            }
+            Msg::MessageFindOpen => {
+                self.find_input = Some(String::new());
+                self.find_query = None;
+                self.find_matches.clear();
+                self.find_origin = self.email_index;
+            }
+            Msg::MessageFindInput(query) => {
+                self.find_input = Some(query.clone());
+                self.find_matches = ctx.store.find_emails(query);
+                let target = self
+                    .find_matches
+                    .iter()
+                    .find(|&&row| row >= self.find_origin)
+                    .or(self.find_matches.first())
+                    .copied()
+                    .unwrap_or(self.find_origin);
+                return self.jump_to(target);
+            }
+            Msg::MessageFind(query) => {
+                self.find_input = None;
+                self.find_matches = ctx.store.find_emails(query);
+                if self.find_matches.is_empty() {
+                    self.find_query = None;
+                    return vec![Msg::StatusSet(format!("Not found: {}", query))];
+                }
+                self.find_query = Some(query.clone());
+            }
+            Msg::MessageFindStep(dir) => {
+                let Some(query) = &self.find_query else {
+                    return Vec::new();
+                };
+                self.find_matches = ctx.store.find_emails(query);
+                let (first, last) = match (self.find_matches.first(), self.find_matches.last()) {
+                    (Some(&first), Some(&last)) => (first, last),
+                    _ => return Vec::new(),
+                };
+                let target = match dir {
+                    Dir::Up => self
+                        .find_matches
+                        .iter()
+                        .rev()
+                        .find(|&&row| row < self.email_index)
+                        .copied()
+                        .unwrap_or(last),
+                    _ => self
+                        .find_matches
+                        .iter()
+                        .find(|&&row| row > self.email_index)
+                        .copied()
+                        .unwrap_or(first),
+                };
+                return self.jump_to(target);
+            }
+            Msg::MessageUnreadStep(dir) => {
+                let target = match dir {
+                    Dir::Up => ctx.store.prev_unread_from(self.email_index),
+                    _ => ctx.store.next_unread_from(self.email_index),
+                };
+                let Some(target) = target else {
+                    return vec![Msg::StatusSet("No unread emails".to_string())];
+                };
+                let wrapped = match dir {
+                    Dir::Up => target >= self.email_index,
+                    _ => target <= self.email_index,
+                };
+                let mut out = self.jump_to(target);
+                if wrapped {
+                    out.push(Msg::StatusSet(
+                        "No more unread emails (wrapped)".to_string(),
+                    ));
+                }
+                return out;
+            }
+            Msg::MessageFindCancel => {
+                let reopen = self.find_input.is_some();
+                self.clear_find();
+                if reopen {
+                    return self.jump_to(self.find_origin);
+                }
+            }
+            Msg::MessageToggleSelect => {
+                if let Some(email) = ctx.store.get_current_folder().emails.get(self.email_index) {
+                    if !self.tagged.remove(&email.file_path) {
+                        self.tagged.insert(email.file_path.clone());
+                    }
+                    self.select_anchor = Some(self.email_index);
+                }
+            }
+            Msg::MessageUntagAll => {
+                self.tagged.clear();
+                self.select_anchor = None;
+            }
+            Msg::MessageSelectRange => {
+                let emails = &ctx.store.get_current_folder().emails;
+                let total = emails.len();
+                if self.email_index < total {
+                    let anchor = self
+                        .select_anchor
+                        .unwrap_or(self.email_index)
+                        .min(total - 1);
+                    let (lo, hi) = if anchor <= self.email_index {
+                        (anchor, self.email_index)
+                    } else {
+                        (self.email_index, anchor)
+                    };
+                    self.tagged
+                        .extend(emails[lo..=hi].iter().map(|e| e.file_path.clone()));
+                    self.select_anchor = Some(self.email_index);
+                }
             }
             Msg::FoldersBlur => {
                 // Focus just moved Folders → Messages. Restore the
@@ -457,7 +968,7 @@ impl Component for MessagesComponent {
                     .filter(|&idx| idx < current.emails.len())
                     .unwrap_or(0);
                 if target < current.emails.len() {
-                    self.email_index = target;
+                    self.email_index = threading::visible_row(&current.emails, target);
                 }
             }
             Msg::MessageOpen(_) => {
@@ -488,11 +999,70 @@ impl Component for MessagesComponent {
         // it would render nothing rather than the wrong folder.
     }
 
-    // No `on_key` override: every action key — including the `g`-prefix
-    // sequences (`gr` reply, `gg`/`G`/`gj`/`gk` jumps) — resolves through
-    // `AppRoot::process_event`'s keymap dispatch. The default trait impl
-    // returning `None` is what we want, so `[keybindings]` overrides on
-    // sequence-bound actions actually fire at runtime (vu-q9b).
+    // Every action key — including the `g`-prefix sequences (`gr`
+    // reply, `gg`/`G`/`gj`/`gk` jumps) — resolves through
+    // `AppRoot::process_event`'s keymap dispatch, so `[keybindings]`
+    // overrides on sequence-bound actions actually fire at runtime
+    // (vu-q9b). Only the search prompt reads keys here: while open it
+    // takes every key, and `Esc` drops a committed term's highlight.
+    fn on_key(&mut self, key: KeyEvent, _ctx: &Ctx) -> Option<Msg> {
+        if let Some(input) = self.find_input.as_mut() {
+            return match key.code {
+                KeyCode::Esc => Some(Msg::MessageFindCancel),
+                KeyCode::Enter => match input.trim() {
+                    "" => Some(Msg::MessageFindCancel),
+                    query => Some(Msg::MessageFind(query.to_string())),
+                },
+                KeyCode::Backspace => {
+                    input.pop();
+                    Some(Msg::MessageFindInput(input.clone()))
+                }
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    input.push(c);
+                    Some(Msg::MessageFindInput(input.clone()))
+                }
+                _ => None,
+            };
+        }
+        if self.find_query.is_some() && key.modifiers.is_empty() && key.code == KeyCode::Esc {
+            return Some(Msg::MessageFindCancel);
+        }
+        None
+    }
+}
+
+/// Name of the folder an email file sits in: the directory above
+/// `cur/` / `new/`, or the mbox file itself.
+fn folder_label(file_path: &Path) -> String {
+    let dir = file_path.parent();
+    let folder = match dir.and_then(|d| d.file_name()).and_then(|n| n.to_str()) {
+        Some("cur" | "new" | "tmp") => dir.and_then(|d| d.parent()),
+        _ => dir,
+    };
+    folder
+        .and_then(|f| f.file_name())
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// `age` in its largest whole unit: `5m`, `3h`, `4d`, `2mo`, `3y`.
+/// Under a minute, and dates in the future, are `now`.
+fn relative_age(age: chrono::TimeDelta) -> String {
+    let minutes = age.num_minutes();
+    let days = age.num_days();
+    if minutes < 1 {
+        "now".to_string()
+    } else if minutes < 60 {
+        format!("{}m", minutes)
+    } else if age.num_hours() < 24 {
+        format!("{}h", age.num_hours())
+    } else if days < 30 {
+        format!("{}d", days)
+    } else if days < 365 {
+        format!("{}mo", days / 30)
+    } else {
+        format!("{}y", days / 365)
+    }
 }
 
 #[cfg(test)]
@@ -585,6 +1155,32 @@ mod tests {
         assert!(followups.is_empty(), "mid-list scroll should not fan out");
     }
 
+    /// Paging resolves into a jump; landing near the loaded tail of a
+    /// partial folder then asks for more headers like any other jump.
+    #[test]
+    fn message_page_jumps_by_visible_rows_and_loads_more_near_tail() {
+        let store = store_with_one_folder(30);
+        let (theme, config) = (Theme::default(), Config::default());
+        let ctx = ctx(&theme, &config, &store);
+
+        let mut m = MessagesComponent::new();
+        m.visible_rows.set(10);
+        let followups = m.handle_msg(&Msg::MessagePage(Dir::Down, 1), &ctx);
+        assert_eq!(followups, vec![Msg::MessageJump(Jump::To(5))]);
+        let followups = m.handle_msg(&Msg::MessagePage(Dir::Down, 2), &ctx);
+        assert_eq!(followups, vec![Msg::MessageJump(Jump::To(10))]);
+
+        m.email_index = 20;
+        let followups = m.handle_msg(&Msg::MessagePage(Dir::Down, 2), &ctx);
+        let followups = m.handle_msg(&followups[0], &ctx);
+        assert_eq!(m.email_index, 29, "clamped to the last loaded row");
+        assert!(
+            followups.iter().any(|x| matches!(x, Msg::StoreLoadMore(_))),
+            "deep page must request more headers, got {:?}",
+            followups,
+        );
+    }
+
     #[test]
     fn folder_move_resets_email_cursor_and_clears_remembered() {
         let store = store_with_one_folder(5);
@@ -625,6 +1221,70 @@ mod tests {
         assert_eq!(m.email_index, 0);
     }
 
+    #[test]
+    fn toggle_select_adds_and_removes_cursor_row() {
+        let store = store_with_one_folder(5);
+        let (theme, config) = (Theme::default(), Config::default());
+        let ctx = ctx(&theme, &config, &store);
+
+        let mut m = MessagesComponent::new();
+        m.email_index = 1;
+        m.handle_msg(&Msg::MessageToggleSelect, &ctx);
+        m.email_index = 3;
+        m.handle_msg(&Msg::MessageToggleSelect, &ctx);
+        let emails = &store.get_current_folder().emails;
+        assert_eq!(m.tagged_rows(emails), [1, 3]);
+
+        m.handle_msg(&Msg::MessageToggleSelect, &ctx);
+        assert_eq!(m.tagged_rows(emails), [1]);
+        m.handle_msg(&Msg::MessageUntagAll, &ctx);
+        assert!(m.tagged.is_empty());
+    }
+
+    #[test]
+    fn select_range_extends_from_last_toggled_row() {
+        let store = store_with_one_folder(6);
+        let (theme, config) = (Theme::default(), Config::default());
+        let ctx = ctx(&theme, &config, &store);
+
+        let mut m = MessagesComponent::new();
+        m.email_index = 4;
+        m.handle_msg(&Msg::MessageToggleSelect, &ctx);
+        m.email_index = 1;
+        m.handle_msg(&Msg::MessageSelectRange, &ctx);
+        let emails = &store.get_current_folder().emails;
+        assert_eq!(m.tagged_rows(emails), [1, 2, 3, 4]);
+
+        // No anchor: `V` just tags the cursor row.
+        let mut fresh = MessagesComponent::new();
+        fresh.email_index = 2;
+        fresh.handle_msg(&Msg::MessageSelectRange, &ctx);
+        assert_eq!(fresh.tagged_rows(emails), [2]);
+    }
+
+    #[test]
+    fn folder_change_clears_tags() {
+        let store = store_with_one_folder(5);
+        let (theme, config) = (Theme::default(), Config::default());
+        let ctx = ctx(&theme, &config, &store);
+
+        for msg in [
+            Msg::FolderMove(Dir::Down),
+            Msg::FolderEnter,
+            Msg::FolderExitParent,
+        ] {
+            let mut m = MessagesComponent::new();
+            m.handle_msg(&Msg::MessageToggleSelect, &ctx);
+            assert!(!m.tagged.is_empty());
+            m.handle_msg(&msg, &ctx);
+            assert!(m.tagged.is_empty(), "{:?} must clear the tags", msg);
+            // The anchor goes too, so a later `V` starts fresh.
+            m.email_index = 3;
+            m.handle_msg(&Msg::MessageSelectRange, &ctx);
+            assert_eq!(m.tagged_rows(&store.get_current_folder().emails), [3]);
+        }
+    }
+
     #[test]
     fn messages_blur_always_remembers_email_index() {
         // The legacy `app.switch_pane` deselects the email *before*
@@ -729,22 +1389,122 @@ mod tests {
     fn format_email_date_today_shows_hhmm() {
         let now = Local::now();
         let date_str = now.to_rfc3339();
-        let formatted = MessagesComponent::format_email_date(&date_str);
+        let formatted = MessagesComponent::format_email_date(&date_str, &Config::default());
         assert_eq!(formatted.len(), 5);
         assert!(formatted.contains(':'));
     }
 
+    #[test]
+    fn relative_and_absolute_date_styles_keep_the_column_aligned() {
+        let now = Local.with_ymd_and_hms(2024, 3, 12, 9, 0, 0).unwrap();
+        let ago = |minutes: i64| (now - chrono::TimeDelta::minutes(minutes)).to_rfc3339();
+        let relative = Config {
+            date_style: DateStyle::Relative,
+            ..Config::default()
+        };
+        let fmt = |date: &str| MessagesComponent::format_email_date_at(date, &relative, now);
+        assert_eq!(fmt(&ago(0)), " now");
+        assert_eq!(fmt(&ago(5)), "  5m");
+        assert_eq!(fmt(&ago(3 * 60)), "  3h");
+        assert_eq!(fmt(&ago(4 * 24 * 60)), "  4d");
+        assert_eq!(fmt(&ago(64 * 24 * 60)), " 2mo");
+        assert_eq!(fmt(&ago(800 * 24 * 60)), "  2y");
+        assert_eq!(fmt(&ago(-90)), " now", "future dates");
+        // RFC 2822, as some fixtures carry it.
+        assert_eq!(fmt("Mon, 11 Mar 2024 09:00:00 +0000").trim(), "1d");
+        assert_eq!(MessagesComponent::date_column_width(&relative), 4);
+
+        let absolute = Config {
+            date_style: DateStyle::Absolute,
+            date_format: "%d %B %Y".to_string(),
+            ..Config::default()
+        };
+        let date = Local
+            .with_ymd_and_hms(2024, 5, 1, 8, 0, 0)
+            .unwrap()
+            .to_rfc3339();
+        assert_eq!(
+            MessagesComponent::format_email_date_at(&date, &absolute, now),
+            "01 May 2024"
+        );
+        // Sized for the longest month name.
+        assert_eq!(MessagesComponent::date_column_width(&absolute), 17);
+        assert_eq!(MessagesComponent::date_column_width(&Config::default()), 10);
+
+        let mut email = Email::new(PathBuf::from("/tmp/x"));
+        email.headers.date = date;
+        let spans = MessagesComponent::build_email_row_spans(
+            &email,
+            100,
+            &absolute.columns,
+            &HashMap::new(),
+            false,
+            &NoopClassifier,
+            0.0,
+            &absolute,
+            &Theme::default(),
+        );
+        assert!(spans.iter().any(|s| s.content == "01 May 2024      "));
+    }
+
     #[test]
     fn format_email_date_past_shows_iso_date() {
         let date_str = "2024-01-15T10:30:00+00:00";
-        let formatted = MessagesComponent::format_email_date(date_str);
+        let formatted = MessagesComponent::format_email_date(date_str, &Config::default());
         assert_eq!(formatted, "2024-01-15");
     }
 
+    #[test]
+    fn format_email_date_picks_the_format_for_each_range() {
+        use chrono::TimeZone;
+        let config = Config::default();
+        // A Tuesday.
+        let now = Local.with_ymd_and_hms(2024, 3, 12, 9, 0, 0).unwrap();
+        let at = |y: i32, m: u32, d: u32, h: u32, min: u32| {
+            Local
+                .with_ymd_and_hms(y, m, d, h, min, 0)
+                .unwrap()
+                .to_rfc3339()
+        };
+        let fmt = |date: String| MessagesComponent::format_email_date_at(&date, &config, now);
+        assert_eq!(fmt(at(2024, 3, 12, 0, 5)), "00:05", "earlier today");
+        assert_eq!(
+            fmt(at(2024, 3, 11, 23, 59)),
+            "Mon 23:59",
+            "yesterday, Monday"
+        );
+        assert_eq!(fmt(at(2024, 3, 6, 14, 30)), "Wed 14:30", "six days ago");
+        assert_eq!(
+            fmt(at(2024, 3, 5, 14, 30)),
+            "Mar 05",
+            "last Tuesday is a week ago"
+        );
+        assert_eq!(fmt(at(2024, 1, 1, 8, 0)), "Jan 01", "this year");
+        assert_eq!(fmt(at(2023, 1, 31, 8, 0)), "2023-01-31", "last year");
+
+        let custom = Config {
+            date_format_this_week: "%A".to_string(),
+            ..Config::default()
+        };
+        assert_eq!(
+            MessagesComponent::format_email_date_at(&at(2024, 3, 11, 12, 0), &custom, now),
+            "Monday"
+        );
+    }
+
+    #[test]
+    fn format_email_date_reparses_rfc2822_and_bare_dates() {
+        let config = Config::default();
+        let now = Local.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        let fmt = |date: &str| MessagesComponent::format_email_date_at(date, &config, now);
+        assert_eq!(fmt("Mon, 01 Jan 2024"), "2024-01-01");
+        assert_eq!(fmt("Mon, 1 Jan 2024 09:00:00 (UTC)"), "2024-01-01");
+    }
+
     #[test]
     fn format_email_date_invalid_falls_back_to_first_ten_chars() {
         let date_str = "invalid date";
-        let formatted = MessagesComponent::format_email_date(date_str);
+        let formatted = MessagesComponent::format_email_date(date_str, &Config::default());
         assert_eq!(formatted, "invalid da");
     }
 
@@ -797,6 +1557,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sender_label_follows_the_sender_display_mode() {
+        let named = "John Doe <john@example.com>";
+        let plain = "jane@example.com";
+        let label = MessagesComponent::sender_label;
+        assert_eq!(label(named, SenderDisplay::Name), "John Doe");
+        assert_eq!(label(plain, SenderDisplay::Name), "jane");
+        assert_eq!(label(named, SenderDisplay::Address), "john@example.com");
+        assert_eq!(label(plain, SenderDisplay::Address), "jane@example.com");
+        assert_eq!(label(named, SenderDisplay::Both), named);
+        assert_eq!(label(plain, SenderDisplay::Both), "jane@example.com");
+        assert_eq!(label("Bob Smith", SenderDisplay::Address), "Bob Smith");
+    }
+
     #[test]
     fn build_email_list_with_truncation_renders_one_per_email() {
         let mut email = Email::new(PathBuf::from("/test/email"));
@@ -807,6 +1581,9 @@ mod tests {
                 .to_string(),
             date: Local::now().to_rfc3339(),
             message_id: "123".to_string(),
+            priority: Priority::Normal,
+            in_reply_to: String::new(),
+            references: Vec::new(),
         };
         email.is_unread = true;
         let emails = vec![email];
@@ -815,14 +1592,30 @@ mod tests {
 
         assert_eq!(
             MessagesComponent::build_email_list_with_truncation(
-                &emails, 80, false, &drafts, &noop, 0.6,
+                &emails,
+                80,
+                &ColumnConfig::defaults(),
+                &drafts,
+                &HashSet::new(),
+                &noop,
+                0.6,
+                &Config::default(),
+                &Theme::default(),
             )
             .len(),
             1
         );
         assert_eq!(
             MessagesComponent::build_email_list_with_truncation(
-                &emails, 80, true, &drafts, &noop, 0.6,
+                &emails,
+                80,
+                &Config::default().columns_for(Path::new("/m/Sent"), FolderRole::Sent),
+                &drafts,
+                &HashSet::new(),
+                &noop,
+                0.6,
+                &Config::default(),
+                &Theme::default(),
             )
             .len(),
             1
@@ -838,6 +1631,9 @@ mod tests {
             subject: "Meeting tomorrow 📅 Important! 🔥🔥🔥".to_string(),
             date: Local::now().to_rfc3339(),
             message_id: "456".to_string(),
+            priority: Priority::Normal,
+            in_reply_to: String::new(),
+            references: Vec::new(),
         };
         email.is_unread = false;
         let emails = vec![email];
@@ -845,7 +1641,15 @@ mod tests {
         let noop = NoopClassifier;
         assert_eq!(
             MessagesComponent::build_email_list_with_truncation(
-                &emails, 60, false, &drafts, &noop, 0.6,
+                &emails,
+                60,
+                &ColumnConfig::defaults(),
+                &drafts,
+                &HashSet::new(),
+                &noop,
+                0.6,
+                &Config::default(),
+                &Theme::default(),
             )
             .len(),
             1
@@ -912,6 +1716,9 @@ mod tests {
                 subject: "subject line".to_string(),
                 date: "2024-01-15T10:30:00+00:00".to_string(),
                 message_id: id.to_string(),
+                priority: Priority::Normal,
+                in_reply_to: String::new(),
+                references: Vec::new(),
             };
             e
         }
@@ -922,10 +1729,28 @@ mod tests {
         drafts.insert("orig-1@x".to_string(), draft(false));
 
         let noop = NoopClassifier;
-        let with_spans =
-            MessagesComponent::build_email_row_spans(&with_match, 80, false, &drafts, &noop, 0.6);
-        let without_spans =
-            MessagesComponent::build_email_row_spans(&without, 80, false, &drafts, &noop, 0.6);
+        let with_spans = MessagesComponent::build_email_row_spans(
+            &with_match,
+            80,
+            &ColumnConfig::defaults(),
+            &drafts,
+            false,
+            &noop,
+            0.6,
+            &Config::default(),
+            &Theme::default(),
+        );
+        let without_spans = MessagesComponent::build_email_row_spans(
+            &without,
+            80,
+            &ColumnConfig::defaults(),
+            &drafts,
+            false,
+            &noop,
+            0.6,
+            &Config::default(),
+            &Theme::default(),
+        );
 
         let width = |spans: &[Span<'static>]| -> usize {
             spans.iter().map(|s| s.content.as_ref().width()).sum()
@@ -951,6 +1776,9 @@ mod tests {
                 subject: "s".to_string(),
                 date: "2024-01-15T10:30:00+00:00".to_string(),
                 message_id: id.to_string(),
+                priority: Priority::Normal,
+                in_reply_to: String::new(),
+                references: Vec::new(),
             };
             e
         }
@@ -963,10 +1791,20 @@ mod tests {
 
         let noop = NoopClassifier;
         let row_text = |email: &Email| -> String {
-            MessagesComponent::build_email_row_spans(email, 80, false, &drafts, &noop, 0.6)
-                .into_iter()
-                .map(|s| s.content.into_owned())
-                .collect::<String>()
+            MessagesComponent::build_email_row_spans(
+                email,
+                80,
+                &ColumnConfig::defaults(),
+                &drafts,
+                false,
+                &noop,
+                0.6,
+                &Config::default(),
+                &Theme::default(),
+            )
+            .into_iter()
+            .map(|s| s.content.into_owned())
+            .collect::<String>()
         };
         let in_progress_row = row_text(&in_progress);
         let later_row = row_text(&later);
@@ -982,6 +1820,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn corrupt_email_row_renders_warning_marker() {
+        let maildir = crate::test_fixtures::TestMailDir::new();
+        maildir.add_custom_email("INBOX", "corrupt", "");
+        let scanner = crate::maildir::MaildirScanner::new(maildir.root_path.clone());
+        let mut root = scanner.scan().unwrap();
+        let inbox = root
+            .subfolders
+            .iter_mut()
+            .find(|f| f.name == "INBOX")
+            .unwrap();
+        scanner.load_folder_emails_with_limit(inbox, None).unwrap();
+        assert_eq!(inbox.corrupt_count, 1);
+        let email = inbox
+            .emails
+            .iter()
+            .find(|e| e.parse_error.is_some())
+            .expect("the empty file is kept as a corrupt placeholder");
+
+        let drafts: HashMap<String, DraftInfo> = HashMap::new();
+        let noop = NoopClassifier;
+        let spans = MessagesComponent::build_email_row_spans(
+            email,
+            80,
+            &ColumnConfig::defaults(),
+            &drafts,
+            false,
+            &noop,
+            0.6,
+            &Config::default(),
+            &Theme::default(),
+        );
+        let marker = spans
+            .iter()
+            .find(|s| s.content.trim() == "!")
+            .expect("corrupt row has a ! marker");
+        assert_eq!(marker.style.fg, Some(VulthorTheme::WARNING));
+    }
+
     // --- Phase 5.a: AI classifier chip rendering. ---
 
     use crate::classifier::Suggestion;
@@ -1005,10 +1882,319 @@ mod tests {
             subject: "subject".to_string(),
             date: "2024-01-15T10:30:00+00:00".to_string(),
             message_id: id.to_string(),
+            priority: Priority::Normal,
+            in_reply_to: String::new(),
+            references: Vec::new(),
         };
         e
     }
 
+    #[test]
+    fn tagged_row_renders_marker_without_shifting_columns() {
+        let email = email_for("e1");
+        let drafts: HashMap<String, DraftInfo> = HashMap::new();
+        let noop = NoopClassifier;
+        let row = |tagged: bool| -> String {
+            MessagesComponent::build_email_row_spans(
+                &email,
+                80,
+                &ColumnConfig::defaults(),
+                &drafts,
+                tagged,
+                &noop,
+                0.6,
+                &Config::default(),
+                &Theme::default(),
+            )
+            .into_iter()
+            .map(|s| s.content.into_owned())
+            .collect()
+        };
+        let (plain, marked) = (row(false), row(true));
+        assert!(!plain.contains('*'));
+        assert!(marked.contains('*'), "tagged row was {:?}", marked);
+        assert_eq!(
+            UnicodeWidthStr::width(plain.as_str()),
+            UnicodeWidthStr::width(marked.as_str())
+        );
+    }
+
+    #[test]
+    fn thread_rows_indent_replies_and_count_folded_ones() {
+        use crate::threading::ThreadPos;
+        let drafts: HashMap<String, DraftInfo> = HashMap::new();
+        let noop = NoopClassifier;
+        let row = |thread: ThreadPos| -> String {
+            let mut email = email_for("e1");
+            email.thread = thread;
+            MessagesComponent::build_email_row_spans(
+                &email,
+                80,
+                &ColumnConfig::defaults(),
+                &drafts,
+                false,
+                &noop,
+                0.6,
+                &Config::default(),
+                &Theme::default(),
+            )
+            .into_iter()
+            .map(|s| s.content.into_owned())
+            .collect()
+        };
+        assert!(row(ThreadPos::default()).contains("  subject"));
+        let reply = row(ThreadPos {
+            depth: 2,
+            ..ThreadPos::default()
+        });
+        assert!(reply.contains("  └ subject"), "reply row was {:?}", reply);
+        let folded = row(ThreadPos {
+            replies: 2,
+            collapsed: true,
+            ..ThreadPos::default()
+        });
+        assert!(
+            folded.contains("[+2] subject"),
+            "folded row was {:?}",
+            folded
+        );
+    }
+
+    #[test]
+    fn spam_row_renders_red_marker_without_shifting_columns() {
+        let mut email = email_for("e1");
+        let drafts: HashMap<String, DraftInfo> = HashMap::new();
+        let noop = NoopClassifier;
+        let row = |email: &Email| {
+            MessagesComponent::build_email_row_spans(
+                email,
+                80,
+                &ColumnConfig::defaults(),
+                &drafts,
+                false,
+                &noop,
+                0.6,
+                &Config::default(),
+                &Theme::default(),
+            )
+        };
+        let plain: String = row(&email).iter().map(|s| s.content.as_ref()).collect();
+        email.is_spam = true;
+        let spans = row(&email);
+        let marked: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        let marker = spans
+            .iter()
+            .find(|s| s.content.trim() == "S")
+            .expect("spam row has an S marker");
+        assert_eq!(marker.style.fg, Some(Color::Red));
+        assert_eq!(
+            UnicodeWidthStr::width(plain.as_str()),
+            UnicodeWidthStr::width(marked.as_str())
+        );
+    }
+
+    #[test]
+    fn flagged_row_renders_star_beside_attachment_slot() {
+        let mut email = email_for("e1");
+        let drafts: HashMap<String, DraftInfo> = HashMap::new();
+        let noop = NoopClassifier;
+        let row = |email: &Email| -> String {
+            MessagesComponent::build_email_row_spans(
+                email,
+                80,
+                &ColumnConfig::defaults(),
+                &drafts,
+                false,
+                &noop,
+                0.6,
+                &Config::default(),
+                &Theme::default(),
+            )
+            .into_iter()
+            .map(|s| s.content.into_owned())
+            .collect()
+        };
+        let plain = row(&email);
+        email.is_flagged = true;
+        let starred = row(&email);
+        assert!(!plain.contains('★'));
+        assert!(starred.contains('★'), "flagged row was {:?}", starred);
+        assert_eq!(
+            UnicodeWidthStr::width(plain.as_str()),
+            UnicodeWidthStr::width(starred.as_str())
+        );
+    }
+
+    #[test]
+    fn row_columns_follow_the_configured_set_and_order() {
+        use crate::config::{ColumnConfig, ColumnWidth};
+        let mut email = email_for("e1");
+        email.size_bytes = 2048;
+        let drafts: HashMap<String, DraftInfo> = HashMap::new();
+        let noop = NoopClassifier;
+        let column = |name: &str, width: Option<ColumnWidth>, enabled: bool| ColumnConfig {
+            name: name.to_string(),
+            width,
+            enabled,
+        };
+        let row = |columns: Vec<ColumnConfig>| -> String {
+            let config = Config {
+                columns,
+                sender_display: SenderDisplay::Address,
+                ..Config::default()
+            };
+            MessagesComponent::build_email_row_spans(
+                &email,
+                80,
+                &config.columns,
+                &drafts,
+                false,
+                &noop,
+                0.6,
+                &config,
+                &Theme::default(),
+            )
+            .into_iter()
+            .map(|s| s.content.into_owned())
+            .collect()
+        };
+
+        let default = row(ColumnConfig::defaults());
+        assert!(default.contains("a@b.test"), "{:?}", default);
+        assert!(!default.contains("2.0 KB"), "{:?}", default);
+
+        let mut columns = ColumnConfig::defaults();
+        columns[1].enabled = false;
+        let no_from = row(columns);
+        assert!(!no_from.contains("a@b.test"), "{:?}", no_from);
+        assert!(no_from.contains("subject"));
+
+        let mut columns = ColumnConfig::defaults();
+        columns.push(column("size", None, true));
+        let sized = row(columns);
+        assert!(sized.trim_end().ends_with("2.0 KB"), "{:?}", sized);
+        assert_eq!(sized.width(), default.width(), "the subject gives way");
+
+        let reordered = row(vec![
+            column("date", None, true),
+            column("subject", Some(ColumnWidth::Fixed(10)), true),
+            column("to", Some(ColumnWidth::Flexible), true),
+            column("nonsense", None, true),
+        ]);
+        assert!(
+            reordered.starts_with("2024-01-15  subject   "),
+            "{:?}",
+            reordered
+        );
+        assert!(reordered.contains("c@d.test"));
+        assert_eq!(
+            reordered.width(),
+            80 - 4,
+            "the flexible column fills the row"
+        );
+    }
+
+    #[test]
+    fn folder_column_names_the_directory_above_cur() {
+        assert_eq!(folder_label(Path::new("/m/Work/cur/1:2,S")), "Work");
+        assert_eq!(folder_label(Path::new("/m/INBOX/new/2")), "INBOX");
+        assert_eq!(
+            folder_label(Path::new("/m/archive.mbox/0-120")),
+            "archive.mbox"
+        );
+
+        let mut email = email_for("e1");
+        email.file_path = PathBuf::from("/m/Work/cur/e1:2,S");
+        let columns: Vec<ColumnConfig> = ["folder", "subject"]
+            .into_iter()
+            .map(|name| ColumnConfig {
+                name: name.to_string(),
+                width: None,
+                enabled: true,
+            })
+            .collect();
+        let row: String = MessagesComponent::build_email_row_spans(
+            &email,
+            80,
+            &columns,
+            &HashMap::new(),
+            false,
+            &NoopClassifier,
+            0.6,
+            &Config::default(),
+            &Theme::default(),
+        )
+        .into_iter()
+        .map(|s| s.content.into_owned())
+        .collect();
+        assert!(row.starts_with("Work "), "{:?}", row);
+        assert!(row.contains("subject"), "{:?}", row);
+        assert_eq!(row.width(), 80 - 4);
+    }
+
+    #[test]
+    fn subject_only_column_set_draws_just_the_subject_across_the_row() {
+        use crate::config::ColumnConfig;
+        let email = email_for("e1");
+        let config = Config {
+            columns: vec![ColumnConfig {
+                name: "subject".to_string(),
+                width: None,
+                enabled: true,
+            }],
+            ..Config::default()
+        };
+        let spans = MessagesComponent::build_email_row_spans(
+            &email,
+            80,
+            &config.columns,
+            &HashMap::new(),
+            false,
+            &NoopClassifier,
+            0.6,
+            &config,
+            &Theme::default(),
+        );
+        assert_eq!(spans.len(), 1, "no gutter, gaps or other columns");
+        assert!(spans[0].content.starts_with("subject"));
+        assert_eq!(spans[0].content.width(), 80 - 4);
+    }
+
+    #[test]
+    fn high_priority_row_renders_accent_bang() {
+        let mut email = email_for("e1");
+        let drafts: HashMap<String, DraftInfo> = HashMap::new();
+        let noop = NoopClassifier;
+        let row = |email: &Email| {
+            MessagesComponent::build_email_row_spans(
+                email,
+                80,
+                &ColumnConfig::defaults(),
+                &drafts,
+                false,
+                &noop,
+                0.6,
+                &Config::default(),
+                &Theme::default(),
+            )
+        };
+        let plain: String = row(&email).iter().map(|s| s.content.as_ref()).collect();
+        email.headers.priority = Priority::Low;
+        assert!(!row(&email).iter().any(|s| s.content == "!"));
+        email.headers.priority = Priority::High;
+        let spans = row(&email);
+        let bang = spans
+            .iter()
+            .find(|s| s.content == "!")
+            .expect("high-priority row has a ! marker");
+        assert_eq!(bang.style.fg, Some(VulthorTheme::ACCENT));
+        let marked: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(
+            UnicodeWidthStr::width(plain.as_str()),
+            UnicodeWidthStr::width(marked.as_str())
+        );
+    }
+
     /// VISION.md acceptance: chip rendering shows the action character
     /// when confidence >= threshold. Confidence 0.9, threshold 0.6 →
     /// row contains 'a' (the default Archive keybinding).
@@ -1020,7 +2206,17 @@ mod tests {
             action: Action::Archive,
             confidence: 0.9,
         });
-        let spans = MessagesComponent::build_email_row_spans(&email, 80, false, &drafts, &clf, 0.6);
+        let spans = MessagesComponent::build_email_row_spans(
+            &email,
+            80,
+            &ColumnConfig::defaults(),
+            &drafts,
+            false,
+            &clf,
+            0.6,
+            &Config::default(),
+            &Theme::default(),
+        );
         let row: String = spans.iter().map(|s| s.content.as_ref()).collect();
         assert!(
             row.contains('a'),
@@ -1070,4 +2266,140 @@ mod tests {
         let s = m.classifier().suggest(&email).expect("Some");
         assert_eq!(s.action, Action::Star);
     }
+
+    #[test]
+    fn a_draft_row_carries_the_d_badge_in_the_accent_colour() {
+        let mut email = Email::new(PathBuf::from("/tmp/Drafts/cur/d1"));
+        email.headers.subject = "Unfinished".to_string();
+        let row = |email: &Email| {
+            MessagesComponent::build_email_row_spans(
+                email,
+                80,
+                &ColumnConfig::defaults(),
+                &HashMap::new(),
+                false,
+                &NoopClassifier,
+                0.6,
+                &Config::default(),
+                &Theme::default(),
+            )
+        };
+        assert!(!row(&email).iter().any(|s| s.content == DRAFT_BADGE));
+
+        email.is_draft = true;
+        let spans = row(&email);
+        let badge = spans
+            .iter()
+            .position(|s| s.content == DRAFT_BADGE)
+            .expect("draft badge");
+        assert_eq!(spans[badge].style.fg, Some(VulthorTheme::ACCENT));
+        assert!(spans[badge + 1].content.starts_with("Unfinished"));
+        let width = |spans: &[Span]| spans.iter().map(|s| s.content.width()).sum::<usize>();
+        assert_eq!(width(&spans), width(&row(&Email::new(PathBuf::new()))));
+    }
+
+    #[test]
+    fn list_scrolls_with_a_margin_and_centres_after_a_jump() {
+        let mut folder = Folder::new("INBOX".into(), PathBuf::from("/m/INBOX"));
+        for i in 0..100 {
+            folder.add_email(email_for(&format!("e{i}")));
+        }
+        let (theme, config) = (Theme::default(), Config::default());
+        let drafts = HashMap::new();
+        let mut c = MessagesComponent::new();
+        // 12 rows: ten list rows inside the borders.
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 12)).unwrap();
+        let mut draw_at = |c: &mut MessagesComponent, index: usize| {
+            c.email_index = index;
+            terminal
+                .draw(|f| {
+                    c.render_with_folder(
+                        f,
+                        f.area(),
+                        true,
+                        &folder,
+                        "INBOX",
+                        &drafts,
+                        &theme,
+                        &config,
+                    )
+                })
+                .unwrap();
+            c.list_offset()
+        };
+
+        for index in 0..=6 {
+            assert_eq!(
+                draw_at(&mut c, index),
+                0,
+                "row {index} is clear of the margin"
+            );
+        }
+        assert_eq!(draw_at(&mut c, 7), 1, "three rows stay below the cursor");
+        assert_eq!(draw_at(&mut c, 8), 2);
+        assert_eq!(draw_at(&mut c, 5), 2, "moving back up holds the list");
+        assert_eq!(draw_at(&mut c, 4), 1);
+
+        assert_eq!(draw_at(&mut c, 50), 45, "a jump centres the cursor");
+        assert_eq!(draw_at(&mut c, 99), 90, "G shows a full last page");
+        assert_eq!(draw_at(&mut c, 0), 0);
+    }
+
+    /// `list_preview` gives each entry a dimmed preview line under it,
+    /// halving the entries that fit, and scrolling counts entries.
+    #[test]
+    fn list_preview_draws_two_line_entries() {
+        let mut folder = Folder::new("INBOX".into(), PathBuf::from("/m/INBOX"));
+        for i in 0..30 {
+            let mut email = email_for(&format!("e{i}"));
+            email.preview = Some(format!("preview of e{i}"));
+            folder.add_email(email);
+        }
+        let theme = Theme::default();
+        let config = Config {
+            list_preview: true,
+            ..Config::default()
+        };
+        let drafts = HashMap::new();
+        let mut c = MessagesComponent::new();
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 12)).unwrap();
+        let mut draw_at = |c: &mut MessagesComponent, index: usize| {
+            c.email_index = index;
+            terminal
+                .draw(|f| {
+                    c.render_with_folder(
+                        f,
+                        f.area(),
+                        true,
+                        &folder,
+                        "INBOX",
+                        &drafts,
+                        &theme,
+                        &config,
+                    )
+                })
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+
+        let buffer = draw_at(&mut c, 0);
+        assert_eq!(c.visible_rows.get(), 5, "ten lines hold five entries");
+        let line = |y: u16| -> String {
+            (1..79)
+                .map(|x| buffer[(x, y)].symbol().to_string())
+                .collect()
+        };
+        assert!(line(1).contains("subject"), "{}", line(1));
+        assert!(line(2).contains("preview of e0"), "{}", line(2));
+        assert!(line(4).contains("preview of e1"), "{}", line(4));
+        let preview_cell = &buffer[(1 + GUTTER_WIDTH as u16, 4)];
+        assert_eq!(preview_cell.fg, theme.gray_dark);
+
+        // Five entries on screen leave room for a two-entry margin, so
+        // the list follows from the fourth.
+        draw_at(&mut c, 3);
+        assert_eq!(c.list_offset(), 1);
+    }
 }
@@ -7,7 +7,9 @@
 #![allow(dead_code, unused_imports)]
 
 mod accounts;
+mod attach_picker;
 mod body_loader;
+mod command;
 mod content;
 mod ctx;
 pub(crate) mod draft;
@@ -16,18 +18,26 @@ mod folder_scanner;
 mod folders;
 mod headers_loader;
 pub(crate) mod help;
-mod html_viewer;
+pub(crate) mod html_viewer;
 mod maildir_watcher;
 mod messages;
 mod msg;
+mod part_picker;
 mod root;
 mod search;
+mod status;
+mod viewport;
 
 /// Re-exported: [`AccountsComponent`].
 pub use accounts::AccountsComponent;
 /// Re-exported: off-thread body parser types ([`BodyLoader`],
 /// [`LoadedBody`], [`ParsedBody`]).
 pub use body_loader::{BodyLoader, LoadedBody, ParsedBody};
+/// Re-exported: `:` command line ([`CommandLineComponent`]) and the
+/// [`CommandRegistry`] other modules extend.
+pub use command::{
+    CommandHandler, CommandLineComponent, CommandRegistry, CommandSpec, parse_command_line,
+};
 /// Re-exported: [`ContentComponent`].
 pub use content::ContentComponent;
 /// Re-exported: shared read-only [`Ctx`].
@@ -52,11 +62,13 @@ pub use messages::MessagesComponent;
 /// Re-exported: cross-component message types and id aliases
 /// ([`Msg`], [`Dir`], [`ReplyKind`], [`AccountId`], [`FolderPath`],
 /// [`MessageId`]).
-pub use msg::{AccountId, Dir, FolderPath, MessageId, Msg, ReplyKind};
+pub use msg::{AccountId, Dir, FolderPath, Jump, MessageId, Msg, ReplyKind, YankWhat};
 /// Re-exported: main-loop driver [`AppRoot`].
 pub use root::AppRoot;
 /// Re-exported: [`SearchComponent`] modal and notmuch helpers.
 pub use search::{SearchComponent, notmuch_available, parse_notmuch_files_output};
+/// Re-exported: status-bar [`StatusMessage`] and its [`StatusKind`].
+pub use status::{StatusKind, StatusMessage};
 
 use std::collections::VecDeque;
 
@@ -27,6 +27,41 @@ pub enum Dir {
     Right,
 }
 
+impl Dir {
+    /// Row `halves` half-pages of `visible` rows from `from` in this
+    /// direction — the target of `Ctrl-d` / `PageDown` and their
+    /// upward twins. Unclamped at the bottom; callers clamp.
+    pub fn paged(self, from: usize, halves: usize, visible: usize) -> usize {
+        let step = (visible / 2).max(1).saturating_mul(halves);
+        match self {
+            Dir::Down => from.saturating_add(step),
+            _ => from.saturating_sub(step),
+        }
+    }
+}
+
+/// Target row of a `gg` / `G` / `{count}G` jump. Handlers clamp `To`
+/// to the last row, so an oversized count lands on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jump {
+    /// 0-based row (or body line, in the Content pane).
+    To(usize),
+    Last,
+}
+
+/// What a `y` yank copies from the selected email.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YankWhat {
+    /// The body as the Content pane shows it (`yy`).
+    Body,
+    /// `ys`
+    Subject,
+    /// The bare From address, display name stripped (`yf`).
+    From,
+    /// Every link in the body, one per line (`yl`).
+    Links,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReplyKind {
     Reply,
@@ -51,6 +86,15 @@ pub enum Msg {
     ViewNext,
     ViewPrev,
     ToggleContentPane,
+    /// Switch between the two-pane views and the three-pane layout.
+    ToggleThreePane,
+    /// Enter (`true`, `Ctrl-w`) or leave the pane resize mode.
+    ResizeMode(bool),
+    /// Move the current view's split by this many percent (`h` / `l`
+    /// in resize mode).
+    SplitAdjust(i16),
+    /// Put the current view's split back to the configured one (`=`).
+    SplitReset,
     FocusNext,
     FocusPrev,
     /// AppRoot publishes the new focused pane after every focus change.
@@ -70,9 +114,27 @@ pub enum Msg {
     ///
     /// [`EmailStore`]: crate::email::EmailStore
     AccountSelect(AccountId),
+    /// Switch to the account after the active one (wrapping), from any
+    /// pane. `AppRoot` resolves it to an [`Msg::AccountSelect`].
+    AccountNext,
+    /// Rescan the current folder from disk, reporting how many emails
+    /// appeared since the last load.
+    FolderRefresh,
+    /// `:empty-trash`: ask before emptying the Trash folder on display.
+    EmptyTrashAsk,
+    /// Permanently delete every email in the Trash folder on display
+    /// (the `y` answer to [`Msg::EmptyTrashAsk`]).
+    EmptyTrash,
 
     // Folders
     FolderMove(Dir),
+    /// Put the folder cursor on a row (`gg`, `G`, `5j`). Components
+    /// treat it like [`Msg::FolderMove`] otherwise.
+    FolderJump(Jump),
+    /// Move the folder cursor by `n` half-pages of visible rows
+    /// (`Ctrl-d` is 1, `PageDown` 2). Resolved into a
+    /// [`Msg::FolderJump`] by the Folders component.
+    FolderPage(Dir, usize),
     FolderEnter,
     FolderLoaded(FolderPath),
     /// Back-navigation out of the current folder (Backspace from the
@@ -80,11 +142,71 @@ pub enum Msg {
     /// path and resets scroll; `FoldersComponent`/`MessagesComponent`
     /// reset their own indices in `handle_msg`.
     FolderExitParent,
+    /// Collapse or expand the highlighted folder's children in the
+    /// folder pane (`za`). Handled entirely by `FoldersComponent`; a
+    /// folder without subfolders ignores it.
+    FolderToggleCollapse,
+    /// Collapse (`true`, `Left`) or expand (`false`, `Right`) the
+    /// highlighted folder; a no-op when it is already that way.
+    FolderSetCollapsed(bool),
+    /// Move the folder-pane cursor to the next (`Down`, `}`) or
+    /// previous (`Up`, `{`) folder with unread mail, wrapping at the
+    /// ends. Handled by `AppRoot`, which loads headers on the way.
+    FolderUnreadStep(Dir),
 
     // Messages
     MessageMove(Dir),
+    /// Put the message cursor on a row (`gg`, `G`, `12G`). Components
+    /// treat it like [`Msg::MessageMove`] otherwise.
+    MessageJump(Jump),
+    /// Go to the 0-based row of the current folder (`12G`, `:goto 12`),
+    /// loading headers up to it first when the folder is only partly
+    /// read. `AppRoot` resolves it into a [`Msg::MessageJump`],
+    /// clamped to the last email with a status note.
+    MessageGoto(usize),
+    /// Move the message cursor by `n` half-pages of visible rows.
+    /// Resolved into a [`Msg::MessageJump`] by the Messages component.
+    MessagePage(Dir, usize),
     MessageOpen(MessageId),
     MessageMarkRead(MessageId),
+    /// Tag the cursor row for a bulk action, or untag it if it is
+    /// already tagged (`Space`). Handled by `MessagesComponent`; the
+    /// row becomes the anchor for a following `MessageSelectRange`.
+    MessageToggleSelect,
+    /// Tag every row between the last toggled row and the cursor,
+    /// inclusive (`V`). With no anchor yet, tags just the cursor.
+    MessageSelectRange,
+    /// Drop every tag (`Esc` in the Messages pane, `:untag-all`).
+    MessageUntagAll,
+    /// Open the in-folder search prompt (`/` in the Messages pane).
+    /// `AppRoot` loads the rest of a partially loaded folder first so
+    /// matches aren't missed.
+    MessageFindOpen,
+    /// The prompt's text changed; jump to the first matching email at
+    /// or after the row the prompt opened on, wrapping.
+    MessageFindInput(String),
+    /// Commit the typed term (`Enter`); `n` / `N` then step through
+    /// its matches.
+    MessageFind(String),
+    /// Move to the next (`Down`, `n`) or previous (`Up`, `N`) email
+    /// matching the committed term, wrapping at the ends.
+    MessageFindStep(Dir),
+    /// `Esc`: close an open prompt, returning to the row it opened on,
+    /// or drop the committed term's highlight.
+    MessageFindCancel,
+    /// Move to the next (`Down`, `]`) or previous (`Up`, `[`) unread
+    /// email, wrapping at the ends.
+    MessageUnreadStep(Dir),
+    /// `]` / `[`: read in more of a partly loaded folder until an
+    /// unread email turns up ahead of the cursor, then step to it with
+    /// `MessageUnreadStep`. Handled by `AppRoot`.
+    MessageUnreadSeek(Dir),
+    /// Switch the Messages pane between load order and conversation
+    /// threads (`T`). Handled by `AppRoot`.
+    MessageThreadsToggle,
+    /// Fold or unfold the replies of the thread under the cursor
+    /// (`Alt+v`, mutt's `Esc v`). Handled by `AppRoot`.
+    MessageThreadCollapse,
     /// Fired by `AppRoot` after a focus change that just blurred the
     /// Folders pane (focus moved Folders → Messages). `MessagesComponent`
     /// uses it to restore the remembered email selection — or pick the
@@ -101,7 +223,7 @@ pub enum Msg {
     /// store knows where the user is reading.
     StoreLoadMore(usize),
 
-    // Direct mutation actions. All three carry a `MessageId` for
+    // Direct mutation actions. All of them carry a `MessageId` for
     // forward compatibility, but until the store grows a real index the
     // action-key handler in `MessagesComponent` emits an empty sentinel
     // string and `AppRoot::apply_root` resolves the target from the
@@ -124,6 +246,21 @@ pub enum Msg {
     /// already in `new/`. Pushes a `MarkUnread` mutation onto the
     /// undo stack.
     MarkUnread(MessageId),
+    /// Explicit mark-read: move the cursor-selected email from `new/`
+    /// to `cur/`. Unlike `MessageMarkRead` (the auto mark-read fired on
+    /// open), this one honours the multi-selection and reports a
+    /// status.
+    MarkRead(MessageId),
+    /// Mark the cursor-selected email (or each tagged one) read if it
+    /// is unread and unread if it is read (`Alt+u`).
+    ToggleRead(MessageId),
+    /// Move the cursor-selected email (or the multi-selection) to
+    /// `<maildir_root>/<[spam].junk_folder>/cur/`. Creates the folder on
+    /// first use. Pushes a `Move` mutation onto the undo stack.
+    MoveToJunk(MessageId),
+    /// Copy part of the selected email to the clipboard (`yy`, `ys`,
+    /// `yf`, `yl`). Handled by `AppRoot`.
+    Yank(YankWhat),
 
     /// Open the folder-picker modal. The
     /// `FolderPickerComponent` populates itself from the live store
@@ -137,9 +274,45 @@ pub enum Msg {
     /// The path is the folder's filesystem path; AppRoot appends
     /// `cur/<filename>` to produce the destination.
     MoveTo(MessageId, FolderPath),
+    /// Open the folder picker as a fuzzy "jump to folder" switcher,
+    /// with the given text already typed into its filter.
+    OpenFolderJump(String),
+    /// Enter the folder at the given filesystem path, as if it had been
+    /// picked in the folder pane.
+    JumpToFolder(FolderPath),
 
     // Content
+    /// Scroll the body by `n` lines (`Up`/`Down`) or `n` columns
+    /// (`Left`/`Right`, only while word wrap is off).
     ContentScroll(Dir, usize),
+    /// Show the next (`Down`, `J`) or previous (`Up`, `K`) email of the
+    /// folder from the top, moving the list cursor along. Stops at the
+    /// ends. Handled by `AppRoot`.
+    ContentMessageStep(Dir),
+    /// Scroll the body so a line is at the top (`gg`, `{count}G`), or
+    /// to the end (`G`), clamped to the last rendered line.
+    ContentJump(Jump),
+    /// Scroll the body by `n` half-pages, stopping at the last
+    /// rendered line.
+    ContentPage(Dir, usize),
+    /// Open the in-body search prompt (`/` in the Content pane).
+    ContentFindOpen,
+    /// Search the displayed body for a term and scroll to its first
+    /// match (`Enter` in the prompt).
+    ContentFind(String),
+    /// Scroll to the next (`Down`, `n`) or previous (`Up`, `N`) match
+    /// of the active in-body search, wrapping at the ends.
+    ContentFindStep(Dir),
+    /// Close the prompt and drop the active in-body search (`Esc`).
+    ContentFindClear,
+    /// Flip word wrap for the Content pane body (`w`). Turning wrap
+    /// back on resets the horizontal scroll offset.
+    ToggleWordWrap,
+    /// Expand the header box to every header, or fold it back to the
+    /// From / To / Subject / Date summary (`H`).
+    ToggleHeaders,
+    /// Swap the body for the message file's raw source, or back (`gs`).
+    ToggleRawSource,
 
     /// Open the indexed attachment of the currently selected email.
     /// AppRoot resolves the attachment, writes its bytes to
@@ -147,18 +320,53 @@ pub enum Msg {
     /// `xdg-open` against the resulting path. No-op when the index is
     /// out of range. Bound to `o` by default.
     AttachmentOpen(usize),
+    /// Focus and open attachment `n`, counting from 1 as the list
+    /// numbers them (`1`-`9` in the Attachments pane, `Alt+1`-`Alt+9`
+    /// in the Content pane). Out of range says "No attachment N".
+    AttachmentOpenNth(usize),
+    /// Show the focused attachment of the selected email in the
+    /// Content pane in place of the body. Textual types up to 1 MB
+    /// only; anything else leaves a status-bar note. Bound to `p` by
+    /// default in the Attachments and Content panes.
+    AttachmentPreview,
+    /// Close the Content-pane attachment preview and return to the
+    /// email body (Backspace / Esc while previewing).
+    AttachmentPreviewClose,
+    /// Open the MIME part popup over the Content pane for the selected
+    /// email (`Alt+m`).
+    MimeTreeOpen,
+    /// Show part `n` of the selected email's MIME tree (depth-first,
+    /// as the popup lists them) in place of the body.
+    MimePartPreview(usize),
 
     // Draft
     DraftStart(ReplyKind, MessageId),
+    /// Reopen the saved draft under the Messages cursor in `$EDITOR`
+    /// (Enter in a Drafts folder). AppRoot resolves the cursor and
+    /// loads the file; saving writes back over the same file.
+    DraftResume,
+    /// `e` in the Messages pane: resume the cursor email when it is a
+    /// draft (follows up with `DraftResume`), else say it isn't one.
+    DraftResumeSelected,
     DraftEditorExited,
     DraftSend,
     /// Relaunch `$EDITOR` on the in-flight draft. AppRoot rebuilds the
     /// template from the live `Compose` and parks a new editor request
     /// for the run loop. No-op when there is no draft in flight.
     DraftEditRelaunch,
-    /// Discard the in-flight draft (q/Esc from the Draft pane). Clears
-    /// the draft state and drops back to the `MessagesContent` view.
-    /// No-op when there is no draft in flight.
+    /// q/Esc from the Draft pane: ask "Save as draft? (y/n)" before
+    /// closing, answered with `DraftSave` or `DraftDiscard`.
+    DraftClose,
+    /// Write the in-flight draft to `Drafts/cur/` (over the file it was
+    /// resumed from, if any) and close it.
+    DraftSave,
+    /// `Ctrl+A` in the Draft pane: open the file picker at `~/`.
+    DraftAttachOpen,
+    /// Add this file to the in-flight draft's attachments.
+    DraftAttach(PathBuf),
+    /// Discard the in-flight draft. Clears the draft state and drops
+    /// back to the `MessagesContent` view. No-op when there is no draft
+    /// in flight.
     DraftDiscard,
 
     // Store mutations (handled by AppRoot/store owner)
@@ -178,6 +386,12 @@ pub enum Msg {
     /// only AppRoot reacts.
     ToggleHtmlViewer,
 
+    /// Open the web server's view of the current email in the system's
+    /// default browser (`xdg-open` / `open` / `start`). Bound to `O`.
+    /// Unlike `ToggleHtmlViewer` the window is not tracked. Only
+    /// AppRoot reacts.
+    OpenInBrowser,
+
     /// Toggle per-session plaintext-only rendering (vu-c1s). When on,
     /// the Content pane renders `body_plain` verbatim — or the literal
     /// `"(no plain part)"` marker if no plain part exists — even when
@@ -187,6 +401,13 @@ pub enum Msg {
     /// reads it on render.
     TogglePlaintext,
 
+    /// Switch the Content pane between the plain-text and HTML parts of
+    /// a `multipart/alternative` message. Bound to `v` while the Content
+    /// pane is focused (`v` elsewhere stays `ToggleHtmlViewer`). AppRoot
+    /// flips `ContentComponent.prefer_html`; the web pane is unaffected
+    /// and always serves the HTML part when one exists.
+    ToggleBodyPart,
+
     /// vu-aoy: reveal HTML images in the web pane for the current
     /// message. AppRoot flips the shared `images_visible` atomic the
     /// web server reads; a subsequent email selection resets it back
@@ -217,6 +438,32 @@ pub enum Msg {
     /// `h` / `Esc` from the Messages pane.
     SearchCancel,
 
+    // `:` command line. `CommandLineComponent` absorbs keys while open;
+    // AppRoot looks the submitted line up in its `CommandRegistry`.
+    /// Open the command-line prompt. Bound to `:`.
+    CommandOpen,
+    /// Run a submitted command line (`Enter` in the prompt). Unknown
+    /// names report `Unknown command: <name>` in the status bar.
+    CommandRun(String),
+    /// Close the prompt without running anything (`Esc`).
+    CommandCancel,
+    /// `:sort <order> [reverse]`: order every folder's message list;
+    /// `None` (bare `:sort`) goes back to load order.
+    SortBy(Option<crate::email::SortOrder>, bool),
+    /// `:mark-all-read`: mark every email of the current folder read,
+    /// as one undo step.
+    MarkAllRead,
+    /// `:filter <text>`: show the current folder's emails whose
+    /// subject, sender or recipient contain the text, as a virtual
+    /// folder left like search results.
+    MessageFilter(String),
+    /// `:export <file>`: write the cursor email, or the tagged ones, to
+    /// an mbox file.
+    Export(std::path::PathBuf),
+    /// `:charset <name>`: decode the selected email's body as `name`;
+    /// `None` (`:charset auto`) goes back to the declared charset.
+    CharsetOverride(Option<String>),
+
     // Phase 4.d — MailDir auto-refresh (inotify/FSEvents). Emitted by
     // `MaildirWatcherComponent` after a debounce window when the
     // filesystem reports a Create/Rename under `<folder>/cur/` or
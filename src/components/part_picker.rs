@@ -0,0 +1,140 @@
+// `PartPicker` — the `Alt+m` MIME structure popup of the Content pane.
+//
+// Lists the parts of the selected message depth-first, each indented
+// two spaces per level of multipart nesting, with its type, file name
+// and decoded size (see `crate::mime_tree`). The list is read from the
+// message file when the popup opens and dropped when it closes.
+//
+// Keys (routed here by AppRoot while `visible`):
+//   - j / k / ↓ / ↑      move the cursor
+//   - Enter              show the part in the Content pane and close
+//   - Esc / q            close
+
+use std::cell::RefCell;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+use crate::mime_tree::MimePart;
+use crate::theme::Theme;
+
+use super::Msg;
+
+/// MIME tree popup state.
+#[derive(Default)]
+pub struct PartPicker {
+    pub visible: bool,
+    pub parts: Vec<MimePart>,
+    pub selected_index: usize,
+    list_state: RefCell<ListState>,
+}
+
+impl PartPicker {
+    /// Show the popup over `parts`, cursor on the first.
+    pub fn open(&mut self, parts: Vec<MimePart>) {
+        self.visible = true;
+        self.parts = parts;
+        self.selected_index = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.parts.clear();
+    }
+
+    /// Handle one key while visible. Returns the preview request when
+    /// a part is chosen.
+    pub fn on_key(&mut self, key: KeyEvent) -> Option<Msg> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.close(),
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.selected_index =
+                    (self.selected_index + 1).min(self.parts.len().saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.selected_index = self.selected_index.saturating_sub(1);
+            }
+            KeyCode::Enter if !self.parts.is_empty() => {
+                let index = self.selected_index;
+                self.close();
+                return Some(Msg::MimePartPreview(index));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Paint the popup centred in `area` (the Content pane).
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let width = (area.width * 4 / 5).max(20).min(area.width);
+        let height = (self.parts.len() as u16 + 2)
+            .clamp(3, (area.height * 4 / 5).max(3))
+            .min(area.height);
+        let rect = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        let items: Vec<ListItem> = self
+            .parts
+            .iter()
+            .map(|part| ListItem::new(part.label()))
+            .collect();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.focus(true, theme.accent))
+            .title("MIME parts [Enter show · Esc]");
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.selection());
+        let mut state = self.list_state.borrow_mut();
+        state.select(Some(self.selected_index));
+        f.render_widget(Clear, rect);
+        f.render_stateful_widget(list, rect, &mut *state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn part(content_type: &str, depth: usize) -> MimePart {
+        MimePart {
+            content_type: content_type.to_string(),
+            size: 10,
+            filename: None,
+            depth,
+        }
+    }
+
+    #[test]
+    fn the_cursor_stays_in_range_and_enter_asks_for_the_part() {
+        let mut picker = PartPicker::default();
+        picker.open(vec![part("multipart/mixed", 0), part("text/plain", 1)]);
+        picker.on_key(key(KeyCode::Char('k')));
+        assert_eq!(picker.selected_index, 0);
+        picker.on_key(key(KeyCode::Char('j')));
+        picker.on_key(key(KeyCode::Down));
+        assert_eq!(picker.selected_index, 1);
+
+        assert_eq!(
+            picker.on_key(key(KeyCode::Enter)),
+            Some(Msg::MimePartPreview(1))
+        );
+        assert!(!picker.visible);
+
+        picker.open(vec![part("text/plain", 0)]);
+        assert_eq!(picker.on_key(key(KeyCode::Esc)), None);
+        assert!(!picker.visible && picker.parts.is_empty());
+    }
+}
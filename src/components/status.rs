@@ -0,0 +1,139 @@
+// Status-bar message with an expiry.
+//
+// `AppRoot` used to drop the message on the next key press, which hid
+// errors before they could be read. Each message now remembers when it
+// was set and what kind it is; `AppRoot::tick` clears it once the
+// `[status]` timeout for its kind has passed. The same messages also
+// pop up in the top-right corner for `[status].notification_timeout_ms`.
+
+use std::ops::Deref;
+use std::time::{Duration, Instant};
+
+use crate::config::StatusConfig;
+
+/// How long a message stays up, and how it is styled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusKind {
+    /// Confirmations and hints ("Theme: nord", "Sent: …").
+    Info,
+    /// Something went sideways but was handled (a yank that fell back
+    /// to a file). Kept as long as an error.
+    Warning,
+    /// Failures; shown in red and kept for `[status].error_timeout_ms`.
+    Error,
+}
+
+/// One status-bar message. Derefs to its text so call sites and tests
+/// can keep treating `Option<StatusMessage>` like `Option<String>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusMessage {
+    pub text: String,
+    pub kind: StatusKind,
+    pub set_at: Instant,
+}
+
+impl StatusMessage {
+    pub fn info(text: impl Into<String>) -> Self {
+        Self::new(text, StatusKind::Info)
+    }
+
+    pub fn warning(text: impl Into<String>) -> Self {
+        Self::new(text, StatusKind::Warning)
+    }
+
+    pub fn error(text: impl Into<String>) -> Self {
+        Self::new(text, StatusKind::Error)
+    }
+
+    fn new(text: impl Into<String>, kind: StatusKind) -> Self {
+        Self {
+            text: text.into(),
+            kind,
+            set_at: Instant::now(),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.kind == StatusKind::Error
+    }
+
+    /// True once the timeout for this message's kind has elapsed at
+    /// `now`. A timeout of 0 keeps the message until it is replaced.
+    pub fn is_expired(&self, now: Instant, config: &StatusConfig) -> bool {
+        let ms = match self.kind {
+            StatusKind::Info => config.timeout_ms,
+            StatusKind::Warning | StatusKind::Error => config.error_timeout_ms,
+        };
+        ms > 0 && now.saturating_duration_since(self.set_at) >= Duration::from_millis(ms)
+    }
+
+    /// True once the popup for this message should be gone at `now`:
+    /// `[status].notification_timeout_ms` after it was set, whatever
+    /// its kind. With the timeout at 0 there are no popups at all.
+    pub fn popup_expired(&self, now: Instant, config: &StatusConfig) -> bool {
+        now.saturating_duration_since(self.set_at)
+            >= Duration::from_millis(config.notification_timeout_ms)
+    }
+}
+
+impl Deref for StatusMessage {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_message_set_just_now_is_not_expired() {
+        let config = StatusConfig::default();
+        let msg = StatusMessage::info("Saved");
+        assert!(!msg.is_expired(Instant::now(), &config));
+        assert_eq!(&*msg, "Saved");
+    }
+
+    #[test]
+    fn errors_outlive_info_messages_and_zero_never_expires() {
+        let config = StatusConfig {
+            timeout_ms: 1_000,
+            error_timeout_ms: 5_000,
+            ..StatusConfig::default()
+        };
+        let info = StatusMessage::info("ok");
+        let error = StatusMessage::error("boom");
+        let later = info.set_at + Duration::from_secs(2);
+        assert!(info.is_expired(later, &config));
+        assert!(!error.is_expired(later, &config));
+        assert!(error.is_expired(later + Duration::from_secs(4), &config));
+
+        let sticky = StatusConfig {
+            timeout_ms: 0,
+            error_timeout_ms: 0,
+            ..StatusConfig::default()
+        };
+        assert!(!info.is_expired(later + Duration::from_secs(3600), &sticky));
+    }
+
+    #[test]
+    fn popups_go_after_the_notification_timeout_whatever_the_kind() {
+        let config = StatusConfig::default();
+        let error = StatusMessage::error("boom");
+        let at = |ms| error.set_at + Duration::from_millis(ms);
+        assert!(!error.popup_expired(at(2_999), &config));
+        assert!(error.popup_expired(at(3_000), &config));
+        assert!(
+            !error.is_expired(at(3_000), &config),
+            "the status bar keeps it"
+        );
+
+        let off = StatusConfig {
+            notification_timeout_ms: 0,
+            ..StatusConfig::default()
+        };
+        assert!(error.popup_expired(error.set_at, &off));
+    }
+}
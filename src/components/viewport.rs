@@ -0,0 +1,85 @@
+// Scroll placement for the Folders and Messages lists.
+//
+// Ratatui's `List` only scrolls far enough to keep the selected row on
+// screen, so stepping down parks the cursor on the bottom edge and a
+// jump lands it wherever the old offset happens to put it. The panes
+// instead pick the offset themselves before each frame: the cursor
+// keeps a margin of rows to either edge while it steps, and a move that
+// would take it off screen re-centres the list on it.
+
+/// Rows kept between the cursor and the top / bottom edge of a list
+/// while there is more list to scroll to.
+pub const SCROLL_MARGIN: usize = 3;
+
+/// First row to draw for a list of `len` rows, `visible` of them on
+/// screen, with the cursor on `selected` and `previous` drawn first
+/// last frame. Keeps `previous` while the cursor sits inside the
+/// margins, scrolls just enough when it steps into one, and centres
+/// the cursor when it lands outside the previous window.
+pub fn list_offset(previous: usize, selected: usize, len: usize, visible: usize) -> usize {
+    if visible == 0 || len <= visible {
+        return 0;
+    }
+    let last = len - visible;
+    let margin = SCROLL_MARGIN.min(visible.saturating_sub(1) / 2);
+    let previous = previous.min(last);
+    let offset = if selected < previous || selected >= previous + visible {
+        selected.saturating_sub(visible / 2)
+    } else if selected < previous + margin {
+        selected.saturating_sub(margin)
+    } else if selected + margin >= previous + visible {
+        selected + margin + 1 - visible
+    } else {
+        previous
+    };
+    offset.min(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_lists_never_scroll() {
+        assert_eq!(list_offset(5, 9, 10, 10), 0);
+        assert_eq!(list_offset(0, 0, 0, 10), 0);
+        assert_eq!(list_offset(3, 2, 40, 0), 0);
+    }
+
+    #[test]
+    fn stepping_keeps_three_rows_to_either_edge() {
+        // 10 visible rows of 100: rows 0..10 on screen. The cursor
+        // rides down to row 6 before the list follows.
+        assert_eq!(list_offset(0, 6, 100, 10), 0);
+        assert_eq!(list_offset(0, 7, 100, 10), 1);
+        assert_eq!(list_offset(1, 8, 100, 10), 2);
+        // Back up: the list holds until the cursor is three from the top.
+        assert_eq!(list_offset(20, 23, 100, 10), 20);
+        assert_eq!(list_offset(20, 22, 100, 10), 19);
+    }
+
+    #[test]
+    fn margins_give_way_at_the_ends_of_the_list() {
+        assert_eq!(list_offset(0, 1, 100, 10), 0);
+        assert_eq!(list_offset(90, 99, 100, 10), 90);
+        assert_eq!(list_offset(89, 97, 100, 10), 90);
+    }
+
+    #[test]
+    fn jumps_centre_the_cursor() {
+        assert_eq!(list_offset(0, 50, 100, 10), 45);
+        assert_eq!(list_offset(60, 12, 100, 10), 7);
+        // Centring near the end still fills the pane.
+        assert_eq!(list_offset(0, 98, 100, 10), 90);
+        // A stale offset past the end (the list shrank) is pulled back.
+        assert_eq!(list_offset(500, 5, 20, 10), 0);
+    }
+
+    #[test]
+    fn tiny_panes_shrink_the_margin() {
+        // Three rows: a one-row margin keeps the cursor in the middle.
+        assert_eq!(list_offset(0, 2, 50, 3), 1);
+        // One row: no margin at all.
+        assert_eq!(list_offset(4, 5, 50, 1), 5);
+    }
+}
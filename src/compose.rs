@@ -3,7 +3,7 @@
 // `Compose` is the in-memory message under construction. `launch_editor`
 // drops the user into `$EDITOR` with a header/body template and parses
 // the result. `serialize_rfc822` produces a wire-format RFC 5322
-// message. `send` pipes the wire format to the account's configured
+// message, `multipart/mixed` once files are attached. `send` pipes the wire format to the account's configured
 // SMTP command (typically `msmtp -a <name>`) and, on success, files a
 // copy under `<maildir>/Sent/cur/`.
 //
@@ -22,6 +22,8 @@ use std::process::{Command, Stdio};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use base64::Engine as _;
+
 use crate::components::ReplyKind;
 use crate::config::AccountConfig;
 use crate::email::Email;
@@ -37,6 +39,9 @@ const BODY_TRAILER: &str = "\n";
 /// will not collide with public DNS.
 const MESSAGE_ID_DOMAIN: &str = "vulthor.local";
 
+/// Base64 attachment bodies wrap at 76 characters (RFC 2045 §6.8).
+const BASE64_LINE_LEN: usize = 76;
+
 /// Process-wide counter that makes Message-IDs and Sent filenames
 /// distinct even when two calls happen within the same microsecond.
 static UNIQUE_COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -48,8 +53,8 @@ static UNIQUE_COUNTER: AtomicU64 = AtomicU64::new(0);
 /// `serialize_rfc822` — the From header has to come from somewhere and
 /// the caller is the one who knows the active account.
 ///
-/// `attachments` is captured but NOT yet emitted by `serialize_rfc822`
-/// (would require MIME multipart, out of Phase 2.a scope).
+/// `attachments` are paths on disk, picked with `Ctrl+A` in the Draft
+/// pane. They are read when the message is serialized, not before.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Compose {
     pub from: String,
@@ -76,7 +81,11 @@ impl Compose {
     /// Headers emitted: Date, Message-ID, From, To, Cc, Bcc,
     /// Subject, [In-Reply-To, References], MIME-Version,
     /// Content-Type, Content-Transfer-Encoding.
-    pub fn serialize_rfc822(&self) -> String {
+    ///
+    /// With attachments the message is `multipart/mixed`: the body as
+    /// a text/plain part, then one base64 part per file. Fails when an
+    /// attached file can't be read.
+    pub fn serialize_rfc822(&self) -> Result<String> {
         let mut out = String::new();
 
         out.push_str(&format!("Date: {}\r\n", current_rfc2822_date()));
@@ -97,6 +106,53 @@ impl Compose {
             out.push_str(&format!("References: {}\r\n", irt));
         }
         out.push_str("MIME-Version: 1.0\r\n");
+        if self.attachments.is_empty() {
+            self.push_text_part(&mut out);
+            return Ok(out);
+        }
+
+        let boundary = mime_boundary();
+        out.push_str(&format!(
+            "Content-Type: multipart/mixed; boundary=\"{}\"\r\n\r\n",
+            boundary
+        ));
+        out.push_str(&format!("--{}\r\n", boundary));
+        self.push_text_part(&mut out);
+        for path in &self.attachments {
+            let data = std::fs::read(path).map_err(|e| VulthorError::AttachmentRead {
+                path: path.clone(),
+                source: e,
+            })?;
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().replace(['"', '\\'], "_"))
+                .unwrap_or_else(|| "attachment".to_string());
+            // The CRLF before a delimiter belongs to the delimiter, so
+            // the part before keeps its own final newline.
+            out.push_str(&format!("\r\n--{}\r\n", boundary));
+            out.push_str(&format!(
+                "Content-Type: {}; name=\"{}\"\r\n",
+                attachment_content_type(path),
+                name
+            ));
+            out.push_str(&format!(
+                "Content-Disposition: attachment; filename=\"{}\"\r\n",
+                name
+            ));
+            out.push_str("Content-Transfer-Encoding: base64\r\n\r\n");
+            let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
+            for line in encoded.as_bytes().chunks(BASE64_LINE_LEN) {
+                out.push_str(std::str::from_utf8(line).expect("base64 is ASCII"));
+                out.push_str("\r\n");
+            }
+        }
+        out.push_str(&format!("\r\n--{}--\r\n", boundary));
+
+        Ok(out)
+    }
+
+    /// The text/plain headers, blank line and body.
+    fn push_text_part(&self, out: &mut String) {
         out.push_str("Content-Type: text/plain; charset=utf-8\r\n");
         out.push_str("Content-Transfer-Encoding: 8bit\r\n");
         out.push_str("\r\n");
@@ -104,8 +160,30 @@ impl Compose {
         if !self.body.ends_with('\n') {
             out.push_str(BODY_TRAILER);
         }
+    }
+}
 
-        out
+/// Content-Type of an attached file, from its extension. Anything
+/// unrecognised goes out as `application/octet-stream`.
+fn attachment_content_type(path: &Path) -> &'static str {
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "txt" | "log" | "md" => "text/plain",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "ics" => "text/calendar",
+        "pdf" => "application/pdf",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        "gz" => "application/gzip",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        _ => "application/octet-stream",
     }
 }
 
@@ -183,20 +261,112 @@ pub fn parse_compose_from_text(text: &str) -> Result<Compose> {
     Ok(compose)
 }
 
-/// Drop the user into `$EDITOR` with `template` pre-loaded and parse
-/// the result. Resolution order: `$EDITOR`, `$VISUAL`, then `vi`. The
-/// caller MUST suspend the TUI (LeaveAlternateScreen, disable raw
-/// mode) before calling and restore afterward; this function inherits
-/// stdio so the editor takes over the terminal directly.
-pub fn launch_editor(template: &str) -> Result<Compose> {
-    let editor = resolve_editor();
-    launch_editor_with(template, &editor)
+/// Parse a saved draft (an RFC 5322 message in `Drafts/`) back into
+/// an editable `Compose`. Address headers come back as a display
+/// list, encoded words are decoded, and the body is the first
+/// text/plain part.
+pub fn parse_draft(raw: &[u8]) -> Result<Compose> {
+    let message = mail_parser::MessageParser::default()
+        .parse(raw)
+        .ok_or_else(|| VulthorError::ComposeParseFailed("draft is not a message".to_string()))?;
+    Ok(Compose {
+        from: address_list(message.from()),
+        to: address_list(message.to()),
+        cc: address_list(message.cc()),
+        bcc: address_list(message.bcc()),
+        subject: message.subject().unwrap_or_default().to_string(),
+        body: message
+            .body_text(0)
+            .map(|b| b.replace("\r\n", "\n"))
+            .unwrap_or_default(),
+        in_reply_to: message.in_reply_to().as_text().and_then(wrap_message_id),
+        ..Compose::new()
+    })
+}
+
+/// Read and parse the draft file at `path`.
+pub fn load_draft(path: &Path) -> Result<Compose> {
+    parse_draft(&std::fs::read(path)?)
+}
+
+/// Write `compose` over the draft file at `path`, so re-editing a
+/// saved draft keeps a single copy in `Drafts/`.
+pub fn save_draft(compose: &Compose, path: &Path) -> Result<()> {
+    std::fs::write(path, compose.serialize_rfc822()?)?;
+    Ok(())
+}
+
+/// `Name <addr>, addr2` — an address header as the user would type it.
+fn address_list(addresses: Option<&mail_parser::Address<'_>>) -> String {
+    let Some(addresses) = addresses else {
+        return String::new();
+    };
+    addresses
+        .iter()
+        .filter_map(|a| match (a.name(), a.address()) {
+            (Some(name), Some(addr)) => Some(format!("{} <{}>", name, addr)),
+            (None, Some(addr)) => Some(addr.to_string()),
+            (Some(name), None) => Some(name.to_string()),
+            (None, None) => None,
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Drop the user into their editor with `template` pre-loaded and
+/// parse the result. Resolution order: the `editor` config option
+/// (`configured`), `$EDITOR`, `$VISUAL`, then `vi`. The caller MUST
+/// suspend the TUI (LeaveAlternateScreen, disable raw mode) before
+/// calling and restore afterward; this function inherits stdio so the
+/// editor takes over the terminal directly.
+pub fn launch_editor(template: &str, configured: Option<&str>) -> Result<Compose> {
+    edit_template(template, &ShellEditor(resolve_editor(configured)))
 }
 
 /// Test seam for `launch_editor`. Splits out env-var resolution so
 /// tests can pass an explicit editor binary without touching the
 /// process-wide environment (which would race with parallel tests).
 pub(crate) fn launch_editor_with(template: &str, editor: &str) -> Result<Compose> {
+    edit_template(template, &ShellEditor(editor.to_string()))
+}
+
+/// Whatever edits the draft file in place. [`ShellEditor`] is the real
+/// one; tests substitute a fake so the seed / read-back
+/// round trip runs without a child process.
+pub trait EditorCommand {
+    /// Edit the file at `path`, returning once the user is done. An
+    /// error means the edit was abandoned and the file is not read back.
+    fn edit(&self, path: &Path) -> Result<()>;
+}
+
+/// An editor command line run through `sh -c`.
+pub struct ShellEditor(pub String);
+
+impl EditorCommand for ShellEditor {
+    fn edit(&self, path: &Path) -> Result<()> {
+        // `sh -c "<editor> <quoted-path>"` lets users put `vim -c '…'` or
+        // other flag-bearing strings into $EDITOR. The path is single-
+        // quoted with embedded single-quotes escaped.
+        let cmd = format!("{} {}", self.0, shell_quote(path));
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(&cmd)
+            .status()
+            .map_err(|e| VulthorError::ComposeEditorFailed(format!("spawn '{}': {}", self.0, e)))?;
+
+        if !status.success() {
+            return Err(VulthorError::ComposeEditorFailed(format!(
+                "editor exited with status {:?}",
+                status.code()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Seed a temp file with `template`, hand it to `editor`, and parse
+/// what comes back. A failed edit discards the file unread.
+pub fn edit_template(template: &str, editor: &dyn EditorCommand) -> Result<Compose> {
     let tempfile = tempfile::NamedTempFile::new()
         .map_err(|e| VulthorError::ComposeEditorFailed(format!("tempfile: {}", e)))?;
     let path = tempfile.path().to_path_buf();
@@ -204,22 +374,7 @@ pub(crate) fn launch_editor_with(template: &str, editor: &str) -> Result<Compose
     std::fs::write(&path, template)
         .map_err(|e| VulthorError::ComposeEditorFailed(format!("write template: {}", e)))?;
 
-    // `sh -c "<editor> <quoted-path>"` lets users put `vim -c '…'` or
-    // other flag-bearing strings into $EDITOR. The path is single-
-    // quoted with embedded single-quotes escaped.
-    let cmd = format!("{} {}", editor, shell_quote(&path));
-    let status = Command::new("sh")
-        .arg("-c")
-        .arg(&cmd)
-        .status()
-        .map_err(|e| VulthorError::ComposeEditorFailed(format!("spawn '{}': {}", editor, e)))?;
-
-    if !status.success() {
-        return Err(VulthorError::ComposeEditorFailed(format!(
-            "editor exited with status {:?}",
-            status.code()
-        )));
-    }
+    editor.edit(&path)?;
 
     let edited = std::fs::read_to_string(&path)
         .map_err(|e| VulthorError::ComposeEditorFailed(format!("read back: {}", e)))?;
@@ -236,7 +391,7 @@ pub(crate) fn launch_editor_with(template: &str, editor: &str) -> Result<Compose
 pub fn send(compose: &Compose, account: &AccountConfig) -> Result<PathBuf> {
     let smtp_cmd = resolve_smtp_command(account);
 
-    let rfc822 = compose.serialize_rfc822();
+    let rfc822 = compose.serialize_rfc822()?;
 
     let mut child = Command::new("sh")
         .arg("-c")
@@ -302,7 +457,10 @@ pub fn resolve_smtp_command(account: &AccountConfig) -> String {
         .unwrap_or_else(|| format!("msmtp -a {}", account.name))
 }
 
-fn resolve_editor() -> String {
+fn resolve_editor(configured: Option<&str>) -> String {
+    if let Some(editor) = configured.map(str::trim).filter(|e| !e.is_empty()) {
+        return editor.to_string();
+    }
     std::env::var("EDITOR")
         .or_else(|_| std::env::var("VISUAL"))
         .unwrap_or_else(|_| "vi".to_string())
@@ -319,6 +477,17 @@ fn current_rfc2822_date() -> String {
     chrono::Utc::now().to_rfc2822()
 }
 
+/// A multipart boundary that can't turn up in the body: unique per
+/// call, and made only of characters RFC 2046 allows in a boundary.
+fn mime_boundary() -> String {
+    let micros = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_micros())
+        .unwrap_or(0);
+    let counter = UNIQUE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("=_vulthor_{}_{}_{}", micros, std::process::id(), counter)
+}
+
 fn new_message_id() -> String {
     let micros = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -354,7 +523,7 @@ pub fn build_reply_template(original: &Email, kind: ReplyKind, account: &Account
 
     let (to, cc, subject, body) = match kind {
         ReplyKind::Reply => (
-            original.headers.from.clone(),
+            quote_mailbox(&original.headers.from),
             String::new(),
             re_subject(&original.headers.subject),
             quoted_body(original),
@@ -372,7 +541,7 @@ pub fn build_reply_template(original: &Email, kind: ReplyKind, account: &Account
             forwarded_body(original),
         ),
         ReplyKind::ReplyLater => (
-            original.headers.from.clone(),
+            quote_mailbox(&original.headers.from),
             String::new(),
             re_subject(&original.headers.subject),
             String::new(),
@@ -392,6 +561,65 @@ pub fn build_reply_template(original: &Email, kind: ReplyKind, account: &Account
     }
 }
 
+/// `Name <address>`, with the name in double quotes when it holds a
+/// character that would otherwise split or end the mailbox — a comma
+/// most often (`"Doe, John" <john@x>`). A bare address without a name.
+pub(crate) fn format_mailbox(name: &str, address: &str) -> String {
+    let name = name.trim();
+    if name.is_empty() {
+        return address.to_string();
+    }
+    if name.contains([',', ';', ':', '<', '>', '@', '"', '\\']) {
+        let escaped = name.replace('\\', "\\\\").replace('"', "\\\"");
+        format!("\"{}\" <{}>", escaped, address)
+    } else {
+        format!("{} <{}>", name, address)
+    }
+}
+
+/// Re-quote the display name of one formatted mailbox
+/// (`Doe, John <john@x>` → `"Doe, John" <john@x>`). The parsed headers
+/// keep names unquoted, so this runs on anything a template copies
+/// into a recipient line. Already-quoted names and bare addresses pass
+/// through.
+fn quote_mailbox(mailbox: &str) -> String {
+    let mailbox = mailbox.trim();
+    match (mailbox.rfind('<'), mailbox.rfind('>')) {
+        (Some(open), Some(close)) if open < close && !mailbox.starts_with('"') => {
+            format_mailbox(&mailbox[..open], &mailbox[open + 1..close])
+        }
+        _ => mailbox.to_string(),
+    }
+}
+
+/// Split a formatted recipient line into mailboxes. Commas inside
+/// quotes don't split, and a piece with no `@` is the front of an
+/// unquoted name (`Doe, John <john@x>`) so it joins the next piece.
+fn split_recipient_line(line: &str) -> Vec<String> {
+    let mut quoted = false;
+    let mut out = Vec::new();
+    let mut pending = String::new();
+    let pieces = line.split(|c| {
+        if c == '"' {
+            quoted = !quoted;
+        }
+        c == ',' && !quoted
+    });
+    for piece in pieces.map(str::trim).filter(|p| !p.is_empty()) {
+        if !pending.is_empty() {
+            pending.push_str(", ");
+        }
+        pending.push_str(piece);
+        if piece.contains('@') {
+            out.push(std::mem::take(&mut pending));
+        }
+    }
+    if !pending.is_empty() {
+        out.push(pending);
+    }
+    out
+}
+
 /// Format the From header line for `account`. Uses `"Name <email>"` when
 /// the account has a non-empty name distinct from the email, otherwise
 /// just the email address.
@@ -452,17 +680,13 @@ fn fwd_subject(subject: &str) -> String {
 fn reply_all_to(original: &Email, our_email: &str) -> String {
     let mut recipients: Vec<String> = Vec::new();
     if !original.headers.from.is_empty() {
-        recipients.push(original.headers.from.clone());
+        recipients.push(quote_mailbox(&original.headers.from));
     }
-    for part in original.headers.to.split(',') {
-        let trimmed = part.trim();
-        if trimmed.is_empty() {
+    for part in split_recipient_line(&original.headers.to) {
+        if address_matches(&part, our_email) {
             continue;
         }
-        if address_matches(trimmed, our_email) {
-            continue;
-        }
-        recipients.push(trimmed.to_string());
+        recipients.push(quote_mailbox(&part));
     }
     recipients.join(", ")
 }
@@ -613,7 +837,7 @@ mod tests {
             body: "hello\n".into(),
             ..Compose::new()
         };
-        let s = c.serialize_rfc822();
+        let s = c.serialize_rfc822().unwrap();
         assert!(s.contains("Date: "), "missing Date header: {}", s);
         assert!(s.contains("Message-ID: <"), "missing Message-ID: {}", s);
         assert!(s.contains("From: Tester <t@example.com>"));
@@ -634,7 +858,7 @@ mod tests {
             in_reply_to: Some("<parent@host>".into()),
             ..Compose::new()
         };
-        let s = c.serialize_rfc822();
+        let s = c.serialize_rfc822().unwrap();
         assert!(s.contains("In-Reply-To: <parent@host>"));
         assert!(s.contains("References: <parent@host>"));
     }
@@ -648,7 +872,7 @@ mod tests {
             body: "ok".into(),
             ..Compose::new()
         };
-        let s = c.serialize_rfc822();
+        let s = c.serialize_rfc822().unwrap();
         assert!(!s.contains("In-Reply-To:"));
         assert!(!s.contains("References:"));
     }
@@ -662,7 +886,7 @@ mod tests {
             body: "ok".into(),
             ..Compose::new()
         };
-        let s = c.serialize_rfc822();
+        let s = c.serialize_rfc822().unwrap();
         assert!(!s.contains("Cc:"));
         assert!(!s.contains("Bcc:"));
     }
@@ -680,7 +904,7 @@ mod tests {
             in_reply_to: Some("<parent@host>".into()),
             ..Compose::new()
         };
-        let s = c.serialize_rfc822();
+        let s = c.serialize_rfc822().unwrap();
         let parsed = mail_parser::MessageParser::default()
             .parse(s.as_bytes())
             .expect("must parse");
@@ -693,8 +917,125 @@ mod tests {
         assert_eq!(parsed.body_text(0).as_deref(), Some("Hello, world.\n"));
     }
 
+    #[test]
+    fn serialize_with_attachments_builds_a_multipart_message() {
+        use mail_parser::MimeHeaders;
+
+        let temp = TempDir::new().unwrap();
+        let report = temp.path().join("report.pdf");
+        let data = temp.path().join("data.csv");
+        std::fs::write(&report, b"%PDF-1.4 binary \x00\xff").unwrap();
+        std::fs::write(&data, "a,b\n1,2\n").unwrap();
+        let c = Compose {
+            from: "t@example.com".into(),
+            to: "alice@example.com".into(),
+            subject: "Files".into(),
+            body: "Both attached.\n".into(),
+            attachments: vec![report, data],
+            ..Compose::new()
+        };
+        let s = c.serialize_rfc822().unwrap();
+        assert!(s.contains("Content-Type: multipart/mixed; boundary="));
+
+        let parsed = mail_parser::MessageParser::default()
+            .parse(s.as_bytes())
+            .expect("must parse");
+        assert_eq!(parsed.body_text(0).as_deref(), Some("Both attached.\n"));
+        assert_eq!(parsed.attachment_count(), 2);
+        let pdf = parsed.attachment(0).unwrap();
+        assert_eq!(pdf.attachment_name(), Some("report.pdf"));
+        assert_eq!(pdf.contents(), b"%PDF-1.4 binary \x00\xff");
+        assert_eq!(
+            parsed
+                .attachment(1)
+                .unwrap()
+                .content_type()
+                .unwrap()
+                .subtype(),
+            Some("csv")
+        );
+
+        let missing = Compose {
+            attachments: vec![temp.path().join("gone.txt")],
+            ..c
+        };
+        assert!(matches!(
+            missing.serialize_rfc822(),
+            Err(VulthorError::AttachmentRead { .. })
+        ));
+    }
+
+    #[test]
+    fn a_saved_draft_loads_back_into_the_compose_buffer() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("draft:2,D");
+        let c = Compose {
+            from: "Tester <t@example.com>".into(),
+            to: "Alice <alice@example.com>, bob@example.com".into(),
+            subject: "Plans for Friday".into(),
+            body: "Line one.\n\nLine three.\n".into(),
+            in_reply_to: Some("<parent@host>".into()),
+            ..Compose::new()
+        };
+        save_draft(&c, &path).unwrap();
+
+        let loaded = load_draft(&path).unwrap();
+        assert_eq!(loaded.subject, "Plans for Friday");
+        assert_eq!(loaded.body, "Line one.\n\nLine three.\n");
+        assert_eq!(loaded.to, c.to);
+        assert_eq!(loaded.from, c.from);
+        assert_eq!(loaded.in_reply_to.as_deref(), Some("<parent@host>"));
+    }
+
     // ---- launch_editor ----
 
+    /// Stand-in editor: checks what it was seeded with, then rewrites
+    /// the file (or fails, leaving it untouched).
+    struct FakeEditor {
+        replacement: Option<&'static str>,
+    }
+
+    impl EditorCommand for FakeEditor {
+        fn edit(&self, path: &Path) -> Result<()> {
+            let seeded = std::fs::read_to_string(path).unwrap();
+            assert!(seeded.starts_with("To: alice@example.com\n"));
+            match self.replacement {
+                Some(text) => Ok(std::fs::write(path, text)?),
+                None => Err(VulthorError::ComposeEditorFailed("exit 1".into())),
+            }
+        }
+    }
+
+    #[test]
+    fn edit_template_seeds_the_temp_file_and_parses_it_back() {
+        let seed = Compose {
+            to: "alice@example.com".into(),
+            subject: "Draft".into(),
+            body: "first\n".into(),
+            ..Compose::new()
+        };
+        let template = default_template(&seed);
+
+        let unchanged = FakeEditor { replacement: None };
+        assert!(edit_template(&template, &unchanged).is_err());
+
+        let rewrite = FakeEditor {
+            replacement: Some("To: alice@example.com\nSubject: Final\n\nsecond\n"),
+        };
+        let c = edit_template(&template, &rewrite).unwrap();
+        assert_eq!(c.subject, "Final");
+        assert_eq!(c.body, "second\n");
+    }
+
+    #[test]
+    fn the_configured_editor_wins_over_the_environment() {
+        assert_eq!(
+            resolve_editor(Some("nvim -c 'set tw=72'")),
+            "nvim -c 'set tw=72'"
+        );
+        assert_ne!(resolve_editor(Some("  ")), "  ");
+    }
+
     #[test]
     fn launch_editor_with_true_returns_unmodified_template() {
         // `true` is the canonical no-op binary: it ignores its args,
@@ -876,7 +1217,7 @@ mod tests {
 
     // ---- build_reply_template ----
 
-    use crate::email::{Email, EmailHeaders};
+    use crate::email::{Email, EmailHeaders, Priority};
 
     fn original_email() -> Email {
         let mut e = Email::new(PathBuf::from("/tmp/orig"));
@@ -886,6 +1227,9 @@ mod tests {
             subject: "Lunch tomorrow?".to_string(),
             date: "2026-05-16T12:00:00+00:00".to_string(),
             message_id: "orig-1@example.com".to_string(),
+            priority: Priority::Normal,
+            in_reply_to: String::new(),
+            references: Vec::new(),
         };
         e.body_plain = Some("Hey,\nWant to grab lunch?\n".to_string());
         e
@@ -940,6 +1284,30 @@ mod tests {
         assert_eq!(c.in_reply_to.as_deref(), Some("<orig-1@example.com>"));
     }
 
+    /// Parsed headers keep names unquoted, so a name with a comma must
+    /// be quoted again or the reply's To line splits it into two
+    /// recipients.
+    #[test]
+    fn reply_templates_quote_display_names_with_commas() {
+        let mut original = original_email();
+        original.headers.from = "Doe, John <john@example.com>".to_string();
+        original.headers.to =
+            "Roe, Jane <jane@example.com>, Tester <tester@example.com>".to_string();
+        let account = signed_account();
+
+        let c = build_reply_template(&original, ReplyKind::Reply, &account);
+        assert_eq!(c.to, "\"Doe, John\" <john@example.com>");
+        let c = build_reply_template(&original, ReplyKind::ReplyAll, &account);
+        assert_eq!(
+            c.to,
+            "\"Doe, John\" <john@example.com>, \"Roe, Jane\" <jane@example.com>"
+        );
+        assert_eq!(
+            format_mailbox("A \"B\" C;", "a@example.com"),
+            "\"A \\\"B\\\" C;\" <a@example.com>"
+        );
+    }
+
     #[test]
     fn build_reply_all_preserves_original_cc_when_present() {
         // Per VISION.md: reply-all Cc carries through the original Cc.
@@ -1023,7 +1391,7 @@ mod tests {
         let account = signed_account();
         let c = build_reply_template(&original, ReplyKind::Reply, &account);
 
-        let wire = c.serialize_rfc822();
+        let wire = c.serialize_rfc822().unwrap();
         assert!(wire.contains("From: Tester <tester@example.com>"));
         assert!(wire.contains("To: Alice <alice@example.com>"));
         assert!(wire.contains("Subject: Re: Lunch tomorrow?"));
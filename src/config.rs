@@ -1,14 +1,15 @@
 use crate::error::{Result, VulthorError};
-use crate::log::LogConfig;
+use crate::keymap::KeySpec;
+use crate::log::{LogConfig, LogLevel};
 use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Command-line arguments parsed by `clap` at startup. Each option may
 /// override the corresponding value from the resolved [`Config`] —
 /// `maildir_path` in particular wins over both the config file and
-/// `default_account`'s maildir. `port` is optional: when omitted, the
+/// `default_account`'s maildir (see [`Config::apply_maildir_override`]). `port` is optional: when omitted, the
 /// effective port falls back to `[web].port` (default 8080).
 ///
 /// `command` is the optional subcommand slot: `None` → run the TUI
@@ -31,6 +32,23 @@ pub struct CliArgs {
     #[arg(short = 'm', long = "maildir")]
     pub maildir_path: Option<PathBuf>,
 
+    /// Write a diagnostic log to this file (appending).
+    #[arg(long = "log-file")]
+    pub log_file: Option<PathBuf>,
+
+    /// Minimum severity written to `--log-file`.
+    #[arg(long = "log-level", value_enum, default_value_t = LogLevel::Info)]
+    pub log_level: LogLevel,
+
+    /// Open this `.eml` file in a virtual "Opened Files" folder at startup.
+    #[arg(long = "open-eml")]
+    pub open_eml: Option<PathBuf>,
+
+    /// Plain output: no colours, emphasis by bold and reverse video
+    /// only. Also set by a non-empty `NO_COLOR` environment variable.
+    #[arg(long = "no-color")]
+    pub no_color: bool,
+
     /// Optional subcommand. `None` runs the TUI; see [`Command`].
     #[command(subcommand)]
     pub command: Option<Command>,
@@ -67,6 +85,12 @@ pub struct WebConfig {
     /// Bind address. Must parse as an `IpAddr` (IPv4 or IPv6 literal).
     #[serde(default = "WebConfig::default_bind")]
     pub bind: String,
+    /// Fixed secret for scripts calling `/api/*`, presented as
+    /// `Authorization: Bearer <token>` or `?token=<token>`. Unlike the
+    /// per-launch viewer token it survives restarts. Unset: the API
+    /// takes the per-launch token only.
+    #[serde(default)]
+    pub api_token: Option<String>,
 }
 
 impl WebConfig {
@@ -83,10 +107,396 @@ impl Default for WebConfig {
         Self {
             port: Self::default_port(),
             bind: Self::default_bind(),
+            api_token: None,
         }
     }
 }
 
+/// On-disk layout of `maildir_path`. A path that names a file is always
+/// read as mbox, whatever this says; see [`MailboxFormat::detect`].
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MailboxFormat {
+    /// Directory tree of `cur/` / `new/` folders.
+    #[default]
+    Maildir,
+    /// Single mbox file, or a directory whose files are mbox folders.
+    Mbox,
+}
+
+impl MailboxFormat {
+    /// Format to read `path` with: `Mbox` for a file, `configured` for
+    /// anything else.
+    pub fn detect(path: &Path, configured: Self) -> Self {
+        if path.is_file() {
+            Self::Mbox
+        } else {
+            configured
+        }
+    }
+}
+
+/// What the message list shows for a sender (or, in Sent, the
+/// recipient).
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SenderDisplay {
+    /// The display name, or the address's local part when there is
+    /// none: `John Doe`, `jane`.
+    #[default]
+    Name,
+    /// The bare address: `john@example.com`.
+    Address,
+    /// Name and address: `John Doe <john@example.com>`.
+    Both,
+}
+
+/// How the message list writes dates.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DateStyle {
+    /// A `date_format_*` string picked by age: a time for today, a
+    /// weekday this week, and so on.
+    #[default]
+    Smart,
+    /// Age of the email: `now`, `5m`, `3h`, `4d`, `2mo`, `3y`.
+    Relative,
+    /// Every date in the one `date_format` string.
+    Absolute,
+}
+
+/// How many panes the main screen shows at once.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutMode {
+    /// Two adjacent panes of the folders → messages → content
+    /// progression; `h` / `l` move between views.
+    #[default]
+    TwoPane,
+    /// Folders, messages and content side by side; `h` / `l` move
+    /// focus instead.
+    ThreePane,
+}
+
+/// One `[[saved_searches]]` entry: a pseudo-folder listed after the
+/// real folders that gathers loaded emails whose subject or sender
+/// contains `query`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: String,
+    /// Shown before the name in the folder pane. Default `🔍`.
+    #[serde(default = "SavedSearch::default_icon")]
+    pub icon: String,
+}
+
+impl SavedSearch {
+    fn default_icon() -> String {
+        "🔍".to_string()
+    }
+}
+
+/// One `[[columns]]` entry: a message-list column, in the order the
+/// entries are written. A column list may also give an entry as a
+/// string: `"subject"`, or `"subject:40"` / `"subject:flex"` with a
+/// width.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(try_from = "ColumnConfigRepr")]
+pub struct ColumnConfig {
+    /// One of [`ColumnConfig::NAMES`]. Unknown names are skipped with a
+    /// warning.
+    pub name: String,
+    /// `width = 20` for a fixed width, `width = "flex"` to share the
+    /// space left over. Omitted: the column's own default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<ColumnWidth>,
+    #[serde(default = "ColumnConfig::default_enabled")]
+    pub enabled: bool,
+}
+
+impl ColumnConfig {
+    /// Columns the message list knows how to draw. `unread` is the
+    /// status gutter (unread dot, tag mark, spam mark, draft and AI
+    /// chips); `flags` is the star and high-priority mark. `folder`
+    /// names the folder an email is filed in, for search results and
+    /// saved searches.
+    pub const NAMES: [&'static str; 9] = [
+        "unread",
+        "from",
+        "to",
+        "subject",
+        "date",
+        "size",
+        "attachments",
+        "flags",
+        "folder",
+    ];
+
+    fn default_enabled() -> bool {
+        true
+    }
+
+    fn named(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            width: None,
+            enabled: true,
+        }
+    }
+
+    /// The layout the message list always had.
+    pub fn defaults() -> Vec<Self> {
+        ["unread", "from", "subject", "flags", "attachments", "date"]
+            .into_iter()
+            .map(Self::named)
+            .collect()
+    }
+}
+
+/// TOML form of [`ColumnConfig`]: a `name[:width]` string or a table.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum ColumnConfigRepr {
+    Short(String),
+    Table {
+        name: String,
+        #[serde(default)]
+        width: Option<ColumnWidth>,
+        #[serde(default = "ColumnConfig::default_enabled")]
+        enabled: bool,
+    },
+}
+
+impl TryFrom<ColumnConfigRepr> for ColumnConfig {
+    type Error = String;
+
+    fn try_from(repr: ColumnConfigRepr) -> std::result::Result<Self, String> {
+        match repr {
+            ColumnConfigRepr::Table {
+                name,
+                width,
+                enabled,
+            } => Ok(Self {
+                name,
+                width,
+                enabled,
+            }),
+            ColumnConfigRepr::Short(short) => {
+                let Some((name, width)) = short.split_once(':') else {
+                    return Ok(Self::named(&short));
+                };
+                let width = match width.parse() {
+                    Ok(n) => ColumnWidthRepr::Fixed(n),
+                    Err(_) => ColumnWidthRepr::Named(width.to_string()),
+                };
+                Ok(Self {
+                    width: Some(width.try_into()?),
+                    ..Self::named(name)
+                })
+            }
+        }
+    }
+}
+
+/// Settings for one folder, under `[folders."<path>"]`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct FolderOverride {
+    /// Message-list columns for this folder, in place of `[[columns]]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_columns: Option<Vec<ColumnConfig>>,
+}
+
+/// Width of a message-list column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "ColumnWidthRepr", into = "ColumnWidthRepr")]
+pub enum ColumnWidth {
+    /// Exactly this many cells.
+    Fixed(usize),
+    /// An equal share of what the other columns leave.
+    Flexible,
+}
+
+/// TOML form of [`ColumnWidth`]: a number or `"flex"`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+enum ColumnWidthRepr {
+    Fixed(usize),
+    Named(String),
+}
+
+impl TryFrom<ColumnWidthRepr> for ColumnWidth {
+    type Error = String;
+
+    fn try_from(repr: ColumnWidthRepr) -> std::result::Result<Self, String> {
+        match repr {
+            ColumnWidthRepr::Fixed(n) => Ok(Self::Fixed(n)),
+            ColumnWidthRepr::Named(name) if matches!(name.as_str(), "flex" | "flexible") => {
+                Ok(Self::Flexible)
+            }
+            ColumnWidthRepr::Named(name) => Err(format!(
+                "column width must be a number or \"flex\" (got {:?})",
+                name
+            )),
+        }
+    }
+}
+
+impl From<ColumnWidth> for ColumnWidthRepr {
+    fn from(width: ColumnWidth) -> Self {
+        match width {
+            ColumnWidth::Fixed(n) => Self::Fixed(n),
+            ColumnWidth::Flexible => Self::Named("flex".to_string()),
+        }
+    }
+}
+
+/// How Maildir subfolders are laid out on disk.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MaildirConvention {
+    /// Nested directories: `Work/Projects/{cur,new,tmp}`.
+    #[default]
+    Standard,
+    /// Maildir++ (Courier, Dovecot, mbsync `SubFolders Maildir++`):
+    /// flat dot-prefixed directories under the root, `.Work.Projects`
+    /// for `Work/Projects`; the root's own `cur/` and `new/` are the
+    /// inbox.
+    #[serde(alias = "maildir++")]
+    MaildirPP,
+}
+
+/// `[status]` configuration block — how long status-bar messages stay
+/// up. A timeout of 0 keeps a message until the next one replaces it.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct StatusConfig {
+    /// Milliseconds before an informational message clears. Default 4000.
+    #[serde(default = "StatusConfig::default_timeout_ms")]
+    pub timeout_ms: u64,
+    /// Milliseconds before an error message clears. Default 10000.
+    #[serde(default = "StatusConfig::default_error_timeout_ms")]
+    pub error_timeout_ms: u64,
+    /// Milliseconds the top-right popup copy of each message stays up.
+    /// 0 turns the popups off. Default 3000.
+    #[serde(default = "StatusConfig::default_notification_timeout_ms")]
+    pub notification_timeout_ms: u64,
+}
+
+impl StatusConfig {
+    fn default_timeout_ms() -> u64 {
+        4_000
+    }
+    fn default_error_timeout_ms() -> u64 {
+        10_000
+    }
+    fn default_notification_timeout_ms() -> u64 {
+        3_000
+    }
+}
+
+impl Default for StatusConfig {
+    fn default() -> Self {
+        Self {
+            timeout_ms: Self::default_timeout_ms(),
+            error_timeout_ms: Self::default_error_timeout_ms(),
+            notification_timeout_ms: Self::default_notification_timeout_ms(),
+        }
+    }
+}
+
+/// `[spam]` configuration block. Names the headers a server-side spam
+/// filter stamps on suspect mail and the folder `!` moves mail into.
+/// Header names are matched case-insensitively.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SpamConfig {
+    /// Header whose `YES` value marks a message as spam. Default
+    /// `X-Spam-Flag` (SpamAssassin, rspamd).
+    #[serde(default = "SpamConfig::default_flag_header")]
+    pub flag_header: String,
+    /// Header carrying the numeric spam score. Default `X-Spam-Score`.
+    /// Only the leading number is read, so `"7.3 / 5.0"` parses as 7.3.
+    #[serde(default = "SpamConfig::default_score_header")]
+    pub score_header: String,
+    /// Folder under the maildir root that `!` moves mail into. Created
+    /// on first use. Default `Junk`.
+    #[serde(default = "SpamConfig::default_junk_folder")]
+    pub junk_folder: String,
+}
+
+impl SpamConfig {
+    fn default_flag_header() -> String {
+        "X-Spam-Flag".to_string()
+    }
+    fn default_score_header() -> String {
+        "X-Spam-Score".to_string()
+    }
+    fn default_junk_folder() -> String {
+        "Junk".to_string()
+    }
+}
+
+impl Default for SpamConfig {
+    fn default() -> Self {
+        Self {
+            flag_header: Self::default_flag_header(),
+            score_header: Self::default_score_header(),
+            junk_folder: Self::default_junk_folder(),
+        }
+    }
+}
+
+/// `[folders]` configuration block. Pins a folder role to a folder,
+/// given as its path relative to the maildir root (`"Sent"`,
+/// `"[Gmail]/Sent Mail"`, `".Sent"`). A role left unset is detected
+/// from folder names instead; see [`crate::email::FolderRole::detect`].
+///
+/// Subtables keyed by such a path (`[folders."Sent"]`) hold that
+/// folder's own settings; see [`FolderOverride`].
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct FoldersConfig {
+    #[serde(default)]
+    pub inbox: Option<String>,
+    /// Mail listed with its recipients instead of its sender.
+    #[serde(default)]
+    pub sent: Option<String>,
+    #[serde(default)]
+    pub drafts: Option<String>,
+    /// Where `d` moves mail. Default `Trash`.
+    #[serde(default)]
+    pub trash: Option<String>,
+    /// Where `a` moves mail. Default `Archive`.
+    #[serde(default)]
+    pub archive: Option<String>,
+    /// `[folders."<path>"]` subtables, keyed by the folder's path
+    /// relative to the maildir root.
+    #[serde(flatten)]
+    pub overrides: BTreeMap<String, FolderOverride>,
+}
+
+impl FoldersConfig {
+    /// Configured relative path for `role`, if any.
+    pub fn path_for(&self, role: crate::email::FolderRole) -> Option<&str> {
+        use crate::email::FolderRole;
+        match role {
+            FolderRole::Inbox => self.inbox.as_deref(),
+            FolderRole::Sent => self.sent.as_deref(),
+            FolderRole::Drafts => self.drafts.as_deref(),
+            FolderRole::Trash => self.trash.as_deref(),
+            FolderRole::Archive => self.archive.as_deref(),
+            FolderRole::Junk | FolderRole::Normal => None,
+        }
+    }
+
+    /// The `[folders."<path>"]` subtable for the folder at `path`: the
+    /// one whose key names the most trailing components of it.
+    pub fn override_for(&self, path: &Path) -> Option<&FolderOverride> {
+        self.overrides
+            .iter()
+            .filter(|(key, _)| path.ends_with(key.trim_matches('/')))
+            .max_by_key(|(key, _)| Path::new(key.trim_matches('/')).components().count())
+            .map(|(_, folder)| folder)
+    }
+}
+
 /// `[ai]` configuration block — Phase 4.a scaffolding. The runtime AI
 /// classifier lands in Phase 6; today these fields are parsed and
 /// validated but otherwise inert.
@@ -155,6 +565,60 @@ pub struct ThemeConfig {
     pub overrides: BTreeMap<String, String>,
 }
 
+/// `[render]` block — how the Content pane picks between the parts of
+/// a `multipart/alternative` message, and how it styles the body.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct RenderConfig {
+    /// Show the HTML part (converted to text) instead of the plain-text
+    /// part when a message carries both. Default `false`. Flipped per
+    /// session with `v` in the Content pane.
+    #[serde(default)]
+    pub prefer_html: bool,
+    /// Dim the trailing signature (after `-- `) and mailing-list footer
+    /// of the body. Default `true`. See [`crate::signature`].
+    #[serde(default = "RenderConfig::default_dim_signatures")]
+    pub dim_signatures: bool,
+    /// Use Nerd Font glyphs instead of emoji for the Attachments-pane
+    /// file-type icons. Default `false`; needs a patched terminal font.
+    #[serde(default)]
+    pub nerd_fonts: bool,
+    /// Terminal graphics protocol used to preview image attachments
+    /// (`p`). Default `none`, which keeps `p` refusing images. See
+    /// [`crate::image_preview`].
+    #[serde(default)]
+    pub image_protocol: ImageProtocol,
+}
+
+/// `[render].image_protocol` values.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageProtocol {
+    /// No inline images.
+    #[default]
+    None,
+    /// kitty graphics protocol (kitty, WezTerm, Ghostty).
+    Kitty,
+    /// DEC sixel graphics (foot, mlterm, WezTerm, xterm `-ti vt340`).
+    Sixel,
+}
+
+impl RenderConfig {
+    fn default_dim_signatures() -> bool {
+        true
+    }
+}
+
+impl Default for RenderConfig {
+    fn default() -> Self {
+        Self {
+            prefer_html: false,
+            dim_signatures: Self::default_dim_signatures(),
+            nerd_fonts: false,
+            image_protocol: ImageProtocol::None,
+        }
+    }
+}
+
 /// A single configured account. One per `[accounts.<key>]` section in
 /// `vulthor.toml`. The TOML table key becomes the [`AccountId`]; `name`
 /// is the human-facing display label rendered in the Accounts pane.
@@ -206,27 +670,108 @@ pub struct Config {
     /// `[theme]` block. Empty by default → built-in palette.
     #[serde(default)]
     pub theme: ThemeConfig,
-    /// `[keybindings]` overrides. Each entry rebinds one of the
-    /// canonical action names (see `crate::keymap::Action::name`) to
-    /// a user-chosen key string. Empty by default — every action
-    /// keeps its VISION.md default until the user opts in.
-    #[serde(default)]
+    /// `[keybindings]` overrides (also accepted as `[keys]`). Each
+    /// entry rebinds one of the canonical action names (see
+    /// `crate::keymap::Action::name`) to a user-chosen key string or
+    /// list of them. Empty by default — every action keeps its
+    /// VISION.md default until the user opts in.
+    #[serde(default, alias = "keys")]
     pub keybindings: KeybindingsConfig,
     /// `[log]` block — routine-log size/age caps. See [`LogConfig`].
     #[serde(default)]
     pub log: LogConfig,
+    /// `[render]` block — Content-pane body preferences. See
+    /// [`RenderConfig`].
+    #[serde(default)]
+    pub render: RenderConfig,
+    /// `[spam]` block — spam-filter header names and the Junk folder.
+    /// See [`SpamConfig`].
+    #[serde(default)]
+    pub spam: SpamConfig,
+    /// `[folders]` block — which folder is Inbox / Sent / Drafts /
+    /// Trash / Archive. See [`FoldersConfig`].
+    #[serde(default)]
+    pub folders: FoldersConfig,
+    /// `[status]` block — status-bar message timeouts. See
+    /// [`StatusConfig`].
+    #[serde(default)]
+    pub status: StatusConfig,
+    /// `maildir` (default) or `mbox`. See [`MailboxFormat`].
+    #[serde(default)]
+    pub mailbox_format: MailboxFormat,
+    /// `standard` (default) or `maildirpp`. See [`MaildirConvention`].
+    #[serde(default)]
+    pub maildir_convention: MaildirConvention,
+    /// `chrono` format for a message-list date from today. Default
+    /// `%H:%M`.
+    #[serde(default = "Config::default_date_format_today")]
+    pub date_format_today: String,
+    /// Format for dates in the past six days. Default `%a %H:%M`
+    /// (`Mon 14:30`).
+    #[serde(default = "Config::default_date_format_this_week")]
+    pub date_format_this_week: String,
+    /// Format for older dates in the current year. Default `%b %d`
+    /// (`Jan 15`).
+    #[serde(default = "Config::default_date_format_this_year")]
+    pub date_format_this_year: String,
+    /// Format for everything else. Default `%Y-%m-%d`.
+    #[serde(default = "Config::default_date_format_other")]
+    pub date_format_other: String,
+    /// `smart` (default), `relative` or `absolute`. See [`DateStyle`].
+    #[serde(default)]
+    pub date_style: DateStyle,
+    /// Format of every date under `date_style = "absolute"`. Default
+    /// `%Y-%m-%d %H:%M`.
+    #[serde(default = "Config::default_date_format")]
+    pub date_format: String,
+    /// `name` (default), `address` or `both`. See [`SenderDisplay`].
+    #[serde(default)]
+    pub sender_display: SenderDisplay,
+    /// Give each message-list entry a second, dimmed row with the
+    /// start of its body. Default `false`.
+    #[serde(default)]
+    pub list_preview: bool,
+    /// Set the terminal window title to `Vulthor [<unread>]` and keep
+    /// it current. Default `true`.
+    #[serde(default = "Config::default_update_window_title")]
+    pub update_window_title: bool,
+    /// `[[columns]]` — the message-list columns, left to right. Default
+    /// [`ColumnConfig::defaults`].
+    #[serde(default = "ColumnConfig::defaults")]
+    pub columns: Vec<ColumnConfig>,
+    /// Command that edits drafts, run through `sh -c` with the draft
+    /// file appended (`"nvim +"`, `"code --wait"`). Unset falls back
+    /// to `$EDITOR`, `$VISUAL`, then `vi`.
+    #[serde(default)]
+    pub editor: Option<String>,
+    /// `[[saved_searches]]` — pseudo-folders listed after the real
+    /// ones. See [`SavedSearch`].
+    #[serde(default)]
+    pub saved_searches: Vec<SavedSearch>,
+    /// Share of the width, in percent, given to the folder pane beside
+    /// the Messages pane (and to the accounts pane beside the folders).
+    /// Default 50; `Ctrl-w` adjusts it for the session.
+    #[serde(default = "Config::default_pane_width")]
+    pub folder_pane_width: u16,
+    /// Share of the width, in percent, given to the Messages pane beside
+    /// the Content or Attachments pane. Default 50.
+    #[serde(default = "Config::default_pane_width")]
+    pub message_pane_width: u16,
+    /// `two_pane` (default) or `three_pane`. See [`LayoutMode`].
+    #[serde(default)]
+    pub layout: LayoutMode,
 }
 
-/// Wrapper around the raw `[keybindings]` table. The inner
-/// `BTreeMap<String, String>` is `action_name -> key_string`; both
-/// fields are owned strings so the user's literal input is preserved
-/// for error messages out of `crate::keymap::resolve_keymap`.
+/// Wrapper around the raw `[keybindings]` table. The inner map is
+/// `action_name -> key_string(s)`; both sides are kept as owned
+/// strings so the user's literal input is preserved for messages out
+/// of `crate::keymap::resolve_keymap`.
 #[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(transparent)]
 pub struct KeybindingsConfig {
-    /// `action_name -> key_string` map. Resolver flips this into a
+    /// `action_name -> key_string(s)` map. Resolver flips this into a
     /// materialised `KeyEvent -> Action` table at app startup.
-    pub inner: BTreeMap<String, String>,
+    pub inner: BTreeMap<String, KeySpec>,
 }
 
 impl Default for Config {
@@ -242,11 +787,54 @@ impl Default for Config {
             theme: ThemeConfig::default(),
             keybindings: KeybindingsConfig::default(),
             log: LogConfig::default(),
+            render: RenderConfig::default(),
+            spam: SpamConfig::default(),
+            folders: FoldersConfig::default(),
+            status: StatusConfig::default(),
+            mailbox_format: MailboxFormat::Maildir,
+            maildir_convention: MaildirConvention::Standard,
+            date_format_today: Self::default_date_format_today(),
+            date_format_this_week: Self::default_date_format_this_week(),
+            date_format_this_year: Self::default_date_format_this_year(),
+            date_format_other: Self::default_date_format_other(),
+            date_style: DateStyle::Smart,
+            date_format: Self::default_date_format(),
+            sender_display: SenderDisplay::Name,
+            list_preview: false,
+            update_window_title: Self::default_update_window_title(),
+            columns: ColumnConfig::defaults(),
+            editor: None,
+            saved_searches: Vec::new(),
+            folder_pane_width: Self::default_pane_width(),
+            message_pane_width: Self::default_pane_width(),
+            layout: LayoutMode::TwoPane,
         }
     }
 }
 
 impl Config {
+    fn default_date_format_today() -> String {
+        "%H:%M".to_string()
+    }
+    fn default_date_format_this_week() -> String {
+        "%a %H:%M".to_string()
+    }
+    fn default_date_format_this_year() -> String {
+        "%b %d".to_string()
+    }
+    fn default_date_format_other() -> String {
+        "%Y-%m-%d".to_string()
+    }
+    fn default_date_format() -> String {
+        "%Y-%m-%d %H:%M".to_string()
+    }
+    fn default_update_window_title() -> bool {
+        true
+    }
+    fn default_pane_width() -> u16 {
+        50
+    }
+
     /// Ordered list of `(account_id, account)` pairs. Empty when no
     /// `[accounts.*]` tables are configured. Stable across calls.
     pub fn ordered_accounts(&self) -> Vec<(String, AccountConfig)> {
@@ -284,6 +872,81 @@ impl Config {
         self.maildir_path.clone()
     }
 
+    /// Account whose maildir is `path`, as `(account_id, account)`.
+    /// `None` when no `[accounts.*]` entry points there.
+    pub fn account_for_maildir(&self, path: &Path) -> Option<(&String, &AccountConfig)> {
+        self.accounts.iter().find(|(_, a)| a.maildir_path == path)
+    }
+
+    /// Apply `-m <path>`. Without `[accounts.*]` tables it replaces
+    /// `maildir_path`. With accounts configured it makes the account
+    /// rooted at `path` the startup account, adding one (named after
+    /// the directory) when no account points there yet.
+    pub fn apply_maildir_override(&mut self, path: PathBuf) {
+        if !self.accounts.is_empty() {
+            let id = match self.account_for_maildir(&path) {
+                Some((id, _)) => id.clone(),
+                None => {
+                    let name = path
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .filter(|n| !n.is_empty())
+                        .unwrap_or_else(|| "cli".to_string());
+                    let mut id = name.clone();
+                    let mut n = 2;
+                    while self.accounts.contains_key(&id) {
+                        id = format!("{}-{}", name, n);
+                        n += 1;
+                    }
+                    let account = AccountConfig {
+                        name,
+                        email: String::new(),
+                        maildir_path: path.clone(),
+                        smtp_command: None,
+                        signature: None,
+                    };
+                    self.accounts.insert(id.clone(), account);
+                    id
+                }
+            };
+            self.default_account = Some(id);
+        }
+        self.maildir_path = path;
+    }
+
+    /// Message-list columns for the folder at `path`: its
+    /// `[folders."<path>"] list_columns`, else `[[columns]]` — with
+    /// `from` showing the recipient (`to`) in the Sent folder.
+    pub fn columns_for(
+        &self,
+        path: &Path,
+        role: crate::email::FolderRole,
+    ) -> std::borrow::Cow<'_, [ColumnConfig]> {
+        use std::borrow::Cow;
+        if let Some(columns) = self
+            .folders
+            .override_for(path)
+            .and_then(|f| f.list_columns.as_deref())
+        {
+            return Cow::Borrowed(columns);
+        }
+        if role != crate::email::FolderRole::Sent {
+            return Cow::Borrowed(&self.columns);
+        }
+        Cow::Owned(
+            self.columns
+                .iter()
+                .map(|c| match c.name.as_str() {
+                    "from" => ColumnConfig {
+                        name: "to".to_string(),
+                        ..c.clone()
+                    },
+                    _ => c.clone(),
+                })
+                .collect(),
+        )
+    }
+
     /// True when more than one account is configured. Drives the
     /// Accounts pane visibility (single-account installs hide it per
     /// VISION.md § "Multi-Account").
@@ -329,6 +992,28 @@ impl Config {
         Ok(config)
     }
 
+    /// One line per `[[columns]]` or `list_columns` entry whose name
+    /// isn't in [`ColumnConfig::NAMES`]. Those columns are skipped, so
+    /// the user needs to see why they vanished.
+    pub fn column_warnings(&self) -> Vec<String> {
+        let unknown = |c: &&ColumnConfig| !ColumnConfig::NAMES.contains(&c.name.as_str());
+        let mut warnings: Vec<String> = self
+            .columns
+            .iter()
+            .filter(unknown)
+            .map(|c| format!("Unknown column '{}' in [[columns]]", c.name))
+            .collect();
+        for (folder, settings) in &self.folders.overrides {
+            for column in settings.list_columns.iter().flatten().filter(unknown) {
+                warnings.push(format!(
+                    "Unknown column '{}' in list_columns for '{}'",
+                    column.name, folder
+                ));
+            }
+        }
+        warnings
+    }
+
     /// Reject malformed `[web].bind` (non-IP literal) and unknown
     /// `[ai].backend`. Centralizing here means every loader path —
     /// explicit `-c`, `~/.config/vulthor/config.toml`, `./vulthor.toml`
@@ -340,19 +1025,55 @@ impl Config {
                 message: format!("[web].bind must be an IP literal (got {:?})", self.web.bind),
             });
         }
+        // A blank token would be matched by a blank `Authorization:
+        // Bearer ` or `?token=`, opening the API to anyone.
+        if self
+            .web
+            .api_token
+            .as_deref()
+            .is_some_and(|t| t.trim().is_empty())
+        {
+            return Err(VulthorError::Config {
+                message:
+                    "[web].api_token must not be empty; remove it to use only the per-launch token"
+                        .to_string(),
+            });
+        }
         if !AiConfig::is_known_backend(&self.ai.backend) {
             return Err(VulthorError::Config {
                 message: format!("unknown [ai].backend {:?}", self.ai.backend),
             });
         }
-        // Resolve the keymap so [keybindings] typos, unknown actions,
-        // and key-string conflicts surface at config-load time rather
-        // than at first keypress. The resolved table is rebuilt by
-        // AppRoot; this is purely a structural-validity check.
+        // Resolve the keymap so key-string conflicts surface at
+        // config-load time rather than at first keypress. Unknown
+        // actions and bad key strings only warn; AppRoot shows those
+        // when it rebuilds the table.
         crate::keymap::resolve_keymap(&self.keybindings.inner)?;
         // Reject unknown [theme].preset names at load time so typos
         // don't silently fall back to default-dark.
         crate::theme::preset_from_config(&self.theme.preset)?;
+        // Unknown column names only warn; AppRoot shows them on the
+        // status line at startup.
+        for warning in self.column_warnings() {
+            tracing::warn!(%warning, "column skipped");
+        }
+        // A bad `%` specifier only fails when formatting, which would
+        // panic mid-render; try each format once here instead.
+        let sample = chrono::Local::now();
+        for (key, format) in [
+            ("date_format_today", &self.date_format_today),
+            ("date_format_this_week", &self.date_format_this_week),
+            ("date_format_this_year", &self.date_format_this_year),
+            ("date_format_other", &self.date_format_other),
+            ("date_format", &self.date_format),
+        ] {
+            use std::fmt::Write;
+            if write!(String::new(), "{}", sample.format(format)).is_err() {
+                return Err(VulthorError::Config {
+                    message: format!("invalid {} {:?}", key, format),
+                });
+            }
+        }
         Ok(())
     }
 }
@@ -372,6 +1093,7 @@ mod tests {
         let args = CliArgs::parse_from(["vulthor"]);
         assert_eq!(args.port, None);
         assert!(args.config_path.is_none());
+        assert!(args.open_eml.is_none());
     }
 
     #[test]
@@ -667,6 +1389,38 @@ maildir_path = "/Mail/solo"
         assert_eq!(cfg.active_maildir(), PathBuf::from("/legacy/Mail"));
     }
 
+    #[test]
+    fn maildir_flag_selects_or_adds_an_account() {
+        let toml_str = r#"
+maildir_path = "/legacy/path"
+
+[accounts.work]
+name = "Work"
+email = "me@work.test"
+maildir_path = "/Mail/work"
+
+[accounts.home]
+name = "Home"
+email = "me@home.test"
+maildir_path = "/Mail/home"
+"#;
+        let mut cfg: Config = toml::from_str(toml_str).expect("parses");
+        cfg.apply_maildir_override(PathBuf::from("/Mail/work"));
+        assert_eq!(cfg.accounts.len(), 2);
+        assert_eq!(cfg.active_maildir(), PathBuf::from("/Mail/work"));
+
+        cfg.apply_maildir_override(PathBuf::from("/tmp/work"));
+        assert_eq!(cfg.accounts.len(), 3);
+        assert_eq!(cfg.default_account.as_deref(), Some("work-2"));
+        assert_eq!(cfg.accounts["work-2"].name, "work");
+        assert_eq!(cfg.active_maildir(), PathBuf::from("/tmp/work"));
+
+        let mut legacy = Config::default();
+        legacy.apply_maildir_override(PathBuf::from("/custom"));
+        assert!(legacy.accounts.is_empty());
+        assert_eq!(legacy.active_maildir(), PathBuf::from("/custom"));
+    }
+
     #[test]
     fn default_account_with_unknown_key_falls_back_to_first() {
         // Typo in default_account → don't crash; pick the first
@@ -694,6 +1448,7 @@ maildir_path = "/Mail/work"
         let cfg: Config = toml::from_str(toml_str).expect("parses");
         assert_eq!(cfg.web.port, 8080);
         assert_eq!(cfg.web.bind, "127.0.0.1");
+        assert_eq!(cfg.web.api_token, None);
         cfg.validate().expect("defaults pass validation");
     }
 
@@ -706,10 +1461,12 @@ maildir_path = "/legacy/Mail"
 [web]
 port = 9090
 bind = "0.0.0.0"
+api_token = "s3cret"
 "#;
         let cfg: Config = toml::from_str(toml_str).expect("parses");
         assert_eq!(cfg.web.port, 9090);
         assert_eq!(cfg.web.bind, "0.0.0.0");
+        assert_eq!(cfg.web.api_token.as_deref(), Some("s3cret"));
         cfg.validate().expect("0.0.0.0 is a valid IP literal");
 
         // Round-trip: serialize and parse again.
@@ -717,6 +1474,20 @@ bind = "0.0.0.0"
         let reparsed: Config = toml::from_str(&serialized).expect("re-parses");
         assert_eq!(reparsed.web.port, 9090);
         assert_eq!(reparsed.web.bind, "0.0.0.0");
+        assert_eq!(reparsed.web.api_token, cfg.web.api_token);
+    }
+
+    #[test]
+    fn blank_api_token_is_rejected() {
+        for blank in ["", "  ", "\t"] {
+            let toml_str = format!(
+                "maildir_path = \"/tmp/Mail\"\n[web]\napi_token = {:?}\n",
+                blank
+            );
+            let cfg: Config = toml::from_str(&toml_str).expect("parses");
+            let err = cfg.validate().expect_err("blank api_token must fail");
+            assert!(err.to_string().contains("[web].api_token"), "{}", err);
+        }
     }
 
     #[test]
@@ -782,6 +1553,7 @@ bind = "not-an-ip"
             web: WebConfig {
                 port: 8080,
                 bind: "localhost".to_string(),
+                api_token: None,
             },
             ..Config::default()
         };
@@ -789,6 +1561,19 @@ bind = "not-an-ip"
         assert!(matches!(err, VulthorError::Config { .. }));
     }
 
+    #[test]
+    fn bad_date_format_rejects_via_validate() {
+        let cfg = Config {
+            date_format_this_week: "%a %Q".to_string(),
+            ..Config::default()
+        };
+        let err = cfg.validate().expect_err("%Q is not a chrono specifier");
+        assert!(
+            err.to_string().contains("date_format_this_week"),
+            "got: {err}"
+        );
+    }
+
     #[tokio::test]
     async fn malformed_backend_rejects_via_load() {
         let temp_dir = TempDir::new().unwrap();
@@ -817,6 +1602,7 @@ backend = "not-a-backend"
             web: WebConfig {
                 port: 8080,
                 bind: "::1".to_string(),
+                api_token: None,
             },
             ..Config::default()
         };
@@ -911,11 +1697,10 @@ draft_edit = "X"
         );
     }
 
-    /// Unknown action name in `[keybindings]` surfaces as a structured
-    /// error rather than silently being ignored — typos must not
-    /// rebind anything.
+    /// Unknown action names in `[keybindings]` don't stop startup but
+    /// are reported, and typos never rebind anything.
     #[test]
-    fn keybindings_unknown_action_is_rejected_at_validate() {
+    fn keybindings_unknown_action_warns_at_resolve() {
         let toml_str = r#"
 maildir_path = "/legacy/Mail"
 
@@ -923,10 +1708,276 @@ maildir_path = "/legacy/Mail"
 teleport = "t"
 "#;
         let cfg: Config = toml::from_str(toml_str).expect("parses");
-        let err = cfg.validate().expect_err("unknown action rejected");
-        assert!(matches!(
-            err,
-            VulthorError::KeybindingUnknownAction { ref action } if action == "teleport"
-        ));
+        cfg.validate().expect("unknown actions only warn");
+        let map = crate::keymap::resolve_keymap(&cfg.keybindings.inner).unwrap();
+        assert_eq!(
+            map.warnings(),
+            ["Unknown action 'teleport' in [keybindings]"]
+        );
+    }
+
+    /// `[keys]` is an alias for `[keybindings]`, and an entry may list
+    /// several keys.
+    #[test]
+    fn keys_section_accepts_key_lists() {
+        let toml_str = r#"
+maildir_path = "/legacy/Mail"
+
+[keys]
+quit = "Q"
+move_down = ["j", "Ctrl+n"]
+"#;
+        let cfg: Config = toml::from_str(toml_str).expect("parses");
+        assert_eq!(cfg.keybindings.inner["quit"], KeySpec::from("Q"));
+        assert_eq!(
+            cfg.keybindings.inner["move_down"].keys(),
+            ["j".to_string(), "Ctrl+n".to_string()]
+        );
+        cfg.validate().expect("resolves");
+    }
+
+    #[test]
+    fn render_prefer_html_defaults_off_and_parses() {
+        let cfg: Config = toml::from_str(r#"maildir_path = "/Mail""#).expect("parses");
+        assert!(!cfg.render.prefer_html);
+
+        let toml_str = r#"
+maildir_path = "/Mail"
+
+[render]
+prefer_html = true
+"#;
+        let cfg: Config = toml::from_str(toml_str).expect("parses");
+        assert!(cfg.render.prefer_html);
+        assert!(cfg.render.dim_signatures, "unset keys keep their default");
+    }
+
+    #[test]
+    fn status_timeouts_default_and_parse() {
+        assert_eq!(Config::default().status, StatusConfig::default());
+
+        let toml_str = r#"
+maildir_path = "/Mail"
+
+[status]
+timeout_ms = 1500
+"#;
+        let cfg: Config = toml::from_str(toml_str).expect("parses");
+        assert_eq!(cfg.status.timeout_ms, 1500);
+        assert_eq!(cfg.status.error_timeout_ms, 10_000);
+    }
+
+    #[test]
+    fn maildir_convention_defaults_to_standard_and_accepts_maildir_plus_plus() {
+        assert_eq!(
+            Config::default().maildir_convention,
+            MaildirConvention::Standard
+        );
+        for value in ["maildirpp", "maildir++"] {
+            let toml_str = format!(
+                "maildir_path = \"/Mail\"\nmaildir_convention = \"{}\"\n",
+                value
+            );
+            let cfg: Config = toml::from_str(&toml_str).expect("parses");
+            assert_eq!(cfg.maildir_convention, MaildirConvention::MaildirPP);
+        }
+    }
+
+    #[test]
+    fn layout_defaults_to_two_panes_and_parses_three_pane() {
+        assert_eq!(Config::default().layout, LayoutMode::TwoPane);
+        let cfg: Config =
+            toml::from_str("maildir_path = \"/Mail\"\nlayout = \"three_pane\"\n").unwrap();
+        assert_eq!(cfg.layout, LayoutMode::ThreePane);
+    }
+
+    #[test]
+    fn date_style_defaults_to_smart_and_parses_the_others() {
+        let cfg = Config::default();
+        assert_eq!(cfg.date_style, DateStyle::Smart);
+        assert_eq!(cfg.date_format, "%Y-%m-%d %H:%M");
+        let cfg: Config = toml::from_str(
+            "maildir_path = \"/Mail\"\ndate_style = \"absolute\"\ndate_format = \"%d.%m.%y\"\n",
+        )
+        .unwrap();
+        assert_eq!(cfg.date_style, DateStyle::Absolute);
+        assert_eq!(cfg.date_format, "%d.%m.%y");
+        let cfg: Config =
+            toml::from_str("maildir_path = \"/Mail\"\ndate_style = \"relative\"\n").unwrap();
+        assert_eq!(cfg.date_style, DateStyle::Relative);
+    }
+
+    #[test]
+    fn per_folder_list_columns_override_the_global_set() {
+        use crate::email::FolderRole;
+        let toml_str = r#"
+maildir_path = "/Mail"
+
+[folders]
+sent = "Sent"
+
+[folders."Drafts"]
+list_columns = ["flags", "subject:flex", "date:12"]
+
+[folders."Work/Archive"]
+list_columns = [{ name = "size", width = 9 }, "subject"]
+"#;
+        let cfg: Config = toml::from_str(toml_str).expect("parses");
+        assert_eq!(cfg.folders.sent.as_deref(), Some("Sent"));
+        let names = |columns: &[ColumnConfig]| -> Vec<String> {
+            columns.iter().map(|c| c.name.clone()).collect()
+        };
+
+        let drafts = cfg.columns_for(Path::new("/Mail/Drafts"), FolderRole::Drafts);
+        assert_eq!(names(&drafts), ["flags", "subject", "date"]);
+        assert_eq!(drafts[1].width, Some(ColumnWidth::Flexible));
+        assert_eq!(drafts[2].width, Some(ColumnWidth::Fixed(12)));
+        let archive = cfg.columns_for(Path::new("/Mail/Work/Archive"), FolderRole::Archive);
+        assert_eq!(names(&archive), ["size", "subject"]);
+        // Only whole trailing components match.
+        let other = cfg.columns_for(Path::new("/Mail/OldDrafts"), FolderRole::Normal);
+        assert_eq!(*other, *ColumnConfig::defaults());
+
+        // Without an override the Sent folder lists recipients.
+        let sent = cfg.columns_for(Path::new("/Mail/Sent"), FolderRole::Sent);
+        assert_eq!(
+            names(&sent),
+            ["unread", "to", "subject", "flags", "attachments", "date"]
+        );
+
+        let bad: std::result::Result<Config, _> =
+            toml::from_str("maildir_path = \"/Mail\"\ncolumns = [\"subject:wide\"]\n");
+        assert!(bad.is_err());
+    }
+
+    #[test]
+    fn sender_display_defaults_to_name() {
+        assert_eq!(Config::default().sender_display, SenderDisplay::Name);
+        let cfg: Config =
+            toml::from_str("maildir_path = \"/Mail\"\nsender_display = \"both\"\n").unwrap();
+        assert_eq!(cfg.sender_display, SenderDisplay::Both);
+    }
+
+    #[test]
+    fn render_dim_signatures_defaults_on_and_can_be_disabled() {
+        assert!(Config::default().render.dim_signatures);
+
+        let toml_str = r#"
+maildir_path = "/Mail"
+
+[render]
+dim_signatures = false
+"#;
+        let cfg: Config = toml::from_str(toml_str).expect("parses");
+        assert!(!cfg.render.dim_signatures);
+    }
+
+    #[test]
+    fn render_image_protocol_defaults_to_none_and_parses_names() {
+        assert_eq!(Config::default().render.image_protocol, ImageProtocol::None);
+
+        for (name, expected) in [
+            ("kitty", ImageProtocol::Kitty),
+            ("sixel", ImageProtocol::Sixel),
+        ] {
+            let toml_str = format!(
+                "maildir_path = \"/Mail\"\n\n[render]\nimage_protocol = \"{}\"\n",
+                name
+            );
+            let cfg: Config = toml::from_str(&toml_str).expect("parses");
+            assert_eq!(cfg.render.image_protocol, expected);
+        }
+        let bad = "maildir_path = \"/Mail\"\n\n[render]\nimage_protocol = \"iterm\"\n";
+        assert!(toml::from_str::<Config>(bad).is_err());
+    }
+
+    #[test]
+    fn spam_config_defaults_and_overrides() {
+        let spam = Config::default().spam;
+        assert_eq!(spam.flag_header, "X-Spam-Flag");
+        assert_eq!(spam.score_header, "X-Spam-Score");
+        assert_eq!(spam.junk_folder, "Junk");
+
+        let toml_str = r#"
+maildir_path = "/Mail"
+
+[spam]
+score_header = "X-Rspamd-Score"
+junk_folder = "Spam"
+"#;
+        let cfg: Config = toml::from_str(toml_str).expect("parses");
+        assert_eq!(cfg.spam.flag_header, "X-Spam-Flag");
+        assert_eq!(cfg.spam.score_header, "X-Rspamd-Score");
+        assert_eq!(cfg.spam.junk_folder, "Spam");
+    }
+
+    #[test]
+    fn folders_config_maps_roles_to_paths() {
+        let toml_str = r#"
+maildir_path = "/Mail"
+
+[folders]
+sent = "[Gmail]/Sent Mail"
+trash = "Deleted Items"
+"#;
+        let cfg: Config = toml::from_str(toml_str).expect("parses");
+        use crate::email::FolderRole;
+        assert_eq!(
+            cfg.folders.path_for(FolderRole::Sent),
+            Some("[Gmail]/Sent Mail")
+        );
+        assert_eq!(
+            cfg.folders.path_for(FolderRole::Trash),
+            Some("Deleted Items")
+        );
+        assert_eq!(cfg.folders.path_for(FolderRole::Archive), None);
+        assert_eq!(Config::default().folders, FoldersConfig::default());
+    }
+
+    #[test]
+    fn columns_parse_fixed_and_flexible_widths_and_default_to_the_classic_layout() {
+        assert_eq!(Config::default().columns, ColumnConfig::defaults());
+        let config: Config = toml::from_str(
+            r#"
+            maildir_path = "/mail"
+
+            [[columns]]
+            name = "from"
+            width = 20
+
+            [[columns]]
+            name = "subject"
+            width = "flex"
+
+            [[columns]]
+            name = "date"
+            enabled = false
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.columns,
+            [
+                ColumnConfig {
+                    name: "from".into(),
+                    width: Some(ColumnWidth::Fixed(20)),
+                    enabled: true,
+                },
+                ColumnConfig {
+                    name: "subject".into(),
+                    width: Some(ColumnWidth::Flexible),
+                    enabled: true,
+                },
+                ColumnConfig {
+                    name: "date".into(),
+                    width: None,
+                    enabled: false,
+                },
+            ]
+        );
+        let bad = toml::from_str::<Config>(
+            "maildir_path = \"/mail\"\n[[columns]]\nname = \"from\"\nwidth = \"wide\"\n",
+        );
+        assert!(bad.is_err());
     }
 }
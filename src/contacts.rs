@@ -0,0 +1,388 @@
+//! Address book sourced from the mail already on disk.
+//!
+//! Every `From` header and every `To` / `Cc` recipient the scanner has
+//! parsed contributes one sighting to a [`ContactBook`]. Contacts are
+//! ranked first by how often the user has sent mail to them
+//! ([`ContactFrequency`], kept across runs in
+//! `~/.local/share/vulthor/contacts.json`), then by how often they were
+//! seen. Compose goes through `$EDITOR`, so completion happens when the
+//! editor exits: a recipient typed without an `@` (`alice`, `Bob`) is
+//! expanded to the best-ranked contact whose address or name starts
+//! with it, mutt-alias style. Entries the reply / forward template
+//! already carried are never touched, and the draft pane lists every
+//! expansion before send.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::email::Folder;
+use crate::error::Result;
+
+/// One address seen in the maildir.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contact {
+    /// Display name from the most recent sighting that carried one.
+    pub name: Option<String>,
+    pub address: String,
+    /// Number of headers the address appeared in.
+    pub count: usize,
+}
+
+impl Contact {
+    /// `Name <address>`, or the bare address when no name is known.
+    /// A name with a comma is quoted so the result stays one mailbox.
+    pub fn display(&self) -> String {
+        crate::compose::format_mailbox(self.name.as_deref().unwrap_or_default(), &self.address)
+    }
+
+    fn matches(&self, prefix: &str) -> bool {
+        let prefix = prefix.to_lowercase();
+        self.address.to_lowercase().starts_with(&prefix)
+            || self.name.as_deref().is_some_and(|name| {
+                let name = name.to_lowercase();
+                name.starts_with(&prefix) || name.split_whitespace().any(|w| w.starts_with(&prefix))
+            })
+    }
+}
+
+/// Result of [`ContactBook::expand_recipients`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Expansion {
+    /// The header value with every resolvable short name replaced.
+    pub value: String,
+    /// `(typed, replacement)` for each short name that was expanded.
+    pub expanded: Vec<(String, String)>,
+    /// Short names no contact matched; left in `value` untouched.
+    pub unresolved: Vec<String>,
+}
+
+/// How many sent emails went to each address (lowercased), counting
+/// every `To` / `Cc` / `Bcc` recipient once per send.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ContactFrequency {
+    counts: HashMap<String, usize>,
+}
+
+impl ContactFrequency {
+    /// `~/.local/share/vulthor/contacts.json` (the platform data dir on
+    /// other systems). `None` when no data dir resolves.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|d| d.join("vulthor").join("contacts.json"))
+    }
+
+    /// Read the counts saved at `path`. A missing file is empty; one
+    /// that doesn't parse is logged and ignored.
+    pub fn load(path: &Path) -> Self {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) => {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    tracing::warn!(error = %e, path = %path.display(), "could not read contacts file");
+                }
+                return Self::default();
+            }
+        };
+        serde_json::from_str(&text).unwrap_or_else(|e| {
+            tracing::warn!(error = %e, path = %path.display(), "ignoring unreadable contacts file");
+            Self::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(&self.counts).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Count one send to every mailbox in the comma-separated recipient
+    /// `fields`.
+    pub fn record_sent(&mut self, fields: &[&str]) {
+        for field in fields {
+            for mailbox in split_mailboxes(field) {
+                if let Some((_, address)) = parse_mailbox(mailbox) {
+                    *self.counts.entry(address.to_lowercase()).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    /// Sends to `address` so far (case-insensitive).
+    pub fn get(&self, address: &str) -> usize {
+        self.counts
+            .get(&address.to_lowercase())
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// Frequency-ranked list of every address seen.
+#[derive(Debug, Clone, Default)]
+pub struct ContactBook {
+    contacts: Vec<Contact>,
+    /// Past sends, ranked above plain sightings by [`Self::complete`].
+    frequency: ContactFrequency,
+}
+
+impl ContactBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Book of every sender and recipient in the loaded part of
+    /// `root`, recursing into subfolders.
+    pub fn from_folder(root: &Folder) -> Self {
+        let mut book = Self::new();
+        book.record_folder(root);
+        book
+    }
+
+    /// Rank completions by `frequency` ahead of the sighting count.
+    pub fn with_frequency(mut self, frequency: ContactFrequency) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    fn record_folder(&mut self, folder: &Folder) {
+        for email in &folder.emails {
+            self.record(&email.headers.from);
+            // Every recipient of the raw `To` / `Cc` headers, so a sent
+            // email counts all the people it went to; the formatted
+            // first `To` covers emails loaded without raw headers.
+            let mut recipients = email
+                .raw_headers
+                .iter()
+                .filter(|(name, _)| {
+                    name.eq_ignore_ascii_case("To") || name.eq_ignore_ascii_case("Cc")
+                })
+                .peekable();
+            if recipients.peek().is_none() {
+                self.record(&email.headers.to);
+            }
+            for (_, value) in recipients {
+                for mailbox in split_mailboxes(value) {
+                    self.record(mailbox);
+                }
+            }
+        }
+        for sub in &folder.subfolders {
+            self.record_folder(sub);
+        }
+    }
+
+    /// Count one sighting of a `Name <address>` or bare-address header
+    /// value. Values without an `@` (parse fallbacks like "Unknown")
+    /// are ignored.
+    pub fn record(&mut self, header: &str) {
+        let Some((name, address)) = parse_mailbox(header) else {
+            return;
+        };
+        match self
+            .contacts
+            .iter_mut()
+            .find(|c| c.address.eq_ignore_ascii_case(&address))
+        {
+            Some(contact) => {
+                contact.count += 1;
+                if name.is_some() {
+                    contact.name = name;
+                }
+            }
+            None => self.contacts.push(Contact {
+                name,
+                address,
+                count: 1,
+            }),
+        }
+    }
+
+    /// Contacts whose address, full name or any name word starts with
+    /// `prefix` (case-insensitive), most often sent to first, then most
+    /// frequently seen.
+    pub fn complete(&self, prefix: &str) -> Vec<&Contact> {
+        let prefix = prefix.trim();
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+        let mut hits: Vec<&Contact> = self.contacts.iter().filter(|c| c.matches(prefix)).collect();
+        hits.sort_by(|a, b| {
+            self.frequency
+                .get(&b.address)
+                .cmp(&self.frequency.get(&a.address))
+                .then_with(|| b.count.cmp(&a.count))
+                .then_with(|| a.address.cmp(&b.address))
+        });
+        hits
+    }
+
+    /// Expand each comma-separated entry of a recipient header that is
+    /// a bare short name — no `@`, `<` or quotes — to its best
+    /// completion. Entries that also appear in one of the `carried`
+    /// header values (the template the editor opened with) were not
+    /// typed by the user and are left alone.
+    pub fn expand_recipients(&self, field: &str, carried: &[&str]) -> Expansion {
+        let carried: Vec<&str> = carried.iter().flat_map(|v| split_mailboxes(v)).collect();
+        let mut expanded = Vec::new();
+        let mut unresolved = Vec::new();
+        let parts: Vec<String> = split_mailboxes(field)
+            .map(|part| {
+                if part.contains(['@', '<', '"']) || carried.contains(&part) {
+                    return part.to_string();
+                }
+                match self.complete(part).first() {
+                    Some(contact) => {
+                        let display = contact.display();
+                        expanded.push((part.to_string(), display.clone()));
+                        display
+                    }
+                    None => {
+                        unresolved.push(part.to_string());
+                        part.to_string()
+                    }
+                }
+            })
+            .collect();
+        Expansion {
+            value: parts.join(", "),
+            expanded,
+            unresolved,
+        }
+    }
+}
+
+/// The comma-separated mailboxes of an address-list header, leaving
+/// commas inside a quoted display name (`"Doe, Jane" <j@x>`) alone.
+fn split_mailboxes(header: &str) -> impl Iterator<Item = &str> {
+    let mut quoted = false;
+    header
+        .split(move |c| {
+            if c == '"' {
+                quoted = !quoted;
+            }
+            c == ',' && !quoted
+        })
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+}
+
+/// Split `Name <address>` / `address` into its parts.
+fn parse_mailbox(header: &str) -> Option<(Option<String>, String)> {
+    let header = header.trim();
+    let (name, address) = match (header.rfind('<'), header.rfind('>')) {
+        (Some(open), Some(close)) if open < close => {
+            let name = header[..open].trim().trim_matches('"').trim();
+            let name = (!name.is_empty()).then(|| name.to_string());
+            (name, header[open + 1..close].trim())
+        }
+        _ => (None, header),
+    };
+    address.contains('@').then(|| (name, address.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maildir::MaildirScanner;
+    use crate::test_fixtures::TestMailDir;
+
+    #[test]
+    fn scanned_fixtures_complete_alice() {
+        let maildir = TestMailDir::new();
+        maildir.add_custom_email(
+            "INBOX",
+            "alice.eml",
+            "From: Alice Liddell <alice@example.com>\nTo: user@example.com\nSubject: Hi\n\nHello\n",
+        );
+        let scanner = MaildirScanner::new(maildir.root_path.clone());
+        let mut root = scanner.scan().unwrap();
+        for folder in root.subfolders.iter_mut() {
+            scanner.load_folder_emails_with_limit(folder, None).unwrap();
+        }
+
+        let book = ContactBook::from_folder(&root);
+        let hits = book.complete("alice");
+        assert_eq!(hits[0].address, "alice@example.com");
+        assert_eq!(hits[0].display(), "Alice Liddell <alice@example.com>");
+        // Name words match too, case-insensitively.
+        assert_eq!(book.complete("LIDD")[0].address, "alice@example.com");
+        // The fixtures' own recipient is the most frequently seen.
+        assert_eq!(book.complete("user")[0].address, "user@example.com");
+        assert!(book.complete("user")[0].count > 1);
+    }
+
+    #[test]
+    fn expand_recipients_fills_short_names_and_reports_misses() {
+        let mut book = ContactBook::new();
+        book.record("Bob <bob@example.com>");
+        book.record("bobby@example.com");
+        book.record("bobby@example.com");
+        book.record("Unknown");
+
+        let out = book.expand_recipients("bob, carol@example.com, zed", &[]);
+        assert_eq!(out.value, "bobby@example.com, carol@example.com, zed");
+        assert_eq!(
+            out.expanded,
+            vec![("bob".to_string(), "bobby@example.com".to_string())]
+        );
+        assert_eq!(out.unresolved, vec!["zed".to_string()]);
+        assert!(book.complete("unknown").is_empty());
+    }
+
+    /// A reply's To line carried over from the template is not the
+    /// user's typing: even a fragment without `@` stays as it was.
+    #[test]
+    fn expand_recipients_leaves_template_entries_alone() {
+        let mut book = ContactBook::new();
+        book.record("Doe Ray <doe.ray@example.com>");
+        book.record("\"Liddell, Alice\" <alice@example.com>");
+        let template = "Doe, John <john@example.com>";
+
+        let out = book.expand_recipients("Doe, John <john@example.com>, ali", &[template]);
+        assert_eq!(
+            out.value,
+            "Doe, John <john@example.com>, \"Liddell, Alice\" <alice@example.com>"
+        );
+        assert_eq!(out.expanded.len(), 1);
+        assert!(out.unresolved.is_empty());
+    }
+
+    #[test]
+    fn sent_recipients_complete_and_rank_by_sends() {
+        let maildir = TestMailDir::new();
+        maildir.add_custom_email(
+            "Sent",
+            "to-alice.eml",
+            "From: user@example.com\nTo: \"Liddell, Alice\" <alice@example.com>, carol@example.com\nCc: Alison <alison@example.com>\nSubject: Tea\n\nHi\n",
+        );
+        let scanner = MaildirScanner::new(maildir.root_path.clone());
+        let mut root = scanner.scan().unwrap();
+        for folder in root.subfolders.iter_mut() {
+            scanner.load_folder_emails_with_limit(folder, None).unwrap();
+        }
+
+        let book = ContactBook::from_folder(&root);
+        let hits: Vec<&str> = book
+            .complete("ali")
+            .iter()
+            .map(|c| c.address.as_str())
+            .collect();
+        assert_eq!(hits, ["alice@example.com", "alison@example.com"]);
+        assert_eq!(book.complete("carol")[0].address, "carol@example.com");
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("vulthor/contacts.json");
+        let mut frequency = ContactFrequency::load(&path);
+        frequency.record_sent(&["Alison <ALISON@example.com>", "", "x@example.com"]);
+        frequency.save(&path).unwrap();
+        let frequency = ContactFrequency::load(&path);
+        assert_eq!(frequency.get("alison@example.com"), 1);
+
+        let book = ContactBook::from_folder(&root).with_frequency(frequency);
+        assert_eq!(book.complete("ali")[0].address, "alison@example.com");
+    }
+}
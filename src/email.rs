@@ -1,8 +1,12 @@
+use crate::config::SpamConfig;
 use crate::error::{Result, VulthorError};
-use mail_parser::{Message, MessageParser, MimeHeaders, PartType};
+use crate::threading::ThreadPos;
+use crate::util::parse_flexible_date;
+use mail_parser::{
+    Encoding, HeaderName, Message, MessageParser, MessagePart, MimeHeaders, PartType,
+};
 use std::borrow::Cow;
-use std::collections::HashMap;
-use std::fs;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 /// True when the MailDir info-flags suffix (`:2,…`) of the path's
@@ -36,6 +40,112 @@ pub struct EmailHeaders {
     /// `Message-ID` header value (bare id, no angle brackets), or empty
     /// when absent. Used as the cross-reference key for drafts.
     pub message_id: String,
+    /// First id in `In-Reply-To` (bare, no angle brackets), or empty.
+    pub in_reply_to: String,
+    /// Ids in `References`, oldest ancestor first (bare).
+    pub references: Vec<String>,
+    /// Normalized `X-Priority` / `Importance`; `Normal` when neither
+    /// header is present.
+    pub priority: Priority,
+}
+
+/// DKIM / SPF / DMARC verdicts read from `Authentication-Results`.
+/// `None` when no result was recorded for that method (or it was
+/// `none` / `neutral` / a temporary error); `Some(true)` for `pass`,
+/// `Some(false)` for a failure.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AuthStatus {
+    pub dkim: Option<bool>,
+    pub spf: Option<bool>,
+    pub dmarc: Option<bool>,
+}
+
+impl AuthStatus {
+    /// Verdicts from every `Authentication-Results` value. Each value
+    /// is a list of `method=result` entries separated by `;` (some
+    /// servers use `,`). One passing result for a method is enough —
+    /// a message may carry several DKIM signatures.
+    pub fn from_results<'a>(values: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut status = Self::default();
+        for entry in values.into_iter().flat_map(|v| v.split([';', ','])) {
+            let Some((method, rest)) = entry.trim().split_once('=') else {
+                continue;
+            };
+            let verdict = match rest.split_whitespace().next().unwrap_or("") {
+                r if r.eq_ignore_ascii_case("pass") => true,
+                r if ["fail", "softfail", "hardfail", "permerror", "policy"]
+                    .iter()
+                    .any(|f| r.eq_ignore_ascii_case(f)) =>
+                {
+                    false
+                }
+                _ => continue,
+            };
+            let slot = match method.trim().to_ascii_lowercase().as_str() {
+                "dkim" => &mut status.dkim,
+                "spf" => &mut status.spf,
+                "dmarc" => &mut status.dmarc,
+                _ => continue,
+            };
+            *slot = Some(slot.unwrap_or(false) || verdict);
+        }
+        status
+    }
+
+    /// `(label, passed)` for each method with a verdict, in DKIM, SPF,
+    /// DMARC order.
+    pub fn badges(&self) -> Vec<(&'static str, bool)> {
+        [
+            ("DKIM", self.dkim),
+            ("SPF", self.spf),
+            ("DMARC", self.dmarc),
+        ]
+        .into_iter()
+        .filter_map(|(label, verdict)| verdict.map(|v| (label, v)))
+        .collect()
+    }
+}
+
+/// Sender-declared urgency. Ordered so that `max` picks the more
+/// urgent of two conflicting headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl Priority {
+    /// `X-Priority` runs from 1 (highest) to 5 (lowest), often with a
+    /// trailing label such as `1 (Highest)`.
+    fn from_x_priority(value: &str) -> Option<Self> {
+        match value.trim().chars().next()? {
+            '1' | '2' => Some(Priority::High),
+            '3' => Some(Priority::Normal),
+            '4' | '5' => Some(Priority::Low),
+            _ => None,
+        }
+    }
+
+    /// `Importance` (RFC 2156) is `high`, `normal` or `low`.
+    fn from_importance(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "high" => Some(Priority::High),
+            "normal" => Some(Priority::Normal),
+            "low" => Some(Priority::Low),
+            _ => None,
+        }
+    }
+
+    /// Display label for the headers pane.
+    pub fn label(self) -> &'static str {
+        match self {
+            Priority::Low => "Low",
+            Priority::Normal => "Normal",
+            Priority::High => "High",
+        }
+    }
 }
 
 /// Attachment descriptor with decoded payload. The bytes are captured
@@ -59,14 +169,79 @@ pub struct Attachment {
     pub raw_bytes: Vec<u8>,
 }
 
+impl Attachment {
+    /// `type/subtype` lowercased with any `; param=…` tail dropped.
+    fn mime(&self) -> String {
+        self.content_type
+            .split(';')
+            .next()
+            .unwrap_or("")
+            .trim()
+            .to_ascii_lowercase()
+    }
+
+    /// True for parts the Content pane can preview as text: `text/*`,
+    /// the common textual `application/*` types (JSON, XML, YAML,
+    /// scripts, patches), anything named `*.diff` / `*.patch`, and
+    /// calendar invites.
+    pub fn is_previewable(&self) -> bool {
+        let mime = self.mime();
+        mime.starts_with("text/")
+            || mime.ends_with("+json")
+            || mime.ends_with("+xml")
+            || matches!(
+                mime.as_str(),
+                "application/json"
+                    | "application/xml"
+                    | "application/javascript"
+                    | "application/x-sh"
+                    | "application/x-yaml"
+                    | "application/yaml"
+                    | "application/toml"
+                    | "application/x-patch"
+                    | "application/x-diff"
+            )
+            || self.is_patch()
+            || self.is_calendar()
+    }
+
+    /// True for iCalendar invites (`text/calendar`, `application/ics`,
+    /// or a `.ics` filename). The preview shows them as an event
+    /// summary.
+    pub fn is_calendar(&self) -> bool {
+        matches!(self.mime().as_str(), "text/calendar" | "application/ics")
+            || self.filename.to_ascii_lowercase().ends_with(".ics")
+    }
+
+    /// True for unified diffs, by MIME type or by `.diff` / `.patch`
+    /// filename. Drives the +/- coloring in the preview.
+    pub fn is_patch(&self) -> bool {
+        let name = self.filename.to_ascii_lowercase();
+        name.ends_with(".diff")
+            || name.ends_with(".patch")
+            || matches!(
+                self.mime().as_str(),
+                "text/x-patch" | "text/x-diff" | "application/x-patch" | "application/x-diff"
+            )
+    }
+
+    /// True for raster image types the terminal image preview can
+    /// decode (PNG, JPEG, GIF, BMP, WebP). SVG and other vector or
+    /// exotic formats are left to `o`.
+    pub fn is_image(&self) -> bool {
+        matches!(
+            self.mime().as_str(),
+            "image/png" | "image/jpeg" | "image/jpg" | "image/gif" | "image/bmp" | "image/webp"
+        )
+    }
+}
+
 /// Inline-image part referenced from an HTML body by `cid:<content-id>`.
 /// Populated when the message is `multipart/related` (or any structure
 /// whose `Content-Disposition: inline` parts carry a `Content-ID`).
-/// The renderer doesn't display these yet — they're preserved so a
-/// future bead can wire `cid:` resolution in the web pane without a
-/// re-parse from disk.
+/// The web pane serves them from `/api/cid/…` and points the body's
+/// `cid:` references there; the terminal doesn't display them.
 #[derive(Debug, Clone)]
-#[allow(dead_code)] // fields read by tests + future cid: resolution bead
 pub struct InlineImage {
     /// Bare `Content-ID` value with surrounding `<…>` stripped. Matches
     /// the `cid:` URL suffix used by HTML bodies.
@@ -77,6 +252,52 @@ pub struct InlineImage {
     pub raw_bytes: Vec<u8>,
 }
 
+/// One rendition of a message body. `multipart/alternative` messages
+/// carry both; the Content pane shows one at a time (`v` switches).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyPart {
+    /// The `text/plain` part, shown verbatim.
+    Text,
+    /// The sanitized `text/html` part, converted to text for the terminal.
+    Html,
+}
+
+impl BodyPart {
+    /// Pane-title tag: `"[text]"` or `"[html]"`.
+    pub const fn label(self) -> &'static str {
+        match self {
+            BodyPart::Text => "[text]",
+            BodyPart::Html => "[html]",
+        }
+    }
+}
+
+/// One row of the Content pane's header box. `label` is the field
+/// name, drawn in the accent colour; `None` for a note that isn't a
+/// header, such as the charset the body was decoded as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderLine {
+    pub label: Option<String>,
+    pub value: String,
+}
+
+impl HeaderLine {
+    fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        HeaderLine {
+            label: Some(label.into()),
+            value: value.into(),
+        }
+    }
+
+    /// `"Label: value"`, or the bare value for a note.
+    pub fn text(&self) -> String {
+        match &self.label {
+            Some(label) => format!("{}: {}", label, self.value),
+            None => self.value.clone(),
+        }
+    }
+}
+
 /// Lazy-load progress for an [`Email`]. The MailDir scanner only parses
 /// headers up front; full bodies and attachments are fetched off-thread
 /// by `BodyLoader` and applied via [`EmailStore::apply_loaded_body`].
@@ -91,6 +312,15 @@ pub enum EmailLoadState {
     FullyLoaded,
 }
 
+/// Parsed body parts carried from `BodyLoader` into the store:
+/// `(body_plain, body_html, attachments, inline_images)`.
+type LoadedBodyParts = (
+    Option<String>,
+    Option<String>,
+    Vec<Attachment>,
+    Vec<InlineImage>,
+);
+
 /// Source and destination paths for a `new/`→`cur/` mark-read move.
 /// Built by `EmailStore::plan_mark_read`; consumed by the AppRoot
 /// handler for `Msg::MessageMarkRead`, which performs the `fs::rename`
@@ -120,7 +350,7 @@ fn strip_angle_brackets(s: &str) -> &str {
 /// `…/<folder>/cur/<name>` sibling. Returns `None` when the parent
 /// directory is not literally `new`, so callers naturally no-op for
 /// emails that are already in `cur/`.
-fn derive_cur_path(p: &std::path::Path) -> Option<PathBuf> {
+pub(crate) fn derive_cur_path(p: &std::path::Path) -> Option<PathBuf> {
     let name = p.file_name()?;
     let parent = p.parent()?;
     if parent.file_name()? != "new" {
@@ -140,6 +370,10 @@ fn derive_cur_path(p: &std::path::Path) -> Option<PathBuf> {
 pub struct Email {
     /// Parsed RFC-822 headers (always populated post-scan).
     pub headers: EmailHeaders,
+    /// Every header as `(name, value)` in file order, unfolded and with
+    /// encoded words decoded. Filled by the header parse; the Content
+    /// pane lists them when its headers are expanded (`H`).
+    pub raw_headers: Vec<(String, String)>,
     /// `text/plain` body, when the message carries one. `None` for
     /// HTML-only messages and while [`EmailLoadState::HeadersOnly`].
     /// For multipart/alternative messages both `body_plain` and
@@ -172,6 +406,41 @@ pub struct Email {
     /// Whether the body + attachments have been parsed yet. See
     /// [`EmailLoadState`].
     pub load_state: EmailLoadState,
+    /// Length of the message file in bytes. Set by
+    /// `parse_headers_only` / `parse_from_file`; 0 until then.
+    pub size_bytes: usize,
+    /// Value of the configured spam-score header (`[spam].score_header`),
+    /// when present and numeric. Set by the header-only parse.
+    pub spam_score: Option<f32>,
+    /// True when the configured spam-flag header (`[spam].flag_header`)
+    /// reads `YES`. Set by the header-only parse.
+    pub is_spam: bool,
+    /// Charset the body was decoded with when the declared one was
+    /// missing or didn't fit the bytes and [`decode_text_part`] had to
+    /// guess. `None` when the declared charset decoded cleanly.
+    pub decoded_charset: Option<&'static str>,
+    /// Why the file couldn't be parsed, for mail the scanner kept as a
+    /// placeholder. Such emails never get a body load; the Content
+    /// pane shows this text instead.
+    pub parse_error: Option<String>,
+    /// Place in the conversation tree while the threaded view is on;
+    /// the default (top level, nothing hidden) otherwise.
+    pub thread: ThreadPos,
+    /// An unsent draft: it carries `X-Draft: true` or sits in the
+    /// Drafts folder. Badged `[D]` in the list; `e` resumes it.
+    pub is_draft: bool,
+    /// Sender authentication recorded by the receiving server. Set by
+    /// the header parse.
+    pub auth_status: AuthStatus,
+    /// Start of the body as one line, whitespace collapsed, for the
+    /// message list's `list_preview` row. Taken cheaply from the start
+    /// of the file by the header scan (see [`body_preview`]); `None`
+    /// when nothing readable was found there.
+    pub preview: Option<String>,
+    /// Body text decoded with a charset picked by `:charset`, shown
+    /// in place of the parsed body. See
+    /// [`Self::reparse_body_with_charset`].
+    pub body_text_override: Option<String>,
 }
 
 impl Email {
@@ -189,7 +458,11 @@ impl Email {
                 subject: String::new(),
                 date: String::new(),
                 message_id: String::new(),
+                priority: Priority::Normal,
+                in_reply_to: String::new(),
+                references: Vec::new(),
             },
+            raw_headers: Vec::new(),
             body_plain: None,
             body_html: None,
             attachments: Vec::new(),
@@ -198,36 +471,96 @@ impl Email {
             is_unread: false,
             is_flagged,
             load_state: EmailLoadState::HeadersOnly,
+            size_bytes: 0,
+            spam_score: None,
+            is_spam: false,
+            decoded_charset: None,
+            parse_error: None,
+            thread: ThreadPos::default(),
+            is_draft: false,
+            auth_status: AuthStatus::default(),
+            preview: None,
+            body_text_override: None,
         }
     }
 
-    /// Parse only headers from file (fast for folder loading)
+    /// Parse only headers from file (fast for folder loading), reading
+    /// spam headers under their default names.
+    #[allow(dead_code)]
     pub fn parse_headers_only(&mut self) -> Result<()> {
-        let content = fs::read(&self.file_path)?;
+        self.parse_headers_only_with(&SpamConfig::default())
+    }
+
+    /// [`Self::parse_headers_only`] with the spam-header names from
+    /// `[spam]`. `MaildirScanner` calls this with the live config.
+    pub fn parse_headers_only_with(&mut self, spam: &SpamConfig) -> Result<()> {
+        let content = crate::mbox::read_message(&self.file_path)?;
+        self.parse_headers_from_bytes(&content, spam)
+    }
+
+    /// [`Self::parse_headers_only_with`] over message bytes the caller
+    /// already holds, such as one span of an mbox file; `file_path` is
+    /// not read.
+    pub fn parse_headers_from_bytes(&mut self, content: &[u8], spam: &SpamConfig) -> Result<()> {
         let message = MessageParser::default()
-            .parse(&content)
+            .parse(content)
             .ok_or(VulthorError::MailParser)?;
 
         self.parse_headers(&message)?;
+        self.parse_spam_headers(&message, spam);
+        self.preview = body_preview(content);
         self.load_state = EmailLoadState::HeadersOnly;
+        // The whole message is already in memory, so its length is the
+        // file size without a separate `fs::metadata` call.
+        self.size_bytes = content.len();
 
         Ok(())
     }
 
     /// Parse email from file (full parsing for reading)
     pub fn parse_from_file(&mut self) -> Result<()> {
-        let content = fs::read(&self.file_path)?;
+        let content = crate::mbox::read_message(&self.file_path)?;
         let message = MessageParser::default()
             .parse(&content)
             .ok_or(VulthorError::MailParser)?;
 
         self.parse_headers(&message)?;
         self.parse_body(&message)?;
+        self.preview = body_preview(&content);
         self.load_state = EmailLoadState::FullyLoaded;
+        self.size_bytes = content.len();
 
         Ok(())
     }
 
+    /// Decode the body again as `charset` (a WHATWG label such as
+    /// `ISO-8859-1`, `latin1` or `shift_jis`), for mail whose declared
+    /// charset doesn't match its bytes. Reads the message file, takes
+    /// the part [`Self::parse_body`] would show (the `text/plain` part,
+    /// else the HTML one as text) and stores the result in
+    /// [`Self::body_text_override`] as well as returning it.
+    pub fn reparse_body_with_charset(&mut self, charset: &str) -> Result<String> {
+        let encoding = encoding_rs::Encoding::for_label(charset.trim().as_bytes())
+            .ok_or_else(|| VulthorError::UnknownCharset(charset.to_string()))?;
+        let content = crate::mbox::read_message(&self.file_path)?;
+        let message = MessageParser::default()
+            .parse(&content)
+            .ok_or(VulthorError::MailParser)?;
+        let (part, is_html) = match message.text_part(0) {
+            Some(part) if matches!(part.body, PartType::Text(_)) => (part, false),
+            _ => (message.html_part(0).ok_or(VulthorError::NoTextBody)?, true),
+        };
+        let bytes = transfer_decoded_bytes(&message, part).ok_or(VulthorError::NoTextBody)?;
+        let (text, _) = encoding.decode_without_bom_handling(&bytes);
+        let text = if is_html {
+            html_to_display_text(&crate::sanitizer::sanitize_email_html(&text))
+        } else {
+            strip_control_chars(&text).into_owned()
+        };
+        self.body_text_override = Some(text.clone());
+        Ok(text)
+    }
+
     /// Ensure email is fully loaded
     #[allow(dead_code)]
     pub fn ensure_fully_loaded(&mut self) -> Result<()> {
@@ -265,12 +598,82 @@ impl Email {
 
         // Subject, date, and message-id are straightforward
         self.headers.subject = message.subject().unwrap_or("(no subject)").to_string();
-        self.headers.date = message.date().map(|d| d.to_rfc3339()).unwrap_or_default();
+        // A Date the parser rejects (or misreads into an impossible
+        // date) keeps its original text, so the list can still try
+        // `parse_flexible_date` on it or show it as-is.
+        self.headers.date = message
+            .date()
+            .filter(|d| d.is_valid())
+            .map(|d| d.to_rfc3339())
+            .or_else(|| {
+                message
+                    .header_raw("Date")
+                    .map(|raw| raw.split_whitespace().collect::<Vec<_>>().join(" "))
+            })
+            .unwrap_or_default();
         self.headers.message_id = message.message_id().unwrap_or_default().to_string();
+        self.headers.in_reply_to = message
+            .in_reply_to()
+            .as_text_list()
+            .and_then(|ids| ids.first())
+            .map(|id| id.to_string())
+            .unwrap_or_default();
+        self.headers.references = message
+            .references()
+            .as_text_list()
+            .map(|ids| ids.iter().map(|id| id.to_string()).collect())
+            .unwrap_or_default();
+
+        self.raw_headers = message
+            .headers_raw()
+            .map(|(name, value)| (name.to_string(), display_header_value(value)))
+            .collect();
+        self.is_draft = self
+            .raw_headers
+            .iter()
+            .any(|(name, value)| name.eq_ignore_ascii_case("X-Draft") && value == "true");
+        self.auth_status = AuthStatus::from_results(
+            self.raw_headers
+                .iter()
+                .filter(|(name, _)| name.eq_ignore_ascii_case("Authentication-Results"))
+                .map(|(_, value)| value.as_str()),
+        );
+
+        // Both headers are common in the wild and sometimes disagree;
+        // the more urgent reading wins.
+        self.headers.priority = message
+            .headers_raw()
+            .filter_map(|(name, value)| {
+                if name.eq_ignore_ascii_case("X-Priority") {
+                    Priority::from_x_priority(value)
+                } else if name.eq_ignore_ascii_case("Importance") {
+                    Priority::from_importance(value)
+                } else {
+                    None
+                }
+            })
+            .max()
+            .unwrap_or_default();
 
         Ok(())
     }
 
+    /// Read the spam flag and score headers named in `spam`. Messages
+    /// without them keep `is_spam = false` and `spam_score = None`.
+    fn parse_spam_headers(&mut self, message: &Message, spam: &SpamConfig) {
+        let mut is_spam = false;
+        let mut spam_score = None;
+        for (name, value) in message.headers_raw() {
+            if name.eq_ignore_ascii_case(&spam.flag_header) {
+                is_spam = value.trim().eq_ignore_ascii_case("yes");
+            } else if name.eq_ignore_ascii_case(&spam.score_header) {
+                spam_score = parse_spam_score(value);
+            }
+        }
+        self.is_spam = is_spam;
+        self.spam_score = spam_score;
+    }
+
     /// Parse the message body into the three canonical buckets:
     /// `body_plain` (raw `text/plain` part), `body_html` (sanitized
     /// `text/html` part), and `inline_images` (cid-referenced parts).
@@ -285,10 +688,15 @@ impl Email {
         // to mail-parser's HTML→text conversion here; the renderer's
         // `display_body` does that explicitly when nothing else is
         // available.
-        if let Some(part) = message.text_part(0) {
-            if let PartType::Text(text) = &part.body {
-                self.body_plain = Some(text.as_ref().to_string());
-            }
+        //
+        // Control characters are dropped so a hostile body can't smuggle
+        // terminal escape sequences through the TUI.
+        if let Some(part) = message.text_part(0)
+            && let PartType::Text(text) = &part.body
+        {
+            let (text, charset) = decode_text_part(message, part, text);
+            self.body_plain = Some(strip_control_chars(&text).into_owned());
+            self.decoded_charset = self.decoded_charset.or(charset);
         }
 
         // text/html — sanitize at this boundary so the unsanitized
@@ -296,10 +704,12 @@ impl Email {
         // writes `body_html` straight into the browser via `innerHTML`,
         // so any tag/handler that survives here is a direct XSS /
         // exfiltration channel. See `sanitizer.rs`.
-        if let Some(part) = message.html_part(0) {
-            if let PartType::Html(html) = &part.body {
-                self.body_html = Some(crate::sanitizer::sanitize_email_html(html.as_ref()));
-            }
+        if let Some(part) = message.html_part(0)
+            && let PartType::Html(html) = &part.body
+        {
+            let (html, charset) = decode_text_part(message, part, html);
+            self.body_html = Some(crate::sanitizer::sanitize_email_html(&html));
+            self.decoded_charset = self.decoded_charset.or(charset);
         }
 
         self.extract_attachments(message)?;
@@ -330,7 +740,9 @@ impl Email {
             // whether mail-parser tags them `InlineBinary` or `Binary`.
             // The cid is the only signal that an HTML body might want
             // to embed this part.
-            let cid = part.content_id().map(|s| strip_angle_brackets(s).to_string());
+            let cid = part
+                .content_id()
+                .map(|s| strip_angle_brackets(s).to_string());
 
             if let Some(content_id) = cid {
                 self.inline_images.push(InlineImage {
@@ -341,9 +753,14 @@ impl Email {
                 continue;
             }
 
-            let filename = part
-                .attachment_name()
-                .map(|s| s.to_string())
+            // Encoded-words are decoded from the header as written:
+            // mail-parser's own pass keeps the whitespace between
+            // adjacent words. Everything else (RFC 2231 included) goes
+            // through `attachment_name`.
+            let filename = raw_name_param(message, part)
+                .filter(|raw| raw.contains("=?"))
+                .or_else(|| part.attachment_name().map(str::to_string))
+                .map(|name| attachment_filename(&name))
                 .unwrap_or_else(|| "unnamed_attachment".to_string());
 
             self.attachments.push(Attachment {
@@ -367,7 +784,7 @@ impl Email {
             return Cow::Borrowed(plain.as_str());
         }
         if let Some(html) = &self.body_html {
-            return Cow::Owned(mail_parser::decoders::html::html_to_text(html));
+            return Cow::Owned(html_to_display_text(html));
         }
         Cow::Borrowed("")
     }
@@ -387,13 +804,95 @@ impl Email {
         self.display_body()
     }
 
+    /// Which rendition the Content pane shows for this email given the
+    /// session's `prefer_html` choice. Only a message carrying both parts
+    /// honours the preference; single-part messages always show the part
+    /// they have. `None` when neither body part is present.
+    pub fn displayed_part(&self, prefer_html: bool) -> Option<BodyPart> {
+        match (&self.body_plain, &self.body_html) {
+            (Some(_), Some(_)) if prefer_html => Some(BodyPart::Html),
+            (Some(_), _) => Some(BodyPart::Text),
+            (None, Some(_)) => Some(BodyPart::Html),
+            (None, None) => None,
+        }
+    }
 
-    /// Get formatted header display
+    /// [`Self::display_body_with_pref`] plus the `v` text/HTML choice.
+    /// `prefer_plaintext` (the `P` paranoia toggle) still wins: with it
+    /// on, the HTML part is never converted regardless of `prefer_html`.
+    pub fn display_body_with_prefs(
+        &self,
+        prefer_plaintext: bool,
+        prefer_html: bool,
+    ) -> Cow<'_, str> {
+        if prefer_plaintext {
+            return self.display_body_with_pref(true);
+        }
+        match (self.displayed_part(prefer_html), &self.body_html) {
+            (Some(BodyPart::Html), Some(html)) => Cow::Owned(html_to_display_text(html)),
+            _ => self.display_body(),
+        }
+    }
+
+    /// The folded header summary: From, To, Subject and Date, then
+    /// Importance and Spam when they apply and a note of the charset a
+    /// mislabelled body was decoded as.
+    pub fn get_header_lines(&self) -> Vec<HeaderLine> {
+        let mut lines = vec![
+            HeaderLine::new("From", &self.headers.from),
+            HeaderLine::new("To", &self.headers.to),
+            HeaderLine::new("Subject", &self.headers.subject),
+            HeaderLine::new("Date", &self.headers.date),
+        ];
+        if self.headers.priority != Priority::Normal {
+            lines.push(HeaderLine::new("Importance", self.headers.priority.label()));
+        }
+        if let Some(spam) = self.spam_display() {
+            lines.push(HeaderLine::new("Spam", spam));
+        }
+        if let Some(charset) = self.decoded_charset {
+            lines.push(HeaderLine {
+                label: None,
+                value: format!("(decoded as {})", charset),
+            });
+        }
+        lines
+    }
+
+    /// Every header, one line each, for the expanded Content-pane
+    /// header view. Falls back to [`Self::get_header_lines`] for an
+    /// email never parsed.
+    pub fn get_full_header_lines(&self) -> Vec<HeaderLine> {
+        if self.raw_headers.is_empty() {
+            return self.get_header_lines();
+        }
+        self.raw_headers
+            .iter()
+            .map(|(name, value)| HeaderLine::new(name, value))
+            .collect()
+    }
+
+    /// [`Self::get_header_lines`] as text, one `Name: value` per line.
+    #[allow(dead_code)]
     pub fn get_header_display(&self) -> String {
-        format!(
-            "From: {}\nTo: {}\nSubject: {}\nDate: {}",
-            self.headers.from, self.headers.to, self.headers.subject, self.headers.date
-        )
+        header_text(&self.get_header_lines())
+    }
+
+    /// [`Self::get_full_header_lines`] as text, one `Name: value` per line.
+    #[allow(dead_code)]
+    pub fn get_full_header_display(&self) -> String {
+        header_text(&self.get_full_header_lines())
+    }
+
+    /// `"7.3 (flagged)"`, `"7.3"` or `"flagged"` for mail the spam
+    /// filter stamped; `None` when neither spam header was present.
+    pub fn spam_display(&self) -> Option<String> {
+        match (self.spam_score, self.is_spam) {
+            (Some(score), true) => Some(format!("{:.1} (flagged)", score)),
+            (Some(score), false) => Some(format!("{:.1}", score)),
+            (None, true) => Some("flagged".to_string()),
+            (None, false) => None,
+        }
     }
 
     /// Check if email has attachments
@@ -407,6 +906,417 @@ impl Email {
     }
 }
 
+fn header_text(lines: &[HeaderLine]) -> String {
+    lines
+        .iter()
+        .map(HeaderLine::text)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A raw header value as one display line: folding whitespace
+/// collapsed, RFC 2047 encoded words decoded and control characters
+/// dropped.
+fn display_header_value(value: &str) -> String {
+    let unfolded = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    strip_control_chars(&decode_encoded_words(&unfolded)).into_owned()
+}
+
+/// Leading number of a spam-score header value: `"7.3"`, `"7.3 / 5.0"`
+/// (rspamd) and `"-1.2"` all parse; anything without one is `None`.
+fn parse_spam_score(value: &str) -> Option<f32> {
+    value
+        .trim()
+        .split(|c: char| c.is_whitespace() || c == '/' || c == ',')
+        .next()
+        .and_then(|token| token.parse().ok())
+}
+
+/// Text of a `text/*` part, re-decoded when mail-parser had nothing
+/// reliable to go on. A part without a charset (or labelled UTF-8 /
+/// US-ASCII, or with a label mail-parser doesn't know) is decoded as
+/// lossy UTF-8 upstream, which turns Latin-1 or Shift_JIS bytes into
+/// U+FFFD soup. For those, the transfer-decoded bytes are run through
+/// [`decode_unlabelled`]; the returned name is the charset that was
+/// guessed, `None` when `decoded` is kept as-is.
+fn decode_text_part(
+    message: &Message,
+    part: &MessagePart,
+    decoded: &str,
+) -> (String, Option<&'static str>) {
+    let declared = part.content_type().and_then(|ct| ct.attribute("charset"));
+    let trusted = declared.is_some_and(|label| {
+        let label = label.trim().to_ascii_lowercase();
+        !matches!(label.as_str(), "utf-8" | "utf8" | "us-ascii" | "ascii")
+            && mail_parser::decoders::charsets::map::charset_decoder(label.as_bytes()).is_some()
+    });
+    if trusted || !decoded.contains(char::REPLACEMENT_CHARACTER) {
+        return (decoded.to_string(), None);
+    }
+    match transfer_decoded_bytes(message, part) {
+        Some(bytes) => decode_unlabelled(&bytes),
+        None => (decoded.to_string(), None),
+    }
+}
+
+/// Body bytes of `part` with the transfer encoding (base64 or
+/// quoted-printable) undone but no charset applied. `None` when the
+/// part's span or its encoding is broken.
+fn transfer_decoded_bytes(message: &Message, part: &MessagePart) -> Option<Vec<u8>> {
+    let raw = message
+        .raw_message()
+        .get(part.raw_body_offset() as usize..part.raw_end_offset() as usize)?;
+    match part.encoding {
+        Encoding::Base64 => mail_parser::decoders::base64::base64_decode(raw),
+        Encoding::QuotedPrintable => {
+            mail_parser::decoders::quoted_printable::quoted_printable_decode(raw)
+        }
+        Encoding::None => Some(raw.to_vec()),
+    }
+}
+
+/// Decode bytes of unknown charset: UTF-8 when they are valid UTF-8,
+/// otherwise whatever `chardetng` guesses, decoded lossily. The second
+/// value names the guessed encoding (`None` for the UTF-8 case).
+pub fn decode_unlabelled(bytes: &[u8]) -> (String, Option<&'static str>) {
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return (text.to_string(), None);
+    }
+    let mut detector = chardetng::EncodingDetector::new();
+    detector.feed(bytes, true);
+    let encoding = detector.guess(None, false);
+    let (text, _) = encoding.decode_without_bom_handling(bytes);
+    (text.into_owned(), Some(encoding.name()))
+}
+
+/// Bytes from the start of a message file that [`body_preview`] looks
+/// at. Enough for the headers and the first lines of a text part.
+const PREVIEW_SCAN_BYTES: usize = 8192;
+
+/// Length of [`Email::preview`], in characters.
+const PREVIEW_CHARS: usize = 100;
+
+/// First words of a message's body, found without a MIME parse: skip
+/// the headers, step over boundary lines into the first `text/*` part,
+/// undo its transfer encoding and drop quoted (`>`) lines. Reads only
+/// the first [`PREVIEW_SCAN_BYTES`] of `content`, so a part that starts
+/// later has no preview. Whitespace is collapsed and the result cut to
+/// [`PREVIEW_CHARS`].
+pub fn body_preview(content: &[u8]) -> Option<String> {
+    let prefix = String::from_utf8_lossy(&content[..content.len().min(PREVIEW_SCAN_BYTES)]);
+    let mut lines = prefix.lines();
+    let mut part = PreviewPart::read(&mut lines);
+    while !part.content_type.starts_with("text/") {
+        lines.by_ref().find(|line| is_boundary(line))?;
+        part = PreviewPart::read(&mut lines);
+    }
+    let body: Vec<&str> = lines
+        .take_while(|line| !is_boundary(line) && *line != "-- ")
+        .collect();
+    let body = body.join("\n");
+    let bytes = match part.encoding.as_str() {
+        "quoted-printable" => {
+            mail_parser::decoders::quoted_printable::quoted_printable_decode(body.as_bytes())?
+        }
+        "base64" => {
+            let mut b64: Vec<u8> = body.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+            b64.truncate(b64.len() / 4 * 4);
+            mail_parser::decoders::base64::base64_decode(&b64)?
+        }
+        _ => body.into_bytes(),
+    };
+    let (text, _) = decode_unlabelled(&bytes);
+    let text = if part.content_type == "text/html" {
+        html_to_display_text(&text)
+    } else {
+        strip_control_chars(&text).into_owned()
+    };
+    let preview: String = text
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('>'))
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(PREVIEW_CHARS)
+        .collect();
+    (!preview.is_empty()).then_some(preview)
+}
+
+/// The two headers of a message or MIME part that [`body_preview`]
+/// needs.
+struct PreviewPart {
+    /// `type/subtype`, lowercase; `text/plain` when absent.
+    content_type: String,
+    /// `Content-Transfer-Encoding`, lowercase; empty when absent.
+    encoding: String,
+}
+
+impl PreviewPart {
+    /// Consume header lines up to and including the blank line that
+    /// ends them, unfolding continuation lines.
+    fn read<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Self {
+        let mut part = PreviewPart {
+            content_type: "text/plain".to_string(),
+            encoding: String::new(),
+        };
+        let mut headers: Vec<String> = Vec::new();
+        for line in lines.by_ref() {
+            if line.trim().is_empty() {
+                break;
+            }
+            match headers.last_mut() {
+                Some(last) if line.starts_with([' ', '\t']) => last.push_str(line),
+                _ => headers.push(line.to_string()),
+            }
+        }
+        for header in headers {
+            let Some((name, value)) = header.split_once(':') else {
+                continue;
+            };
+            let value = value
+                .split(';')
+                .next()
+                .unwrap_or("")
+                .trim()
+                .to_ascii_lowercase();
+            if name.eq_ignore_ascii_case("Content-Type") {
+                part.content_type = value;
+            } else if name.eq_ignore_ascii_case("Content-Transfer-Encoding") {
+                part.encoding = value;
+            }
+        }
+        part
+    }
+}
+
+/// A MIME boundary line (`--boundary` or `--boundary--`), as far as a
+/// preview can tell without knowing the boundary.
+fn is_boundary(line: &str) -> bool {
+    line.len() > 2 && line.starts_with("--") && !line.contains(char::is_whitespace)
+}
+
+/// Display name for an attachment: RFC 2047 encoded-words decoded
+/// (undecodable ones kept raw) and control characters dropped, so a
+/// name cannot emit terminal escapes.
+fn attachment_filename(name: &str) -> String {
+    strip_control_chars(&decode_encoded_words(name)).into_owned()
+}
+
+/// The `filename=` parameter of the part's `Content-Disposition`, else
+/// the `name=` parameter of its `Content-Type`, exactly as written in
+/// the message (unfolded, quotes removed). RFC 2231 `filename*` forms
+/// are not matched.
+fn raw_name_param(message: &Message, part: &MessagePart) -> Option<String> {
+    [
+        (HeaderName::ContentDisposition, "filename"),
+        (HeaderName::ContentType, "name"),
+    ]
+    .into_iter()
+    .find_map(|(name, key)| {
+        let header = part.headers().iter().find(|h| h.name == name)?;
+        let raw = message
+            .raw_message()
+            .get(header.offset_start as usize..header.offset_end as usize)?;
+        let value = String::from_utf8_lossy(raw).replace(['\r', '\n'], "");
+        header_param(&value, key)
+    })
+}
+
+/// Value of `key=` in a `type; key=value; …` header value.
+fn header_param(value: &str, key: &str) -> Option<String> {
+    // ASCII lowercasing keeps byte offsets, so they index `value` too.
+    let lower = value.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(i) = lower[from..].find(key) {
+        let at = from + i;
+        from = at + key.len();
+        let rest = lower[from..].trim_start();
+        if !lower[..at].trim_end().ends_with(';') || !rest.starts_with('=') {
+            continue;
+        }
+        let v = value[value.len() - rest.len() + 1..].trim_start();
+        let v = match v.strip_prefix('"') {
+            Some(quoted) => &quoted[..quoted.find('"').unwrap_or(quoted.len())],
+            None => &v[..v.find([';', ' ', '\t']).unwrap_or(v.len())],
+        };
+        return Some(v.to_string());
+    }
+    None
+}
+
+/// Decode every RFC 2047 `=?charset?B|Q?text?=` word in `text`,
+/// dropping the whitespace between adjacent ones. Words that do not
+/// decode (bad base64, unknown encoding) are kept verbatim.
+fn decode_encoded_words(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut after_word = false;
+    while let Some(start) = rest.find("=?") {
+        let (before, candidate) = rest.split_at(start);
+        let decoded = encoded_word_end(candidate)
+            .and_then(|end| decode_encoded_word(&candidate[..end]).map(|d| (d, end)));
+        match decoded {
+            Some((word, end)) => {
+                if !(after_word && before.trim().is_empty()) {
+                    out.push_str(before);
+                }
+                out.push_str(&word);
+                rest = &candidate[end..];
+                after_word = true;
+            }
+            None => {
+                out.push_str(before);
+                out.push_str("=?");
+                rest = &candidate[2..];
+                after_word = false;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Byte length of the encoded word at the start of `s`
+/// (`=?charset?X?text?=`), if it is one.
+fn encoded_word_end(s: &str) -> Option<usize> {
+    let body = s.strip_prefix("=?")?;
+    let charset_end = body.find('?')?;
+    let after_charset = &body[charset_end + 1..];
+    let encoding = after_charset.get(..2).filter(|e| e.ends_with('?'))?;
+    let text = &after_charset[encoding.len()..];
+    let text_end = text.find("?=")?;
+    Some(2 + charset_end + 1 + encoding.len() + text_end + 2)
+}
+
+/// Decode one complete `=?charset?X?text?=` word.
+fn decode_encoded_word(word: &str) -> Option<String> {
+    let inner = word.strip_prefix("=?")?.strip_suffix("?=")?;
+    let mut fields = inner.splitn(3, '?');
+    // RFC 2231 allows a `*lang` suffix on the charset.
+    let charset = fields.next()?.split('*').next()?;
+    let encoding = fields.next()?;
+    let text = fields.next()?;
+    let bytes = match encoding {
+        "B" | "b" => mail_parser::decoders::base64::base64_decode(text.as_bytes())?,
+        "Q" | "q" => mail_parser::decoders::quoted_printable::quoted_printable_decode(
+            text.replace('_', " ").as_bytes(),
+        )?,
+        _ => return None,
+    };
+    match mail_parser::decoders::charsets::map::charset_decoder(charset.as_bytes()) {
+        Some(decode) => Some(decode(&bytes)),
+        None => Some(decode_unlabelled(&bytes).0),
+    }
+}
+
+/// Drop control characters other than newline (`\n` or `\r\n`) and
+/// tab, so message text can't emit terminal escape sequences (ESC, CSI,
+/// BEL, a bare `\r` that rewrites the line, …) through the TUI.
+/// Borrowed when there is nothing to strip.
+pub fn strip_control_chars(text: &str) -> Cow<'_, str> {
+    let stray = |(i, c): (usize, char)| {
+        c.is_control() && c != '\n' && c != '\t' && !(c == '\r' && text[i + 1..].starts_with('\n'))
+    };
+    if !text.char_indices().any(stray) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.char_indices()
+            .filter(|&ic| !stray(ic))
+            .map(|(_, c)| c)
+            .collect(),
+    )
+}
+
+/// HTML→text conversion for the terminal, with control characters
+/// (which entities like `&#27;` can reintroduce) stripped.
+fn html_to_display_text(html: &str) -> String {
+    let text = mail_parser::decoders::html::html_to_text(html);
+    strip_control_chars(&text).into_owned()
+}
+
+/// What a folder is for. Drives the Messages pane's sender/recipient
+/// column and where `a` / `d` move mail. Assigned by the scanner, from
+/// `[folders]` when configured and [`FolderRole::detect`] otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FolderRole {
+    #[default]
+    Normal,
+    Inbox,
+    Sent,
+    Drafts,
+    Trash,
+    Archive,
+    Junk,
+}
+
+impl FolderRole {
+    /// Every role that can be pinned in `[folders]` or detected.
+    pub const ALL: [FolderRole; 6] = [
+        FolderRole::Inbox,
+        FolderRole::Sent,
+        FolderRole::Drafts,
+        FolderRole::Trash,
+        FolderRole::Archive,
+        FolderRole::Junk,
+    ];
+
+    /// Role implied by a folder's name: a whole-name, case-insensitive
+    /// match against the common English and localized names (`Sent`,
+    /// `Sent Items`, `Gesendet`, `Envoyés`, …). A Maildir++ leading dot
+    /// is ignored. Partial matches never count, so `Present` or
+    /// `Unsent Drafts` stay [`FolderRole::Normal`].
+    pub fn detect(name: &str) -> FolderRole {
+        let name = name.trim_start_matches('.').to_lowercase();
+        match name.as_str() {
+            "inbox" | "posteingang" | "boîte de réception" | "bandeja de entrada" => {
+                FolderRole::Inbox
+            }
+            "sent"
+            | "sent items"
+            | "sent mail"
+            | "sent messages"
+            | "gesendet"
+            | "gesendete objekte"
+            | "gesendete elemente"
+            | "envoyés"
+            | "éléments envoyés"
+            | "enviados"
+            | "elementos enviados"
+            | "inviati"
+            | "posta inviata"
+            | "verzonden"
+            | "skickat"
+            | "отправленные" => FolderRole::Sent,
+            "drafts" | "draft" | "entwürfe" | "brouillons" | "borradores" | "bozze"
+            | "concepten" | "черновики" => FolderRole::Drafts,
+            "trash"
+            | "deleted"
+            | "deleted items"
+            | "deleted messages"
+            | "bin"
+            | "papierkorb"
+            | "gelöschte elemente"
+            | "corbeille"
+            | "papelera"
+            | "cestino"
+            | "prullenbak"
+            | "корзина" => FolderRole::Trash,
+            "archive" | "archives" | "archiv" | "all mail" | "archivio" | "archivo" => {
+                FolderRole::Archive
+            }
+            "junk"
+            | "spam"
+            | "junk e-mail"
+            | "junk email"
+            | "bulk mail"
+            | "courrier indésirable" => FolderRole::Junk,
+            _ => FolderRole::Normal,
+        }
+    }
+}
+
 /// Node in the in-memory folder tree mirroring the MailDir hierarchy.
 /// Leaves are maildir directories (containing `cur/`, `new/`, `tmp/`);
 /// inner nodes are simple containers. `emails` and counts cover only
@@ -428,10 +1338,21 @@ pub struct Folder {
     /// Count of emails added via `add_email`. Same caveat as
     /// `unread_count`.
     pub total_count: usize,
+    /// Count of `emails` carrying a `parse_error`. Same caveat as
+    /// `unread_count`.
+    pub corrupt_count: usize,
     /// True once a non-limited header scan has populated `emails`. The
     /// paged loader short-circuits when this is set, so future scrolls
     /// stop re-walking the directory.
     pub is_loaded: bool,
+    /// What the folder is for. [`FolderRole::Normal`] until the scanner
+    /// assigns one.
+    pub role: FolderRole,
+    /// A `[[saved_searches]]` pseudo-folder rather than a directory:
+    /// its emails live in other folders, and opening one goes there.
+    /// Notmuch and `:filter` results leave it unset — they stay on
+    /// screen while an email is read.
+    pub is_virtual: bool,
 }
 
 impl Folder {
@@ -446,21 +1367,51 @@ impl Folder {
             subfolders: Vec::new(),
             unread_count: 0,
             total_count: 0,
+            corrupt_count: 0,
             is_loaded: false,
+            role: FolderRole::Normal,
+            is_virtual: false,
         }
     }
 
-    /// Append an email, updating `total_count` and (when unread)
-    /// `unread_count`. The only correct way to grow `emails` —
-    /// pushing directly would desync the cached counts.
-    pub fn add_email(&mut self, email: Email) {
+    /// Append an email, updating `total_count` and (when unread or
+    /// corrupt) `unread_count` / `corrupt_count`. The only correct way
+    /// to grow `emails` — pushing directly would desync the cached
+    /// counts.
+    pub fn add_email(&mut self, mut email: Email) {
+        email.is_draft |= self.role == FolderRole::Drafts;
         if email.is_unread {
             self.unread_count += 1;
         }
+        if email.parse_error.is_some() {
+            self.corrupt_count += 1;
+        }
         self.total_count += 1;
         self.emails.push(email);
     }
 
+    /// A copy to page more headers into without the store lock held:
+    /// the same path, role and `is_loaded`, and an email per loaded one
+    /// carrying only its path, which is all the paged loader looks at.
+    /// No subfolders.
+    pub fn detached_copy(&self) -> Folder {
+        let mut copy = Folder::new(self.name.clone(), self.path.clone());
+        copy.role = self.role;
+        copy.is_loaded = self.is_loaded;
+        copy.emails = self
+            .emails
+            .iter()
+            .map(|e| Email::new(e.file_path.clone()))
+            .collect();
+        copy
+    }
+
+    /// True while `emails` are sorted into conversation threads, i.e.
+    /// any email sits below another or has its replies folded.
+    pub fn in_thread_order(&self) -> bool {
+        self.emails.iter().any(|e| e.thread != ThreadPos::default())
+    }
+
     /// Append a child folder. No counts to maintain — Vulthor does not
     /// roll subfolder totals into the parent.
     pub fn add_subfolder(&mut self, folder: Folder) {
@@ -480,6 +1431,17 @@ impl Folder {
         sorted
     }
 
+    /// Unread emails in this folder and every folder below it. Only
+    /// loaded folders have counts, so unopened ones add nothing.
+    pub fn total_unread_count(&self) -> usize {
+        self.unread_count
+            + self
+                .subfolders
+                .iter()
+                .map(Folder::total_unread_count)
+                .sum::<usize>()
+    }
+
     /// Folder name decorated with the unread-count chip: `"INBOX (5)"`
     /// when there are unread emails, plain `"INBOX"` otherwise.
     pub fn get_display_name(&self) -> String {
@@ -507,6 +1469,56 @@ pub struct DraftInfo {
     pub body_empty: bool,
 }
 
+/// Message-list order set with `:sort`. Each key has its natural
+/// direction — newest, A to Z, largest first — which
+/// [`EmailStore::sort_reversed`] flips.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Date,
+    From,
+    Subject,
+    Size,
+}
+
+impl SortOrder {
+    pub const ALL: [SortOrder; 4] = [Self::Date, Self::From, Self::Subject, Self::Size];
+
+    /// Name used by `:sort`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Date => "date",
+            Self::From => "from",
+            Self::Subject => "subject",
+            Self::Size => "size",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|o| o.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Order `a` before `b` under this key. Emails whose `Date:` doesn't
+    /// parse go last when sorting by date.
+    fn compare(self, a: &Email, b: &Email) -> std::cmp::Ordering {
+        let lower = |s: &str| s.to_lowercase();
+        match self {
+            Self::Date => {
+                let date = |e: &Email| parse_flexible_date(&e.headers.date);
+                match (date(a), date(b)) {
+                    (Some(a), Some(b)) => b.cmp(&a),
+                    (a, b) => a.is_none().cmp(&b.is_none()),
+                }
+            }
+            Self::From => lower(&a.headers.from).cmp(&lower(&b.headers.from)),
+            Self::Subject => lower(&a.headers.subject).cmp(&lower(&b.headers.subject)),
+            Self::Size => b.size_bytes.cmp(&a.size_bytes),
+        }
+    }
+}
+
 /// The single shared data plane between the TUI and the web pane.
 /// Holds the folder tree, the user's navigation state (which folder /
 /// which email is selected), and the drafts cross-reference index.
@@ -548,6 +1560,18 @@ pub struct EmailStore {
     /// active. Kept separate from `selected_email` so the prior-folder
     /// selection survives the search round-trip.
     pub search_selected: Option<usize>,
+    /// Threaded view (`T`): the current folder's emails are kept in
+    /// conversation order by [`Self::thread_current_folder`].
+    pub threaded: bool,
+    /// [`crate::threading::thread_key`]s of the threads folded with
+    /// `Alt+v`. Kept across reloads and folder changes.
+    pub collapsed_threads: HashSet<String>,
+    /// `:sort` order of the current folder, `None` for load order.
+    /// `AppRoot` swaps it in from the folder's saved preferences on
+    /// every folder change. The threaded view takes precedence.
+    pub sort: Option<SortOrder>,
+    /// Flip [`Self::sort`]'s direction (`:sort date reverse`).
+    pub sort_reversed: bool,
 }
 
 impl EmailStore {
@@ -564,6 +1588,10 @@ impl EmailStore {
             drafts: HashMap::new(),
             search_results: None,
             search_selected: None,
+            threaded: false,
+            collapsed_threads: HashSet::new(),
+            sort: None,
+            sort_reversed: false,
         }
     }
 
@@ -580,6 +1608,44 @@ impl EmailStore {
         self.search_results = Some(folder);
     }
 
+    /// A virtual folder named `name` holding every loaded email, in any
+    /// folder, whose subject or sender contains `query` (ignoring
+    /// case). Backs the `[[saved_searches]]` pseudo-folders; folders
+    /// not read in yet contribute nothing.
+    pub fn execute_saved_search(&self, name: &str, query: &str) -> Folder {
+        fn walk(folder: &Folder, needle: &str, out: &mut Folder) {
+            for email in &folder.emails {
+                if email.headers.subject.to_lowercase().contains(needle)
+                    || email.headers.from.to_lowercase().contains(needle)
+                {
+                    out.add_email(email.clone());
+                }
+            }
+            for sub in &folder.subfolders {
+                walk(sub, needle, out);
+            }
+        }
+        let mut results = Folder::new(name.to_string(), PathBuf::from(":search:"));
+        results.is_virtual = true;
+        results.is_loaded = true;
+        walk(&self.root_folder, &query.to_lowercase(), &mut results);
+        results
+    }
+
+    /// The loaded folder that holds the email at `file_path`.
+    pub fn folder_of_email(&self, file_path: &Path) -> Option<&Folder> {
+        fn walk<'a>(folder: &'a Folder, file_path: &Path) -> Option<&'a Folder> {
+            if folder.emails.iter().any(|e| e.file_path == file_path) {
+                return Some(folder);
+            }
+            folder
+                .subfolders
+                .iter()
+                .find_map(|sub| walk(sub, file_path))
+        }
+        walk(&self.root_folder, file_path)
+    }
+
     /// Clear the active search-results virtual folder, dropping the
     /// search cursor back to the prior MailDir folder + email
     /// selection. No-op when no search is active.
@@ -599,6 +1665,38 @@ impl EmailStore {
         folder
     }
 
+    /// Path of `folder` relative to the MailDir root (`"INBOX"`,
+    /// `"Work/Projects"`); empty for the root itself.
+    pub fn relative_folder_path(&self, folder: &Folder) -> String {
+        folder
+            .path
+            .strip_prefix(&self.root_folder.path)
+            .unwrap_or(&folder.path)
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Inverse of [`Self::relative_folder_path`]: the folder whose path
+    /// relative to the MailDir root is `rel`.
+    pub fn folder_by_relative_path(&self, rel: &str) -> Option<&Folder> {
+        fn walk<'a>(folder: &'a Folder, target: &std::path::Path) -> Option<&'a Folder> {
+            if folder.path == target {
+                return Some(folder);
+            }
+            folder.subfolders.iter().find_map(|sub| walk(sub, target))
+        }
+        walk(&self.root_folder, &self.root_folder.path.join(rel))
+    }
+
+    /// Unparseable emails across every loaded folder — the sum of each
+    /// folder's `corrupt_count`.
+    pub fn corrupt_email_count(&self) -> usize {
+        fn walk(folder: &Folder) -> usize {
+            folder.corrupt_count + folder.subfolders.iter().map(walk).sum::<usize>()
+        }
+        walk(&self.root_folder)
+    }
+
     /// Get mutable reference to current folder
     pub fn get_current_folder_mut(&mut self) -> &mut Folder {
         let mut folder = &mut self.root_folder;
@@ -617,7 +1715,6 @@ impl EmailStore {
     }
 
     /// Load emails for a folder at a specific path with visible row limit
-    #[allow(dead_code)]
     pub fn ensure_folder_at_path_loaded(
         &mut self,
         path: &[usize],
@@ -687,14 +1784,282 @@ impl EmailStore {
         scanner: &crate::maildir::MaildirScanner,
         index: usize,
     ) -> Result<()> {
+        Self::load_more_into(self.get_current_folder_mut(), scanner, index)?;
+        Ok(())
+    }
+
+    /// [`Self::load_more_messages_if_needed`] for the folder at
+    /// `fs_path`, current or not, repeated until the emails up to
+    /// `index` are loaded or the folder runs out. Returns false when no
+    /// folder has that path.
+    pub fn load_more_messages_in(
+        &mut self,
+        scanner: &crate::maildir::MaildirScanner,
+        fs_path: &std::path::Path,
+        index: usize,
+    ) -> Result<bool> {
+        let Some(folder) = self.folder_by_path_mut(fs_path) else {
+            return Ok(false);
+        };
+        Self::load_more_detached(folder, scanner, index)?;
+        Ok(true)
+    }
+
+    /// The paging loop of [`Self::load_more_messages_in`] on a folder
+    /// outside the store, such as a [`Folder::detached_copy`], so the
+    /// header parsing can run without the store lock held. The web
+    /// folder view pages this way and hands the result to
+    /// [`Self::merge_loaded_emails`].
+    pub fn load_more_detached(
+        folder: &mut Folder,
+        scanner: &crate::maildir::MaildirScanner,
+        index: usize,
+    ) -> Result<()> {
+        while Self::load_more_into(folder, scanner, index)? > 0 {}
+        Ok(())
+    }
+
+    /// Add the emails `loaded` gained to the folder at its path,
+    /// skipping any the folder picked up meanwhile, and carry over
+    /// `is_loaded`. Returns false when no folder has that path.
+    pub fn merge_loaded_emails(&mut self, loaded: Folder) -> bool {
+        let Some(folder) = self.folder_by_path_mut(&loaded.path) else {
+            return false;
+        };
+        let known: HashSet<PathBuf> = folder.emails.iter().map(|e| e.file_path.clone()).collect();
+        for email in loaded.emails {
+            if !known.contains(&email.file_path) {
+                folder.add_email(email);
+            }
+        }
+        folder.is_loaded |= loaded.is_loaded;
+        true
+    }
+
+    fn folder_by_path_mut(&mut self, fs_path: &Path) -> Option<&mut Folder> {
+        fn walk<'a>(folder: &'a mut Folder, target: &Path) -> Option<&'a mut Folder> {
+            if folder.path == target {
+                return Some(folder);
+            }
+            folder
+                .subfolders
+                .iter_mut()
+                .find_map(|sub| walk(sub, target))
+        }
+        walk(&mut self.root_folder, fs_path)
+    }
+
+    /// One chunk of [`Self::load_more_messages_if_needed`]: headers
+    /// added, 0 when `index` is far from the loaded tail or the folder
+    /// is exhausted.
+    fn load_more_into(
+        folder: &mut Folder,
+        scanner: &crate::maildir::MaildirScanner,
+        index: usize,
+    ) -> Result<usize> {
         const SCROLL_LOAD_CHUNK: usize = 50;
+        if !folder.is_loaded && index.saturating_add(5) >= folder.emails.len() {
+            return scanner.load_more_folder_emails(folder, SCROLL_LOAD_CHUNK);
+        }
+        Ok(0)
+    }
+
+    /// Bring the current folder's order in line with [`Self::threaded`]
+    /// and [`Self::sort`] after its emails changed or the view was
+    /// toggled. Threaded, the emails are re-sorted into conversations;
+    /// unthreaded, they are sorted by `:sort` when one is set, and a
+    /// folder still in thread order is otherwise reloaded from disk in
+    /// load order. The
+    /// selection follows the selected email, landing on its thread's
+    /// root when that email is folded away.
+    pub fn thread_current_folder(
+        &mut self,
+        scanner: &crate::maildir::MaildirScanner,
+    ) -> Result<()> {
+        if !self.threaded {
+            if let Some(order) = self.sort {
+                self.sort_current_folder(scanner, order)?;
+            } else if self.get_current_folder().in_thread_order() {
+                self.refresh_folder(scanner)?;
+            }
+            return Ok(());
+        }
+        let collapsed = std::mem::take(&mut self.collapsed_threads);
+        let selected = self.selected_email;
         let folder = self.get_current_folder_mut();
-        if !folder.is_loaded && index + 5 >= folder.emails.len() {
-            scanner.load_more_folder_emails(folder, SCROLL_LOAD_CHUNK)?;
+        let selected_path = selected
+            .and_then(|index| folder.emails.get(index))
+            .map(|e| e.file_path.clone());
+        crate::threading::apply(&mut folder.emails, &collapsed);
+        let row = selected_path
+            .and_then(|path| folder.emails.iter().position(|e| e.file_path == path))
+            .map(|row| crate::threading::visible_row(&folder.emails, row));
+        self.collapsed_threads = collapsed;
+        if row.is_some() {
+            self.selected_email = row;
+        }
+        Ok(())
+    }
+
+    /// Read in the rest of the current folder and sort it by `order`
+    /// (reversed with [`Self::sort_reversed`]). Stable, so ties keep
+    /// load order. The selection follows the selected email.
+    fn sort_current_folder(
+        &mut self,
+        scanner: &crate::maildir::MaildirScanner,
+        order: SortOrder,
+    ) -> Result<()> {
+        let reversed = self.sort_reversed;
+        let selected = self.selected_email;
+        let folder = self.get_current_folder_mut();
+        if folder.in_thread_order() {
+            folder.is_loaded = false;
+            folder.emails.clear();
+        }
+        while !folder.is_loaded && scanner.load_more_folder_emails(folder, 500)? > 0 {}
+        let selected_path = selected
+            .and_then(|index| folder.emails.get(index))
+            .map(|e| e.file_path.clone());
+        folder.emails.sort_by(|a, b| {
+            let ord = order.compare(a, b);
+            if reversed { ord.reverse() } else { ord }
+        });
+        if let Some(row) =
+            selected_path.and_then(|path| folder.emails.iter().position(|e| e.file_path == path))
+        {
+            self.selected_email = Some(row);
         }
         Ok(())
     }
 
+    /// First unread row of the current folder after `from_index`,
+    /// wrapping around to the top; a result at or before `from_index`
+    /// means the search wrapped. Rows folded away under a collapsed
+    /// thread are skipped. `None` when nothing is unread.
+    pub fn next_unread_from(&self, from_index: usize) -> Option<usize> {
+        let emails = &self.get_current_folder().emails;
+        let unread = |i: &usize| emails[*i].is_unread && !emails[*i].thread.hidden;
+        let after = from_index.saturating_add(1).min(emails.len());
+        (after..emails.len())
+            .find(unread)
+            .or_else(|| (0..after).find(unread))
+    }
+
+    /// Last unread row of the current folder before `from_index`,
+    /// wrapping around to the bottom; a result at or after `from_index`
+    /// means the search wrapped. Otherwise as [`Self::next_unread_from`].
+    pub fn prev_unread_from(&self, from_index: usize) -> Option<usize> {
+        let emails = &self.get_current_folder().emails;
+        let unread = |i: &usize| emails[*i].is_unread && !emails[*i].thread.hidden;
+        let before = from_index.min(emails.len());
+        (0..before)
+            .rev()
+            .find(unread)
+            .or_else(|| (before..emails.len()).rev().find(unread))
+    }
+
+    /// Rows of the current folder whose subject, sender or recipient
+    /// contains `query`, case-insensitively. Empty for a blank query.
+    pub fn find_emails(&self, query: &str) -> Vec<usize> {
+        let needle = query.trim().to_lowercase();
+        if needle.is_empty() {
+            return Vec::new();
+        }
+        self.get_current_folder()
+            .emails
+            .iter()
+            .enumerate()
+            .filter(|(_, email)| {
+                let h = &email.headers;
+                [&h.subject, &h.from, &h.to]
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&needle))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Rescan the current folder from disk, replacing its cached
+    /// headers. Returns how many emails are new — those whose
+    /// Message-ID (file path when the header is missing) was not in
+    /// the folder before. The selection follows the previously
+    /// selected email by Message-ID, falling back to the same row
+    /// clamped to the new length.
+    pub fn refresh_folder(&mut self, scanner: &crate::maildir::MaildirScanner) -> Result<usize> {
+        fn identity(email: &Email) -> String {
+            if email.headers.message_id.is_empty() {
+                email.file_path.display().to_string()
+            } else {
+                email.headers.message_id.clone()
+            }
+        }
+        let selected = self.selected_email;
+        let selected_id = selected
+            .and_then(|index| self.get_current_folder().emails.get(index))
+            .map(identity);
+
+        let folder = self.get_current_folder_mut();
+        let previous: std::collections::HashSet<String> =
+            folder.emails.iter().map(identity).collect();
+        folder.is_loaded = false;
+        folder.emails.clear();
+        scanner.load_folder_emails_with_limit(folder, None)?;
+        let added = folder
+            .emails
+            .iter()
+            .filter(|e| !previous.contains(&identity(e)))
+            .count();
+
+        let len = folder.emails.len();
+        let by_id = selected_id.and_then(|id| folder.emails.iter().position(|e| identity(e) == id));
+        self.selected_email = match (by_id, selected) {
+            (Some(index), _) => Some(index),
+            (None, Some(index)) if len > 0 => Some(index.min(len - 1)),
+            _ => None,
+        };
+        Ok(added)
+    }
+
+    /// Permanently delete every email in the current folder, which
+    /// must be the Trash (`VulthorError::NotTrashFolder` otherwise, and
+    /// for search results shown over it). Removes the files under
+    /// `cur/` and `new/` — an mbox Trash is truncated instead — then
+    /// reloads the folder so it shows what is left. Returns how many
+    /// emails were deleted.
+    pub fn empty_trash(&mut self, scanner: &crate::maildir::MaildirScanner) -> Result<usize> {
+        let in_results = self.search_results.is_some();
+        let folder = self.get_current_folder_mut();
+        if folder.role != FolderRole::Trash || folder.is_virtual || in_results {
+            return Err(VulthorError::NotTrashFolder(folder.path.clone()));
+        }
+        let removed = if folder.path.is_file() {
+            // Count what the mbox holds before dropping all of it.
+            folder.is_loaded = false;
+            scanner.load_folder_emails_with_limit(folder, None)?;
+            std::fs::File::create(&folder.path)?;
+            folder.emails.len()
+        } else {
+            let mut removed = 0;
+            for sub in ["cur", "new"] {
+                let Ok(entries) = std::fs::read_dir(folder.path.join(sub)) else {
+                    continue;
+                };
+                for entry in entries.flatten() {
+                    if entry.file_type().is_ok_and(|t| t.is_file()) {
+                        std::fs::remove_file(entry.path())?;
+                        removed += 1;
+                    }
+                }
+            }
+            removed
+        };
+        folder.emails.clear();
+        folder.is_loaded = false;
+        scanner.load_folder_emails_with_limit(folder, None)?;
+        self.selected_email = None;
+        Ok(removed)
+    }
+
     /// Navigate back to parent folder
     pub fn exit_folder(&mut self) {
         if self.current_folder.pop().is_some() {
@@ -737,6 +2102,19 @@ impl EmailStore {
             .and_then(|index| current.emails.get(index))
     }
 
+    /// Identity of the selected email that outlives a change of row or
+    /// a flag rename: its MailDir unique name, the filename up to the
+    /// `:2,` info suffix. `AppRoot` compares it across messages to
+    /// notice that a different email is showing.
+    pub fn selected_email_key(&self) -> Option<String> {
+        let name = self.get_selected_email()?.file_path.file_name()?.to_str()?;
+        Some(
+            name.split_once(":2,")
+                .map_or(name, |(base, _)| base)
+                .to_string(),
+        )
+    }
+
     /// Alias retained for clarity at call sites that explicitly want "headers only".
     pub fn get_selected_email_headers(&self) -> Option<&Email> {
         self.get_selected_email()
@@ -761,9 +2139,17 @@ impl EmailStore {
         self.get_selected_email()
     }
 
-    /// Get currently selected email mutably
-    #[allow(dead_code)]
+    /// Get currently selected email mutably. Like
+    /// [`Self::get_selected_email`], a search-results folder on display
+    /// takes precedence.
     pub fn get_selected_email_mut(&mut self) -> Option<&mut Email> {
+        if self.search_results.is_some() {
+            let selected = self.search_selected;
+            return self
+                .search_results
+                .as_mut()
+                .and_then(|results| results.emails.get_mut(selected?));
+        }
         let selected = self.selected_email;
         let current = self.get_current_folder_mut();
         selected.and_then(move |index| current.emails.get_mut(index))
@@ -774,15 +2160,23 @@ impl EmailStore {
     /// while the email is still `HeadersOnly`. The UI checks
     /// `load_state` to show a "Loading body…" placeholder in that
     /// window.
-    /// `prefer_plaintext`-aware variant: when the toggle is on the
-    /// returned body refuses to fall back to an HTML→text conversion.
-    /// (vu-c1s) See [`Email::display_body_with_pref`].
-    pub fn get_selected_email_markdown_with_pref(
+    /// `prefer_plaintext`-aware: when the toggle is on the returned body
+    /// refuses to fall back to an HTML→text conversion (vu-c1s).
+    /// `prefer_html` picks the HTML part of a `multipart/alternative`
+    /// message. See [`Email::display_body_with_prefs`]. A `:charset`
+    /// override replaces the body whatever the preferences.
+    pub fn get_selected_email_markdown_with_prefs(
         &self,
         prefer_plaintext: bool,
+        prefer_html: bool,
     ) -> Option<String> {
         self.get_selected_email()
-            .map(|e| e.display_body_with_pref(prefer_plaintext).into_owned())
+            .map(|e| match &e.body_text_override {
+                Some(text) => text.clone(),
+                None => e
+                    .display_body_with_prefs(prefer_plaintext, prefer_html)
+                    .into_owned(),
+            })
     }
 
     /// Apply a body load result (from the off-thread body loader) to the
@@ -835,6 +2229,7 @@ impl EmailStore {
             folder.is_loaded = false;
             folder.unread_count = 0;
             folder.total_count = 0;
+            folder.corrupt_count = 0;
             return true;
         }
         for sub in &mut folder.subfolders {
@@ -857,11 +2252,17 @@ impl EmailStore {
                 // a synchronous fallback path loaded it, or a previous reply
                 // already landed. Either way, we don't want to clobber it.
                 if !folder.is_loaded && folder.emails.is_empty() {
+                    let mut emails = emails;
+                    if folder.role == FolderRole::Drafts {
+                        emails.iter_mut().for_each(|e| e.is_draft = true);
+                    }
                     let unread = emails.iter().filter(|e| e.is_unread).count();
+                    let corrupt = emails.iter().filter(|e| e.parse_error.is_some()).count();
                     let total = emails.len();
                     folder.emails = emails;
                     folder.unread_count = unread;
                     folder.total_count = total;
+                    folder.corrupt_count = corrupt;
                 }
                 if fully_loaded {
                     folder.is_loaded = true;
@@ -888,6 +2289,18 @@ impl EmailStore {
         Self::swap_email_path_in_folder(&mut self.root_folder, old, new)
     }
 
+    /// The loaded email whose file is at `path`, in any folder.
+    pub fn email_by_path(&self, path: &std::path::Path) -> Option<&Email> {
+        fn walk<'a>(folder: &'a Folder, path: &std::path::Path) -> Option<&'a Email> {
+            folder
+                .emails
+                .iter()
+                .find(|e| e.file_path == path)
+                .or_else(|| folder.subfolders.iter().find_map(|sub| walk(sub, path)))
+        }
+        walk(&self.root_folder, path)
+    }
+
     /// Plan a mark-read transition for the email at `email_index` in
     /// the current folder. Returns `None` when the index is out of
     /// range, the email is already read, or the file path is not under
@@ -982,12 +2395,7 @@ impl EmailStore {
     fn apply_loaded_body_to_folder(
         folder: &mut Folder,
         path: &std::path::Path,
-        payload: &mut Option<(
-            Option<String>,
-            Option<String>,
-            Vec<Attachment>,
-            Vec<InlineImage>,
-        )>,
+        payload: &mut Option<LoadedBodyParts>,
     ) -> bool {
         for email in &mut folder.emails {
             if email.file_path == path {
@@ -1009,98 +2417,371 @@ impl EmailStore {
         false
     }
 
-    /// Get folder path as breadcrumb string
-    pub fn get_folder_path(&self) -> String {
-        let mut path = vec!["Mail".to_string()];
-        let mut folder = &self.root_folder;
+    /// Get folder path as breadcrumb string
+    pub fn get_folder_path(&self) -> String {
+        let mut path = vec!["Mail".to_string()];
+        let mut folder = &self.root_folder;
+
+        for &index in &self.current_folder {
+            if index < folder.subfolders.len() {
+                folder = &folder.subfolders[index];
+                path.push(folder.name.clone());
+            }
+        }
+
+        path.join(" > ")
+    }
+
+    /// Get folder path for a specific folder path
+    pub fn get_folder_path_for_indices(&self, indices: &[usize]) -> String {
+        let mut path = vec!["Mail".to_string()];
+        let mut folder = &self.root_folder;
+
+        for &index in indices {
+            if index < folder.subfolders.len() {
+                folder = &folder.subfolders[index];
+                path.push(folder.name.clone());
+            }
+        }
+
+        path.join(" > ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::TestMailDir;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_email_new() {
+        let email_path = PathBuf::from("/tmp/test_email");
+        let email = Email::new(email_path.clone());
+
+        assert_eq!(email.file_path, email_path);
+        assert_eq!(email.headers.subject, "");
+        assert_eq!(email.headers.from, "");
+        assert_eq!(email.headers.to, "");
+        assert!(email.body_plain.is_none());
+        assert!(email.body_html.is_none());
+        assert!(email.attachments.is_empty());
+        assert!(email.inline_images.is_empty());
+        assert!(!email.is_unread);
+        assert!(matches!(email.load_state, EmailLoadState::HeadersOnly));
+    }
+
+    #[test]
+    fn test_email_parse_headers_only() {
+        let test_maildir = TestMailDir::new();
+        let inbox_path = test_maildir.get_folder_path("INBOX").join("cur");
+
+        // Find the first email file
+        let email_files: Vec<_> = fs::read_dir(&inbox_path)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .collect();
+
+        assert!(
+            !email_files.is_empty(),
+            "No email files found in test INBOX"
+        );
+
+        let email_path = email_files[0].path();
+        let mut email = Email::new(email_path);
+
+        // Parse headers only
+        let result = email.parse_headers_only();
+        assert!(
+            result.is_ok(),
+            "Failed to parse email headers: {:?}",
+            result
+        );
+
+        // Verify headers were parsed
+        assert!(!email.headers.subject.is_empty());
+        assert!(!email.headers.from.is_empty());
+        assert!(matches!(email.load_state, EmailLoadState::HeadersOnly));
+
+        // Body should still be empty since we only parsed headers
+        assert!(email.body_plain.is_none());
+        assert!(email.body_html.is_none());
+    }
+
+    #[test]
+    fn size_bytes_matches_fixture_file_length() {
+        let test_maildir = TestMailDir::new();
+        let inbox_path = test_maildir.get_folder_path("INBOX").join("cur");
+        let email_path = fs::read_dir(&inbox_path)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .find(|path| path.is_file())
+            .expect("fixture INBOX has an email");
+        let file_len = fs::metadata(&email_path).unwrap().len() as usize;
+
+        let mut email = Email::new(email_path);
+        assert_eq!(email.size_bytes, 0);
+        email.parse_headers_only().unwrap();
+        assert!(email.size_bytes > 0);
+        assert_eq!(email.size_bytes, file_len);
+
+        email.parse_from_file().unwrap();
+        assert_eq!(email.size_bytes, file_len);
+    }
+
+    #[test]
+    fn refresh_folder_counts_new_mail_and_keeps_the_selection() {
+        let test_maildir = TestMailDir::new();
+        let scanner = crate::maildir::MaildirScanner::new(test_maildir.root_path.clone());
+        let mut store = EmailStore::new(test_maildir.root_path.clone());
+        store.root_folder = scanner.scan().unwrap();
+        let inbox = store
+            .root_folder
+            .subfolders
+            .iter()
+            .position(|f| f.name == "INBOX")
+            .unwrap();
+        store.enter_folder_by_path(&[inbox]);
+        assert_eq!(
+            store.refresh_folder(&scanner).unwrap(),
+            store.get_current_folder().emails.len()
+        );
+        store.select_email(1);
+        let selected_id = store
+            .get_selected_email()
+            .unwrap()
+            .headers
+            .message_id
+            .clone();
+
+        assert_eq!(store.refresh_folder(&scanner).unwrap(), 0);
+        test_maildir.add_custom_email(
+            "INBOX",
+            "fresh.eml",
+            "From: new@example.com\nSubject: Fresh\nMessage-ID: <fresh@example.com>\n\nhi\n",
+        );
+        assert_eq!(store.refresh_folder(&scanner).unwrap(), 1);
+        assert_eq!(
+            store.get_selected_email().unwrap().headers.message_id,
+            selected_id
+        );
+    }
+
+    #[test]
+    fn empty_trash_deletes_the_trash_files_and_refuses_other_folders() {
+        let test_maildir = TestMailDir::new();
+        test_maildir.add_custom_email("Trash", "old.eml", "Subject: Old\n\nbye\n");
+        test_maildir.add_unread_email("Trash", "spam.eml", "Subject: Spam\n\nbuy\n");
+        let scanner = crate::maildir::MaildirScanner::new(test_maildir.root_path.clone());
+        let mut store = EmailStore::new(test_maildir.root_path.clone());
+        store.root_folder = scanner.scan().unwrap();
+        let position = |store: &EmailStore, name: &str| {
+            store
+                .root_folder
+                .subfolders
+                .iter()
+                .position(|f| f.name == name)
+                .unwrap()
+        };
+
+        store.enter_folder_by_path(&[position(&store, "INBOX")]);
+        let inbox_count = test_maildir.get_email_count("INBOX");
+        assert!(matches!(
+            store.empty_trash(&scanner),
+            Err(VulthorError::NotTrashFolder(_))
+        ));
+        assert_eq!(test_maildir.get_email_count("INBOX"), inbox_count);
+
+        store.exit_folder();
+        store.enter_folder_by_path(&[position(&store, "Trash")]);
+        let before = test_maildir.get_email_count("Trash");
+        assert!(before >= 2);
+        store.refresh_folder(&scanner).unwrap();
+        store.select_email(0);
+        assert!(store.get_selected_email().is_some());
+        assert_eq!(store.empty_trash(&scanner).unwrap(), before);
+        assert_eq!(test_maildir.get_email_count("Trash"), 0);
+        assert!(store.get_current_folder().emails.is_empty());
+        assert_eq!(store.get_current_folder().total_count, 0);
+        assert!(store.get_selected_email().is_none());
+    }
+
+    #[test]
+    fn unparseable_date_header_keeps_its_original_text() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("odd-date");
+        fs::write(
+            &path,
+            "From: a@b.c\r\nSubject: Hi\r\nDate: 2024-01-05   10:00:00\r\n\r\nbody\r\n",
+        )
+        .unwrap();
+
+        let mut email = Email::new(path);
+        email.parse_headers_only().unwrap();
+        assert_eq!(email.headers.date, "2024-01-05 10:00:00");
+        assert_eq!(
+            parse_flexible_date(&email.headers.date),
+            parse_flexible_date("2024-01-05T10:00:00Z")
+        );
+    }
+
+    #[test]
+    fn date_sort_reads_rfc2822_and_puts_junk_last() {
+        let dated = |name: &str, date: &str| {
+            let mut email = Email::new(PathBuf::from(name));
+            email.headers.date = date.to_string();
+            email
+        };
+        let mut emails = [
+            dated("junk", "sometime last week"),
+            dated("older", "2024-01-01T00:00:00Z"),
+            dated("newer", "Tue, 02 Jan 2024 10:00:00 +0000"),
+        ];
+        emails.sort_by(|a, b| SortOrder::Date.compare(a, b));
+        let order: Vec<_> = emails
+            .iter()
+            .map(|e| e.file_path.to_str().unwrap())
+            .collect();
+        assert_eq!(order, ["newer", "older", "junk"]);
+    }
+
+    #[test]
+    fn authentication_results_become_per_method_verdicts() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("signed");
+        fs::write(
+            &path,
+            "From: a@b.c\r\nSubject: Hi\r\n\
+             Authentication-Results: mx.example.org;\r\n \
+             dkim=pass header.d=b.c header.s=s1;\r\n spf=softfail smtp.mailfrom=b.c\r\n\
+             \r\nbody\r\n",
+        )
+        .unwrap();
 
-        for &index in &self.current_folder {
-            if index < folder.subfolders.len() {
-                folder = &folder.subfolders[index];
-                path.push(folder.name.clone());
+        let mut email = Email::new(path);
+        email.parse_headers_only().unwrap();
+        assert_eq!(
+            email.auth_status,
+            AuthStatus {
+                dkim: Some(true),
+                spf: Some(false),
+                dmarc: None,
             }
-        }
+        );
+        assert_eq!(
+            email.auth_status.badges(),
+            vec![("DKIM", true), ("SPF", false)]
+        );
+    }
 
-        path.join(" > ")
+    #[test]
+    fn auth_status_takes_any_passing_signature_and_skips_neutral_results() {
+        let status = AuthStatus::from_results([
+            "mx; dkim=fail header.d=old.example",
+            "mx; dkim=pass header.d=new.example, dmarc=none, spf=neutral",
+        ]);
+        assert_eq!(status.dkim, Some(true));
+        assert_eq!(status.dmarc, None);
+        assert_eq!(status.spf, None);
+        assert!(AuthStatus::from_results(["mx; none"]).badges().is_empty());
     }
 
-    /// Get folder path for a specific folder path
-    pub fn get_folder_path_for_indices(&self, indices: &[usize]) -> String {
-        let mut path = vec!["Mail".to_string()];
-        let mut folder = &self.root_folder;
+    #[test]
+    fn spam_headers_parse_with_default_and_custom_names() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("spam");
+        fs::write(
+            &path,
+            "From: a@b.c\r\nSubject: Win\r\nX-Spam-Flag: YES\r\n\
+             X-Spam-Score: 7.3 / 5.0\r\nX-Rspamd-Score: 12.5\r\n\r\nbody\r\n",
+        )
+        .unwrap();
 
-        for &index in indices {
-            if index < folder.subfolders.len() {
-                folder = &folder.subfolders[index];
-                path.push(folder.name.clone());
-            }
-        }
+        let mut email = Email::new(path.clone());
+        email.parse_headers_only().unwrap();
+        assert!(email.is_spam);
+        assert_eq!(email.spam_score, Some(7.3));
+        assert_eq!(email.spam_display().as_deref(), Some("7.3 (flagged)"));
+        assert!(email.get_header_display().contains("Spam: 7.3 (flagged)"));
+
+        let custom = SpamConfig {
+            flag_header: "X-Rspamd-Flag".to_string(),
+            score_header: "x-rspamd-score".to_string(),
+            ..SpamConfig::default()
+        };
+        let mut email = Email::new(path);
+        email.parse_headers_only_with(&custom).unwrap();
+        assert!(!email.is_spam);
+        assert_eq!(email.spam_score, Some(12.5));
 
-        path.join(" > ")
+        assert_eq!(parse_spam_score(" -1.2,tests=FOO"), Some(-1.2));
+        assert_eq!(parse_spam_score("n/a"), None);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::test_fixtures::TestMailDir;
-    use std::fs;
-    use std::path::PathBuf;
-    use tempfile::TempDir;
 
     #[test]
-    fn test_email_new() {
-        let email_path = PathBuf::from("/tmp/test_email");
-        let email = Email::new(email_path.clone());
+    fn priority_headers_normalize_and_prefer_the_more_urgent() {
+        let temp = TempDir::new().unwrap();
+        let parse = |extra: &str| {
+            let path = temp.path().join("msg");
+            fs::write(
+                &path,
+                format!("From: a@b.c\r\nSubject: s\r\n{}\r\nbody\r\n", extra),
+            )
+            .unwrap();
+            let mut email = Email::new(path);
+            email.parse_headers_only().unwrap();
+            email
+        };
 
-        assert_eq!(email.file_path, email_path);
-        assert_eq!(email.headers.subject, "");
-        assert_eq!(email.headers.from, "");
-        assert_eq!(email.headers.to, "");
-        assert!(email.body_plain.is_none());
-        assert!(email.body_html.is_none());
-        assert!(email.attachments.is_empty());
-        assert!(email.inline_images.is_empty());
-        assert!(!email.is_unread);
-        assert!(matches!(email.load_state, EmailLoadState::HeadersOnly));
+        assert_eq!(parse("").headers.priority, Priority::Normal);
+        let urgent = parse("X-Priority: 1 (Highest)\r\n");
+        assert_eq!(urgent.headers.priority, Priority::High);
+        assert!(urgent.get_header_display().contains("Importance: High"));
+        assert_eq!(
+            parse("Importance: HIGH\r\n").headers.priority,
+            Priority::High
+        );
+        assert_eq!(parse("X-Priority: 5\r\n").headers.priority, Priority::Low);
+        assert_eq!(
+            parse("X-Priority: 5\r\nImportance: high\r\n")
+                .headers
+                .priority,
+            Priority::High
+        );
+        assert_eq!(
+            parse("X-Priority: 3\r\nImportance: low\r\n")
+                .headers
+                .priority,
+            Priority::Normal
+        );
+        assert!(
+            !parse("X-Priority: 3\r\n")
+                .get_header_display()
+                .contains("Importance")
+        );
     }
 
     #[test]
-    fn test_email_parse_headers_only() {
+    fn email_without_spam_headers_is_unaffected() {
         let test_maildir = TestMailDir::new();
         let inbox_path = test_maildir.get_folder_path("INBOX").join("cur");
-
-        // Find the first email file
-        let email_files: Vec<_> = fs::read_dir(&inbox_path)
+        let email_path = fs::read_dir(&inbox_path)
             .unwrap()
             .filter_map(|entry| entry.ok())
-            .filter(|entry| entry.path().is_file())
-            .collect();
+            .map(|entry| entry.path())
+            .find(|path| path.is_file())
+            .expect("fixture INBOX has an email");
 
-        assert!(
-            !email_files.is_empty(),
-            "No email files found in test INBOX"
-        );
-
-        let email_path = email_files[0].path();
         let mut email = Email::new(email_path);
-
-        // Parse headers only
-        let result = email.parse_headers_only();
-        assert!(
-            result.is_ok(),
-            "Failed to parse email headers: {:?}",
-            result
-        );
-
-        // Verify headers were parsed
-        assert!(!email.headers.subject.is_empty());
-        assert!(!email.headers.from.is_empty());
-        assert!(matches!(email.load_state, EmailLoadState::HeadersOnly));
-
-        // Body should still be empty since we only parsed headers
-        assert!(email.body_plain.is_none());
-        assert!(email.body_html.is_none());
+        email.parse_headers_only().unwrap();
+        assert!(!email.is_spam);
+        assert_eq!(email.spam_score, None);
+        assert_eq!(email.spam_display(), None);
+        assert!(!email.get_header_display().contains("Spam:"));
     }
 
     #[test]
@@ -1246,6 +2927,63 @@ mod tests {
         assert_eq!(att.size, payload.len(), "size must match raw_bytes length",);
     }
 
+    /// Attachment names arrive RFC 2047-encoded from most clients
+    /// (`=?UTF-8?B?…?=`) and RFC 2231-encoded from others; both must
+    /// reach the list as UTF-8.
+    #[test]
+    fn encoded_word_attachment_filenames_are_decoded() {
+        let temp_dir = TempDir::new().unwrap();
+        let email_path = temp_dir.path().join("encoded_names.eml");
+        let raw = "From: a@b.test\r\n\
+                   Subject: t\r\n\
+                   MIME-Version: 1.0\r\n\
+                   Content-Type: multipart/mixed; boundary=BOUND\r\n\
+                   \r\n\
+                   --BOUND\r\n\
+                   Content-Type: text/plain\r\n\
+                   \r\n\
+                   body\r\n\
+                   --BOUND\r\n\
+                   Content-Type: application/pdf\r\n\
+                   Content-Disposition: attachment;\r\n \
+                   filename=\"=?UTF-8?B?w6lsw6h2ZQ==?= =?UTF-8?Q?_notes.pdf?=\"\r\n\
+                   \r\n\
+                   x\r\n\
+                   --BOUND\r\n\
+                   Content-Type: text/plain\r\n\
+                   Content-Disposition: attachment; filename*=iso-8859-1''%E9t%E9.txt\r\n\
+                   \r\n\
+                   y\r\n\
+                   --BOUND--\r\n";
+        fs::write(&email_path, raw).unwrap();
+
+        let mut email = Email::new(email_path);
+        email.parse_from_file().unwrap();
+        let names: Vec<&str> = email
+            .attachments
+            .iter()
+            .map(|a| a.filename.as_str())
+            .collect();
+        assert_eq!(names, ["élève notes.pdf", "été.txt"]);
+    }
+
+    #[test]
+    fn decode_encoded_words_joins_adjacent_words_and_keeps_bad_ones() {
+        assert_eq!(
+            decode_encoded_words("=?UTF-8?Q?a?= =?UTF-8?Q?b.pdf?="),
+            "ab.pdf"
+        );
+        assert_eq!(
+            decode_encoded_words("report =?iso-8859-1?q?=E9t=E9?=.pdf"),
+            "report été.pdf"
+        );
+        assert_eq!(
+            decode_encoded_words("=?UTF-8?B?!!!bad?="),
+            "=?UTF-8?B?!!!bad?="
+        );
+        assert_eq!(decode_encoded_words("plain.txt"), "plain.txt");
+    }
+
     // --- vu-hy8: MIME multipart selection / inline-image preservation. ---
 
     fn write_eml(dir: &TempDir, name: &str, raw: &str) -> PathBuf {
@@ -1254,6 +2992,34 @@ mod tests {
         p
     }
 
+    #[test]
+    fn control_characters_are_stripped_from_the_body() {
+        let temp = TempDir::new().unwrap();
+        let raw = "From: a@b.test\r\nSubject: esc\r\n\r\n\
+                   red\x1b[31malert\x07\tok\r\nnext\r\n";
+        let mut email = Email::new(write_eml(&temp, "esc.eml", raw));
+        email.parse_from_file().unwrap();
+        assert_eq!(
+            email.body_plain.as_deref(),
+            Some("red[31malert\tok\r\nnext\r\n")
+        );
+        assert_eq!(strip_control_chars("over\rwrite\r\n"), "overwrite\r\n");
+    }
+
+    #[test]
+    fn undeclared_shift_jis_bytes_are_detected() {
+        let text = "こんにちは、明日の会議は午後三時からです。よろしくお願いします。";
+        let (bytes, _, _) = encoding_rs::SHIFT_JIS.encode(text);
+        assert_eq!(
+            decode_unlabelled(&bytes),
+            (text.to_string(), Some("Shift_JIS"))
+        );
+        assert_eq!(
+            decode_unlabelled("naïve".as_bytes()),
+            ("naïve".to_string(), None)
+        );
+    }
+
     /// `multipart/alternative` must populate **both** `body_plain` and
     /// `body_html` with the raw parts (HTML sanitized at the boundary).
     /// The legacy behaviour leaned on `body_text(0)` which collapsed the
@@ -1324,6 +3090,134 @@ mod tests {
         assert_eq!(email.display_body_with_pref(false), "plain rendition");
     }
 
+    /// `v` toggle: a message with both parts shows the HTML rendition
+    /// (converted to text) when `prefer_html` is on, plain otherwise.
+    #[test]
+    fn display_body_with_prefs_switches_between_alternative_parts() {
+        let mut email = Email::new(PathBuf::from("/test/foo.eml"));
+        email.body_plain = Some("plain rendition".to_string());
+        email.body_html = Some("<p>html rendition</p>".to_string());
+
+        assert_eq!(email.displayed_part(false), Some(BodyPart::Text));
+        assert_eq!(email.displayed_part(true), Some(BodyPart::Html));
+        assert_eq!(
+            email.display_body_with_prefs(false, false),
+            "plain rendition"
+        );
+        let html = email.display_body_with_prefs(false, true);
+        assert!(html.contains("html rendition"), "{}", html);
+        assert!(!html.contains("<p>"), "HTML must be converted: {}", html);
+    }
+
+    /// Single-part messages ignore `prefer_html`, and the `P` paranoia
+    /// toggle still blocks the HTML conversion.
+    #[test]
+    fn display_body_with_prefs_single_part_and_paranoia() {
+        let mut plain_only = Email::new(PathBuf::from("/test/a.eml"));
+        plain_only.body_plain = Some("only plain".to_string());
+        assert_eq!(plain_only.displayed_part(true), Some(BodyPart::Text));
+        assert_eq!(
+            plain_only.display_body_with_prefs(false, true),
+            "only plain"
+        );
+
+        let mut html_only = Email::new(PathBuf::from("/test/b.eml"));
+        html_only.body_html = Some("<p>only html</p>".to_string());
+        assert_eq!(html_only.displayed_part(false), Some(BodyPart::Html));
+        assert_eq!(
+            html_only.display_body_with_prefs(true, true),
+            "(no plain part)"
+        );
+    }
+
+    #[test]
+    fn body_preview_collapses_the_first_text_lines() {
+        let preview = body_preview(
+            b"From: a@b.test\r\nSubject: hi\r\n\r\n\
+              Hello   there,\r\n\r\n> quoted reply\r\nsee you\tsoon\r\n-- \r\nSig\r\n",
+        );
+        assert_eq!(preview.as_deref(), Some("Hello there, see you soon"));
+
+        let long = format!("Subject: x\n\n{}", "word ".repeat(100));
+        let preview = body_preview(long.as_bytes()).unwrap();
+        assert_eq!(preview.chars().count(), PREVIEW_CHARS);
+
+        assert_eq!(body_preview(b"Subject: empty\r\n\r\n\r\n"), None);
+    }
+
+    /// Multipart mail: the preamble, part headers and non-text parts
+    /// are stepped over, and quoted-printable is undone.
+    #[test]
+    fn body_preview_finds_the_first_text_part() {
+        let raw = "Content-Type: multipart/mixed; boundary=\"o\"\r\n\r\n\
+                   This is a multi-part message in MIME format.\r\n\
+                   --o\r\nContent-Type: image/png\r\nContent-Transfer-Encoding: base64\r\n\r\n\
+                   iVBORw0KGgo=\r\n\
+                   --o\r\nContent-Type: multipart/alternative;\r\n boundary=\"i\"\r\n\r\n\
+                   --i\r\nContent-Type: text/plain; charset=utf-8\r\n\
+                   Content-Transfer-Encoding: quoted-printable\r\n\r\n\
+                   Caf=C3=A9 at noon, bring the =\r\nslides\r\n\
+                   --i--\r\n--o--\r\n";
+        assert_eq!(
+            body_preview(raw.as_bytes()).as_deref(),
+            Some("Café at noon, bring the slides")
+        );
+
+        let html = "Content-Type: text/html\r\n\r\n<p>Hi <b>team</b></p>\r\n";
+        assert_eq!(body_preview(html.as_bytes()).as_deref(), Some("Hi team"));
+    }
+
+    /// The header scan fills the preview for the list.
+    #[test]
+    fn parse_headers_only_captures_a_preview() {
+        let maildir = crate::test_fixtures::TestMailDir::new();
+        let mut email = Email::new(
+            maildir
+                .get_folder_path("INBOX")
+                .join("cur")
+                .join("1234567894.email5"),
+        );
+        email.parse_headers_only().unwrap();
+        let preview = email.preview.expect("preview");
+        assert!(
+            preview.contains("Please find the attached project documents"),
+            "{}",
+            preview
+        );
+    }
+
+    /// A Latin-1 body labelled ISO-8859-7 decodes to Greek letters; the
+    /// `:charset` re-decode reads the file again and gets the accents
+    /// back, and an unknown label is refused.
+    #[test]
+    fn reparse_body_with_charset_overrides_a_wrong_declaration() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("latin1.eml");
+        let mut raw = b"From: a@b.test\r\n\
+            Subject: menu\r\n\
+            Content-Type: text/plain; charset=iso-8859-7\r\n\r\n"
+            .to_vec();
+        raw.extend_from_slice(b"Caf\xe9 cr\xe8me br\xfbl\xe9e\r\n");
+        std::fs::write(&path, raw).unwrap();
+        let mut email = Email::new(path);
+        email.parse_from_file().unwrap();
+        assert!(
+            !email.display_body().contains("Café"),
+            "{}",
+            email.display_body()
+        );
+
+        let text = email.reparse_body_with_charset("ISO-8859-1").unwrap();
+        assert_eq!(text.trim_end(), "Café crème brûlée");
+        assert_eq!(email.body_text_override.as_deref(), Some(text.as_str()));
+
+        assert!(matches!(
+            email.reparse_body_with_charset("klingon"),
+            Err(VulthorError::UnknownCharset(_))
+        ));
+        assert_eq!(email.body_text_override.as_deref(), Some(text.as_str()));
+    }
+
     /// `multipart/related` (HTML body + inline `cid:` images) must
     /// preserve the inline parts in `inline_images` and keep them out
     /// of the regular `attachments` list — that's what makes future
@@ -1405,7 +3299,11 @@ mod tests {
             "no text/plain part → body_plain must be None",
         );
         assert!(
-            email.body_html.as_deref().unwrap_or("").contains("html only"),
+            email
+                .body_html
+                .as_deref()
+                .unwrap_or("")
+                .contains("html only"),
             "html part must populate body_html",
         );
         // display_body falls back through html_to_text so the TUI
@@ -1784,9 +3682,9 @@ Hello 世界! This email contains unicode: 🎉 αβγ 中文"#;
         store.enter_folder_by_path(&[0]);
         store.select_email(0);
 
-        // Render-path: get_selected_email_markdown_with_pref. Must return Some("") and
+        // Render-path: get_selected_email_markdown_with_prefs. Must return Some("") and
         // must NOT transition the email to FullyLoaded.
-        let markdown = store.get_selected_email_markdown_with_pref(false);
+        let markdown = store.get_selected_email_markdown_with_prefs(false, false);
         assert_eq!(markdown.as_deref(), Some(""));
         let email = store.get_selected_email().expect("email is selected");
         assert!(
@@ -2024,4 +3922,200 @@ Hello 世界! This email contains unicode: 🎉 αβγ 中文"#;
             false,
         ));
     }
+
+    #[test]
+    fn attachment_previewable_covers_textual_types_and_patch_names() {
+        let att = |filename: &str, content_type: &str| Attachment {
+            filename: filename.into(),
+            content_type: content_type.into(),
+            size: 0,
+            raw_bytes: Vec::new(),
+        };
+        assert!(att("a.txt", "text/plain; charset=utf-8").is_previewable());
+        assert!(att("a.json", "application/json").is_previewable());
+        assert!(att("a.json", "application/vnd.api+json").is_previewable());
+        assert!(att("fix.patch", "application/octet-stream").is_previewable());
+        assert!(!att("a.pdf", "application/pdf").is_previewable());
+        assert!(!att("a.png", "image/png").is_previewable());
+
+        assert!(att("x", "text/x-patch").is_patch());
+        assert!(att("change.DIFF", "text/plain").is_patch());
+        assert!(!att("notes.txt", "text/plain").is_patch());
+
+        assert!(att("photo.jpg", "image/jpeg").is_image());
+        assert!(att("a.png", "IMAGE/PNG; name=a.png").is_image());
+        assert!(!att("logo.svg", "image/svg+xml").is_image());
+        assert!(!att("a.txt", "text/plain").is_image());
+    }
+
+    #[test]
+    fn folder_role_detect_matches_whole_names_only() {
+        assert_eq!(FolderRole::detect("Sent"), FolderRole::Sent);
+        assert_eq!(FolderRole::detect(".Sent"), FolderRole::Sent);
+        assert_eq!(FolderRole::detect("Sent Items"), FolderRole::Sent);
+        assert_eq!(FolderRole::detect("Gesendet"), FolderRole::Sent);
+        assert_eq!(FolderRole::detect("Present"), FolderRole::Normal);
+        assert_eq!(FolderRole::detect("Unsent Drafts"), FolderRole::Normal);
+        assert_eq!(FolderRole::detect("Deleted Items"), FolderRole::Trash);
+        assert_eq!(FolderRole::detect("Projects"), FolderRole::Normal);
+    }
+
+    #[test]
+    fn unread_search_steps_both_ways_and_wraps() {
+        let mut store = EmailStore::new(PathBuf::from("/tmp"));
+        let mut inbox = Folder::new("INBOX".to_string(), PathBuf::from("/tmp/INBOX"));
+        for (i, unread) in [true, false, true, false].into_iter().enumerate() {
+            let mut email = Email::new(PathBuf::from(format!("/tmp/INBOX/m{}", i)));
+            email.is_unread = unread;
+            inbox.add_email(email);
+        }
+        store.root_folder.add_subfolder(inbox);
+        store.current_folder = vec![0];
+
+        assert_eq!(store.next_unread_from(0), Some(2));
+        assert_eq!(store.next_unread_from(2), Some(0), "wraps to the top");
+        assert_eq!(store.prev_unread_from(2), Some(0));
+        assert_eq!(store.prev_unread_from(0), Some(2), "wraps to the bottom");
+        assert_eq!(store.prev_unread_from(3), Some(2));
+
+        store.get_current_folder_mut().emails[2].is_unread = false;
+        assert_eq!(store.next_unread_from(0), Some(0), "only the cursor row");
+        store.get_current_folder_mut().emails[0].is_unread = false;
+        assert_eq!(store.next_unread_from(0), None);
+        assert_eq!(store.prev_unread_from(0), None);
+    }
+
+    #[test]
+    fn saved_search_matches_subjects_across_every_loaded_folder() {
+        let mut store = EmailStore::new(PathBuf::from("/tmp"));
+        let mut inbox = Folder::new("INBOX".to_string(), PathBuf::from("/tmp/INBOX"));
+        let mut work = Folder::new("Work".to_string(), PathBuf::from("/tmp/Work"));
+        for (folder, subjects) in [
+            (&mut inbox, ["Team Meeting notes", "Lunch?"]),
+            (&mut work, ["meeting moved to 3pm", "Quarterly report"]),
+        ] {
+            for (i, subject) in subjects.into_iter().enumerate() {
+                let mut email = Email::new(folder.path.join(format!("m{}", i)));
+                email.headers.subject = subject.to_string();
+                folder.add_email(email);
+            }
+        }
+        store.root_folder.add_subfolder(inbox);
+        store.root_folder.add_subfolder(work);
+
+        let results = store.execute_saved_search("Meetings", "meeting");
+        assert!(results.is_virtual);
+        let subjects: Vec<_> = results
+            .emails
+            .iter()
+            .map(|e| e.headers.subject.as_str())
+            .collect();
+        assert_eq!(subjects, ["Team Meeting notes", "meeting moved to 3pm"]);
+        assert_eq!(
+            store
+                .folder_of_email(&results.emails[1].file_path)
+                .map(|f| f.name.as_str()),
+            Some("Work")
+        );
+    }
+
+    #[test]
+    fn full_header_display_lists_every_header_and_the_summary_does_not() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("msg");
+        fs::write(
+            &path,
+            "From: Alice <alice@example.com>\r\n\
+             To: bob@example.com\r\n\
+             Cc: carol@example.com\r\n\
+             Reply-To: list@example.com\r\n\
+             Subject: =?UTF-8?Q?Caf=C3=A9?= plans\r\n\
+             Message-ID: <abc@example.com>\r\n\
+             X-Folded: first\r\n\tsecond\r\n\
+             \r\n\
+             body\r\n",
+        )
+        .unwrap();
+        let mut email = Email::new(path);
+        email.parse_headers_only().unwrap();
+
+        let summary = email.get_header_display();
+        assert!(!summary.contains("Message-ID"));
+        assert!(!summary.contains("Cc:"));
+
+        let full = email.get_full_header_display();
+        assert!(full.contains("Message-ID: <abc@example.com>"));
+        assert!(full.contains("Cc: carol@example.com"));
+        assert!(full.contains("Reply-To: list@example.com"));
+        assert!(full.contains("Subject: Café plans"), "{}", full);
+        assert!(full.contains("X-Folded: first second"), "{}", full);
+        assert_eq!(full.lines().count(), 7);
+    }
+
+    #[test]
+    fn headers_paged_into_a_detached_copy_merge_back_without_duplicates() {
+        let test_maildir = TestMailDir::new();
+        let scanner = crate::maildir::MaildirScanner::new(test_maildir.root_path.clone());
+        let mut store = EmailStore::new(test_maildir.root_path.clone());
+        let mut inbox = Folder::new("INBOX".into(), test_maildir.get_folder_path("INBOX"));
+        scanner
+            .load_folder_emails_with_limit(&mut inbox, Some(1))
+            .unwrap();
+        store.root_folder.add_subfolder(inbox);
+
+        let mut copy = store
+            .folder_by_relative_path("INBOX")
+            .unwrap()
+            .detached_copy();
+        assert_eq!(copy.emails.len(), 1);
+        EmailStore::load_more_detached(&mut copy, &scanner, usize::MAX).unwrap();
+        assert!(copy.is_loaded);
+        let on_disk = copy.emails.len();
+        assert!(on_disk > 1);
+
+        // The TUI pages in the same folder while the copy is loading.
+        let path = copy.path.clone();
+        store
+            .load_more_messages_in(&scanner, &path, usize::MAX)
+            .unwrap();
+        assert!(store.merge_loaded_emails(copy));
+        let inbox = store.folder_by_relative_path("INBOX").unwrap();
+        assert_eq!(inbox.emails.len(), on_disk);
+        assert_eq!(inbox.total_count, on_disk);
+        assert!(inbox.is_loaded);
+    }
+
+    #[test]
+    fn header_lines_keep_the_field_name_apart_from_the_value() {
+        let mut email = Email::new(PathBuf::from("/tmp/m"));
+        email.headers.from = "Alice <alice@example.com>".into();
+        email.headers.subject = "Plans: Friday".into();
+        email.decoded_charset = Some("windows-1252");
+
+        let lines = email.get_header_lines();
+        let labels: Vec<Option<&str>> = lines.iter().map(|l| l.label.as_deref()).collect();
+        assert_eq!(
+            labels,
+            [
+                Some("From"),
+                Some("To"),
+                Some("Subject"),
+                Some("Date"),
+                None
+            ]
+        );
+        assert_eq!(lines[2].value, "Plans: Friday");
+        assert_eq!(lines[4].text(), "(decoded as windows-1252)");
+        assert!(
+            email
+                .get_header_display()
+                .starts_with("From: Alice <alice@example.com>\nTo: \nSubject: Plans: Friday")
+        );
+
+        email.raw_headers = vec![("X-Mailer".into(), "mutt".into())];
+        assert_eq!(
+            email.get_full_header_lines(),
+            [HeaderLine::new("X-Mailer", "mutt")]
+        );
+    }
 }
@@ -58,6 +58,13 @@ pub enum VulthorError {
         source: std::io::Error,
     },
 
+    #[error("Cannot attach {path}: {source}")]
+    AttachmentRead {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
     // Phase 3.a — notmuch search.
     #[error("notmuch not found on PATH")]
     NotmuchNotFound,
@@ -94,6 +101,22 @@ pub enum VulthorError {
 
     #[error("Unknown action '{action}' in [keybindings]")]
     KeybindingUnknownAction { action: String },
+
+    #[error("Not the Trash folder: {0}")]
+    NotTrashFolder(PathBuf),
+
+    #[error("No MIME part {0} in this message")]
+    MimePartNotFound(usize),
+
+    #[error("Unknown charset: {0}")]
+    UnknownCharset(String),
+
+    #[error("This message has no text body")]
+    NoTextBody,
+
+    // Remembered folder preferences (`state.toml`).
+    #[error("Failed to write state file: {0}")]
+    StateSerialize(#[from] toml::ser::Error),
 }
 
 /// Crate-wide result alias: every fallible Vulthor API returns
@@ -0,0 +1,279 @@
+//! Minimal iCalendar (RFC 5545) reader for the attachment preview.
+//!
+//! Only the first `VEVENT` is read, and only the fields the preview
+//! shows. Folded lines are unfolded and text values unescaped. There is
+//! no time-zone database: a `DTSTART;TZID=…` time is taken as local wall
+//! time and the zone name kept alongside for display.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+/// The fields of one calendar event the preview renders.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CalendarEvent {
+    pub summary: Option<String>,
+    pub start: Option<EventTime>,
+    pub end: Option<EventTime>,
+    pub location: Option<String>,
+    /// `Name <address>` when the organizer has a `CN`, else the address.
+    pub organizer: Option<String>,
+    pub description: Option<String>,
+}
+
+/// A `DTSTART` / `DTEND` value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventTime {
+    pub at: DateTime<Local>,
+    /// `VALUE=DATE` (or a bare `YYYYMMDD`): a whole day, no time.
+    pub all_day: bool,
+    /// The `TZID` parameter, when the time was given in a named zone.
+    pub tzid: Option<String>,
+}
+
+impl CalendarEvent {
+    /// Read the first `VEVENT` in `ics`. `None` when there is none.
+    pub fn parse(ics: &str) -> Option<Self> {
+        let mut event = None;
+        for line in unfold(ics) {
+            let Some((name, params, value)) = split_content_line(&line) else {
+                continue;
+            };
+            let name = name.to_ascii_uppercase();
+            if name == "BEGIN" && value.eq_ignore_ascii_case("VEVENT") {
+                event = Some(CalendarEvent::default());
+                continue;
+            }
+            let Some(ev) = event.as_mut() else {
+                continue;
+            };
+            match name.as_str() {
+                "END" if value.eq_ignore_ascii_case("VEVENT") => break,
+                "SUMMARY" => ev.summary = Some(unescape(value)),
+                "LOCATION" => ev.location = Some(unescape(value)),
+                "DESCRIPTION" => ev.description = Some(unescape(value)),
+                "DTSTART" => ev.start = parse_time(value, &params),
+                "DTEND" => ev.end = parse_time(value, &params),
+                "ORGANIZER" => {
+                    let address = value
+                        .strip_prefix("mailto:")
+                        .or_else(|| value.strip_prefix("MAILTO:"))
+                        .unwrap_or(value);
+                    ev.organizer = Some(match param(&params, "CN") {
+                        Some(cn) => format!("{} <{}>", cn, address),
+                        None => address.to_string(),
+                    });
+                }
+                _ => {}
+            }
+        }
+        event
+    }
+}
+
+/// "In 3 days" / "Was 2 weeks ago" for an event starting at `start`,
+/// seen from `now`. All-day events count whole days, so one today is
+/// "Today".
+pub fn countdown(start: &EventTime, now: DateTime<Local>) -> String {
+    let delta = if start.all_day {
+        let days = (start.at.date_naive() - now.date_naive()).num_days();
+        if days == 0 {
+            return "Today".to_string();
+        }
+        chrono::Duration::days(days)
+    } else {
+        start.at - now
+    };
+    let secs = delta.num_seconds().unsigned_abs();
+    if secs < 60 {
+        return "Now".to_string();
+    }
+    let (n, unit) = match secs {
+        s if s < 3_600 => (s / 60, "minute"),
+        s if s < 86_400 => (s / 3_600, "hour"),
+        s if s < 14 * 86_400 => (s / 86_400, "day"),
+        s if s < 60 * 86_400 => (s / (7 * 86_400), "week"),
+        s if s < 365 * 86_400 => (s / (30 * 86_400), "month"),
+        s => (s / (365 * 86_400), "year"),
+    };
+    let plural = if n == 1 { "" } else { "s" };
+    if delta.num_seconds() > 0 {
+        format!("In {} {}{}", n, unit, plural)
+    } else {
+        format!("Was {} {}{} ago", n, unit, plural)
+    }
+}
+
+/// A content line's parameters as (upper-cased name, unquoted value).
+type Params = Vec<(String, String)>;
+
+/// Content lines with RFC 5545 folding undone: a line starting with a
+/// space or tab continues the one before it.
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in ics.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+/// `NAME;P1=a;P2="b:c":value` → (`NAME`, [(`P1`, `a`), (`P2`, `b:c`)],
+/// `value`). Colons and semicolons inside quoted parameter values don't
+/// split.
+fn split_content_line(line: &str) -> Option<(&str, Params, &str)> {
+    let mut in_quotes = false;
+    let mut cuts = Vec::new();
+    let mut colon = None;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => cuts.push(i),
+            ':' if !in_quotes => {
+                colon = Some(i);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let colon = colon?;
+    let name_end = cuts.first().copied().unwrap_or(colon);
+    cuts.push(colon);
+    let params = cuts
+        .windows(2)
+        .filter_map(|w| {
+            let (key, value) = line[w[0] + 1..w[1]].split_once('=')?;
+            Some((
+                key.to_ascii_uppercase(),
+                value.trim_matches('"').to_string(),
+            ))
+        })
+        .collect();
+    Some((&line[..name_end], params, &line[colon + 1..]))
+}
+
+fn param<'a>(params: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+/// Undo TEXT escaping: `\n`, `\,`, `\;` and `\\`.
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// `20240315`, `20240315T140000` (local, or in `TZID`) or
+/// `20240315T140000Z` (UTC).
+fn parse_time(value: &str, params: &[(String, String)]) -> Option<EventTime> {
+    let tzid = param(params, "TZID").map(str::to_string);
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(EventTime {
+            at: Utc.from_utc_datetime(&naive).with_timezone(&Local),
+            all_day: false,
+            tzid: None,
+        });
+    }
+    if let Ok(naive) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S") {
+        return Some(EventTime {
+            at: Local.from_local_datetime(&naive).earliest()?,
+            all_day: false,
+            tzid,
+        });
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+    Some(EventTime {
+        at: Local
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+            .earliest()?,
+        all_day: true,
+        tzid: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INVITE: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Example//EN\r\n\
+METHOD:REQUEST\r\n\
+BEGIN:VEVENT\r\n\
+UID:1234@example.com\r\n\
+SUMMARY:Quarterly planning\\, Q3\r\n\
+DTSTART:20240315T140000Z\r\n\
+DTEND;TZID=Europe/Paris:20240315T163000\r\n\
+LOCATION:Room 4\\; second floor\r\n\
+ORGANIZER;CN=\"Doe: Jane\":mailto:jane@example.com\r\n\
+DESCRIPTION:Agenda:\\n1. Budget\\n2. Hiring plan for the new team th\r\n at starts in July\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+SUMMARY:Second event\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn parse_extracts_the_first_event() {
+        let event = CalendarEvent::parse(INVITE).expect("has a VEVENT");
+        assert_eq!(event.summary.as_deref(), Some("Quarterly planning, Q3"));
+        assert_eq!(event.location.as_deref(), Some("Room 4; second floor"));
+        assert_eq!(
+            event.organizer.as_deref(),
+            Some("Doe: Jane <jane@example.com>")
+        );
+        assert_eq!(
+            event.description.as_deref(),
+            Some("Agenda:\n1. Budget\n2. Hiring plan for the new team that starts in July")
+        );
+
+        let start = event.start.expect("DTSTART");
+        assert_eq!(
+            start.at,
+            Utc.with_ymd_and_hms(2024, 3, 15, 14, 0, 0).unwrap()
+        );
+        assert!(!start.all_day);
+        let end = event.end.expect("DTEND");
+        assert_eq!(end.tzid.as_deref(), Some("Europe/Paris"));
+        assert_eq!(end.at.naive_local().to_string(), "2024-03-15 16:30:00");
+
+        assert!(CalendarEvent::parse("BEGIN:VCALENDAR\nEND:VCALENDAR\n").is_none());
+    }
+
+    #[test]
+    fn countdown_counts_forward_and_back_in_the_largest_unit() {
+        let now = Local.with_ymd_and_hms(2024, 3, 12, 9, 0, 0).unwrap();
+        let at = |y, m, d, h| EventTime {
+            at: Local.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap(),
+            all_day: false,
+            tzid: None,
+        };
+        assert_eq!(countdown(&at(2024, 3, 15, 9), now), "In 3 days");
+        assert_eq!(countdown(&at(2024, 3, 12, 10), now), "In 1 hour");
+        assert_eq!(countdown(&at(2024, 2, 27, 9), now), "Was 2 weeks ago");
+        assert_eq!(countdown(&at(2025, 3, 20, 9), now), "In 1 year");
+        assert_eq!(countdown(&at(2024, 3, 12, 9), now), "Now");
+
+        let today = EventTime {
+            all_day: true,
+            ..at(2024, 3, 12, 0)
+        };
+        assert_eq!(countdown(&today, now), "Today");
+    }
+}
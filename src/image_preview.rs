@@ -0,0 +1,314 @@
+//! Inline previews of image attachments for terminals that speak a
+//! graphics protocol.
+//!
+//! ratatui only knows about character cells, so the image is painted
+//! out of band: the Content pane reserves (and records) the body area,
+//! and `AppRoot::render` writes the escape sequence produced here at
+//! that position once the frame is on screen. Two protocols are
+//! supported, picked by `[render].image_protocol`:
+//!
+//! - **kitty** — the PNG-encoded image is sent base64 in 4 KiB chunks
+//!   (`ESC _ G … ESC \`). Placements are deleted explicitly with
+//!   [`KITTY_DELETE_ALL`].
+//! - **sixel** — the image is quantized to a 6×6×6 colour cube and
+//!   streamed as a DEC sixel (`ESC P q … ESC \`). Sixel pixels live in
+//!   the cell grid, so clearing one takes a full repaint.
+//!
+//! Terminal detection is best-effort, from environment variables only;
+//! a terminal we don't recognise gets the "not supported" fallback
+//! rather than a screen full of escape garbage.
+
+use std::fmt::Write as _;
+use std::io::Cursor;
+
+use base64::Engine as _;
+use image::imageops::FilterType;
+use image::{DynamicImage, RgbImage};
+
+use crate::config::ImageProtocol;
+
+/// Cell size in pixels assumed when the terminal doesn't report its
+/// pixel dimensions (`TIOCGWINSZ` with zero `ws_xpixel`).
+pub const FALLBACK_CELL_PX: (u32, u32) = (8, 16);
+
+/// Largest base64 payload per kitty escape; the protocol caps chunks
+/// at 4096 bytes.
+const KITTY_CHUNK: usize = 4096;
+
+/// Delete every kitty image placement on screen, quietly.
+pub const KITTY_DELETE_ALL: &str = "\x1b_Ga=d,d=A,q=2\x1b\\";
+
+/// Side of the sixel colour cube — 6 levels per channel, 216 colours.
+const CUBE: u16 = 6;
+
+/// Best guess whether the running terminal renders `protocol`.
+pub fn terminal_supports(protocol: ImageProtocol) -> bool {
+    supports_with(protocol, |key| std::env::var(key).ok())
+}
+
+/// [`terminal_supports`] over an injectable environment lookup.
+fn supports_with(protocol: ImageProtocol, env: impl Fn(&str) -> Option<String>) -> bool {
+    // tmux / screen swallow graphics escapes unless passthrough is
+    // configured, which we can't see from here.
+    if env("TMUX").is_some() || env("STY").is_some() {
+        return false;
+    }
+    let term = env("TERM").unwrap_or_default().to_ascii_lowercase();
+    let program = env("TERM_PROGRAM").unwrap_or_default();
+    match protocol {
+        ImageProtocol::None => false,
+        ImageProtocol::Kitty => {
+            env("KITTY_WINDOW_ID").is_some()
+                || term.contains("kitty")
+                || term.contains("ghostty")
+                || matches!(program.as_str(), "WezTerm" | "ghostty")
+        }
+        ImageProtocol::Sixel => {
+            term.contains("sixel")
+                || term.starts_with("foot")
+                || term.starts_with("mlterm")
+                || matches!(
+                    program.as_str(),
+                    "WezTerm" | "iTerm.app" | "mlterm" | "contour"
+                )
+        }
+    }
+}
+
+/// Pixel size of one terminal cell, from the window size the terminal
+/// reports, or [`FALLBACK_CELL_PX`].
+pub fn cell_pixel_size() -> (u32, u32) {
+    match crossterm::terminal::window_size() {
+        Ok(ws) if ws.width > 0 && ws.height > 0 && ws.columns > 0 && ws.rows > 0 => (
+            u32::from(ws.width) / u32::from(ws.columns),
+            u32::from(ws.height) / u32::from(ws.rows),
+        ),
+        _ => FALLBACK_CELL_PX,
+    }
+}
+
+/// Scale `img` down to fit `cols` × `rows` cells, keeping its aspect
+/// ratio. Never scales up.
+pub fn fit(img: &DynamicImage, cols: u16, rows: u16, cell: (u32, u32)) -> DynamicImage {
+    let max_w = (u32::from(cols) * cell.0).max(1);
+    let max_h = (u32::from(rows) * cell.1).max(1);
+    if img.width() <= max_w && img.height() <= max_h {
+        return img.clone();
+    }
+    img.resize(max_w, max_h, FilterType::Triangle)
+}
+
+/// Escape sequence drawing `img` scaled into `cols` × `rows` cells,
+/// starting at the cursor. `None` for [`ImageProtocol::None`] or when
+/// encoding fails.
+pub fn encode(
+    img: &DynamicImage,
+    protocol: ImageProtocol,
+    cols: u16,
+    rows: u16,
+    cell: (u32, u32),
+) -> Option<String> {
+    if cols == 0 || rows == 0 {
+        return None;
+    }
+    let fitted = fit(img, cols, rows, cell);
+    match protocol {
+        ImageProtocol::None => None,
+        ImageProtocol::Kitty => kitty_sequence(&fitted),
+        ImageProtocol::Sixel => Some(sixel_sequence(&fitted.to_rgb8())),
+    }
+}
+
+/// kitty transmit-and-display (`a=T`) of `img` as PNG. `C=1` leaves
+/// the cursor where it was so ratatui's bookkeeping stays valid.
+fn kitty_sequence(img: &DynamicImage) -> Option<String> {
+    let mut png = Vec::new();
+    img.write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)
+        .ok()?;
+    let data = base64::engine::general_purpose::STANDARD.encode(png);
+    let chunks: Vec<&str> = data
+        .as_bytes()
+        .chunks(KITTY_CHUNK)
+        // base64 output is ASCII, so every chunk boundary is a char
+        // boundary.
+        .map(|c| std::str::from_utf8(c).unwrap_or_default())
+        .collect();
+    let mut out = String::with_capacity(data.len() + chunks.len() * 16);
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 < chunks.len());
+        if i == 0 {
+            let _ = write!(out, "\x1b_Gf=100,a=T,q=2,C=1,m={};", more);
+        } else {
+            let _ = write!(out, "\x1b_Gm={};", more);
+        }
+        out.push_str(chunk);
+        out.push_str("\x1b\\");
+    }
+    Some(out)
+}
+
+/// DEC sixel rendering of `img` over a fixed 216-colour cube palette.
+fn sixel_sequence(img: &RgbImage) -> String {
+    let (w, h) = img.dimensions();
+    let (w, h) = (w as usize, h as usize);
+    let palette_size = usize::from(CUBE.pow(3));
+    let level = |c: u8| (u16::from(c) * (CUBE - 1) + 127) / 255;
+    let index: Vec<usize> = img
+        .pixels()
+        .map(|p| usize::from(level(p[0]) * CUBE * CUBE + level(p[1]) * CUBE + level(p[2])))
+        .collect();
+
+    let mut out = format!("\x1bPq\"1;1;{};{}", w, h);
+    for i in 0..CUBE.pow(3) {
+        let pct = |l: u16| l * 100 / (CUBE - 1);
+        let _ = write!(
+            out,
+            "#{};2;{};{};{}",
+            i,
+            pct(i / (CUBE * CUBE)),
+            pct(i / CUBE % CUBE),
+            pct(i % CUBE)
+        );
+    }
+
+    // One six-pixel-high band at a time: per colour, a row of sixel
+    // characters whose bits mark the band's pixels in that colour.
+    let mut planes = vec![0u8; palette_size * w];
+    for top in (0..h).step_by(6) {
+        planes.iter_mut().for_each(|b| *b = 0);
+        let mut used = vec![false; palette_size];
+        for dy in 0..6.min(h - top) {
+            for x in 0..w {
+                let c = index[(top + dy) * w + x];
+                planes[c * w + x] |= 1 << dy;
+                used[c] = true;
+            }
+        }
+        let mut first = true;
+        for c in (0..palette_size).filter(|&c| used[c]) {
+            if !first {
+                out.push('$');
+            }
+            first = false;
+            let _ = write!(out, "#{}", c);
+            push_sixel_row(&mut out, &planes[c * w..(c + 1) * w]);
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out
+}
+
+/// Append one colour's sixel row, run-length encoding repeats (`!n?`).
+fn push_sixel_row(out: &mut String, bits: &[u8]) {
+    let mut iter = bits.iter().peekable();
+    while let Some(&b) = iter.next() {
+        let mut run = 1;
+        while iter.peek() == Some(&&b) {
+            iter.next();
+            run += 1;
+        }
+        let ch = char::from(63 + b);
+        if run > 3 {
+            let _ = write!(out, "!{}{}", run, ch);
+        } else {
+            (0..run).for_each(|_| out.push(ch));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgb;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    #[test]
+    fn detects_kitty_and_sixel_terminals_from_env() {
+        assert!(supports_with(
+            ImageProtocol::Kitty,
+            env(&[("TERM", "xterm-kitty")])
+        ));
+        assert!(supports_with(
+            ImageProtocol::Kitty,
+            env(&[("KITTY_WINDOW_ID", "1")])
+        ));
+        assert!(supports_with(
+            ImageProtocol::Sixel,
+            env(&[("TERM", "foot")])
+        ));
+        assert!(supports_with(
+            ImageProtocol::Sixel,
+            env(&[("TERM_PROGRAM", "WezTerm")])
+        ));
+        assert!(!supports_with(
+            ImageProtocol::Sixel,
+            env(&[("TERM", "xterm-kitty")])
+        ));
+        assert!(!supports_with(
+            ImageProtocol::Kitty,
+            env(&[("TERM", "xterm-kitty"), ("TMUX", "/tmp/tmux")])
+        ));
+        assert!(!supports_with(
+            ImageProtocol::None,
+            env(&[("TERM", "xterm-kitty")])
+        ));
+    }
+
+    #[test]
+    fn fit_keeps_aspect_ratio_and_never_upscales() {
+        let wide = DynamicImage::new_rgb8(400, 100);
+        let fitted = fit(&wide, 10, 10, (8, 16));
+        assert_eq!((fitted.width(), fitted.height()), (80, 20));
+
+        let small = DynamicImage::new_rgb8(20, 20);
+        let fitted = fit(&small, 10, 10, (8, 16));
+        assert_eq!((fitted.width(), fitted.height()), (20, 20));
+    }
+
+    #[test]
+    fn kitty_sequence_chunks_base64_png() {
+        let img = DynamicImage::new_rgb8(200, 200);
+        let seq = encode(&img, ImageProtocol::Kitty, 40, 20, (8, 16)).unwrap();
+        assert!(seq.starts_with("\x1b_Gf=100,a=T,"));
+        assert!(seq.ends_with("\x1b\\"));
+        for part in seq.split("\x1b\\").filter(|p| !p.is_empty()) {
+            let payload = part.split_once(';').unwrap().1;
+            assert!(payload.len() <= KITTY_CHUNK);
+        }
+        // Last chunk says "no more data".
+        let last = seq.rsplit("\x1b_G").next().unwrap();
+        assert!(last.contains("m=0;"), "last chunk was {:?}", last);
+    }
+
+    #[test]
+    fn sixel_sequence_encodes_dimensions_and_runs() {
+        let mut img = RgbImage::from_pixel(10, 7, Rgb([255, 0, 0]));
+        img.put_pixel(0, 6, Rgb([0, 0, 255]));
+        let seq = sixel_sequence(&img);
+        assert!(seq.starts_with("\x1bPq\"1;1;10;7"));
+        assert!(seq.ends_with("-\x1b\\"));
+        // Pure red is cube index 5*36 = 180; its first band is a full
+        // run of all-six-bits-set ('~').
+        assert!(seq.contains("#180!10~"), "sequence was {:?}", seq);
+        // Two bands of six rows.
+        assert_eq!(seq.matches('-').count(), 2);
+        assert!(
+            encode(
+                &DynamicImage::ImageRgb8(img),
+                ImageProtocol::None,
+                10,
+                10,
+                (8, 16)
+            )
+            .is_none()
+        );
+    }
+}
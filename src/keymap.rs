@@ -5,16 +5,19 @@
 // owns the contract: the `Action` enum (closed set of remappable
 // intents), the `DEFAULT_KEYMAP` mirroring VISION.md §Action
 // Keybindings, and `resolve_keymap` which folds user overrides over the
-// defaults and rejects conflicts.
+// defaults and rejects conflicts. Overrides naming an unknown action or
+// an unparseable key are skipped with a warning (the action keeps its
+// defaults) rather than refusing to start.
 //
 // The resolver returns a `Keymap` with two tables — single-key and
 // sequence — so AppRoot can dispatch atomic keys via the fast path
 // while sequences (gg, gj, gk, gr) still flow through component-local
 // prefix logic.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
 
 use crate::error::{Result, VulthorError};
 
@@ -29,6 +32,8 @@ pub enum Action {
     MoveUp,
     PageDown,
     PageUp,
+    HalfPageDown,
+    HalfPageUp,
     ViewPrev,
     ViewNext,
     FocusNext,
@@ -39,6 +44,20 @@ pub enum Action {
     JumpBottom,
     JumpNextUnread,
     JumpPrevUnread,
+    /// Move the folder-pane cursor to the next / previous folder with
+    /// unread mail.
+    JumpNextUnreadFolder,
+    JumpPrevUnreadFolder,
+    /// Collapse / expand the highlighted folder's subtree in the
+    /// folder pane (vim's `za` fold toggle).
+    ToggleFolderCollapse,
+    /// Open the fuzzy folder switcher and enter the chosen folder.
+    /// Bound to `gf`.
+    JumpToFolder,
+    /// Group the message list into conversations (and back).
+    ToggleThreads,
+    /// Fold / unfold the replies of the thread under the cursor.
+    ToggleThreadCollapse,
     // Email actions
     Archive,
     Star,
@@ -50,15 +69,33 @@ pub enum Action {
     ReplyLater,
     Forward,
     MoveToFolder,
+    /// Move to the `[spam].junk_folder` (default `Junk`).
+    MoveToJunk,
     ToggleFlag,
     MarkUnread,
+    MarkRead,
+    /// Mark read when unread, unread when read.
+    ToggleRead,
+    /// Tag / untag the cursor row for the bulk actions (archive,
+    /// delete, move, star, mark read/unread).
+    ToggleSelect,
+    /// Tag every row from the last toggled row to the cursor.
+    SelectRange,
     OpenAttachment,
+    PreviewAttachment,
+    /// Copy the selected email's body / subject / bare From address /
+    /// links to the clipboard. Bound to `yy`, `ys`, `yf`, `yl`.
+    YankBody,
+    YankSubject,
+    YankFrom,
+    YankLinks,
     // Search
     Search,
     SearchNext,
     SearchPrev,
     // View control
     ToggleContentPane,
+    ToggleThreePane,
     ToggleViewer,
     ToggleHelp,
     /// Per-session paranoia toggle — when on the Content pane refuses
@@ -70,12 +107,45 @@ pub enum Action {
     /// resets to false on every email selection change. Bound to `I`
     /// (Shift+I).
     ToggleImages,
+    /// Flip word wrap for the Content pane body; with wrap off,
+    /// Left/Right scroll horizontally.
+    ToggleWordWrap,
+    /// Expand the Content pane's header box to every header of the
+    /// message, or fold it back to the summary.
+    ToggleHeaders,
+    /// Show the message's raw RFC 5322 source in the Content pane, or
+    /// return to the formatted body.
+    ToggleSource,
+    /// Open a popup of the message's MIME parts; Enter shows one.
+    MimeTree,
+    /// Enter the pane resize mode: `h` / `l` move the current split,
+    /// `=` resets it, `Esc` leaves.
+    ResizePanes,
+    /// Open the next / previous email of the folder without leaving
+    /// the Content pane.
+    NextMessage,
+    PrevMessage,
     CycleTheme,
+    /// Switch to the next `[accounts.*]` account, wrapping after the
+    /// last. Bound to `A`.
+    NextAccount,
+    /// Rescan the current folder from disk, picking up mail the
+    /// watcher missed. Bound to `Ctrl+r`.
+    Refresh,
+    /// Open the embedded web server's view of the current email in the
+    /// system's default browser. Bound to `O`.
+    OpenInBrowser,
+    /// Open the `:` command line. Bound to `:`.
+    CommandMode,
     Quit,
     // Draft pane
     DraftSend,
+    /// Relaunch the editor on the draft; in the Messages pane, resume
+    /// the draft under the cursor.
     DraftEdit,
     DraftDiscard,
+    /// Open the file picker to attach a file. Bound to `Ctrl+a`.
+    DraftAttach,
 }
 
 /// Pane context an [`Action`] is most naturally associated with. Drives
@@ -128,6 +198,8 @@ impl Action {
             Action::MoveUp => "move_up",
             Action::PageDown => "page_down",
             Action::PageUp => "page_up",
+            Action::HalfPageDown => "half_page_down",
+            Action::HalfPageUp => "half_page_up",
             Action::ViewPrev => "view_prev",
             Action::ViewNext => "view_next",
             Action::FocusNext => "focus_next",
@@ -138,6 +210,12 @@ impl Action {
             Action::JumpBottom => "jump_bottom",
             Action::JumpNextUnread => "jump_next_unread",
             Action::JumpPrevUnread => "jump_prev_unread",
+            Action::JumpNextUnreadFolder => "jump_next_unread_folder",
+            Action::JumpPrevUnreadFolder => "jump_prev_unread_folder",
+            Action::ToggleFolderCollapse => "toggle_folder_collapse",
+            Action::JumpToFolder => "jump_to_folder",
+            Action::ToggleThreads => "toggle_threads",
+            Action::ToggleThreadCollapse => "toggle_thread_collapse",
             Action::Archive => "archive",
             Action::Star => "star",
             Action::Delete => "delete",
@@ -148,22 +226,45 @@ impl Action {
             Action::ReplyLater => "reply_later",
             Action::Forward => "forward",
             Action::MoveToFolder => "move_to_folder",
+            Action::MoveToJunk => "move_to_junk",
             Action::ToggleFlag => "toggle_flag",
             Action::MarkUnread => "mark_unread",
+            Action::MarkRead => "mark_read",
+            Action::ToggleRead => "toggle_read",
+            Action::ToggleSelect => "toggle_select",
+            Action::SelectRange => "select_range",
             Action::OpenAttachment => "open_attachment",
+            Action::PreviewAttachment => "preview_attachment",
+            Action::YankBody => "yank_body",
+            Action::YankSubject => "yank_subject",
+            Action::YankFrom => "yank_from",
+            Action::YankLinks => "yank_links",
             Action::Search => "search",
             Action::SearchNext => "search_next",
             Action::SearchPrev => "search_prev",
             Action::ToggleContentPane => "toggle_content_pane",
+            Action::ToggleThreePane => "toggle_three_pane",
             Action::ToggleViewer => "toggle_viewer",
             Action::ToggleHelp => "toggle_help",
             Action::ToggleHtmlOff => "toggle_html_off",
             Action::ToggleImages => "toggle_images",
+            Action::ToggleWordWrap => "toggle_word_wrap",
+            Action::ToggleHeaders => "toggle_headers",
+            Action::ToggleSource => "toggle_source",
+            Action::MimeTree => "mime_tree",
+            Action::ResizePanes => "resize_panes",
+            Action::NextMessage => "next_message",
+            Action::PrevMessage => "prev_message",
             Action::CycleTheme => "cycle_theme",
+            Action::NextAccount => "next_account",
+            Action::Refresh => "refresh",
+            Action::OpenInBrowser => "open_in_browser",
+            Action::CommandMode => "command_mode",
             Action::Quit => "quit",
             Action::DraftSend => "draft_send",
             Action::DraftEdit => "draft_edit",
             Action::DraftDiscard => "draft_discard",
+            Action::DraftAttach => "draft_attach",
         }
     }
 
@@ -179,6 +280,8 @@ impl Action {
             | Action::MoveUp
             | Action::PageDown
             | Action::PageUp
+            | Action::HalfPageDown
+            | Action::HalfPageUp
             | Action::ViewPrev
             | Action::ViewNext
             | Action::FocusNext
@@ -188,9 +291,16 @@ impl Action {
             | Action::JumpTop
             | Action::JumpBottom
             | Action::ToggleContentPane
+            | Action::ToggleThreePane
+            | Action::ResizePanes
             | Action::ToggleViewer
             | Action::ToggleHelp
             | Action::CycleTheme
+            | Action::NextAccount
+            | Action::Refresh
+            | Action::OpenInBrowser
+            | Action::CommandMode
+            | Action::JumpToFolder
             | Action::Quit
             | Action::Search
             | Action::SearchNext
@@ -206,18 +316,41 @@ impl Action {
             | Action::ReplyLater
             | Action::Forward
             | Action::MoveToFolder
+            | Action::MoveToJunk
             | Action::ToggleFlag
             | Action::MarkUnread
+            | Action::MarkRead
+            | Action::ToggleRead
+            | Action::ToggleSelect
+            | Action::SelectRange
             | Action::JumpNextUnread
-            | Action::JumpPrevUnread => PaneScope::Messages,
+            | Action::JumpPrevUnread
+            | Action::ToggleThreads
+            | Action::ToggleThreadCollapse
+            | Action::YankBody
+            | Action::YankSubject
+            | Action::YankFrom
+            | Action::YankLinks => PaneScope::Messages,
+            // Fold toggle and unread-folder steps only exist in the
+            // folder tree.
+            Action::ToggleFolderCollapse
+            | Action::JumpNextUnreadFolder
+            | Action::JumpPrevUnreadFolder => PaneScope::Folders,
             // Open-attachment lives where the attachment list is.
-            Action::OpenAttachment => PaneScope::Content,
+            Action::OpenAttachment | Action::PreviewAttachment => PaneScope::Content,
             // vu-c1s paranoia toggle reads from the Content pane state.
             Action::ToggleHtmlOff => PaneScope::Content,
             // vu-aoy image-reveal affects the Content pane (web body).
             Action::ToggleImages => PaneScope::Content,
+            Action::ToggleWordWrap
+            | Action::ToggleHeaders
+            | Action::ToggleSource
+            | Action::MimeTree => PaneScope::Content,
+            Action::NextMessage | Action::PrevMessage => PaneScope::Content,
             // Draft-pane lifecycle keys.
-            Action::DraftSend | Action::DraftEdit | Action::DraftDiscard => PaneScope::Compose,
+            Action::DraftSend | Action::DraftEdit | Action::DraftDiscard | Action::DraftAttach => {
+                PaneScope::Compose
+            }
         }
     }
 
@@ -230,6 +363,8 @@ impl Action {
             Action::MoveUp => "Move up",
             Action::PageDown => "Page down",
             Action::PageUp => "Page up",
+            Action::HalfPageDown => "Half page down",
+            Action::HalfPageUp => "Half page up",
             Action::ViewPrev => "Previous view (broader)",
             Action::ViewNext => "Next view (deeper)",
             Action::FocusNext => "Focus next pane",
@@ -240,6 +375,12 @@ impl Action {
             Action::JumpBottom => "Jump to bottom",
             Action::JumpNextUnread => "Jump to next unread",
             Action::JumpPrevUnread => "Jump to previous unread",
+            Action::JumpNextUnreadFolder => "Next folder with unread mail",
+            Action::JumpPrevUnreadFolder => "Previous folder with unread mail",
+            Action::ToggleFolderCollapse => "Collapse / expand folder",
+            Action::JumpToFolder => "Jump to folder by name",
+            Action::ToggleThreads => "Toggle threaded view",
+            Action::ToggleThreadCollapse => "Collapse / expand thread",
             Action::Archive => "Archive email",
             Action::Star => "Toggle star",
             Action::Delete => "Delete (move to Trash)",
@@ -250,22 +391,45 @@ impl Action {
             Action::ReplyLater => "Reply later (empty draft)",
             Action::Forward => "Forward email",
             Action::MoveToFolder => "Move to folder",
+            Action::MoveToJunk => "Move to Junk",
             Action::ToggleFlag => "Toggle flag",
             Action::MarkUnread => "Mark unread",
+            Action::MarkRead => "Mark read",
+            Action::ToggleRead => "Toggle read / unread",
+            Action::ToggleSelect => "Tag / untag email",
+            Action::SelectRange => "Tag range to cursor",
             Action::OpenAttachment => "Open attachment",
-            Action::Search => "Search (notmuch)",
+            Action::PreviewAttachment => "Preview text attachment",
+            Action::YankBody => "Copy body to clipboard",
+            Action::YankSubject => "Copy subject to clipboard",
+            Action::YankFrom => "Copy sender address to clipboard",
+            Action::YankLinks => "Copy links to clipboard",
+            Action::Search => "Search (notmuch; Messages / Content: find)",
             Action::SearchNext => "Next search hit",
             Action::SearchPrev => "Previous search hit",
             Action::ToggleContentPane => "Toggle content pane",
-            Action::ToggleViewer => "Toggle web viewer",
+            Action::ToggleThreePane => "Toggle three-pane layout",
+            Action::ToggleViewer => "Toggle web viewer (Content: text/HTML part)",
             Action::ToggleHelp => "Toggle this help",
             Action::ToggleHtmlOff => "Force plain-text body",
             Action::ToggleImages => "Reveal images for this message",
+            Action::ToggleWordWrap => "Toggle word wrap",
+            Action::ToggleHeaders => "Show all headers / fold back",
+            Action::ToggleSource => "Show raw message source / formatted body",
+            Action::MimeTree => "Browse the message's MIME parts",
+            Action::ResizePanes => "Resize panes (h/l, = reset, Esc done)",
+            Action::NextMessage => "Read next message",
+            Action::PrevMessage => "Read previous message",
             Action::CycleTheme => "Cycle theme preset",
+            Action::NextAccount => "Switch to the next account",
+            Action::Refresh => "Rescan the current folder",
+            Action::OpenInBrowser => "Open the email in the default browser",
+            Action::CommandMode => "Open the command line",
             Action::Quit => "Quit Vulthor",
             Action::DraftSend => "Send draft",
-            Action::DraftEdit => "Edit draft in $EDITOR",
-            Action::DraftDiscard => "Discard draft",
+            Action::DraftEdit => "Edit draft in $EDITOR (Messages: resume a draft)",
+            Action::DraftDiscard => "Close draft (asks to save it)",
+            Action::DraftAttach => "Attach a file to the draft",
         }
     }
 
@@ -278,6 +442,8 @@ impl Action {
             Action::MoveUp,
             Action::PageDown,
             Action::PageUp,
+            Action::HalfPageDown,
+            Action::HalfPageUp,
             Action::ViewPrev,
             Action::ViewNext,
             Action::FocusNext,
@@ -288,6 +454,12 @@ impl Action {
             Action::JumpBottom,
             Action::JumpNextUnread,
             Action::JumpPrevUnread,
+            Action::JumpNextUnreadFolder,
+            Action::JumpPrevUnreadFolder,
+            Action::ToggleFolderCollapse,
+            Action::JumpToFolder,
+            Action::ToggleThreads,
+            Action::ToggleThreadCollapse,
             Action::Archive,
             Action::Star,
             Action::Delete,
@@ -298,29 +470,104 @@ impl Action {
             Action::ReplyLater,
             Action::Forward,
             Action::MoveToFolder,
+            Action::MoveToJunk,
             Action::ToggleFlag,
             Action::MarkUnread,
+            Action::MarkRead,
+            Action::ToggleRead,
+            Action::ToggleSelect,
+            Action::SelectRange,
             Action::OpenAttachment,
+            Action::PreviewAttachment,
+            Action::YankBody,
+            Action::YankSubject,
+            Action::YankFrom,
+            Action::YankLinks,
             Action::Search,
             Action::SearchNext,
             Action::SearchPrev,
             Action::ToggleContentPane,
+            Action::ToggleThreePane,
             Action::ToggleViewer,
             Action::ToggleHelp,
             Action::ToggleHtmlOff,
             Action::ToggleImages,
+            Action::ToggleWordWrap,
+            Action::ToggleHeaders,
+            Action::ToggleSource,
+            Action::MimeTree,
+            Action::ResizePanes,
+            Action::NextMessage,
+            Action::PrevMessage,
             Action::CycleTheme,
+            Action::NextAccount,
+            Action::Refresh,
+            Action::OpenInBrowser,
+            Action::CommandMode,
             Action::Quit,
             Action::DraftSend,
             Action::DraftEdit,
             Action::DraftDiscard,
+            Action::DraftAttach,
         ]
     }
 
     /// Parse a TOML name back into an `Action`. Returns `None` for
     /// unknown names so the resolver can raise a structured error.
     pub fn from_name(name: &str) -> Option<Action> {
-        Action::all().iter().copied().find(|a| a.name() == name)
+        Action::all()
+            .iter()
+            .copied()
+            .find(|a| a.name() == name)
+            .or_else(|| {
+                ACTION_ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == name)
+                    .map(|(_, a)| *a)
+            })
+    }
+}
+
+/// Extra names accepted in `[keybindings]` for a few actions, so the
+/// spellings people reach for first (`next_view`, `scroll_down`) work
+/// too. [`Action::name`] stays the canonical one.
+const ACTION_ALIASES: &[(&str, Action)] = &[
+    ("next_view", Action::ViewNext),
+    ("prev_view", Action::ViewPrev),
+    ("scroll_down", Action::MoveDown),
+    ("scroll_up", Action::MoveUp),
+    ("toggle_content", Action::ToggleContentPane),
+];
+
+/// Right-hand side of a `[keybindings]` entry: one key string, or a
+/// list of them (`move_down = ["j", "Down"]`) to keep several keys on
+/// one action.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum KeySpec {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeySpec {
+    /// The key strings, in the order written.
+    pub fn keys(&self) -> &[String] {
+        match self {
+            KeySpec::One(key) => std::slice::from_ref(key),
+            KeySpec::Many(keys) => keys,
+        }
+    }
+}
+
+impl From<&str> for KeySpec {
+    fn from(key: &str) -> Self {
+        KeySpec::One(key.to_string())
+    }
+}
+
+impl From<String> for KeySpec {
+    fn from(key: String) -> Self {
+        KeySpec::One(key)
     }
 }
 
@@ -340,7 +587,11 @@ pub const DEFAULT_KEYMAP: &[(Action, &str)] = &[
     (Action::MoveUp, "k"),
     (Action::MoveUp, "Up"),
     (Action::PageDown, "PageDown"),
+    (Action::PageDown, "Ctrl+f"),
     (Action::PageUp, "PageUp"),
+    (Action::PageUp, "Ctrl+b"),
+    (Action::HalfPageDown, "Ctrl+d"),
+    (Action::HalfPageUp, "Ctrl+u"),
     (Action::ViewPrev, "h"),
     (Action::ViewNext, "l"),
     (Action::FocusNext, "Tab"),
@@ -348,39 +599,76 @@ pub const DEFAULT_KEYMAP: &[(Action, &str)] = &[
     (Action::Confirm, "Enter"),
     (Action::Back, "Backspace"),
     (Action::JumpTop, "gg"),
+    (Action::JumpTop, "Home"),
     (Action::JumpBottom, "G"),
+    (Action::JumpBottom, "End"),
+    (Action::JumpNextUnread, "]"),
     (Action::JumpNextUnread, "gj"),
+    (Action::JumpPrevUnread, "["),
     (Action::JumpPrevUnread, "gk"),
+    (Action::JumpNextUnreadFolder, "}"),
+    (Action::JumpPrevUnreadFolder, "{"),
+    (Action::ToggleFolderCollapse, "za"),
+    (Action::JumpToFolder, "gf"),
+    (Action::JumpToFolder, "Ctrl+p"),
+    (Action::ToggleThreads, "T"),
+    (Action::ToggleThreadCollapse, "Alt+v"),
     // Email actions
     (Action::Archive, "a"),
     (Action::Star, "s"),
     (Action::Delete, "d"),
     (Action::AcceptSuggestion, ";"),
     (Action::Undo, "u"),
+    (Action::Undo, "Ctrl+z"),
     (Action::ReplyAll, "r"),
     (Action::Reply, "gr"),
     (Action::ReplyLater, "R"),
     (Action::Forward, "f"),
     (Action::MoveToFolder, "m"),
+    (Action::MoveToJunk, "!"),
     (Action::ToggleFlag, "F"),
     (Action::MarkUnread, "U"),
+    (Action::MarkRead, "M"),
+    (Action::ToggleRead, "Alt+u"),
+    (Action::ToggleSelect, "Space"),
+    (Action::SelectRange, "V"),
     (Action::OpenAttachment, "o"),
+    (Action::PreviewAttachment, "p"),
+    (Action::YankBody, "yy"),
+    (Action::YankSubject, "ys"),
+    (Action::YankFrom, "yf"),
+    (Action::YankLinks, "yl"),
     // Search
     (Action::Search, "/"),
     (Action::SearchNext, "n"),
     (Action::SearchPrev, "N"),
     // View control
     (Action::ToggleContentPane, "Alt+c"),
+    (Action::ToggleThreePane, "Alt+3"),
     (Action::ToggleViewer, "v"),
     (Action::ToggleHelp, "?"),
     (Action::ToggleHtmlOff, "P"),
     (Action::ToggleImages, "I"),
+    (Action::ToggleWordWrap, "w"),
+    (Action::ToggleHeaders, "H"),
+    (Action::ToggleSource, "gs"),
+    (Action::MimeTree, "Alt+m"),
+    (Action::ResizePanes, "Ctrl+w"),
+    (Action::NextMessage, "J"),
+    (Action::NextMessage, "Ctrl+j"),
+    (Action::PrevMessage, "K"),
+    (Action::PrevMessage, "Ctrl+k"),
     (Action::CycleTheme, "Ctrl+t"),
+    (Action::NextAccount, "A"),
+    (Action::Refresh, "Ctrl+r"),
+    (Action::OpenInBrowser, "O"),
+    (Action::CommandMode, ":"),
     (Action::Quit, "q"),
     // Draft pane
     (Action::DraftSend, "S"),
     (Action::DraftEdit, "e"),
     (Action::DraftDiscard, "Esc"),
+    (Action::DraftAttach, "Ctrl+a"),
 ];
 
 /// Parse a key-string into a sequence of `KeyEvent`s. Length 1 for
@@ -393,18 +681,23 @@ pub const DEFAULT_KEYMAP: &[(Action, &str)] = &[
 /// - modified atomic: `Alt+c`, `Ctrl+x`, `Shift+Tab`
 /// - sequence: any other multi-character ASCII string is decomposed
 ///   into one `Char` event per character (e.g. `gr` → `g` then `r`).
+///
+/// Modifier prefixes and key names are case-insensitive (`alt+c`,
+/// `down`); the character after a modifier is taken as written.
 pub fn parse_key_string(s: &str) -> std::result::Result<Vec<KeyEvent>, String> {
     if s.is_empty() {
         return Err("empty key string".into());
     }
-    if let Some(rest) = s.strip_prefix("Alt+") {
-        return Ok(vec![parse_modified(rest, KeyModifiers::ALT)?]);
-    }
-    if let Some(rest) = s.strip_prefix("Ctrl+") {
-        return Ok(vec![parse_modified(rest, KeyModifiers::CONTROL)?]);
-    }
-    if let Some(rest) = s.strip_prefix("Shift+") {
-        return Ok(vec![parse_modified(rest, KeyModifiers::SHIFT)?]);
+    for (prefix, mods) in [
+        ("Alt+", KeyModifiers::ALT),
+        ("Ctrl+", KeyModifiers::CONTROL),
+        ("Shift+", KeyModifiers::SHIFT),
+    ] {
+        if let Some(head) = s.get(..prefix.len())
+            && head.eq_ignore_ascii_case(prefix)
+        {
+            return Ok(vec![parse_modified(&s[prefix.len()..], mods)?]);
+        }
     }
     if let Some(ev) = parse_named(s) {
         return Ok(vec![ev]);
@@ -436,21 +729,21 @@ fn parse_modified(rest: &str, mods: KeyModifiers) -> std::result::Result<KeyEven
 }
 
 fn parse_named(s: &str) -> Option<KeyEvent> {
-    let code = match s {
-        "Enter" => KeyCode::Enter,
-        "Backspace" => KeyCode::Backspace,
-        "Esc" => KeyCode::Esc,
-        "Tab" => KeyCode::Tab,
-        "BackTab" => KeyCode::BackTab,
-        "Up" => KeyCode::Up,
-        "Down" => KeyCode::Down,
-        "Left" => KeyCode::Left,
-        "Right" => KeyCode::Right,
-        "PageUp" => KeyCode::PageUp,
-        "PageDown" => KeyCode::PageDown,
-        "Home" => KeyCode::Home,
-        "End" => KeyCode::End,
-        "Space" => KeyCode::Char(' '),
+    let code = match s.to_ascii_lowercase().as_str() {
+        "enter" => KeyCode::Enter,
+        "backspace" => KeyCode::Backspace,
+        "esc" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "space" => KeyCode::Char(' '),
         _ => return None,
     };
     Some(KeyEvent::new(code, KeyModifiers::NONE))
@@ -475,6 +768,9 @@ pub struct Keymap {
     /// so it shows the exact key string a user would type (e.g. `Alt+c`,
     /// `gr`, `Down`) rather than re-printing a `KeyEvent`.
     bindings: Vec<(Action, String)>,
+    /// One line per `[keybindings]` entry that was skipped (unknown
+    /// action or unparseable key); that action kept its defaults.
+    warnings: Vec<String>,
 }
 
 impl Keymap {
@@ -522,6 +818,20 @@ impl Keymap {
         self.bindings.iter().map(|(a, s)| (*a, s.as_str()))
     }
 
+    /// First key string bound to `action`, as the user would type it.
+    /// `None` when an override left the action unbound.
+    pub fn key_for(&self, action: Action) -> Option<&str> {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, s)| s.as_str())
+    }
+
+    /// Overrides skipped while resolving; see [`resolve_keymap`].
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
     /// Crossterm reports uppercase ASCII letters as `Char(c)` with
     /// `SHIFT` on some terminals and `NONE` on others. We canonicalise
     /// to NONE so DEFAULT_KEYMAP entries match either way.
@@ -543,7 +853,11 @@ impl Keymap {
 /// `j` and the arrow `Down` for MoveDown. Conflict detection runs after
 /// override application: rebinding `archive` to `e` without freeing the
 /// default `e`-bound `draft_edit` is still a structured error.
-pub fn resolve_keymap(overrides: &BTreeMap<String, String>) -> Result<Keymap> {
+///
+/// An override naming an unknown action, or with a key string that
+/// doesn't parse, is skipped: the action keeps its defaults and the
+/// reason lands in [`Keymap::warnings`].
+pub fn resolve_keymap(overrides: &BTreeMap<String, KeySpec>) -> Result<Keymap> {
     // Defaults as `(action, key)` pairs — `Vec` (not `BTreeMap`) so a
     // single action can carry multiple keys (e.g. MoveDown ↔ j, Down).
     let mut bindings: Vec<(Action, String)> = DEFAULT_KEYMAP
@@ -552,18 +866,55 @@ pub fn resolve_keymap(overrides: &BTreeMap<String, String>) -> Result<Keymap> {
         .collect();
 
     // Apply overrides. Each entry retires every default binding for
-    // that action, then claims the user-chosen key. Unknown action
-    // names are rejected up-front so typos don't silently no-op.
-    for (action_name, key_string) in overrides.iter() {
+    // that action, then claims the user-chosen keys. Typos are
+    // reported rather than silently no-op'd, but don't stop startup.
+    let mut warnings = Vec::new();
+    let mut overridden: HashSet<Action> = HashSet::new();
+    for (action_name, spec) in overrides.iter() {
         let Some(action) = Action::from_name(action_name) else {
-            return Err(VulthorError::KeybindingUnknownAction {
+            let err = VulthorError::KeybindingUnknownAction {
                 action: action_name.clone(),
-            });
+            };
+            warnings.push(err.to_string());
+            continue;
         };
+        let bad_key = spec
+            .keys()
+            .iter()
+            .find_map(|key| match parse_key_string(key) {
+                Ok(_) => None,
+                Err(reason) => Some(VulthorError::KeybindingInvalidKey {
+                    key: key.clone(),
+                    action: action_name.clone(),
+                    reason,
+                }),
+            });
+        if let Some(err) = bad_key {
+            warnings.push(err.to_string());
+            continue;
+        }
         bindings.retain(|(a, _)| *a != action);
-        bindings.push((action, key_string.clone()));
+        bindings.extend(spec.keys().iter().map(|key| (action, key.clone())));
+        overridden.insert(action);
     }
 
+    // A key the user bound on its own would otherwise sit waiting for
+    // the rest of any default sequence it starts (`star = "y"` against
+    // the `yy` / `ys` yanks), so those defaults give way to it.
+    let user_singles: HashSet<KeyEvent> = bindings
+        .iter()
+        .filter(|(a, _)| overridden.contains(a))
+        .filter_map(|(_, key)| match parse_key_string(key).ok()?.as_slice() {
+            [event] => Some(*event),
+            _ => None,
+        })
+        .collect();
+    bindings.retain(|(a, key)| {
+        overridden.contains(a)
+            || !matches!(parse_key_string(key).as_deref(),
+                Ok([first, _, ..]) if user_singles.contains(first))
+    });
+
     // Materialise to the two lookup tables, raising on either a parse
     // failure or a key-string collision.
     let mut single: HashMap<KeyEvent, Action> = HashMap::new();
@@ -633,6 +984,7 @@ pub fn resolve_keymap(overrides: &BTreeMap<String, String>) -> Result<Keymap> {
         single,
         sequences,
         bindings,
+        warnings,
     })
 }
 
@@ -699,10 +1051,10 @@ mod tests {
         // assert the override sticks AND the prior default `a` no
         // longer maps to Archive.
         let mut overrides = BTreeMap::new();
-        overrides.insert("archive".to_string(), "e".to_string());
+        overrides.insert("archive".to_string(), "e".into());
         // Also rebind `draft_edit` so `e` is free for `archive` —
         // otherwise the conflict check fires (see next test).
-        overrides.insert("draft_edit".to_string(), "E".to_string());
+        overrides.insert("draft_edit".to_string(), "E".into());
 
         let map = resolve_keymap(&overrides).expect("overrides resolve");
 
@@ -730,7 +1082,7 @@ mod tests {
         // (DraftEdit). resolve_keymap must reject with a structured
         // error naming both action keys.
         let mut overrides = BTreeMap::new();
-        overrides.insert("archive".to_string(), "e".to_string());
+        overrides.insert("archive".to_string(), "e".into());
 
         let err = resolve_keymap(&overrides).expect_err("conflict expected");
         match err {
@@ -749,16 +1101,40 @@ mod tests {
     }
 
     #[test]
-    fn unknown_action_name_is_rejected() {
+    fn unknown_action_and_bad_key_warn_and_keep_defaults() {
         let mut overrides = BTreeMap::new();
-        overrides.insert("teleport".to_string(), "t".to_string());
-        let err = resolve_keymap(&overrides).expect_err("unknown action");
-        match err {
-            VulthorError::KeybindingUnknownAction { action } => {
-                assert_eq!(action, "teleport");
-            }
-            other => panic!("unexpected error: {other:?}"),
-        }
+        overrides.insert("teleport".to_string(), "t".into());
+        overrides.insert("quit".to_string(), "Ctrl+xy".into());
+        let map = resolve_keymap(&overrides).expect("typos only warn");
+        assert_eq!(
+            map.warnings(),
+            [
+                "Invalid key string 'Ctrl+xy' for action 'quit': cannot apply modifier to multi-char key 'xy'",
+                "Unknown action 'teleport' in [keybindings]",
+            ]
+        );
+        assert_eq!(map.lookup_single(char_event('q')), Some(Action::Quit));
+        assert_eq!(map.lookup_single(char_event('t')), None);
+    }
+
+    #[test]
+    fn list_overrides_aliases_and_lowercase_chords_resolve() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert(
+            "scroll_down".to_string(),
+            KeySpec::Many(vec!["x".to_string(), "down".to_string()]),
+        );
+        overrides.insert("toggle_content".to_string(), "alt+z".into());
+        let map = resolve_keymap(&overrides).expect("overrides resolve");
+        assert!(map.warnings().is_empty());
+        assert_eq!(map.lookup_single(char_event('x')), Some(Action::MoveDown));
+        let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        assert_eq!(map.lookup_single(down), Some(Action::MoveDown));
+        assert_eq!(map.lookup_single(char_event('j')), None);
+        let alt_z = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::ALT);
+        assert_eq!(map.lookup_single(alt_z), Some(Action::ToggleContentPane));
+        assert_eq!(map.key_for(Action::MoveDown), Some("x"));
+        assert_eq!(map.key_for(Action::ToggleContentPane), Some("alt+z"));
     }
 
     #[test]
@@ -819,7 +1195,7 @@ mod tests {
         // the user's mental model breaks: they explicitly renamed the
         // action, but ghosts of the old bindings keep firing.
         let mut overrides = BTreeMap::new();
-        overrides.insert("move_down".to_string(), "x".to_string());
+        overrides.insert("move_down".to_string(), "x".into());
 
         let map = resolve_keymap(&overrides).expect("override resolves");
 
@@ -860,10 +1236,10 @@ mod tests {
         // rebindable from `[keybindings]`. The bead's TDD anchor
         // exercises this for the new PageDown key: a user can rehome
         // `jump_next_unread` to `PageDown` by first freeing the key
-        // (`page_down = "Ctrl+d"`) and then claiming it.
+        // (`page_down = "Ctrl+n"`) and then claiming it.
         let mut overrides = BTreeMap::new();
-        overrides.insert("page_down".to_string(), "Ctrl+d".to_string());
-        overrides.insert("jump_next_unread".to_string(), "PageDown".to_string());
+        overrides.insert("page_down".to_string(), "Ctrl+n".into());
+        overrides.insert("jump_next_unread".to_string(), "PageDown".into());
 
         let map = resolve_keymap(&overrides).expect("override resolves");
 
@@ -873,11 +1249,27 @@ mod tests {
             Some(Action::JumpNextUnread),
             "PageDown must fire JumpNextUnread after the rebind",
         );
-        let ctrl_d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
+        let ctrl_n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
         assert_eq!(
-            map.lookup_single(ctrl_d),
+            map.lookup_single(ctrl_n),
             Some(Action::PageDown),
-            "page_down moved to Ctrl+d",
+            "page_down moved to Ctrl+n",
         );
     }
+
+    #[test]
+    fn single_key_override_displaces_default_sequences_it_starts() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert("star".to_string(), "y".into());
+        overrides.insert("yank_links".to_string(), "yu".into());
+
+        let map = resolve_keymap(&overrides).expect("override resolves");
+
+        assert_eq!(map.lookup_single(char_event('y')), Some(Action::Star));
+        let yy = [char_event('y'), char_event('y')];
+        assert_eq!(map.lookup_sequence(&yy), None, "default yy gives way");
+        // The user's own `y…` sequence is theirs to keep.
+        let yu = [char_event('y'), char_event('u')];
+        assert_eq!(map.lookup_sequence(&yu), Some(Action::YankLinks));
+    }
 }
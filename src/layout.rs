@@ -14,6 +14,12 @@
 // pane into the `Arc<AtomicU8>` the web server reads to decide between
 // serving the selected email and the welcome screen.
 
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use ratatui::layout::Rect;
+
 use crate::email::Folder;
 
 /// One step in the left-to-right view progression (VISION.md
@@ -43,6 +49,11 @@ pub enum View {
     /// reply draft exists for the current email.
     #[allow(dead_code)]
     ContentDraft,
+    /// Folders + Messages + Content side by side (`layout =
+    /// "three_pane"` or `Alt+3`). Stands in for every view of the
+    /// folders → messages → content progression; `h` / `l` move focus
+    /// across its panes instead of changing views.
+    ThreePane,
 }
 
 impl View {
@@ -56,6 +67,7 @@ impl View {
                 View::Messages => vec![ActivePane::Messages],
                 View::MessagesAttachments => vec![ActivePane::Messages, ActivePane::Attachments],
                 View::AccountsFolders => vec![ActivePane::Accounts, ActivePane::Folders],
+                View::ThreePane => vec![ActivePane::Folders, ActivePane::Messages],
                 _ => vec![ActivePane::Messages],
             }
         } else {
@@ -65,6 +77,11 @@ impl View {
                 View::Content => vec![ActivePane::Content],
                 View::AccountsFolders => vec![ActivePane::Accounts, ActivePane::Folders],
                 View::ContentDraft => vec![ActivePane::Content, ActivePane::Draft],
+                View::ThreePane => vec![
+                    ActivePane::Folders,
+                    ActivePane::Messages,
+                    ActivePane::Content,
+                ],
                 _ => vec![ActivePane::Messages],
             }
         }
@@ -94,6 +111,12 @@ impl View {
         }
     }
 
+    /// Whether the three-pane view stands in for this view: every
+    /// view of the folders → messages → content progression does.
+    pub fn fits_three_pane(&self) -> bool {
+        !matches!(self, View::AccountsFolders | View::ContentDraft)
+    }
+
     /// The view one step deeper (rightward, via `l`). Returns `None`
     /// at the rightmost view; respects `content_hidden` so Alt+c
     /// doesn't surface views with the hidden pane.
@@ -212,6 +235,40 @@ pub struct SelectionState {
     pub attachment_index: usize,
 }
 
+/// Where a pane landed in the last frame: its outer `Rect` (borders
+/// included) and, for list panes, the index of the first row drawn,
+/// the number of rows in the list and the screen lines each row takes.
+/// Recorded by `UI::draw` so mouse clicks can be mapped back to panes
+/// and rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneArea {
+    pub pane: ActivePane,
+    pub rect: Rect,
+    pub offset: usize,
+    pub len: usize,
+    /// 2 for the message list under `list_preview`, else 1.
+    pub row_height: usize,
+}
+
+impl PaneArea {
+    /// Whether screen cell (`x`, `y`) is inside the pane's borders.
+    pub fn inner_contains(&self, x: u16, y: u16) -> bool {
+        let r = self.rect;
+        x > r.x && x + 1 < r.right() && y > r.y && y + 1 < r.bottom()
+    }
+
+    /// List row under screen cell (`x`, `y`), counting from the top of
+    /// the list rather than the top of the pane. `None` on the borders,
+    /// outside the pane, or below the last row.
+    pub fn row_at(&self, x: u16, y: u16) -> Option<usize> {
+        if !self.inner_contains(x, y) {
+            return None;
+        }
+        let row = self.offset + usize::from(y - self.rect.y - 1) / self.row_height.max(1);
+        (row < self.len).then_some(row)
+    }
+}
+
 /// Direction passed to [`Layout::switch_pane`] for Tab / Shift-Tab.
 #[derive(Debug, Clone)]
 pub enum PaneSwitchDirection {
@@ -236,6 +293,22 @@ pub struct Layout {
     pub content_pane_hidden: bool,
     /// Attachment-pane cursor (no dedicated component yet).
     pub selection: SelectionState,
+    /// Panes drawn in the last frame, refilled by every `UI::draw`.
+    /// `RefCell` because drawing only borrows the layout.
+    pub pane_areas: RefCell<Vec<PaneArea>>,
+    /// Percent of the width for the left pane of the Folders + Messages
+    /// and Accounts + Folders views.
+    pub folder_split: u16,
+    /// Percent of the width for the Messages pane beside the Content or
+    /// Attachments pane.
+    pub message_split: u16,
+    /// `Ctrl-w` resize mode: `h` / `l` move the current view's split.
+    pub resizing: bool,
+    /// `(folder_split, message_split)` that `=` in resize mode restores.
+    default_splits: (u16, u16),
+    /// Three-pane mode: views the three-pane view covers are shown as
+    /// [`View::ThreePane`]. See [`Self::set_view`].
+    pub three_pane: bool,
 }
 
 impl Layout {
@@ -247,14 +320,92 @@ impl Layout {
             active_pane: ActivePane::Folders,
             content_pane_hidden: false,
             selection: SelectionState::default(),
+            pane_areas: RefCell::new(Vec::new()),
+            folder_split: 50,
+            message_split: 50,
+            resizing: false,
+            default_splits: (50, 50),
+            three_pane: false,
+        }
+    }
+
+    /// [`Self::new`] with the configured split percentages, clamped to
+    /// [`MIN_SPLIT`]..=[`MAX_SPLIT`].
+    pub fn with_splits(folder: u16, message: u16) -> Self {
+        let folder = folder.clamp(MIN_SPLIT, MAX_SPLIT);
+        let message = message.clamp(MIN_SPLIT, MAX_SPLIT);
+        Self {
+            folder_split: folder,
+            message_split: message,
+            default_splits: (folder, message),
+            ..Self::new()
+        }
+    }
+
+    /// The split the current view is drawn with, `None` for the
+    /// single-pane views (and the Content + Draft pair, which stays
+    /// even).
+    ///
+    /// The three-pane view resizes the Messages / Content split; with
+    /// the content pane hidden it is drawn, and resized, like Folders +
+    /// Messages.
+    pub fn split_mut(&mut self) -> Option<&mut u16> {
+        match self.current_view {
+            View::FolderMessages | View::AccountsFolders => Some(&mut self.folder_split),
+            View::ThreePane if self.content_pane_hidden => Some(&mut self.folder_split),
+            View::MessagesContent | View::MessagesAttachments | View::ThreePane => {
+                Some(&mut self.message_split)
+            }
+            View::Content | View::Messages | View::ContentDraft => None,
         }
     }
 
+    /// Move the current view's split by `delta` percent, kept within
+    /// [`MIN_SPLIT`]..=[`MAX_SPLIT`]. Returns the new value, or `None`
+    /// when the view has no split.
+    pub fn adjust_split(&mut self, delta: i16) -> Option<u16> {
+        let split = self.split_mut()?;
+        *split = (*split as i16 + delta).clamp(MIN_SPLIT as i16, MAX_SPLIT as i16) as u16;
+        Some(*split)
+    }
+
+    /// Put the current view's split back to its configured value.
+    pub fn reset_split(&mut self) -> Option<u16> {
+        let (folder, message) = self.default_splits;
+        let default = match self.current_view {
+            View::FolderMessages | View::AccountsFolders => folder,
+            View::ThreePane if self.content_pane_hidden => folder,
+            _ => message,
+        };
+        let split = self.split_mut()?;
+        *split = default;
+        Some(default)
+    }
+
+    /// The pane drawn under screen cell (`x`, `y`) in the last frame,
+    /// borders included.
+    pub fn pane_at(&self, x: u16, y: u16) -> Option<PaneArea> {
+        let position = ratatui::layout::Position { x, y };
+        self.pane_areas
+            .borrow()
+            .iter()
+            .find(|a| a.rect.contains(position))
+            .copied()
+    }
+
     /// Toggle Alt+c. Adjusts `current_view` so the displayed panes stay
     /// consistent with the new visibility, and resets focus to the new
     /// view's default pane. Mirrors the legacy `App::toggle_content_pane`.
+    /// The three-pane view stays put and just drops or regains its
+    /// Content column.
     pub fn toggle_content_pane(&mut self) {
         self.content_pane_hidden = !self.content_pane_hidden;
+        if self.current_view == View::ThreePane {
+            if self.active_pane == ActivePane::Content {
+                self.active_pane = ActivePane::Messages;
+            }
+            return;
+        }
         if self.content_pane_hidden {
             match self.current_view {
                 View::MessagesContent | View::Content => {
@@ -281,26 +432,96 @@ impl Layout {
             .get_default_active_pane(self.content_pane_hidden);
     }
 
-    /// Advance one step rightward in the view progression (`l`).
-    /// No-op when already at the rightmost view. Focus snaps to the
-    /// new view's default pane.
-    pub fn next_view(&mut self) {
-        if let Some(new_view) = self.current_view.next_view(self.content_pane_hidden) {
-            self.current_view = new_view;
+    /// Show `view`, or the three-pane view in its place when
+    /// three-pane mode is on and covers it. Focus is left to the
+    /// caller.
+    pub fn set_view(&mut self, view: View) {
+        self.current_view = if self.three_pane && view.fits_three_pane() {
+            View::ThreePane
+        } else {
+            view
+        };
+    }
+
+    /// Turn three-pane mode on or off, keeping the focused pane when
+    /// the new view shows it.
+    pub fn toggle_three_pane(&mut self) {
+        self.three_pane = !self.three_pane;
+        if self.three_pane {
+            self.set_view(self.current_view);
+        } else if self.current_view == View::ThreePane {
+            self.current_view = match self.active_pane {
+                ActivePane::Folders => View::FolderMessages,
+                _ if self.content_pane_hidden => View::Messages,
+                _ => View::MessagesContent,
+            };
+        }
+        let panes = self
+            .current_view
+            .get_available_panes(self.content_pane_hidden);
+        if !panes.contains(&self.active_pane) {
             self.active_pane = self
                 .current_view
                 .get_default_active_pane(self.content_pane_hidden);
         }
     }
 
+    /// Advance one step rightward in the view progression (`l`).
+    /// No-op when already at the rightmost view. Focus snaps to the
+    /// new view's default pane. In the three-pane view focus moves one
+    /// pane right instead.
+    pub fn next_view(&mut self) {
+        if self.current_view == View::ThreePane {
+            self.step_focus(PaneSwitchDirection::Right);
+        } else if let Some(new_view) = self.current_view.next_view(self.content_pane_hidden) {
+            self.enter_view(new_view);
+        }
+    }
+
     /// Move one step leftward in the view progression (`h`). No-op at
     /// the leftmost view. Focus snaps to the new view's default pane.
+    /// In the three-pane view focus moves one pane left instead.
     pub fn prev_view(&mut self) {
-        if let Some(new_view) = self.current_view.prev_view(self.content_pane_hidden) {
-            self.current_view = new_view;
-            self.active_pane = self
-                .current_view
-                .get_default_active_pane(self.content_pane_hidden);
+        if self.current_view == View::ThreePane {
+            self.step_focus(PaneSwitchDirection::Left);
+        } else if let Some(new_view) = self.current_view.prev_view(self.content_pane_hidden) {
+            self.enter_view(new_view);
+        }
+    }
+
+    /// Show `view` (see [`Self::set_view`]) focused on its default
+    /// pane, or on the pane `view` itself would have focused when the
+    /// three-pane view shows it instead.
+    fn enter_view(&mut self, view: View) {
+        self.set_view(view);
+        let pane = view.get_default_active_pane(self.content_pane_hidden);
+        self.active_pane = if self
+            .current_view
+            .get_available_panes(self.content_pane_hidden)
+            .contains(&pane)
+        {
+            pane
+        } else {
+            self.current_view
+                .get_default_active_pane(self.content_pane_hidden)
+        };
+    }
+
+    /// Focus the neighbouring pane of the current view, stopping at
+    /// either end rather than wrapping like Tab.
+    fn step_focus(&mut self, direction: PaneSwitchDirection) {
+        let panes = self
+            .current_view
+            .get_available_panes(self.content_pane_hidden);
+        let Some(i) = panes.iter().position(|p| *p == self.active_pane) else {
+            return;
+        };
+        let next = match direction {
+            PaneSwitchDirection::Left => i.checked_sub(1),
+            PaneSwitchDirection::Right => Some(i + 1),
+        };
+        if let Some(&pane) = next.and_then(|j| panes.get(j)) {
+            self.active_pane = pane;
         }
     }
 
@@ -345,14 +566,56 @@ impl Default for Layout {
     }
 }
 
+/// Bounds on a split percentage, from the config or `Ctrl-w`.
+pub const MIN_SPLIT: u16 = 10;
+pub const MAX_SPLIT: u16 = 90;
+
+/// Percent one `h` / `l` in resize mode moves a split.
+pub const SPLIT_STEP: i16 = 2;
+
+/// Share of the width the Folders column takes in the three-pane view;
+/// the split setting divides the rest between Messages and Content.
+pub const THREE_PANE_FOLDER_PERCENT: u16 = 20;
+
+/// Narrowest a pane may be squeezed to by a split; below twice this
+/// width the area is simply halved.
+pub const MIN_PANE_WIDTH: u16 = 15;
+
+/// Cut `area` into a left and right pane, the left taking `percent` of
+/// the width but leaving each side at least [`MIN_PANE_WIDTH`] columns.
+pub fn split_columns(area: Rect, percent: u16) -> [Rect; 2] {
+    let left = if area.width >= 2 * MIN_PANE_WIDTH {
+        ((area.width as u32 * percent.min(100) as u32 / 100) as u16)
+            .clamp(MIN_PANE_WIDTH, area.width - MIN_PANE_WIDTH)
+    } else {
+        area.width / 2
+    };
+    [
+        Rect {
+            width: left,
+            ..area
+        },
+        Rect {
+            x: area.x + left,
+            width: area.width - left,
+            ..area
+        },
+    ]
+}
+
 /// Resolve a flat display index (counting subfolders with their depth) to
 /// the path of subfolder indices the store uses. Moved out of `input.rs`
 /// — it's purely a function over the folder tree, not input handling.
+///
+/// `collapsed` holds the filesystem paths of folders whose children are
+/// hidden in the folder pane; their descendants take no display slot, so
+/// the index stays in step with what the pane actually renders.
 pub fn get_folder_path_from_display_index(
     folder: &Folder,
     display_index: usize,
+    collapsed: &HashSet<PathBuf>,
 ) -> Option<Vec<usize>> {
-    let flat = build_flat_folder_list(folder, 0);
+    let flat = build_flat_folder_list(folder, 0, collapsed);
     if display_index < flat.len() {
         let (target, _depth) = &flat[display_index];
         return find_folder_path(folder, target);
@@ -360,6 +623,36 @@ pub fn get_folder_path_from_display_index(
     None
 }
 
+/// Count every folder rendered in the folder pane (root excluded),
+/// skipping the descendants of collapsed folders.
+pub fn count_visible_folders(folder: &Folder, collapsed: &HashSet<PathBuf>) -> usize {
+    build_flat_folder_list(folder, 0, collapsed).len()
+}
+
+/// Step from display row `from` through `len` folder rows, forward or
+/// back, wrapping at the ends, to the first row `has_unread` answers
+/// true for. `from` itself is checked last, so a lone unread folder is
+/// found again from its own row. Returns the row and whether the
+/// search wrapped. `has_unread` may load the folder; `None` counts as
+/// no unread mail.
+pub fn find_next_unread_folder(
+    len: usize,
+    from: usize,
+    forward: bool,
+    mut has_unread: impl FnMut(usize) -> Option<bool>,
+) -> Option<(usize, bool)> {
+    (1..=len)
+        .map(|step| {
+            if forward {
+                (from + step) % len
+            } else {
+                (from + len - step % len) % len
+            }
+        })
+        .find(|&row| has_unread(row).unwrap_or(false))
+        .map(|row| (row, if forward { row <= from } else { row >= from }))
+}
+
 fn find_folder_path(current: &Folder, target: &Folder) -> Option<Vec<usize>> {
     if std::ptr::eq(current, target) {
         return Some(Vec::new());
@@ -373,13 +666,23 @@ fn find_folder_path(current: &Folder, target: &Folder) -> Option<Vec<usize>> {
     None
 }
 
-fn build_flat_folder_list(folder: &Folder, depth: usize) -> Vec<(&Folder, usize)> {
+/// Depth-first, display-ordered list of the folders the pane renders,
+/// paired with their depth. Children of a collapsed folder are omitted;
+/// the collapsed folder itself stays listed.
+pub fn build_flat_folder_list<'a>(
+    folder: &'a Folder,
+    depth: usize,
+    collapsed: &HashSet<PathBuf>,
+) -> Vec<(&'a Folder, usize)> {
     let mut result = Vec::new();
     if depth > 0 {
         result.push((folder, depth));
+        if collapsed.contains(&folder.path) {
+            return result;
+        }
     }
     for sub in folder.get_sorted_subfolders() {
-        result.extend(build_flat_folder_list(sub, depth + 1));
+        result.extend(build_flat_folder_list(sub, depth + 1, collapsed));
     }
     result
 }
@@ -388,6 +691,41 @@ fn build_flat_folder_list(folder: &Folder, depth: usize) -> Vec<(&Folder, usize)
 mod tests {
     use super::*;
 
+    #[test]
+    fn pane_area_maps_clicks_to_list_rows_inside_the_borders() {
+        let area = PaneArea {
+            pane: ActivePane::Messages,
+            rect: Rect::new(40, 0, 40, 24),
+            offset: 10,
+            len: 15,
+            row_height: 1,
+        };
+        assert_eq!(area.row_at(41, 1), Some(10));
+        assert_eq!(area.row_at(78, 4), Some(13));
+        // Borders.
+        assert_eq!(area.row_at(40, 4), None);
+        assert_eq!(area.row_at(79, 4), None);
+        assert_eq!(area.row_at(50, 0), None);
+        assert_eq!(area.row_at(50, 23), None);
+        // Below the last email.
+        assert_eq!(area.row_at(50, 6), None);
+        assert!(area.inner_contains(50, 6));
+
+        let layout = Layout::new();
+        layout.pane_areas.borrow_mut().push(area);
+        assert_eq!(layout.pane_at(40, 23), Some(area));
+        assert_eq!(layout.pane_at(39, 5), None);
+
+        // Two-line rows: both lines of an entry map to it.
+        let tall = PaneArea {
+            row_height: 2,
+            ..area
+        };
+        assert_eq!(tall.row_at(41, 1), Some(10));
+        assert_eq!(tall.row_at(41, 2), Some(10));
+        assert_eq!(tall.row_at(41, 3), Some(11));
+    }
+
     #[test]
     fn accounts_folders_view_exposes_accounts_then_folders() {
         let panes = View::AccountsFolders.get_available_panes(false);
@@ -469,4 +807,160 @@ mod tests {
         assert!(ActivePane::Attachments.serves_email());
         assert!(ActivePane::Draft.serves_email());
     }
+
+    /// Tree used by the collapse tests, in display order:
+    /// `A`, `A/x`, `A/x/deep`, `A/y`, `B`, `B/z`.
+    fn collapse_tree() -> Folder {
+        fn f(name: &str, path: &str) -> Folder {
+            Folder::new(name.to_string(), PathBuf::from(path))
+        }
+        let mut root = f("Mail", "/m");
+        let mut a = f("A", "/m/A");
+        let mut x = f("x", "/m/A/x");
+        x.add_subfolder(f("deep", "/m/A/x/deep"));
+        a.add_subfolder(x);
+        a.add_subfolder(f("y", "/m/A/y"));
+        let mut b = f("B", "/m/B");
+        b.add_subfolder(f("z", "/m/B/z"));
+        root.add_subfolder(a);
+        root.add_subfolder(b);
+        root
+    }
+
+    #[test]
+    fn display_index_maps_every_folder_when_nothing_collapsed() {
+        let root = collapse_tree();
+        let none = HashSet::new();
+        assert_eq!(count_visible_folders(&root, &none), 6);
+        assert_eq!(
+            get_folder_path_from_display_index(&root, 2, &none),
+            Some(vec![0, 0, 0])
+        );
+        assert_eq!(
+            get_folder_path_from_display_index(&root, 4, &none),
+            Some(vec![1])
+        );
+    }
+
+    #[test]
+    fn collapsed_subtree_is_skipped_by_display_index() {
+        let root = collapse_tree();
+        let collapsed: HashSet<PathBuf> = [PathBuf::from("/m/A")].into_iter().collect();
+        // Visible: A, B, B/z.
+        assert_eq!(count_visible_folders(&root, &collapsed), 3);
+        assert_eq!(
+            get_folder_path_from_display_index(&root, 0, &collapsed),
+            Some(vec![0])
+        );
+        assert_eq!(
+            get_folder_path_from_display_index(&root, 1, &collapsed),
+            Some(vec![1])
+        );
+        assert_eq!(
+            get_folder_path_from_display_index(&root, 2, &collapsed),
+            Some(vec![1, 0])
+        );
+        assert_eq!(
+            get_folder_path_from_display_index(&root, 3, &collapsed),
+            None
+        );
+    }
+
+    #[test]
+    fn collapsing_a_nested_folder_hides_only_its_descendants() {
+        let root = collapse_tree();
+        let collapsed: HashSet<PathBuf> = [PathBuf::from("/m/A/x")].into_iter().collect();
+        // Visible: A, A/x, A/y, B, B/z.
+        assert_eq!(count_visible_folders(&root, &collapsed), 5);
+        assert_eq!(
+            get_folder_path_from_display_index(&root, 2, &collapsed),
+            Some(vec![0, 1])
+        );
+        assert_eq!(
+            get_folder_path_from_display_index(&root, 3, &collapsed),
+            Some(vec![1])
+        );
+    }
+
+    #[test]
+    fn find_next_unread_folder_steps_both_ways_and_reports_wrapping() {
+        let unread = [false, true, false, true];
+        let has = |row: usize| Some(unread[row]);
+        assert_eq!(find_next_unread_folder(4, 1, true, has), Some((3, false)));
+        assert_eq!(find_next_unread_folder(4, 3, true, has), Some((1, true)));
+        assert_eq!(find_next_unread_folder(4, 1, false, has), Some((3, true)));
+        assert_eq!(find_next_unread_folder(4, 0, false, has), Some((3, true)));
+        // A lone unread folder is found again from its own row.
+        assert_eq!(
+            find_next_unread_folder(3, 2, true, |row| Some(row == 2)),
+            Some((2, true))
+        );
+        assert_eq!(find_next_unread_folder(3, 0, true, |_| None), None);
+        assert_eq!(find_next_unread_folder(0, 0, true, has), None);
+    }
+
+    #[test]
+    fn splits_follow_the_view_and_keep_both_panes_usable() {
+        let mut layout = Layout::with_splits(25, 95);
+        assert_eq!((layout.folder_split, layout.message_split), (25, MAX_SPLIT));
+        assert_eq!(layout.adjust_split(-SPLIT_STEP), Some(23));
+        for _ in 0..20 {
+            layout.adjust_split(-SPLIT_STEP);
+        }
+        assert_eq!(layout.folder_split, MIN_SPLIT);
+        layout.current_view = View::MessagesContent;
+        assert_eq!(layout.adjust_split(-SPLIT_STEP), Some(88));
+        assert_eq!(layout.reset_split(), Some(MAX_SPLIT));
+        assert_eq!(
+            layout.folder_split, MIN_SPLIT,
+            "only the view's own split moves"
+        );
+        layout.current_view = View::Content;
+        assert_eq!(layout.adjust_split(SPLIT_STEP), None);
+
+        let [left, right] = split_columns(Rect::new(0, 0, 100, 10), 25);
+        assert_eq!((left.width, right.x, right.width), (25, 25, 75));
+        let [left, right] = split_columns(Rect::new(0, 0, 100, 10), MIN_SPLIT);
+        assert_eq!((left.width, right.width), (MIN_PANE_WIDTH, 85));
+        let [left, right] = split_columns(Rect::new(5, 0, 40, 10), MAX_SPLIT);
+        assert_eq!((left.width, right.x, right.width), (25, 30, MIN_PANE_WIDTH));
+        let [left, right] = split_columns(Rect::new(0, 0, 20, 10), MAX_SPLIT);
+        assert_eq!((left.width, right.width), (10, 10), "too narrow: halved");
+    }
+
+    #[test]
+    fn three_pane_mode_stands_in_for_the_progression_and_steps_focus() {
+        let mut layout = Layout::new();
+        layout.toggle_three_pane();
+        assert_eq!(layout.current_view, View::ThreePane);
+        assert_eq!(layout.active_pane, ActivePane::Folders);
+
+        layout.next_view();
+        layout.next_view();
+        assert_eq!(layout.active_pane, ActivePane::Content);
+        layout.next_view();
+        assert_eq!(layout.active_pane, ActivePane::Content, "no wrap");
+        layout.prev_view();
+        assert_eq!(layout.active_pane, ActivePane::Messages);
+
+        layout.set_view(View::MessagesContent);
+        assert_eq!(layout.current_view, View::ThreePane);
+        layout.set_view(View::ContentDraft);
+        assert_eq!(layout.current_view, View::ContentDraft);
+
+        layout.set_view(View::ThreePane);
+        layout.active_pane = ActivePane::Content;
+        layout.toggle_content_pane();
+        assert_eq!(layout.current_view, View::ThreePane);
+        assert_eq!(layout.active_pane, ActivePane::Messages);
+        assert_eq!(
+            View::ThreePane.get_available_panes(true),
+            vec![ActivePane::Folders, ActivePane::Messages]
+        );
+        assert_eq!(layout.split_mut().copied(), Some(layout.folder_split));
+
+        layout.toggle_three_pane();
+        assert_eq!(layout.current_view, View::Messages);
+        assert_eq!(layout.active_pane, ActivePane::Messages);
+    }
 }
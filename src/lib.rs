@@ -8,23 +8,33 @@
 //! and have no other path to internal types.
 
 pub mod classifier;
+pub mod clipboard;
 pub mod components;
 pub mod compose;
 pub mod config;
+pub mod contacts;
 pub mod crash;
 pub mod doctor;
 pub mod email;
 pub mod error;
+pub mod ical;
+pub mod image_preview;
 pub mod keymap;
 pub mod layout;
 pub mod link_check;
 pub mod log;
 pub mod maildir;
+pub mod mbox;
+pub mod mime_tree;
 pub mod sanitizer;
+pub mod signature;
+pub mod state;
 pub mod stats;
 pub mod theme;
+pub mod threading;
 pub mod ui;
 pub mod undo;
+pub mod util;
 pub mod web;
 
 #[cfg(test)]
@@ -198,14 +198,10 @@ fn extract_host(url: &str) -> Option<String> {
             return None;
         }
         &trimmed[idx + 3..]
-    } else if let Some(r) = trimmed.strip_prefix("//") {
-        r
     } else {
-        return None;
+        trimmed.strip_prefix("//")?
     };
-    let host_end = rest
-        .find(|c: char| c == '/' || c == '?' || c == '#')
-        .unwrap_or(rest.len());
+    let host_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
     let host_part = &rest[..host_end];
     // Drop user:pass@ if present.
     let host = host_part.rsplit('@').next().unwrap_or(host_part);
@@ -238,10 +234,7 @@ fn find_domain_in_text(text: &str) -> Option<String> {
                 while end < bytes.len() && is_label_char(bytes[end]) {
                     end += 1;
                 }
-                if end + 1 < bytes.len()
-                    && bytes[end] == b'.'
-                    && is_label_start(bytes[end + 1])
-                {
+                if end + 1 < bytes.len() && bytes[end] == b'.' && is_label_start(bytes[end + 1]) {
                     saw_dot = true;
                     end += 1;
                 } else {
@@ -285,7 +278,10 @@ fn domains_match(href_host: &str, text_domain: &str) -> bool {
 }
 
 fn base_domain(host: &str) -> String {
-    let host = host.strip_prefix("www.").unwrap_or(host).to_ascii_lowercase();
+    let host = host
+        .strip_prefix("www.")
+        .unwrap_or(host)
+        .to_ascii_lowercase();
     let parts: Vec<&str> = host.split('.').filter(|s| !s.is_empty()).collect();
     if parts.len() >= 2 {
         format!("{}.{}", parts[parts.len() - 2], parts[parts.len() - 1])
@@ -410,7 +406,11 @@ mod tests {
                        <a href="https://evil.tld">paypal.com</a></p>"#;
         let out = flag_spoofed_links(input);
         let count = out.matches("spoof-warn").count();
-        assert_eq!(count, 1, "expected exactly one wrap, got {}: {}", count, out);
+        assert_eq!(
+            count, 1,
+            "expected exactly one wrap, got {}: {}",
+            count, out
+        );
     }
 
     /// Surrounding text outside anchors must round-trip unchanged so
@@ -11,10 +11,12 @@
 //! The writer is intentionally tiny — `vu-61a` already routes panics
 //! through their own crash log under `~/.cache/vulthor/`, so this
 //! module exists only to prevent the eventual routine-log stream from
-//! growing without bound. The actual `tracing` / `log` integration
-//! will land in a follow-up bead; today this module ships the disk
-//! discipline so it's already in place when the framework is wired
-//! up.
+//! growing without bound.
+//!
+//! `tracing` events go to a file only when the user asks for one with
+//! `--log-file <path>` (filtered by `--log-level`, default `info`);
+//! see [`init_tracing`]. Without the flag no subscriber is installed
+//! and every `tracing` macro is a no-op.
 //!
 //! Testable seams: `RotatingLogWriter::open` + `Write` impl drive the
 //! rotation; `prune_old_logs` is pure given an explicit `now`; and
@@ -25,6 +27,7 @@ use std::fs::{self, File, OpenOptions};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::level_filters::LevelFilter;
 
 /// Default byte cap per log file before rotation, in MiB. Mirrors the
 /// `[log].max_size_mb` config field.
@@ -108,10 +111,7 @@ impl RotatingLogWriter {
         let path = dir.join(LOG_FILE_NAME);
         let file = OpenOptions::new().create(true).append(true).open(&path)?;
         let current_bytes = file.metadata()?.len();
-        let max_bytes = max_size_mb
-            .checked_mul(1024 * 1024)
-            .unwrap_or(u64::MAX)
-            .max(1);
+        let max_bytes = max_size_mb.saturating_mul(1024 * 1024).max(1);
         Ok(Self {
             dir,
             max_bytes,
@@ -163,10 +163,7 @@ impl RotatingLogWriter {
 impl Write for RotatingLogWriter {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         if self.current_bytes > 0
-            && self
-                .current_bytes
-                .saturating_add(buf.len() as u64)
-                > self.max_bytes
+            && self.current_bytes.saturating_add(buf.len() as u64) > self.max_bytes
         {
             self.rotate()?;
         }
@@ -280,6 +277,62 @@ pub fn init(config: &LogConfig) -> io::Result<RotatingLogWriter> {
     Ok(writer)
 }
 
+/// `--log-level` values, most to least severe.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => LevelFilter::ERROR,
+            LogLevel::Warn => LevelFilter::WARN,
+            LogLevel::Info => LevelFilter::INFO,
+            LogLevel::Debug => LevelFilter::DEBUG,
+            LogLevel::Trace => LevelFilter::TRACE,
+        }
+    }
+}
+
+/// Subscriber appending plain-text events at `level` and above to
+/// `path`. The file (and its directory) is created up front so a bad
+/// path is an error here rather than a panic inside the appender.
+pub fn file_subscriber(
+    path: &Path,
+    level: LogLevel,
+) -> io::Result<impl tracing::Subscriber + Send + Sync + 'static> {
+    let Some(file_name) = path.file_name() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("log path {} has no file name", path.display()),
+        ));
+    };
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(dir)?;
+    OpenOptions::new().create(true).append(true).open(path)?;
+    let writer = tracing_appender::rolling::never(dir, file_name);
+    Ok(tracing_subscriber::fmt()
+        .with_writer(writer)
+        .with_ansi(false)
+        .with_max_level(LevelFilter::from(level))
+        .finish())
+}
+
+/// Install [`file_subscriber`] as the process-wide `tracing`
+/// subscriber. Call once, before anything worth logging runs.
+pub fn init_tracing(path: &Path, level: LogLevel) -> io::Result<()> {
+    tracing::subscriber::set_global_default(file_subscriber(path, level)?).map_err(io::Error::other)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -417,9 +470,12 @@ mod tests {
         w.write_all(b"hi\n").unwrap();
         drop(w);
 
-        let removed =
-            prune_old_logs(dir.path(), 0, SystemTime::now() + Duration::from_secs(10_000_000))
-                .unwrap();
+        let removed = prune_old_logs(
+            dir.path(),
+            0,
+            SystemTime::now() + Duration::from_secs(10_000_000),
+        )
+        .unwrap();
         assert_eq!(removed, 0);
         assert!(dir.path().join("vulthor.log").exists());
     }
@@ -473,4 +529,71 @@ mod tests {
         assert!(!is_log_filename("other.log"));
         assert!(!is_log_filename("vulthor.log.1.gz"));
     }
+
+    /// Log `ev` through a subscriber built from the given CLI flags and
+    /// return the file's contents.
+    fn log_with_flags(flags: &[&str], ev: impl FnOnce()) -> (String, bool) {
+        let parent = TempDir::new().unwrap();
+        let path = parent.path().join("logs").join("test.log");
+        let path_arg = path.to_string_lossy().into_owned();
+        let mut argv = vec!["vulthor", "--log-file", path_arg.as_str()];
+        argv.extend_from_slice(flags);
+        let args = <crate::config::CliArgs as clap::Parser>::try_parse_from(argv).unwrap();
+
+        let subscriber =
+            file_subscriber(args.log_file.as_deref().unwrap(), args.log_level).unwrap();
+        let created = path.exists();
+        tracing::subscriber::with_default(subscriber, ev);
+        (read_to_string(&path), created)
+    }
+
+    #[test]
+    fn log_file_flag_records_debug_events_and_parse_warnings() {
+        let maildir = crate::test_fixtures::TestMailDir::new();
+        maildir.add_custom_email("INBOX", "corrupt", "");
+        let scan = || {
+            let scanner = crate::maildir::MaildirScanner::new(maildir.root_path.clone());
+            let mut root = scanner.scan().unwrap();
+            let inbox = root
+                .subfolders
+                .iter_mut()
+                .find(|f| f.name == "INBOX")
+                .unwrap();
+            // The chunked loader parses on this thread, so its events reach
+            // the scoped subscriber (rayon workers only see the global one).
+            scanner.load_more_folder_emails(inbox, 50).unwrap();
+        };
+
+        let (log, created) = log_with_flags(&["--log-level", "debug"], scan);
+        assert!(created, "the file exists before the first event");
+        assert!(log.contains("DEBUG"), "log was {log:?}");
+        assert!(log.contains("scanned folder structure"));
+        assert!(log.contains("loaded email headers"));
+        assert!(log.contains("WARN") && log.contains("unparseable email"));
+
+        let (log, _) = log_with_flags(&["--log-level", "warn"], scan);
+        assert!(!log.contains("scanned folder structure"), "log was {log:?}");
+        assert!(log.contains("unparseable email"));
+    }
+
+    #[test]
+    fn log_level_defaults_to_info() {
+        let args = <crate::config::CliArgs as clap::Parser>::try_parse_from(["vulthor"]).unwrap();
+        assert_eq!(args.log_level, LogLevel::Info);
+        assert!(args.log_file.is_none());
+        assert!(
+            <crate::config::CliArgs as clap::Parser>::try_parse_from([
+                "vulthor",
+                "--log-level",
+                "loud"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn file_subscriber_rejects_a_directory_path() {
+        let parent = TempDir::new().unwrap();
+        assert!(file_subscriber(parent.path(), LogLevel::Info).is_err());
+    }
 }
@@ -1,26 +1,101 @@
-use crate::email::{DraftInfo, Email, Folder};
+use crate::config::{FoldersConfig, MailboxFormat, MaildirConvention, SpamConfig};
+use crate::email::{DraftInfo, Email, Folder, FolderRole};
 use crate::error::{Result, VulthorError};
+use crate::mbox::MboxReader;
 use mail_parser::{HeaderValue, MessageParser};
+use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
+/// Whether `path` holds mail: at least one of `cur/` or `new/` exists.
+/// `tmp/` is not required — read-only archives and some sync tools
+/// leave it out, and a folder may carry only `cur/` or only `new/`.
+/// The loaders scan whichever of the two are present.
+pub fn is_maildir(path: &Path) -> bool {
+    path.join("cur").is_dir() || path.join("new").is_dir()
+}
+
 /// Read-only MailDir filesystem scanner. Walks `root_path` to build
 /// the folder tree, paged-loads message headers, and builds the
-/// drafts-by-parent-id index. Cloning is cheap (just a `PathBuf`),
-/// which lets the off-thread workers (`FolderScannerHandle`,
+/// drafts-by-parent-id index. Cloning is cheap (a `PathBuf` and the
+/// spam-header names), which lets the off-thread workers (`FolderScannerHandle`,
 /// `HeadersLoader`) own their own copy without contention.
 #[derive(Debug, Clone)]
 pub struct MaildirScanner {
     root_path: PathBuf,
+    /// Spam-header names read during header-only loads. Defaults to
+    /// [`SpamConfig::default`]; see [`Self::with_spam`].
+    spam: SpamConfig,
+    /// `[folders]` role pins applied while scanning; see
+    /// [`Self::with_folders`].
+    folders: FoldersConfig,
+    /// Configured `mailbox_format`; a file `root_path` is read as mbox
+    /// regardless. See [`Self::with_format`].
+    format: MailboxFormat,
+    /// Subfolder layout; see [`Self::with_convention`].
+    convention: MaildirConvention,
 }
 
 impl MaildirScanner {
     /// Build a scanner rooted at `root_path`. No filesystem work
     /// happens until one of the scan / load methods runs.
     pub fn new(root_path: PathBuf) -> Self {
-        Self { root_path }
+        Self {
+            root_path,
+            spam: SpamConfig::default(),
+            folders: FoldersConfig::default(),
+            format: MailboxFormat::Maildir,
+            convention: MaildirConvention::Standard,
+        }
+    }
+
+    /// Read spam headers under the names configured in `[spam]`.
+    pub fn with_spam(mut self, spam: SpamConfig) -> Self {
+        self.spam = spam;
+        self
+    }
+
+    /// Assign folder roles from `[folders]`, falling back to name
+    /// detection for roles it leaves unset.
+    pub fn with_folders(mut self, folders: FoldersConfig) -> Self {
+        self.folders = folders;
+        self
+    }
+
+    /// Read `root_path` as `format` when it is a directory. `Mbox`
+    /// turns each regular file in it into a folder.
+    pub fn with_format(mut self, format: MailboxFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Read subfolders with `convention`: nested directories, or
+    /// Maildir++ dot-prefixed ones.
+    pub fn with_convention(mut self, convention: MaildirConvention) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// Role of the folder at `path`: the `[folders]` entry naming this
+    /// path, else [`FolderRole::detect`] on its name — unless that role
+    /// is pinned to a different folder in `[folders]`.
+    fn role_for(&self, path: &Path, name: &str) -> FolderRole {
+        if let Ok(rel) = path.strip_prefix(&self.root_path) {
+            let pinned = FolderRole::ALL.into_iter().find(|role| {
+                self.folders
+                    .path_for(*role)
+                    .is_some_and(|p| Path::new(p.trim_matches('/')) == rel)
+            });
+            if let Some(role) = pinned {
+                return role;
+            }
+        }
+        match FolderRole::detect(name) {
+            role if self.folders.path_for(role).is_some() => FolderRole::Normal,
+            role => role,
+        }
     }
 
     /// Scan the MailDir structure and build folder hierarchy (fast startup - structure only)
@@ -29,20 +104,34 @@ impl MaildirScanner {
             return Err(VulthorError::MaildirPathNotFound(self.root_path.clone()));
         }
 
-        if !self.root_path.is_dir() {
+        if !self.root_path.is_dir() && !self.root_path.is_file() {
             return Err(VulthorError::MaildirPathNotDirectory(
                 self.root_path.clone(),
             ));
         }
 
         let mut root_folder = Folder::new("Mail".to_string(), self.root_path.clone());
-        self.scan_folder_structure_only(&mut root_folder, &self.root_path)?;
+        match MailboxFormat::detect(&self.root_path, self.format) {
+            MailboxFormat::Maildir => {
+                self.scan_folder_structure_only(&mut root_folder, &self.root_path)?
+            }
+            MailboxFormat::Mbox => self.scan_mbox_files(&mut root_folder),
+        }
+        tracing::debug!(
+            root = %self.root_path.display(),
+            folders = crate::layout::count_visible_folders(&root_folder, &HashSet::new()),
+            "scanned folder structure"
+        );
 
         Ok(root_folder)
     }
 
     /// Scan folder structure only (no email loading) for fast startup
     fn scan_folder_structure_only(&self, folder: &mut Folder, path: &Path) -> Result<()> {
+        if self.convention == MaildirConvention::MaildirPP && path == self.root_path {
+            self.scan_maildirpp(folder);
+            return Ok(());
+        }
         // Look for subfolders only
         if let Ok(entries) = fs::read_dir(path) {
             for entry in entries.flatten() {
@@ -60,6 +149,7 @@ impl MaildirScanner {
 
                     // Create subfolder and recursively scan its structure only
                     let mut subfolder = Folder::new(dir_name.to_string(), entry_path.clone());
+                    subfolder.role = self.role_for(&entry_path, dir_name);
                     self.scan_folder_structure_only(&mut subfolder, &entry_path)?;
                     folder.add_subfolder(subfolder);
                 }
@@ -69,6 +159,83 @@ impl MaildirScanner {
         Ok(())
     }
 
+    /// Maildir++ layout: the root's own mail becomes `INBOX`, and each
+    /// dot-prefixed maildir under it is split on `.` into nested
+    /// folders. `.Work.Projects.Alpha` yields `Work → Projects → Alpha`;
+    /// parents with no directory of their own hold no mail.
+    fn scan_maildirpp(&self, root: &mut Folder) {
+        if is_maildir(&self.root_path) {
+            let mut inbox = Folder::new("INBOX".to_string(), self.root_path.clone());
+            inbox.role = self.role_for(&self.root_path, "INBOX");
+            root.add_subfolder(inbox);
+        }
+        let mut names: Vec<String> = fs::read_dir(&self.root_path)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter(|entry| is_maildir(&entry.path()))
+                    .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+                    .filter(|name| name.starts_with('.') && name.len() > 1)
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Sorted, so `.Work` is placed before `.Work.Projects` claims it.
+        names.sort();
+        for name in names {
+            let parts: Vec<&str> = name[1..].split('.').filter(|p| !p.is_empty()).collect();
+            let mut parent = &mut *root;
+            for depth in 0..parts.len() {
+                let path = self
+                    .root_path
+                    .join(format!(".{}", parts[..=depth].join(".")));
+                let idx = match parent
+                    .subfolders
+                    .iter()
+                    .position(|f| f.name == parts[depth])
+                {
+                    Some(idx) => idx,
+                    None => {
+                        let mut folder = Folder::new(parts[depth].to_string(), path.clone());
+                        folder.role = self.role_for(&path, parts[depth]);
+                        parent.add_subfolder(folder);
+                        parent.subfolders.len() - 1
+                    }
+                };
+                parent = &mut parent.subfolders[idx];
+            }
+        }
+    }
+
+    /// One folder per mbox file: the root itself when it is a file,
+    /// else every non-hidden file directly inside it. Folders are named
+    /// after the file stem (`archive.mbox` → `archive`).
+    fn scan_mbox_files(&self, root: &mut Folder) {
+        let mut files = if self.root_path.is_file() {
+            vec![self.root_path.clone()]
+        } else {
+            fs::read_dir(&self.root_path)
+                .map(|entries| {
+                    entries
+                        .flatten()
+                        .map(|entry| entry.path())
+                        .filter(|path| path.is_file() && self.is_email_file(path))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        files.sort();
+        for path in files {
+            let name = path
+                .file_stem()
+                .and_then(|name| name.to_str())
+                .unwrap_or("Unknown")
+                .to_string();
+            let mut folder = Folder::new(name.clone(), path.clone());
+            folder.role = self.role_for(&path, &name);
+            root.add_subfolder(folder);
+        }
+    }
+
     /// Load up to `chunk_size` additional emails into a folder that is
     /// already partially loaded. Bounded paged loader: replaces the
     /// unbounded `load_folder_emails` call from the scroll-triggered
@@ -92,12 +259,15 @@ impl MaildirScanner {
         }
 
         let path = &folder.path;
-        let cur_path = path.join("cur");
-        let new_path = path.join("new");
-        let tmp_path = path.join("tmp");
-        if !(cur_path.exists() && new_path.exists() && tmp_path.exists()) {
+        if path.is_file() {
+            MboxReader::new(path.clone()).load_into(folder, &self.spam)?;
+            return Ok(folder.emails.len());
+        }
+        if !is_maildir(path) {
             return Ok(0);
         }
+        let cur_path = path.join("cur");
+        let new_path = path.join("new");
 
         let loaded: HashSet<PathBuf> = folder.emails.iter().map(|e| e.file_path.clone()).collect();
 
@@ -111,6 +281,7 @@ impl MaildirScanner {
         };
 
         let added = cur_added + new_added;
+        tracing::debug!(folder = %folder.path.display(), added, "loaded email headers");
         // No new emails despite a non-zero budget => folder is exhausted.
         if added == 0 {
             folder.is_loaded = true;
@@ -153,13 +324,15 @@ impl MaildirScanner {
 
             let mut email = Email::new(path.to_path_buf());
             email.is_unread = is_new;
-            match email.parse_headers_only() {
+            match email.parse_headers_only_with(&self.spam) {
                 Ok(()) => {
                     folder.add_email(email);
                     added += 1;
                 }
                 Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "unparseable email");
                     email.headers.subject = format!("Parse Error: {}", e);
+                    email.parse_error = Some(e.to_string());
                     folder.add_email(email);
                     added += 1;
                 }
@@ -185,19 +358,21 @@ impl MaildirScanner {
         }
 
         let path = &folder.path;
+        // An mbox has no cheap partial read: the whole file is split
+        // to find any one message, so load it all at once.
+        if path.is_file() {
+            return MboxReader::new(path.clone()).load_into(folder, &self.spam);
+        }
 
-        // Check if this is a maildir folder (contains cur/, new/, tmp/)
         let cur_path = path.join("cur");
         let new_path = path.join("new");
-        let tmp_path = path.join("tmp");
-
-        let is_maildir = cur_path.exists() && new_path.exists() && tmp_path.exists();
 
-        if is_maildir {
+        if is_maildir(path) {
             // Clear existing emails to prevent duplicates
             folder.emails.clear();
             folder.unread_count = 0;
             folder.total_count = 0;
+            folder.corrupt_count = 0;
 
             // This is a maildir folder, scan for emails with optional limit
             self.scan_emails_in_folder_with_limit(folder, &cur_path, limit)?;
@@ -207,6 +382,11 @@ impl MaildirScanner {
             }
         }
 
+        tracing::debug!(
+            folder = %folder.path.display(),
+            loaded = folder.emails.len(),
+            "loaded email headers"
+        );
         // Only mark as fully loaded if we didn't use a limit
         if limit.is_none() {
             folder.is_loaded = true;
@@ -214,7 +394,14 @@ impl MaildirScanner {
         Ok(())
     }
 
-    /// Scan emails in a specific directory with optional limit (cur or new)
+    /// Scan emails in a specific directory with optional limit (cur or new).
+    ///
+    /// Header parsing dominates load time for large folders, so the
+    /// per-file `fs::read` + `parse_headers_only` work fans out across
+    /// rayon's pool. Candidate paths are sorted by filename first and
+    /// truncated to `limit`, which keeps both the chosen subset and the
+    /// resulting `folder.emails` order deterministic regardless of
+    /// `readdir` order or thread scheduling.
     fn scan_emails_in_folder_with_limit(
         &self,
         folder: &mut Folder,
@@ -225,43 +412,41 @@ impl MaildirScanner {
             return Ok(()); // Skip if directory doesn't exist
         }
 
-        let mut count = 0;
-        for entry in WalkDir::new(dir_path).min_depth(1).max_depth(1) {
-            // Check limit early to avoid unnecessary processing
-            if let Some(limit) = limit
-                && count >= limit
-            {
-                break;
-            }
+        let is_unread = dir_path.file_name().and_then(|name| name.to_str()) == Some("new");
 
+        let mut paths = Vec::new();
+        for entry in WalkDir::new(dir_path).min_depth(1).max_depth(1) {
             let entry = entry?;
             let path = entry.path();
+            if path.is_file() && self.is_email_file(path) {
+                paths.push(path.to_path_buf());
+            }
+        }
+        paths.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+        if let Some(limit) = limit {
+            paths.truncate(limit);
+        }
 
-            if path.is_file() {
-                // Check if this looks like an email file
-                if self.is_email_file(path) {
-                    let is_unread =
-                        dir_path.file_name().and_then(|name| name.to_str()) == Some("new");
-
-                    let mut email = Email::new(path.to_path_buf());
-                    email.is_unread = is_unread;
-
-                    // Parse only headers for fast loading
-                    match email.parse_headers_only() {
-                        Ok(()) => {
-                            // Headers parsed successfully
-                            folder.add_email(email);
-                            count += 1;
-                        }
-                        Err(e) => {
-                            // If parsing fails, create a placeholder with error info
-                            email.headers.subject = format!("Parse Error: {}", e);
-                            folder.add_email(email);
-                            count += 1;
-                        }
-                    }
+        // `collect` on an indexed parallel iterator preserves input
+        // order, so the sorted order above carries through.
+        let emails: Vec<Email> = paths
+            .into_par_iter()
+            .map(|path| {
+                let mut email = Email::new(path);
+                email.is_unread = is_unread;
+                // Parse only headers for fast loading. If parsing
+                // fails, keep a placeholder carrying the error.
+                if let Err(e) = email.parse_headers_only_with(&self.spam) {
+                    tracing::warn!(path = %email.file_path.display(), error = %e, "unparseable email");
+                    email.headers.subject = format!("Parse Error: {}", e);
+                    email.parse_error = Some(e.to_string());
                 }
-            }
+                email
+            })
+            .collect();
+
+        for email in emails {
+            folder.add_email(email);
         }
 
         Ok(())
@@ -296,6 +481,11 @@ impl MaildirScanner {
                 Some(n) => n,
                 None => continue,
             };
+            if name == ".Drafts" {
+                // Maildir++ spelling of the drafts folder.
+                Self::scan_drafts_folder(&path, index);
+                continue;
+            }
             if matches!(name, "cur" | "new" | "tmp") || name.starts_with('.') {
                 continue;
             }
@@ -349,6 +539,22 @@ impl MaildirScanner {
         }
     }
 
+    /// Messages on disk in `folder`'s `cur/` and `new/`, loaded or not.
+    /// Lists the directories without parsing anything. An mbox file or a
+    /// non-Maildir directory reports what is loaded.
+    pub fn count_folder_emails(&self, folder: &Folder) -> usize {
+        if !is_maildir(&folder.path) {
+            return folder.emails.len();
+        }
+        ["cur", "new"]
+            .iter()
+            .filter_map(|sub| std::fs::read_dir(folder.path.join(sub)).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| self.is_email_file(&entry.path()))
+            .count()
+    }
+
     /// Check if a file looks like an email file
     fn is_email_file(&self, path: &Path) -> bool {
         path.file_name()
@@ -434,19 +640,92 @@ mod tests {
     }
 
     #[test]
-    fn test_maildir_scanner_path_is_file_returns_typed_error() {
+    fn test_maildir_scanner_path_is_file_reads_it_as_mbox() {
         let temp_dir = TempDir::new().unwrap();
         let file_path = temp_dir.path().join("not_a_dir");
         fs::write(&file_path, b"i am a file").unwrap();
         let scanner = MaildirScanner::new(file_path.clone());
 
-        let err = scanner
-            .scan()
-            .expect_err("scan of non-directory path should fail");
-        match err {
-            VulthorError::MaildirPathNotDirectory(p) => assert_eq!(p, file_path),
-            other => panic!("expected MaildirPathNotDirectory, got {:?}", other),
+        let root = scanner.scan().expect("a file path scans as one mbox");
+        assert_eq!(root.subfolders.len(), 1);
+        assert_eq!(root.subfolders[0].path, file_path);
+
+        // No `From ` separator, so no messages.
+        let mut folder = root.subfolders[0].clone();
+        scanner
+            .load_folder_emails_with_limit(&mut folder, None)
+            .unwrap();
+        assert!(folder.emails.is_empty());
+        assert!(folder.is_loaded);
+    }
+
+    #[test]
+    fn mbox_format_turns_each_file_in_the_directory_into_a_folder() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("inbox"),
+            b"From a@b Mon Jan  1 00:00:00 2024\nSubject: x\n\nbody\n",
+        )
+        .unwrap();
+        fs::write(root.join("sent.mbox"), b"").unwrap();
+        fs::write(root.join(".hidden"), b"").unwrap();
+
+        let names = |scanner: &MaildirScanner| -> Vec<String> {
+            let root = scanner.scan().unwrap();
+            root.subfolders.iter().map(|f| f.name.clone()).collect()
+        };
+        assert!(names(&MaildirScanner::new(root.to_path_buf())).is_empty());
+        let scanner = MaildirScanner::new(root.to_path_buf()).with_format(MailboxFormat::Mbox);
+        assert_eq!(names(&scanner), ["inbox", "sent"]);
+    }
+
+    #[test]
+    fn maildirpp_dot_folders_nest_under_their_parents() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for dir in [
+            ".",
+            ".Sent",
+            ".Work.Projects.Alpha",
+            ".Work",
+            ".Work.Projects.Beta",
+        ] {
+            for sub in ["cur", "new", "tmp"] {
+                fs::create_dir_all(root.join(dir).join(sub)).unwrap();
+            }
         }
+        // Not a maildir: ignored.
+        fs::create_dir_all(root.join(".notmuch/xapian")).unwrap();
+
+        let scanner =
+            MaildirScanner::new(root.to_path_buf()).with_convention(MaildirConvention::MaildirPP);
+        let tree = scanner.scan().unwrap();
+        let names: Vec<&str> = tree.subfolders.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["INBOX", "Sent", "Work"]);
+        assert_eq!(tree.subfolders[0].path, root);
+        assert_eq!(tree.subfolders[0].role, FolderRole::Inbox);
+        assert_eq!(tree.subfolders[1].role, FolderRole::Sent);
+
+        let work = &tree.subfolders[2];
+        assert_eq!(work.path, root.join(".Work"));
+        assert_eq!(work.subfolders.len(), 1);
+        let projects = &work.subfolders[0];
+        assert_eq!(projects.name, "Projects");
+        let leaves: Vec<&str> = projects
+            .subfolders
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        assert_eq!(leaves, ["Alpha", "Beta"]);
+        assert_eq!(
+            projects.subfolders[0].path,
+            root.join(".Work.Projects.Alpha")
+        );
+
+        // The standard convention hides dot directories.
+        let standard = MaildirScanner::new(root.to_path_buf()).scan().unwrap();
+        assert!(standard.subfolders.is_empty());
     }
 
     #[test]
@@ -478,6 +757,36 @@ mod tests {
         assert_eq!(result.subfolders[0].emails.len(), 1);
     }
 
+    #[test]
+    fn scan_assigns_roles_from_config_before_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for name in ["Outbox", "Present", "Sent", "Trash"] {
+            fs::create_dir_all(root.join(name).join("cur")).unwrap();
+        }
+
+        let folders = FoldersConfig {
+            sent: Some("Outbox".into()),
+            ..FoldersConfig::default()
+        };
+        let scanner = MaildirScanner::new(root.to_path_buf()).with_folders(folders);
+        let result = scanner.scan().unwrap();
+        let role = |name: &str| {
+            result
+                .subfolders
+                .iter()
+                .find(|f| f.name == name)
+                .map(|f| f.role)
+                .unwrap()
+        };
+
+        assert_eq!(role("Outbox"), FolderRole::Sent);
+        assert_eq!(role("Present"), FolderRole::Normal);
+        // "Sent" would be detected, but the role is pinned elsewhere.
+        assert_eq!(role("Sent"), FolderRole::Normal);
+        assert_eq!(role("Trash"), FolderRole::Trash);
+    }
+
     /// Build a `cur/`-only INBOX with `n` minimal RFC-822 messages and
     /// return (TempDir, scanner, root Folder). Used by the paged-loader
     /// regression tests below.
@@ -523,6 +832,31 @@ mod tests {
         );
     }
 
+    /// A folder missing `tmp/` (and one of `cur/` / `new/`) still pages
+    /// in through `load_more_folder_emails`; a directory with neither is
+    /// not a maildir.
+    #[test]
+    fn paged_load_reads_folder_without_tmp() {
+        let temp = TempDir::new().unwrap();
+        let folder_path = temp.path().join("Archive");
+        fs::create_dir_all(folder_path.join("cur")).unwrap();
+        for i in 0..3 {
+            fs::write(
+                folder_path.join("cur").join(format!("{i}.eml:2,S")),
+                format!("From: a@b.test\r\nSubject: old {i}\r\n\r\nbody\r\n"),
+            )
+            .unwrap();
+        }
+        fs::create_dir_all(temp.path().join("Plain")).unwrap();
+        assert!(is_maildir(&folder_path));
+        assert!(!is_maildir(&temp.path().join("Plain")));
+
+        let scanner = MaildirScanner::new(temp.path().to_path_buf());
+        let mut folder = Folder::new("Archive".to_string(), folder_path);
+        assert_eq!(scanner.load_more_folder_emails(&mut folder, 10).unwrap(), 3);
+        assert_eq!(folder.emails.len(), 3);
+    }
+
     /// Repeated paged calls eventually exhaust the folder and flip
     /// `is_loaded = true`, after which further calls are no-ops.
     #[test]
@@ -549,6 +883,59 @@ mod tests {
         assert_eq!(d, 0);
     }
 
+    /// The rayon-parallel header load of a large synthetic folder must
+    /// come back with the same count and the same per-email headers, in
+    /// the same order, as a plain sequential parse of the
+    /// filename-sorted directory listing.
+    #[test]
+    fn parallel_load_matches_sequential_parse_on_large_folder() {
+        let (temp, scanner, mut root) = build_folder_with_n_emails(2000);
+        let folder = &mut root.subfolders[0];
+
+        scanner.load_folder_emails_with_limit(folder, None).unwrap();
+
+        let mut files: Vec<PathBuf> = fs::read_dir(temp.path().join("INBOX/cur"))
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect();
+        files.sort();
+        let sequential: Vec<(PathBuf, String)> = files
+            .into_iter()
+            .map(|path| {
+                let mut email = Email::new(path.clone());
+                email.parse_headers_only().unwrap();
+                (path, email.headers.subject)
+            })
+            .collect();
+
+        assert_eq!(folder.emails.len(), sequential.len());
+        assert_eq!(folder.total_count, sequential.len());
+        assert!(folder.is_loaded);
+        let parallel: Vec<(PathBuf, String)> = folder
+            .emails
+            .iter()
+            .map(|e| (e.file_path.clone(), e.headers.subject.clone()))
+            .collect();
+        assert_eq!(parallel, sequential);
+    }
+
+    /// `limit` keeps its "first N" meaning, now pinned to filename
+    /// order rather than whatever order `readdir` happened to return.
+    #[test]
+    fn limited_load_takes_first_n_by_filename() {
+        let (_temp, scanner, mut root) = build_folder_with_n_emails(50);
+        let folder = &mut root.subfolders[0];
+        scanner
+            .load_folder_emails_with_limit(folder, Some(5))
+            .unwrap();
+        let subjects: Vec<&str> = folder
+            .emails
+            .iter()
+            .map(|e| e.headers.subject.as_str())
+            .collect();
+        assert_eq!(subjects, ["msg 0", "msg 1", "msg 2", "msg 3", "msg 4"]);
+    }
+
     // --- Phase 2.c: drafts-index acceptance. ---
 
     /// The fixture's `Drafts/` contains two reply drafts referencing
@@ -11,22 +11,32 @@
 #![deny(missing_docs)]
 
 mod classifier;
+mod clipboard;
 mod components;
 mod compose;
 mod config;
+mod contacts;
 mod crash;
 mod doctor;
 mod email;
 mod error;
+mod ical;
+mod image_preview;
 mod keymap;
 mod layout;
 mod log;
 mod maildir;
+mod mbox;
+mod mime_tree;
 mod sanitizer;
+mod signature;
+mod state;
 mod stats;
 mod theme;
+mod threading;
 mod ui;
 mod undo;
+mod util;
 mod web;
 
 #[cfg(test)]
@@ -45,6 +55,7 @@ use clap::Parser;
 use components::{AppRoot, FolderScannerHandle};
 use config::{CliArgs, Command, Config};
 use crossterm::{
+    cursor,
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
@@ -55,7 +66,11 @@ use maildir::MaildirScanner;
 use ratatui::{Terminal, backend::CrosstermBackend};
 use std::{
     io,
-    sync::{Arc, Mutex},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
 };
 use ui::UI;
 use web::WebServer;
@@ -69,6 +84,13 @@ async fn main() -> Result<()> {
 
     let args = CliArgs::parse();
 
+    if let Some(path) = &args.log_file
+        && let Err(e) = log::init_tracing(path, args.log_level)
+    {
+        eprintln!("Warning: could not open log file {}: {e}", path.display());
+    }
+    tracing::info!(version = env!("CARGO_PKG_VERSION"), "vulthor starting");
+
     let mut config = match Config::load(args.config_path).await {
         Ok(config) => config,
         Err(e) => {
@@ -79,7 +101,7 @@ async fn main() -> Result<()> {
     };
 
     if let Some(maildir_path) = args.maildir_path {
-        config.maildir_path = maildir_path;
+        config.apply_maildir_override(maildir_path);
     }
 
     // vu-bdy: prune aged-out routine logs and keep the rotating writer
@@ -114,17 +136,21 @@ async fn main() -> Result<()> {
         std::process::exit(0);
     }
 
-    // `-m` overrides the maildir for single-account runs; for
-    // multi-account configs, the active account's `maildir_path`
-    // wins. `Config::active_maildir()` resolves both cases.
+    // `-m` has already been folded into the config: it replaces the
+    // maildir for single-account runs and selects (or adds) the
+    // startup account otherwise. `Config::active_maildir()` resolves
+    // both cases.
     let initial_maildir = config.active_maildir();
 
     // Folder-structure scan runs off the main thread. We start the
     // worker but do NOT block; the TUI comes up immediately and
     // renders a splash until the scan reply lands in
     // `drain_scanned_folders`.
-    let scanner = MaildirScanner::new(initial_maildir.clone());
-    let folder_scanner_handle = FolderScannerHandle::spawn(initial_maildir.clone());
+    let scanner = MaildirScanner::new(initial_maildir.clone())
+        .with_folders(config.folders.clone())
+        .with_format(config.mailbox_format)
+        .with_convention(config.maildir_convention);
+    let folder_scanner_handle = FolderScannerHandle::spawn(scanner.clone());
 
     let mut email_store = EmailStore::new(initial_maildir.clone());
     email_store.scanning_folders = true;
@@ -133,16 +159,24 @@ async fn main() -> Result<()> {
     // CLI `--port` wins over `[web].port`; both default to 8080.
     let web_port = args.port.unwrap_or(config.web.port);
     let web_bind = config.web.bind.clone();
+    let web_api_token = config.web.api_token.clone();
 
     // Resolve the runtime theme before building AppRoot so a malformed
     // user theme / override fails loud at startup instead of silently
     // rendering the built-in palette.
-    let resolved_theme = match theme::build_theme(&config) {
-        Ok(t) => t,
-        Err(e) => {
-            eprintln!("Theme configuration error: {}", e);
-            eprintln!("Falling back to built-in theme.");
-            theme::Theme::default()
+    // `--no-color` / `NO_COLOR` skip the palette entirely.
+    let no_color =
+        args.no_color || theme::no_color_requested(std::env::var_os("NO_COLOR").as_deref());
+    let resolved_theme = if no_color {
+        theme::Theme::monochrome()
+    } else {
+        match theme::build_theme(&config) {
+            Ok(t) => t,
+            Err(e) => {
+                eprintln!("Theme configuration error: {}", e);
+                eprintln!("Falling back to built-in theme.");
+                theme::Theme::default()
+            }
         }
     };
     // The Ctrl+T cycle starts from `[theme].preset` (default
@@ -155,7 +189,7 @@ async fn main() -> Result<()> {
         config.theme.overrides.is_empty(),
         theme::preset_from_config(&config.theme.preset),
     ) {
-        (None, true, Ok(preset)) => Some(preset),
+        (None, true, Ok(preset)) if !no_color => Some(preset),
         _ => None,
     };
 
@@ -165,12 +199,18 @@ async fn main() -> Result<()> {
     let classifier = classifier::build_classifier(&config.ai);
     let ai_threshold = config.ai.threshold;
 
+    let web_scanner = scanner.clone();
     let mut app_root = AppRoot::with_config(email_store.clone(), scanner, config);
     app_root.attach_folder_scanner(folder_scanner_handle);
     app_root.set_web_port(web_port);
     app_root.set_theme_with_preset(resolved_theme, preset_anchor);
     app_root.set_classifier(classifier, ai_threshold);
     app_root.init_maildir_watcher();
+    app_root.load_ui_state(state::UiState::default_path());
+    app_root.load_contact_frequency(contacts::ContactFrequency::default_path());
+    if let Some(path) = &args.open_eml {
+        app_root.open_eml(path);
+    }
 
     let web_server = WebServer::new(
         web_bind.clone(),
@@ -179,7 +219,10 @@ async fn main() -> Result<()> {
         app_root.focused_pane(),
         app_root.images_visible(),
         app_root.body_request_sender(),
-    );
+        app_root.web_events(),
+    )
+    .with_scanner(web_scanner)
+    .with_api_token(web_api_token);
     // vu-fi1: the per-launch loopback token is now the gate on every web
     // route. Capture the printable URL (token included) *before* the server
     // moves into the spawn closure — we need to surface it on the TUI
@@ -197,34 +240,42 @@ async fn main() -> Result<()> {
             web_bind,
         );
     }
-    let web_handle = tokio::spawn(async move {
-        if let Err(e) = web_server.start().await {
+    let (web_shutdown_tx, web_shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let mut web_handle = tokio::spawn(async move {
+        let signal = async {
+            let _ = web_shutdown_rx.await;
+        };
+        if let Err(e) = web_server.start_with_shutdown(signal).await {
             eprintln!("Web server error: {}", e);
         }
     });
 
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    let mut ui = UI::new();
+    // SIGINT / SIGTERM (a `kill`, or Ctrl-C while the terminal is
+    // suspended for `$EDITOR`) end the session the same way `q` does,
+    // so the terminal and web server are torn down below instead of
+    // the process dying with the shell still in raw mode.
+    let quit_requested = Arc::new(AtomicBool::new(false));
+    let signal_task = tokio::spawn(listen_for_quit_signals(quit_requested.clone()));
 
     println!("Vulthor started! Web interface available at {}", web_url);
     println!("Press 'q' to quit, '?' for help");
 
-    let result = run_app(&mut terminal, &mut ui, &mut app_root).await;
-
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
-
-    web_handle.abort();
+    // Every exit from here — a clean quit, an error out of the event
+    // loop, a failed terminal setup — falls through to the web
+    // shutdown; `TerminalGuard` restores the terminal on the way.
+    let result = run_tui(&mut app_root, &quit_requested).await;
+    signal_task.abort();
+
+    // Ask the server to drain in-flight requests and close SSE streams
+    // rather than killing it mid-response. Bounded so a wedged client
+    // can't hold the process open; fall back to abort past the deadline.
+    let _ = web_shutdown_tx.send(());
+    if tokio::time::timeout(Duration::from_secs(2), &mut web_handle)
+        .await
+        .is_err()
+    {
+        web_handle.abort();
+    }
 
     if let Err(e) = result {
         eprintln!("Application error: {}", e);
@@ -235,12 +286,79 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Raw mode, the alt-screen and mouse capture for the lifetime of the
+/// value. Dropping it puts the terminal back, so an early `?` return
+/// or an unwinding panic can't leave the user's shell unusable.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> Result<Self> {
+        enable_raw_mode()?;
+        // Constructed before the alt-screen switch so a failure there
+        // still turns raw mode back off.
+        let guard = TerminalGuard;
+        execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            cursor::Show
+        );
+    }
+}
+
+/// Set up the terminal, run the event loop, and restore the terminal
+/// whichever way the loop ends.
+async fn run_tui(app_root: &mut AppRoot, quit_requested: &AtomicBool) -> Result<()> {
+    let _guard = TerminalGuard::enter()?;
+    let backend = CrosstermBackend::new(io::stdout());
+    let mut terminal = Terminal::new(backend)?;
+    let mut ui = UI::new();
+    run_app(&mut terminal, &mut ui, app_root, quit_requested).await
+}
+
+/// Resolve on the first SIGINT or (on unix) SIGTERM and raise
+/// `quit_requested` for the event loop to pick up.
+async fn listen_for_quit_signals(quit_requested: Arc<AtomicBool>) {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
+                }
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "could not listen for SIGTERM");
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = tokio::signal::ctrl_c().await;
+    tracing::info!("quit signal received");
+    quit_requested.store(true, Ordering::SeqCst);
+}
+
 async fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     ui: &mut UI,
     app_root: &mut AppRoot,
+    quit_requested: &AtomicBool,
 ) -> Result<()> {
     loop {
+        if quit_requested.load(Ordering::SeqCst) {
+            app_root.request_quit();
+        }
         if app_root.render(terminal, ui)? {
             break;
         }
@@ -255,7 +373,7 @@ async fn run_app(
         // is still useful.
         if let Some(launch) = app_root.take_pending_editor() {
             suspend_terminal(terminal)?;
-            let result = compose::launch_editor(&launch.template);
+            let result = compose::launch_editor(&launch.template, launch.editor.as_deref());
             restore_terminal(terminal)?;
             match result {
                 Ok(parsed) => app_root.apply_editor_result(parsed),
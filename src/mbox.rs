@@ -0,0 +1,290 @@
+//! mbox support: reading archives, and writing `:export` files.
+//!
+//! An mbox file holds a whole folder: messages are concatenated, each
+//! introduced by a `From ` separator line, and body lines that would
+//! look like a separator are escaped as `>From `. Every message is
+//! addressed by a synthetic path `<mbox file>/<start>-<end>` naming its
+//! byte span, so the rest of the app — body loading, the web viewer,
+//! selection by path — keeps working on `Email::file_path`.
+//! [`read_message`] resolves those paths back to the message bytes.
+//!
+//! Mutations (move, delete, flag) act on Maildir files and fail on mbox
+//! messages; the format is for reading archives and for [`write_mbox`]
+//! exports.
+
+use crate::config::SpamConfig;
+use crate::email::{Email, Folder};
+use crate::error::Result;
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Splits one mbox file into messages and parses their headers.
+#[derive(Debug, Clone)]
+pub struct MboxReader {
+    path: PathBuf,
+}
+
+impl MboxReader {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Replace `folder.emails` with the file's messages, headers only.
+    /// Messages without an `R` in their `Status:` header are unread,
+    /// matching mutt.
+    pub fn load_into(&self, folder: &mut Folder, spam: &SpamConfig) -> Result<()> {
+        let content = fs::read(&self.path)?;
+        self.load_from(&content, folder, spam);
+        Ok(())
+    }
+
+    /// [`Self::load_into`] over the file's `content`, already read.
+    /// Each message's headers are parsed from its span in `content`;
+    /// nothing is read from disk again.
+    fn load_from(&self, content: &[u8], folder: &mut Folder, spam: &SpamConfig) {
+        folder.emails.clear();
+        folder.unread_count = 0;
+        folder.total_count = 0;
+        folder.corrupt_count = 0;
+        for span in split_spans(content) {
+            let raw = &content[span.clone()];
+            let mut email = Email::new(message_path(&self.path, &span));
+            email.is_unread = !status_has_read_flag(raw);
+            if let Err(e) = email.parse_headers_from_bytes(&unescape_from_lines(raw), spam) {
+                tracing::warn!(path = %email.file_path.display(), error = %e, "unparseable email");
+                email.headers.subject = format!("Parse Error: {}", e);
+                email.parse_error = Some(e.to_string());
+            }
+            folder.add_email(email);
+        }
+        folder.is_loaded = true;
+    }
+}
+
+/// Synthetic path for the message at `span` inside `mbox`.
+pub fn message_path(mbox: &Path, span: &Range<usize>) -> PathBuf {
+    mbox.join(format!("{}-{}", span.start, span.end))
+}
+
+/// Bytes of the message at `path`: the unescaped span when `path` is
+/// one of [`message_path`]'s, the file itself otherwise.
+pub fn read_message(path: &Path) -> io::Result<Vec<u8>> {
+    match split_message_path(path) {
+        Some((mbox, span)) => {
+            let content = fs::read(mbox)?;
+            let raw = content.get(span).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::UnexpectedEof, "mbox message out of range")
+            })?;
+            Ok(unescape_from_lines(raw))
+        }
+        None => fs::read(path),
+    }
+}
+
+/// Inverse of [`message_path`]. Only matches when the parent is a
+/// regular file, so no real Maildir path is ever mistaken for one.
+fn split_message_path(path: &Path) -> Option<(&Path, Range<usize>)> {
+    let mbox = path.parent()?;
+    let (start, end) = path.file_name()?.to_str()?.split_once('-')?;
+    let span = start.parse().ok()?..end.parse().ok()?;
+    mbox.is_file().then_some((mbox, span))
+}
+
+/// Message spans between `From ` separator lines. Text before the first
+/// separator is ignored, and the blank line that precedes each
+/// separator is trimmed from the message before it.
+fn split_spans(content: &[u8]) -> Vec<Range<usize>> {
+    let mut separators = Vec::new();
+    let mut line_start = 0;
+    while line_start < content.len() {
+        let line_end = content[line_start..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(content.len(), |i| line_start + i + 1);
+        if content[line_start..].starts_with(b"From ") {
+            separators.push(line_start..line_end);
+        }
+        line_start = line_end;
+    }
+
+    let mut spans = Vec::new();
+    for (i, sep) in separators.iter().enumerate() {
+        let end = separators
+            .get(i + 1)
+            .map_or(content.len(), |next| next.start);
+        let mut body = &content[sep.end..end];
+        while let Some(rest) = body.strip_suffix(b"\n") {
+            body = rest.strip_suffix(b"\r").unwrap_or(rest);
+        }
+        if !body.is_empty() {
+            spans.push(sep.end..sep.end + body.len());
+        }
+    }
+    spans
+}
+
+/// Write `messages` to a new mbox file at `path`, each behind a
+/// `From ` separator and quoted mboxrd-style, so [`read_message`]
+/// gives them back unchanged but for trailing line breaks.
+pub fn write_mbox(path: &Path, messages: &[Vec<u8>]) -> io::Result<()> {
+    let mut out = Vec::new();
+    for message in messages {
+        out.extend_from_slice(b"From MAILER-DAEMON Thu Jan  1 00:00:00 1970\n");
+        for line in message.split_inclusive(|&b| b == b'\n') {
+            let quotes = line.iter().take_while(|&&b| b == b'>').count();
+            if line[quotes..].starts_with(b"From ") {
+                out.push(b'>');
+            }
+            out.extend_from_slice(line);
+        }
+        if !message.ends_with(b"\n") {
+            out.push(b'\n');
+        }
+        out.push(b'\n');
+    }
+    fs::write(path, out)
+}
+
+/// Undo mboxrd quoting: drop one `>` from every `>…>From ` line.
+fn unescape_from_lines(raw: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(raw.len());
+    for line in raw.split_inclusive(|&b| b == b'\n') {
+        let quotes = line.iter().take_while(|&&b| b == b'>').count();
+        if quotes > 0 && line[quotes..].starts_with(b"From ") {
+            out.extend_from_slice(&line[1..]);
+        } else {
+            out.extend_from_slice(line);
+        }
+    }
+    out
+}
+
+/// Whether the header block carries `Status:` with the `R` (read) flag.
+fn status_has_read_flag(raw: &[u8]) -> bool {
+    for line in raw.split(|&b| b == b'\n') {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.is_empty() {
+            break;
+        }
+        if line.len() > 7 && line[..7].eq_ignore_ascii_case(b"status:") {
+            return line[7..].contains(&b'R');
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maildir::MaildirScanner;
+    use tempfile::TempDir;
+
+    const THREE_MESSAGES: &str = "From alice@example.com Mon Jan  1 00:00:00 2024\n\
+From: Alice <alice@example.com>\n\
+Subject: First\n\
+Status: RO\n\
+\n\
+Hello.\n\
+>From the archive, with love.\n\
+\n\
+From bob@example.com Tue Jan  2 00:00:00 2024\n\
+From: Bob <bob@example.com>\n\
+Subject: Second\n\
+\n\
+Hi.\n\
+\n\
+From carol@example.com Wed Jan  3 00:00:00 2024\n\
+From: Carol <carol@example.com>\n\
+Subject: Third\n\
+\n\
+Hey.\n";
+
+    #[test]
+    fn a_file_path_scans_as_one_mbox_folder_with_three_emails() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("archive.mbox");
+        fs::write(&path, THREE_MESSAGES).unwrap();
+
+        let scanner = MaildirScanner::new(path.clone());
+        let mut root = scanner.scan().unwrap();
+        assert_eq!(root.subfolders.len(), 1);
+        let folder = &mut root.subfolders[0];
+        assert_eq!(folder.name, "archive");
+        scanner
+            .load_folder_emails_with_limit(folder, Some(1))
+            .unwrap();
+
+        assert_eq!(folder.emails.len(), 3);
+        let subjects: Vec<&str> = folder
+            .emails
+            .iter()
+            .map(|e| e.headers.subject.as_str())
+            .collect();
+        assert_eq!(subjects, ["First", "Second", "Third"]);
+        assert!(!folder.emails[0].is_unread);
+        assert!(folder.emails[1].is_unread);
+        assert_eq!(folder.unread_count, 2);
+    }
+
+    #[test]
+    fn headers_load_from_the_file_read_once() {
+        // No file at `path`: loading succeeds only if every message is
+        // parsed from the bytes already in memory.
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("gone.mbox");
+        let mut folder = Folder::new("gone".into(), path.clone());
+        MboxReader::new(path).load_from(
+            THREE_MESSAGES.as_bytes(),
+            &mut folder,
+            &SpamConfig::default(),
+        );
+
+        let subjects: Vec<&str> = folder
+            .emails
+            .iter()
+            .map(|e| e.headers.subject.as_str())
+            .collect();
+        assert_eq!(subjects, ["First", "Second", "Third"]);
+        assert!(folder.emails.iter().all(|e| e.parse_error.is_none()));
+        // The span is unescaped before parsing, as `read_message` does.
+        let first = split_spans(THREE_MESSAGES.as_bytes())[0].clone();
+        assert_eq!(folder.emails[0].size_bytes, first.len() - 1);
+    }
+
+    #[test]
+    fn message_paths_read_back_the_unescaped_message() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("archive.mbox");
+        fs::write(&path, THREE_MESSAGES).unwrap();
+
+        let spans = split_spans(THREE_MESSAGES.as_bytes());
+        let first = read_message(&message_path(&path, &spans[0])).unwrap();
+        let text = String::from_utf8(first).unwrap();
+        assert!(text.starts_with("From: Alice"));
+        assert!(text.ends_with("Hello.\nFrom the archive, with love."));
+
+        let mut email = Email::new(message_path(&path, &spans[2]));
+        email.parse_from_file().unwrap();
+        assert_eq!(email.body_plain.as_deref().map(str::trim), Some("Hey."));
+    }
+
+    #[test]
+    fn written_mbox_reads_back_with_from_lines_intact() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("export.mbox");
+        let first = b"Subject: One\n\nFrom here on.\n>From quoted.\n".to_vec();
+        let second = b"Subject: Two\r\n\r\nBye".to_vec();
+        write_mbox(&path, &[first.clone(), second.clone()]).unwrap();
+
+        let mut folder = Folder::new("export".into(), path.clone());
+        MboxReader::new(path)
+            .load_into(&mut folder, &SpamConfig::default())
+            .unwrap();
+        assert_eq!(folder.emails.len(), 2);
+        let read = |i: usize| read_message(&folder.emails[i].file_path).unwrap();
+        assert_eq!(read(0), first.strip_suffix(b"\n").unwrap());
+        assert_eq!(read(1), second);
+    }
+}
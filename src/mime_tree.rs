@@ -0,0 +1,217 @@
+//! MIME structure of a message, for the Content pane's part browser.
+//!
+//! The tree is read straight from the message file rather than from
+//! [`crate::email::Email`], which keeps only the body text and the
+//! attachment list. Parts come out depth-first, each with its nesting
+//! depth, so the popup can draw the hierarchy as indented rows; a part's
+//! position in that list is how it is asked for again with
+//! [`part_preview`].
+
+use std::path::Path;
+
+use mail_parser::{Message, MessageParser, MessagePart, MimeHeaders, PartType};
+
+use crate::email::strip_control_chars;
+use crate::error::{Result, VulthorError};
+use crate::util::format_file_size;
+
+/// One MIME part as listed in the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MimePart {
+    /// `type/subtype`, lowercase. Parts without a `Content-Type` are
+    /// `text/plain`, as RFC 2045 defaults them.
+    pub content_type: String,
+    /// Decoded body size in bytes; 0 for a multipart container.
+    pub size: usize,
+    /// `filename` / `name` parameter, when the part carries one.
+    pub filename: Option<String>,
+    /// 0 for the message itself, one more per enclosing multipart.
+    pub depth: usize,
+}
+
+impl MimePart {
+    /// The row drawn in the popup: indent, type, then name and size
+    /// where they apply.
+    pub fn label(&self) -> String {
+        let mut label = format!("{}{}", "  ".repeat(self.depth), self.content_type);
+        if let Some(name) = &self.filename {
+            label.push_str(&format!(" \"{}\"", name));
+        }
+        if !self.content_type.starts_with("multipart/") {
+            label.push_str(&format!(" ({})", format_file_size(self.size)));
+        }
+        label
+    }
+}
+
+/// Parts of the message at `path` (a MailDir file or an mbox span),
+/// depth-first.
+pub fn mime_tree(path: &Path) -> Result<Vec<MimePart>> {
+    let raw = crate::mbox::read_message(path)?;
+    let message = parse(&raw)?;
+    let mut parts = Vec::new();
+    walk(&message, |part, depth| {
+        parts.push(MimePart {
+            content_type: content_type(part),
+            size: part.len(),
+            filename: part.attachment_name().map(str::to_string),
+            depth,
+        })
+    });
+    Ok(parts)
+}
+
+/// Content type and text of the `index`th part of [`mime_tree`], for
+/// the Content pane: the decoded body of a text part, or a one-line
+/// summary of anything that can't be shown as text. Control characters
+/// other than newlines and tabs are stripped, as in the body view.
+pub fn part_preview(path: &Path, index: usize) -> Result<(String, String)> {
+    let raw = crate::mbox::read_message(path)?;
+    let message = parse(&raw)?;
+    let mut found = None;
+    let mut seen = 0;
+    walk(&message, |part, _| {
+        if seen == index {
+            let text = match &part.body {
+                PartType::Text(text) | PartType::Html(text) => text.to_string(),
+                PartType::Message(inner) => String::from_utf8_lossy(inner.raw_message()).into(),
+                PartType::Multipart(children) => {
+                    format!("{} with {} parts", content_type(part), children.len())
+                }
+                PartType::Binary(_) | PartType::InlineBinary(_) => format!(
+                    "{} of {} data; open it from the attachment list to view it.",
+                    format_file_size(part.len()),
+                    content_type(part)
+                ),
+            };
+            found = Some((content_type(part), strip_control_chars(&text).into_owned()));
+        }
+        seen += 1;
+    });
+    found.ok_or(VulthorError::MimePartNotFound(index))
+}
+
+fn parse(raw: &[u8]) -> Result<Message<'_>> {
+    MessageParser::default()
+        .parse(raw)
+        .ok_or(VulthorError::MailParser)
+}
+
+/// Call `visit` on every part depth-first, the message itself first.
+/// A nested `message/rfc822` is one leaf; its own parts are not listed.
+fn walk<'x>(message: &Message<'x>, mut visit: impl FnMut(&MessagePart<'x>, usize)) {
+    let mut stack = vec![(0u32, 0usize)];
+    while let Some((id, depth)) = stack.pop() {
+        let Some(part) = message.parts.get(id as usize) else {
+            continue;
+        };
+        visit(part, depth);
+        if let PartType::Multipart(children) = &part.body {
+            stack.extend(children.iter().rev().map(|&child| (child, depth + 1)));
+        }
+    }
+}
+
+fn content_type(part: &MessagePart) -> String {
+    match part.content_type() {
+        Some(ct) => match ct.subtype() {
+            Some(sub) => format!("{}/{}", ct.ctype(), sub),
+            None => ct.ctype().to_string(),
+        }
+        .to_ascii_lowercase(),
+        None => "text/plain".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::TestMailDir;
+
+    fn attachment_email(maildir: &TestMailDir) -> std::path::PathBuf {
+        maildir
+            .get_folder_path("INBOX")
+            .join("cur")
+            .join("1234567894.email5")
+    }
+
+    #[test]
+    fn attachment_email_is_a_mixed_multipart_of_text_and_two_files() {
+        let maildir = TestMailDir::new();
+        let parts = mime_tree(&attachment_email(&maildir)).unwrap();
+        let shape: Vec<(&str, usize, Option<&str>)> = parts
+            .iter()
+            .map(|p| (p.content_type.as_str(), p.depth, p.filename.as_deref()))
+            .collect();
+        assert_eq!(
+            shape,
+            [
+                ("multipart/mixed", 0, None),
+                ("text/plain", 1, None),
+                ("application/pdf", 1, Some("project_specs.pdf")),
+                ("image/jpeg", 1, Some("prototype_photo.jpg")),
+            ]
+        );
+        assert_eq!(parts[0].label(), "multipart/mixed");
+        assert!(
+            parts[2]
+                .label()
+                .starts_with("  application/pdf \"project_specs.pdf\" (")
+        );
+    }
+
+    #[test]
+    fn part_preview_decodes_text_and_summarises_binary() {
+        let maildir = TestMailDir::new();
+        let path = attachment_email(&maildir);
+        let (content_type, text) = part_preview(&path, 1).unwrap();
+        assert_eq!(content_type, "text/plain");
+        assert!(text.contains("Please find the attached project documents"));
+        let (content_type, text) = part_preview(&path, 2).unwrap();
+        assert_eq!(content_type, "application/pdf");
+        assert!(text.contains("application/pdf data"));
+        assert!(part_preview(&path, 9).is_err());
+
+        let escaped = tempfile::TempDir::new().unwrap();
+        let path = escaped.path().join("esc");
+        std::fs::write(
+            &path,
+            "Content-Type: text/plain\r\n\r\nred \x1b[31malert\x1b[0m\tdone\n",
+        )
+        .unwrap();
+        let (_, text) = part_preview(&path, 0).unwrap();
+        assert!(!text.contains('\x1b'), "{text:?}");
+        assert!(text.contains("red [31malert[0m\tdone"), "{text:?}");
+    }
+
+    #[test]
+    fn nested_multiparts_indent_their_children() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("alt");
+        std::fs::write(
+            &path,
+            "Content-Type: multipart/mixed; boundary=\"o\"\r\n\r\n\
+             --o\r\nContent-Type: multipart/alternative; boundary=\"i\"\r\n\r\n\
+             --i\r\nContent-Type: text/plain\r\n\r\nplain\r\n\
+             --i\r\nContent-Type: text/html\r\n\r\n<p>html</p>\r\n--i--\r\n\
+             --o\r\nContent-Type: text/calendar; name=\"invite.ics\"\r\n\r\nBEGIN:VCALENDAR\r\n\
+             --o--\r\n",
+        )
+        .unwrap();
+        let labels: Vec<(String, usize)> = mime_tree(&path)
+            .unwrap()
+            .into_iter()
+            .map(|p| (p.content_type, p.depth))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                ("multipart/mixed".to_string(), 0),
+                ("multipart/alternative".to_string(), 1),
+                ("text/plain".to_string(), 2),
+                ("text/html".to_string(), 2),
+                ("text/calendar".to_string(), 1),
+            ]
+        );
+    }
+}
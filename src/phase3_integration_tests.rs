@@ -295,7 +295,7 @@ fn search_with_no_notmuch_surfaces_status_and_does_not_crash() {
     press_key(&mut root, KeyCode::Char('/'));
 
     // The status bar carries the install hint; no panic, no search.
-    let status = root.status_message().clone().unwrap_or_default();
+    let status = root.status_message().unwrap_or_default();
     assert!(
         status.contains("notmuch not found"),
         "expected notmuch-missing status; got {:?}",
@@ -332,7 +332,6 @@ fn html_viewer_toggle_spawns_and_terminates_child() {
     press_key(&mut root, KeyCode::Char('v'));
     assert!(
         root.status_message()
-            .as_deref()
             .map(|s| s.starts_with("HTML viewer launched"))
             .unwrap_or(false),
         "expected launch status; got {:?}",
@@ -355,7 +354,7 @@ fn html_viewer_toggle_spawns_and_terminates_child() {
     // confirms the close.
     press_key(&mut root, KeyCode::Char('v'));
     assert_eq!(
-        root.status_message().as_deref(),
+        root.status_message(),
         Some("HTML viewer closed"),
         "second press must report close",
     );
@@ -371,7 +370,7 @@ fn html_viewer_toggle_spawns_and_terminates_child() {
 /// disconnected (rx dropped) on purpose: the welcome path never
 /// dispatches a body parse.
 fn webstate_with_no_selection(maildir: PathBuf) -> WebState {
-    let store = EmailStore::new(maildir);
+    let store = EmailStore::new(maildir.clone());
     let (tx, _rx) = std::sync::mpsc::channel::<PathBuf>();
     WebState {
         email_store: Arc::new(Mutex::new(store)),
@@ -379,6 +378,11 @@ fn webstate_with_no_selection(maildir: PathBuf) -> WebState {
         images_visible: Arc::new(std::sync::atomic::AtomicBool::new(false)),
         body_request_tx: tx,
         token: Arc::from("test-token"),
+        api_token: None,
+        shutdown: tokio::sync::watch::channel(false).1,
+        events: tokio::sync::broadcast::channel(crate::web::WS_EVENT_CAPACITY).0,
+        started_at: std::time::Instant::now(),
+        scanner: crate::maildir::MaildirScanner::new(maildir),
     }
 }
 
@@ -19,7 +19,7 @@
 //!
 //! 1. `web_block_round_trips_through_config_and_binds_listener`
 //!    — `[web] port=<free> bind="127.0.0.1"` in TOML reaches the live
-//!    `WebServer::start`; `/health` returns 200. A second case asserts
+//!    `WebServer::start`; `/healthz` returns 200. A second case asserts
 //!    `bind = "0.0.0.0"` survives validation (the bind we don't actually
 //!    open in CI).
 //! 2. `keybindings_override_archive_to_e_propagates_to_apphroot_keymap`
@@ -159,7 +159,7 @@ bind = "127.0.0.1"
     assert_eq!(cfg.web.bind, "127.0.0.1");
 
     // Forward the resolved bind/port into `WebServer::new` (same call
-    // shape as `main.rs`) and spawn `start`. The /health endpoint is a
+    // shape as `main.rs`) and spawn `start`. The /healthz endpoint is a
     // single async handler — once it returns 200, we've proven the
     // listener accepted a real TCP connection on the configured port.
     let store = Arc::new(Mutex::new(EmailStore::new(tmp.path().to_path_buf())));
@@ -173,12 +173,13 @@ bind = "127.0.0.1"
         focused,
         images_visible,
         tx,
+        tokio::sync::broadcast::channel(crate::web::WS_EVENT_CAPACITY).0,
     );
     let server_task = tokio::spawn(async move {
-        let _ = server.start().await;
+        let _ = server.start_with_shutdown(std::future::pending()).await;
     });
 
-    // Poll /health by hand over a raw TcpStream — `reqwest` is not in
+    // Poll /healthz by hand over a raw TcpStream — `reqwest` is not in
     // the dep graph, and the handler returns a plain `"OK"` body that
     // a one-shot GET is sufficient to verify. Once we see `HTTP/1.1 200`
     // we've proven the listener accepted the connection on the
@@ -186,7 +187,7 @@ bind = "127.0.0.1"
     use tokio::io::{AsyncReadExt, AsyncWriteExt};
     let addr = format!("127.0.0.1:{}", port);
     let request =
-        format!("GET /health HTTP/1.1\r\nHost: 127.0.0.1:{port}\r\nConnection: close\r\n\r\n");
+        format!("GET /healthz HTTP/1.1\r\nHost: 127.0.0.1:{port}\r\nConnection: close\r\n\r\n");
     let mut last_outcome: Option<String> = None;
     let deadline = Instant::now() + Duration::from_secs(2);
     while Instant::now() < deadline {
@@ -214,7 +215,7 @@ bind = "127.0.0.1"
     }
     server_task.abort();
     panic!(
-        "web server on configured port {} never returned 200 from /health (last outcome: {:?})",
+        "web server on configured port {} never returned 200 from /healthz (last outcome: {:?})",
         port, last_outcome,
     );
 }
@@ -556,7 +557,7 @@ fn override_root(
     for (action, key) in overrides {
         cfg.keybindings
             .inner
-            .insert((*action).to_string(), (*key).to_string());
+            .insert((*action).to_string(), (*key).into());
     }
 
     let scanner = MaildirScanner::new(maildir.to_path_buf());
@@ -644,18 +645,18 @@ fn keybindings_override_delete_drives_through_process_event_to_trash() {
 
 #[test]
 fn keybindings_override_reply_all_drives_through_process_event_to_draft_start() {
-    // ReplyAll defaults to `r`. Rebind to `w` (free in the default
+    // ReplyAll defaults to `r`. Rebind to `x` (free in the default
     // keymap) and verify the runtime starts the draft with reply-all
     // semantics — the original To recipient must appear on the new To
     // line, distinguishing from sender-only reply.
     let tmp = TempDir::new().unwrap();
-    let (mut root, _src) = override_root(tmp.path(), "msg-r", &[("reply_all", "w")]);
+    let (mut root, _src) = override_root(tmp.path(), "msg-r", &[("reply_all", "x")]);
 
-    root.process_event(key('w')).unwrap();
+    root.process_event(key('x')).unwrap();
 
     assert!(
         root.has_pending_editor(),
-        "override 'w' must park an editor"
+        "override 'x' must park an editor"
     );
     let state = root.draft().state().expect("draft started");
     assert_eq!(state.reply_kind, ReplyKind::ReplyAll);
@@ -824,24 +825,29 @@ fn keybindings_override_reply_to_single_key_dispatches_via_single_lookup() {
 }
 
 #[test]
-fn g_prefix_in_folders_pane_does_not_hold_pending_buffer() {
+fn g_prefix_in_accounts_pane_does_not_hold_pending_buffer() {
     // Sequence prefixes are pane-aware: every default `g`-prefix
-    // sequence (`gr → Reply`, `gg`/`G`/`gj`/`gk` → unimplemented jumps)
-    // either targets the Messages pane or has no Msg variant yet, so
-    // none of them are meaningful in the Folders pane. Pressing `g` in
-    // Folders must therefore NOT hold a sequence prefix — otherwise the
-    // next key would be silently consumed against a sequence that can
-    // never resolve to a meaningful action there.
+    // sequence (`gr → Reply`, `gg`/`G` → list/body jumps, `gj`/`gk` →
+    // unimplemented jumps) targets the Folders, Messages or Content
+    // pane, so none of them are meaningful in the Accounts pane.
+    // Pressing `g` there must therefore NOT hold a sequence prefix —
+    // otherwise the next key would be silently consumed against a
+    // sequence that can never resolve to a meaningful action there.
     let tmp = TempDir::new().unwrap();
-    let (mut root, _src) = override_root(tmp.path(), "fld-g", &[]);
-    root.set_active_pane_for_test(ActivePane::Folders);
+    let (mut root, _src) = override_root(tmp.path(), "acct-g", &[]);
+    root.set_active_pane_for_test(ActivePane::Accounts);
 
     root.process_event(key('g')).unwrap();
     assert_eq!(
         root.pending_keys_len_for_test(),
         0,
-        "`g` in Folders must not hold — no `g`-prefix sequence is meaningful here",
+        "`g` in Accounts must not hold — no `g`-prefix sequence is meaningful here",
     );
+
+    // `gg` jumps in Folders, so the same `g` holds there.
+    root.set_active_pane_for_test(ActivePane::Folders);
+    root.process_event(key('g')).unwrap();
+    assert_eq!(root.pending_keys_len_for_test(), 1);
 }
 
 #[test]
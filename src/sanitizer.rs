@@ -119,15 +119,52 @@ pub fn sanitize_email_html(raw: &str) -> String {
 }
 
 /// vu-aoy: strip every `<img>` tag from already-sanitized HTML so the
-/// web pane shows no images by default. Re-runs ammonia with `<img>`
-/// removed from the allowlist — this is the SECOND pass on output
-/// that already went through `sanitize_email_html`, so the input is
-/// trusted; we're just narrowing what survives.
+/// web pane shows no images by default — except `cid:` references,
+/// which point at the message's own inline parts and never leave this
+/// server. Re-runs ammonia, then drops the other `<img>` tags from its
+/// output — this is the SECOND pass on output that already went
+/// through `sanitize_email_html`, so the input is trusted; we're just
+/// narrowing what survives.
 pub fn strip_images(sanitized_html: &str) -> String {
     let allowed_tags: HashSet<&str> = [
-        "p", "br", "hr", "b", "i", "em", "strong", "u", "s", "small", "sub", "sup", "a", "ul",
-        "ol", "li", "blockquote", "pre", "code", "table", "thead", "tbody", "tfoot", "tr", "td",
-        "th", "caption", "colgroup", "col", "h1", "h2", "h3", "h4", "h5", "h6", "span", "div",
+        "p",
+        "br",
+        "hr",
+        "b",
+        "i",
+        "em",
+        "strong",
+        "u",
+        "s",
+        "small",
+        "sub",
+        "sup",
+        "a",
+        "ul",
+        "ol",
+        "li",
+        "blockquote",
+        "pre",
+        "code",
+        "table",
+        "thead",
+        "tbody",
+        "tfoot",
+        "tr",
+        "td",
+        "th",
+        "caption",
+        "colgroup",
+        "col",
+        "h1",
+        "h2",
+        "h3",
+        "h4",
+        "h5",
+        "h6",
+        "span",
+        "div",
+        "img",
     ]
     .into_iter()
     .collect();
@@ -136,11 +173,36 @@ pub fn strip_images(sanitized_html: &str) -> String {
     ]
     .into_iter()
     .collect();
-    Builder::default()
+    let cleaned = Builder::default()
         .tags(allowed_tags)
         .url_schemes(url_schemes)
         .clean(sanitized_html)
-        .to_string()
+        .to_string();
+
+    // Ammonia's serializer writes `<img` lowercase with double-quoted
+    // attributes, so a quote-aware scan finds each tag's end.
+    let mut out = String::with_capacity(cleaned.len());
+    let mut rest = cleaned.as_str();
+    while let Some(at) = rest.find("<img") {
+        out.push_str(&rest[..at]);
+        let mut in_quotes = false;
+        let end = rest[at..]
+            .char_indices()
+            .find(|&(_, c)| {
+                if c == '"' {
+                    in_quotes = !in_quotes;
+                }
+                c == '>' && !in_quotes
+            })
+            .map_or(rest.len(), |(i, _)| at + i + 1);
+        let tag = &rest[at..end];
+        if tag.contains(" src=\"cid:") {
+            out.push_str(tag);
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
 }
 
 #[cfg(test)]
@@ -152,7 +214,8 @@ mod tests {
     /// hasn't pressed Shift+I).
     #[test]
     fn strip_images_drops_img_tags() {
-        let pre_sanitized = "<p>before</p><img src=\"data:image/png;base64,abc\" alt=\"pixel\"><p>after</p>";
+        let pre_sanitized =
+            "<p>before</p><img src=\"data:image/png;base64,abc\" alt=\"pixel\"><p>after</p>";
         let out = strip_images(pre_sanitized);
         assert!(
             !out.contains("<img"),
@@ -163,6 +226,27 @@ mod tests {
         assert!(out.contains("<p>after</p>"));
     }
 
+    /// `cid:` images are the message's own inline parts, so they stay
+    /// while every other `<img>` goes — even one whose `alt` holds `>`.
+    #[test]
+    fn strip_images_keeps_cid_images() {
+        let pre_sanitized = "<p>a</p><img alt=\"x > y\" src=\"cid:logo@x\">\
+                             <img src=\"data:image/png;base64,abc\" alt=\"1 > 0\"><p>b</p>";
+        let out = strip_images(pre_sanitized);
+        assert_eq!(out.matches("<img").count(), 1, "{}", out);
+        assert!(out.contains("src=\"cid:logo@x\""), "{}", out);
+        assert!(
+            !out.contains("data:") && !out.contains("1 &gt; 0"),
+            "{}",
+            out
+        );
+        assert!(
+            out.contains("<p>a</p>") && out.contains("<p>b</p>"),
+            "{}",
+            out
+        );
+    }
+
     /// `<script>` is the canonical XSS vector — the sanitizer must strip
     /// the tag and its contents so injected JS never reaches the DOM.
     #[test]
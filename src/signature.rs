@@ -0,0 +1,133 @@
+//! Signature and mailing-list footer detection for plain-text bodies.
+//!
+//! The Content pane dims the trailing block of a message that is
+//! boilerplate rather than content: the signature after a `-- `
+//! delimiter (RFC 3676 §4.3) and list footers fenced off by a long run
+//! of dashes or underscores ("To unsubscribe…"). Detection is
+//! deliberately conservative — only the last [`TRAILER_MAX_LINES`]
+//! lines are considered, delimiters inside fenced code or quoted
+//! (`>`-prefixed) text are ignored, and a candidate block that is
+//! followed by quoted text is rejected (a top-poster's signature sits
+//! mid-body, above the quoted reply).
+
+/// Longest trailing block that still counts as a signature or footer.
+/// Anything longer is more likely real content that happens to follow
+/// a rule line.
+pub const TRAILER_MAX_LINES: usize = 20;
+
+/// Shortest run of `-` / `_` accepted as a list-footer separator.
+const FOOTER_RULE_MIN: usize = 10;
+
+/// Phrases that mark a block after a rule line as list boilerplate.
+/// Matched case-insensitively.
+const FOOTER_MARKERS: &[&str] = &[
+    "unsubscribe",
+    "mailing list",
+    "listinfo",
+    "list-info",
+    "you received this message because",
+    "you are receiving this",
+    "manage your subscription",
+];
+
+/// Index of the first line of the trailing signature / list-footer
+/// block in `body`, or `None` when no such block is detected. Lines are
+/// counted as by [`str::lines`].
+pub fn trailer_start(body: &str) -> Option<usize> {
+    let lines: Vec<&str> = body.lines().collect();
+    let window_start = lines.len().saturating_sub(TRAILER_MAX_LINES);
+
+    // Fence state has to be tracked from the top so a ``` opened above
+    // the window still masks delimiters inside it.
+    let mut in_fence = false;
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence || i < window_start || is_quoted(line) {
+            continue;
+        }
+        let tail = &lines[i + 1..];
+        let hit = (is_signature_delimiter(line) && !tail.iter().any(|l| is_quoted(l)))
+            || (is_footer_rule(line) && is_footer_block(tail));
+        if hit {
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn is_quoted(line: &str) -> bool {
+    line.trim_start().starts_with('>')
+}
+
+/// `-- ` per RFC 3676; bare `--` too, since HTML→text conversion and
+/// some clients strip the trailing space.
+fn is_signature_delimiter(line: &str) -> bool {
+    line.trim_end_matches('\r') == "-- " || line.trim_end() == "--"
+}
+
+fn is_footer_rule(line: &str) -> bool {
+    let t = line.trim();
+    t.len() >= FOOTER_RULE_MIN && (t.chars().all(|c| c == '-') || t.chars().all(|c| c == '_'))
+}
+
+fn is_footer_block(tail: &[&str]) -> bool {
+    !tail.iter().any(|l| is_quoted(l))
+        && tail.iter().any(|l| {
+            let lower = l.to_lowercase();
+            FOOTER_MARKERS.iter().any(|m| lower.contains(m))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_standard_signature_delimiter() {
+        let body = "Hi,\n\nSee you Tuesday.\n\n-- \nAda Lovelace\nAnalytical Engines Ltd";
+        assert_eq!(trailer_start(body), Some(4));
+    }
+
+    #[test]
+    fn detects_list_footer_after_rule() {
+        let body = "Release notes below.\n\n______________________________\n\
+                    dev mailing list\nhttps://lists.example.org/listinfo/dev";
+        assert_eq!(trailer_start(body), Some(2));
+    }
+
+    #[test]
+    fn rule_without_footer_markers_is_not_dimmed() {
+        let body = "Table:\n----------------\nrow one\nrow two";
+        assert_eq!(trailer_start(body), None);
+    }
+
+    #[test]
+    fn delimiter_in_quoted_text_is_ignored() {
+        let body = "Agreed.\n\n> Earlier point\n> -- \n> Bob\n";
+        assert_eq!(trailer_start(body), None);
+    }
+
+    #[test]
+    fn top_posted_signature_above_quote_is_ignored() {
+        let body = "Sounds good.\n-- \nAlice\n\n> original message\n> more";
+        assert_eq!(trailer_start(body), None);
+    }
+
+    #[test]
+    fn delimiter_inside_code_fence_is_ignored() {
+        let body = "Run this:\n```\n-- \nSELECT 1;\n```\nThanks";
+        assert_eq!(trailer_start(body), None);
+    }
+
+    #[test]
+    fn delimiter_far_above_the_tail_is_ignored() {
+        let mut body = String::from("Intro\n-- \n");
+        for i in 0..(TRAILER_MAX_LINES + 5) {
+            body.push_str(&format!("content line {i}\n"));
+        }
+        assert_eq!(trailer_start(&body), None);
+    }
+}
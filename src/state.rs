@@ -0,0 +1,140 @@
+//! UI state remembered between runs.
+//!
+//! Unlike `vulthor.toml`, which the user writes, this file is written by
+//! vulthor itself whenever a remembered choice changes: which folders
+//! are folded in the folder pane and how each folder's message list is
+//! sorted. It lives at `~/.local/share/vulthor/state.toml` and is keyed
+//! by folder path, so entries survive rescans and a folder that moves
+//! simply falls back to the defaults.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::email::SortOrder;
+use crate::error::Result;
+
+/// One folder's remembered choices. A `None` means the user never
+/// changed it, and the global default applies.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FolderPrefs {
+    /// Folded (`true`) or unfolded (`false`) in the folder pane.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collapsed: Option<bool>,
+    /// `:sort` order of the folder's message list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SortOrder>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub sort_reversed: bool,
+}
+
+/// Everything in the state file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UiState {
+    #[serde(default)]
+    pub folders: BTreeMap<PathBuf, FolderPrefs>,
+}
+
+impl UiState {
+    /// `~/.local/share/vulthor/state.toml` (the platform data dir on
+    /// other systems). `None` when no data dir resolves; nothing is
+    /// remembered then.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|d| d.join("vulthor").join("state.toml"))
+    }
+
+    /// Read the state file. A missing file is an empty state; a file
+    /// that doesn't parse is logged and ignored rather than blocking
+    /// startup.
+    pub fn load(path: &Path) -> Self {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) => {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    tracing::warn!(error = %e, path = %path.display(), "could not read state file");
+                }
+                return Self::default();
+            }
+        };
+        toml::from_str(&text).unwrap_or_else(|e| {
+            tracing::warn!(error = %e, path = %path.display(), "ignoring unreadable state file");
+            Self::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// The saved choices for `folder`, all defaults when there are none.
+    pub fn folder(&self, folder: &Path) -> FolderPrefs {
+        self.folders.get(folder).cloned().unwrap_or_default()
+    }
+
+    /// Change `folder`'s choices, dropping the entry once nothing is
+    /// left in it so the file doesn't fill up with empty tables.
+    pub fn update_folder(&mut self, folder: &Path, change: impl FnOnce(&mut FolderPrefs)) {
+        let prefs = self.folders.entry(folder.to_path_buf()).or_default();
+        change(prefs);
+        if *prefs == FolderPrefs::default() {
+            self.folders.remove(folder);
+        }
+    }
+
+    /// Lay the remembered fold state over the folder pane's defaults.
+    pub fn apply_collapsed(&self, collapsed: &mut HashSet<PathBuf>) {
+        for (path, prefs) in &self.folders {
+            match prefs.collapsed {
+                Some(true) => {
+                    collapsed.insert(path.clone());
+                }
+                Some(false) => {
+                    collapsed.remove(path);
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folder_prefs_round_trip_through_the_state_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("vulthor/state.toml");
+        assert_eq!(UiState::load(&path), UiState::default());
+
+        let mut state = UiState::default();
+        state.update_folder(Path::new("/mail/INBOX"), |p| {
+            p.sort = Some(SortOrder::Subject);
+            p.sort_reversed = true;
+        });
+        state.update_folder(Path::new("/mail/Work"), |p| p.collapsed = Some(false));
+        state.update_folder(Path::new("/mail/Old"), |p| p.collapsed = Some(true));
+        state.save(&path).unwrap();
+        assert_eq!(UiState::load(&path), state);
+
+        state.update_folder(Path::new("/mail/Old"), |p| p.collapsed = None);
+        assert_eq!(state.folders.len(), 2, "an emptied entry is dropped");
+        assert_eq!(
+            state.folder(Path::new("/mail/Elsewhere")),
+            FolderPrefs::default()
+        );
+
+        let mut collapsed = HashSet::from([PathBuf::from("/mail/Work")]);
+        state.update_folder(Path::new("/mail/Old"), |p| p.collapsed = Some(true));
+        state.apply_collapsed(&mut collapsed);
+        assert_eq!(collapsed, HashSet::from([PathBuf::from("/mail/Old")]));
+
+        std::fs::write(&path, "folders = 3").unwrap();
+        assert_eq!(UiState::load(&path), UiState::default());
+    }
+}
@@ -793,6 +793,36 @@ CLICK HERE IMMEDIATELY to claim your prize:
         self.write_email(&format!("{}/new", folder), filename, content);
     }
 
+    /// Write a `multipart/related` HTML email into `<folder>/cur` whose
+    /// body shows a 1×1 PNG by `cid:pixel@v.test`, next to a remote
+    /// image that must stay blocked.
+    pub fn add_inline_image_email(&self, folder: &str, filename: &str) {
+        let png_b64 = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=";
+        let content = format!(
+            "From: news@v.test\r\n\
+             To: me@v.test\r\n\
+             Subject: Inline pixel\r\n\
+             Date: Mon, 01 Jan 2024 10:00:00 +0000\r\n\
+             MIME-Version: 1.0\r\n\
+             Content-Type: multipart/related; boundary=REL; type=\"text/html\"\r\n\
+             \r\n\
+             --REL\r\n\
+             Content-Type: text/html; charset=UTF-8\r\n\
+             \r\n\
+             <p><img src=\"cid:pixel@v.test\"> <img src=\"https://tracker.test/p.gif\"></p>\r\n\
+             --REL\r\n\
+             Content-Type: image/png\r\n\
+             Content-Transfer-Encoding: base64\r\n\
+             Content-ID: <pixel@v.test>\r\n\
+             Content-Disposition: inline\r\n\
+             \r\n\
+             {}\r\n\
+             --REL--\r\n",
+            png_b64
+        );
+        self.add_custom_email(folder, filename, &content);
+    }
+
     pub fn list_folders(&self) -> Vec<String> {
         let mut folders = Vec::new();
         self.collect_folders(&self.root_path, "", &mut folders);
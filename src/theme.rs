@@ -2,17 +2,17 @@
 //
 // Two surfaces live here:
 //   - `VulthorTheme` — the historical unit struct exposing the built-in
-//     palette as compile-time `Color` constants. Rendering code still
-//     reads `VulthorTheme::PRIMARY` etc. directly; refactoring those to
-//     read from the runtime `Theme` is tracked separately.
+//     palette as compile-time `Color` constants. `Theme::default()`
+//     is built from them; rendering code reads the runtime `Theme`.
 //   - `Theme` — a runtime, per-role color struct that can be loaded from
 //     `~/.config/vulthor/themes/<name>.toml` and tweaked via the
 //     `[theme].overrides` map in `vulthor.toml`. `build_theme(&config)`
 //     resolves built-in → user-file → overrides into a final `Theme`.
+//     `Theme::monochrome()` replaces it under `--no-color` / `NO_COLOR`.
 
 use crate::config::Config;
 use crate::error::{Result, VulthorError};
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier, Style};
 use serde::Deserialize;
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
@@ -58,7 +58,10 @@ impl ThemePreset {
     /// Parse a TOML preset name back into a variant. `None` for typos so
     /// the config validator can raise a structured error.
     pub fn from_name(name: &str) -> Option<ThemePreset> {
-        ThemePreset::all().iter().copied().find(|p| p.name() == name)
+        ThemePreset::all()
+            .iter()
+            .copied()
+            .find(|p| p.name() == name)
     }
 
     /// Next preset in cycle order, wrapping past the last variant.
@@ -85,30 +88,33 @@ impl ThemePreset {
                 cyan_light: Color::Rgb(0x4A, 0xB0, 0xDC),
                 gray_dark: Color::Rgb(0x55, 0x5B, 0x6C),
                 gray_light: Color::Rgb(0x2C, 0x30, 0x3A),
+                monochrome: false,
             },
             ThemePreset::SolarizedDark => Theme {
                 // Solarized dark base palette (Ethan Schoonover).
-                dark: Color::Rgb(0x00, 0x2B, 0x36),       // base03
-                primary: Color::Rgb(0x07, 0x36, 0x42),    // base02
-                light: Color::Rgb(0x58, 0x6E, 0x75),      // base01
-                accent: Color::Rgb(0xCB, 0x4B, 0x16),     // orange
+                dark: Color::Rgb(0x00, 0x2B, 0x36),    // base03
+                primary: Color::Rgb(0x07, 0x36, 0x42), // base02
+                light: Color::Rgb(0x58, 0x6E, 0x75),   // base01
+                accent: Color::Rgb(0xCB, 0x4B, 0x16),  // orange
                 accent_light: Color::Rgb(0xB5, 0x89, 0x00), // yellow
-                cyan: Color::Rgb(0x2A, 0xA1, 0x98),       // cyan
+                cyan: Color::Rgb(0x2A, 0xA1, 0x98),    // cyan
                 cyan_light: Color::Rgb(0x26, 0x8B, 0xD2), // blue
-                gray_dark: Color::Rgb(0x65, 0x7B, 0x83),  // base00
+                gray_dark: Color::Rgb(0x65, 0x7B, 0x83), // base00
                 gray_light: Color::Rgb(0xEE, 0xE8, 0xD5), // base2
+                monochrome: false,
             },
             ThemePreset::Nord => Theme {
                 // Nord palette (Arctic Ice Studio).
-                dark: Color::Rgb(0x2E, 0x34, 0x40),       // nord0
-                primary: Color::Rgb(0x3B, 0x42, 0x52),    // nord1
-                light: Color::Rgb(0x43, 0x4C, 0x5E),      // nord2
-                accent: Color::Rgb(0xD0, 0x87, 0x70),     // nord12 (orange)
+                dark: Color::Rgb(0x2E, 0x34, 0x40),    // nord0
+                primary: Color::Rgb(0x3B, 0x42, 0x52), // nord1
+                light: Color::Rgb(0x43, 0x4C, 0x5E),   // nord2
+                accent: Color::Rgb(0xD0, 0x87, 0x70),  // nord12 (orange)
                 accent_light: Color::Rgb(0xEB, 0xCB, 0x8B), // nord13 (yellow)
-                cyan: Color::Rgb(0x88, 0xC0, 0xD0),       // nord8
+                cyan: Color::Rgb(0x88, 0xC0, 0xD0),    // nord8
                 cyan_light: Color::Rgb(0x8F, 0xBC, 0xBB), // nord7
-                gray_dark: Color::Rgb(0x4C, 0x56, 0x6A),  // nord3
+                gray_dark: Color::Rgb(0x4C, 0x56, 0x6A), // nord3
                 gray_light: Color::Rgb(0xEC, 0xEF, 0xF4), // nord6
+                monochrome: false,
             },
         }
     }
@@ -179,6 +185,11 @@ impl VulthorTheme {
 /// Override keys (TOML field names) match the snake_case role names
 /// below verbatim — e.g. `primary = "#2C4F5D"` or
 /// `accent = "#FF8C42"`. Unknown keys are rejected (typo guard).
+///
+/// Rendering code styles through the helpers below (`selection`,
+/// `focus`, `error`, …) rather than building colours itself, so the
+/// monochrome variant from `--no-color` / `NO_COLOR` reaches every
+/// pane.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Theme {
     pub dark: Color,
@@ -190,6 +201,9 @@ pub struct Theme {
     pub cyan_light: Color,
     pub gray_dark: Color,
     pub gray_light: Color,
+    /// No colours at all: every role is `Color::Reset` and emphasis
+    /// falls back to bold / reverse video. See [`Theme::monochrome`].
+    pub monochrome: bool,
 }
 
 impl Default for Theme {
@@ -204,10 +218,80 @@ impl Default for Theme {
             cyan_light: VulthorTheme::CYAN_LIGHT,
             gray_dark: VulthorTheme::GRAY_DARK,
             gray_light: VulthorTheme::GRAY_LIGHT,
+            monochrome: false,
         }
     }
 }
 
+impl Theme {
+    /// The `--no-color` / `NO_COLOR` theme: the terminal's own
+    /// foreground and background everywhere. Focus and selection show
+    /// as bold and reverse video instead of colour.
+    pub fn monochrome() -> Self {
+        Self {
+            dark: Color::Reset,
+            primary: Color::Reset,
+            light: Color::Reset,
+            accent: Color::Reset,
+            accent_light: Color::Reset,
+            cyan: Color::Reset,
+            cyan_light: Color::Reset,
+            gray_dark: Color::Reset,
+            gray_light: Color::Reset,
+            monochrome: true,
+        }
+    }
+
+    /// Highlight of the cursor row in every list.
+    pub fn selection(&self) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+        } else {
+            Style::default()
+                .bg(self.primary)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        }
+    }
+
+    /// Block style of a pane: `color` while it has focus, plain
+    /// otherwise. Monochrome marks the focused pane bold instead.
+    pub fn focus(&self, focused: bool, color: Color) -> Style {
+        match (focused, self.monochrome) {
+            (false, _) => Style::default(),
+            (true, false) => Style::default().fg(color),
+            (true, true) => Style::default().add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Foreground of errors: parse failures, the spam mark and error
+    /// status messages.
+    pub fn error(&self) -> Color {
+        if self.monochrome {
+            Color::Reset
+        } else {
+            Color::Red
+        }
+    }
+
+    /// The status bar. Monochrome draws it in reverse video so it still
+    /// stands apart from the panes.
+    pub fn status_bar(&self) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().bg(self.dark).fg(Color::White)
+        }
+    }
+}
+
+/// `true` when the `NO_COLOR` convention (<https://no-color.org>) asks
+/// for plain output: the variable is set to anything but the empty
+/// string.
+pub fn no_color_requested(value: Option<&std::ffi::OsStr>) -> bool {
+    value.is_some_and(|v| !v.is_empty())
+}
+
 /// On-disk schema for `~/.config/vulthor/themes/<name>.toml`. Every
 /// field is optional — missing roles fall back to the built-in default.
 #[derive(Debug, Default, Deserialize)]
@@ -435,6 +519,45 @@ mod tests {
         assert_eq!(t.gray_dark, VulthorTheme::GRAY_DARK);
     }
 
+    #[test]
+    fn monochrome_theme_uses_terminal_defaults_instead_of_rgb() {
+        let t = Theme::monochrome();
+        for color in [
+            t.dark,
+            t.primary,
+            t.light,
+            t.accent,
+            t.accent_light,
+            t.cyan,
+            t.cyan_light,
+            t.gray_dark,
+            t.gray_light,
+            t.error(),
+        ] {
+            assert_eq!(color, Color::Reset);
+        }
+        let selection = t.selection();
+        assert_eq!((selection.fg, selection.bg), (None, None));
+        assert!(selection.add_modifier.contains(Modifier::REVERSED));
+        assert_eq!(t.focus(true, t.cyan).fg, None);
+        assert!(t.focus(true, t.cyan).add_modifier.contains(Modifier::BOLD));
+        assert_eq!(t.status_bar().bg, None);
+
+        assert_eq!(Theme::default().error(), Color::Red);
+        assert!(matches!(
+            Theme::default().selection().bg,
+            Some(Color::Rgb(..))
+        ));
+    }
+
+    #[test]
+    fn no_color_is_requested_by_any_non_empty_value() {
+        use std::ffi::OsStr;
+        assert!(!no_color_requested(None));
+        assert!(!no_color_requested(Some(OsStr::new(""))));
+        assert!(no_color_requested(Some(OsStr::new("1"))));
+    }
+
     #[test]
     fn build_theme_returns_built_in_when_unset() {
         // name=None + overrides empty → identical to `Theme::default`.
@@ -0,0 +1,254 @@
+//! Conversation threading for the message list.
+//!
+//! An email's parent is the message its `In-Reply-To` names, falling
+//! back to the nearest ancestor in `References` that is loaded. Only the
+//! loaded emails of one folder take part, so a reply whose parent lives
+//! in another folder (or hasn't been paged in yet) stays top-level, as
+//! do singletons. A thread sits where its root sits in load order;
+//! replies follow their parent, siblings in load order.
+
+use crate::email::Email;
+use std::collections::{HashMap, HashSet};
+
+/// An email's place in the thread tree. All zero / false outside the
+/// threaded view.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreadPos {
+    /// 0 for a thread root, one more per reply level.
+    pub depth: usize,
+    /// Number of emails below this one in its subtree.
+    pub replies: usize,
+    /// Set on a root whose replies are folded away.
+    pub collapsed: bool,
+    /// Set on a reply under a collapsed root. Hidden rows stay in
+    /// `Folder::emails`; the Messages pane skips them.
+    pub hidden: bool,
+}
+
+/// Identity a collapsed thread is remembered by: the root's
+/// Message-ID, or its path when the header is missing.
+pub fn thread_key(email: &Email) -> String {
+    if email.headers.message_id.is_empty() {
+        email.file_path.display().to_string()
+    } else {
+        email.headers.message_id.clone()
+    }
+}
+
+/// Display order of `emails` as threads: `(index into emails, depth)`
+/// for every email, each root followed by its subtree.
+pub fn thread_order(emails: &[Email]) -> Vec<(usize, usize)> {
+    let mut by_id: HashMap<&str, usize> = HashMap::new();
+    for (i, email) in emails.iter().enumerate() {
+        let id = email.headers.message_id.as_str();
+        if !id.is_empty() {
+            by_id.entry(id).or_insert(i);
+        }
+    }
+
+    let mut parent: Vec<Option<usize>> = emails
+        .iter()
+        .enumerate()
+        .map(|(i, email)| {
+            let h = &email.headers;
+            std::iter::once(&h.in_reply_to)
+                .chain(h.references.iter().rev())
+                .filter_map(|id| by_id.get(id.as_str()).copied())
+                .find(|&p| p != i)
+        })
+        .collect();
+    // Headers can form a loop (two messages replying to each other);
+    // cut it where it's found so every email still has a root.
+    for i in 0..emails.len() {
+        let mut seen = HashSet::from([i]);
+        let mut at = i;
+        while let Some(p) = parent[at] {
+            if !seen.insert(p) {
+                parent[at] = None;
+                break;
+            }
+            at = p;
+        }
+    }
+
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); emails.len()];
+    let mut roots = Vec::new();
+    for (i, p) in parent.iter().enumerate() {
+        match p {
+            Some(p) => children[*p].push(i),
+            None => roots.push(i),
+        }
+    }
+
+    let mut order = Vec::with_capacity(emails.len());
+    let mut stack: Vec<(usize, usize)> = roots.into_iter().rev().map(|r| (r, 0)).collect();
+    while let Some((i, depth)) = stack.pop() {
+        order.push((i, depth));
+        stack.extend(children[i].iter().rev().map(|&c| (c, depth + 1)));
+    }
+    order
+}
+
+/// Reorder `emails` into thread order and fill in each `thread`
+/// position. Roots whose [`thread_key`] is in `collapsed` fold their
+/// replies away.
+pub fn apply(emails: &mut Vec<Email>, collapsed: &HashSet<String>) {
+    let order = thread_order(emails);
+    let mut slots: Vec<Option<Email>> = std::mem::take(emails).into_iter().map(Some).collect();
+    for (i, depth) in order {
+        if let Some(mut email) = slots[i].take() {
+            email.thread = ThreadPos {
+                depth,
+                ..ThreadPos::default()
+            };
+            emails.push(email);
+        }
+    }
+
+    // Subtree sizes: a row's subtree ends at the next row no deeper
+    // than it.
+    let mut open: Vec<usize> = Vec::new();
+    for row in 0..=emails.len() {
+        let depth = emails.get(row).map_or(0, |e| e.thread.depth);
+        while let Some(&top) = open.last() {
+            if row < emails.len() && emails[top].thread.depth < depth {
+                break;
+            }
+            emails[top].thread.replies = row - top - 1;
+            open.pop();
+        }
+        open.push(row);
+    }
+
+    let mut row = 0;
+    while row < emails.len() {
+        let replies = emails[row].thread.replies;
+        if replies > 0 && collapsed.contains(&thread_key(&emails[row])) {
+            emails[row].thread.collapsed = true;
+            for reply in &mut emails[row + 1..=row + replies] {
+                reply.thread.hidden = true;
+            }
+        }
+        row += replies + 1;
+    }
+}
+
+/// Row of the root of the thread containing `row`.
+pub fn root_row(emails: &[Email], row: usize) -> usize {
+    (0..=row.min(emails.len().saturating_sub(1)))
+        .rev()
+        .find(|&r| emails[r].thread.depth == 0)
+        .unwrap_or(0)
+}
+
+/// Rows the message list shows: every row but the folded-away replies.
+pub fn shown(emails: &[Email]) -> impl Iterator<Item = usize> + '_ {
+    emails
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| !e.thread.hidden)
+        .map(|(i, _)| i)
+}
+
+/// `row`, or its thread's root when `row` is folded away.
+pub fn visible_row(emails: &[Email], row: usize) -> usize {
+    if emails.get(row).is_some_and(|e| e.thread.hidden) {
+        root_row(emails, row)
+    } else {
+        row
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maildir::MaildirScanner;
+    use crate::test_fixtures::TestMailDir;
+    use std::path::PathBuf;
+
+    fn email(id: &str, in_reply_to: &str, references: &[&str]) -> Email {
+        let mut e = Email::new(PathBuf::from(format!("/mail/{}", id)));
+        e.headers.message_id = id.to_string();
+        e.headers.in_reply_to = in_reply_to.to_string();
+        e.headers.references = references.iter().map(|r| r.to_string()).collect();
+        e
+    }
+
+    fn ids_and_depths(emails: &[Email]) -> Vec<(&str, usize)> {
+        emails
+            .iter()
+            .map(|e| (e.headers.message_id.as_str(), e.thread.depth))
+            .collect()
+    }
+
+    #[test]
+    fn the_fixture_reply_threads_under_the_meeting_email() {
+        let fixture = TestMailDir::new();
+        let scanner = MaildirScanner::new(fixture.root_path.clone());
+        let mut emails = Vec::new();
+        for name in ["INBOX", "Sent"] {
+            let mut folder =
+                crate::email::Folder::new(name.to_string(), fixture.get_folder_path(name));
+            scanner
+                .load_folder_emails_with_limit(&mut folder, None)
+                .unwrap();
+            emails.append(&mut folder.emails);
+        }
+
+        apply(&mut emails, &HashSet::new());
+        let meeting = emails
+            .iter()
+            .position(|e| e.headers.message_id == "meeting-001@company.com")
+            .expect("fixture meeting email");
+        let reply = &emails[meeting + 1];
+        assert_eq!(reply.headers.in_reply_to, "meeting-001@company.com");
+        assert_eq!(reply.headers.references, ["meeting-001@company.com"]);
+        assert_eq!(reply.thread.depth, 1);
+        assert_eq!(emails[meeting].thread.depth, 0);
+        assert_eq!(emails[meeting].thread.replies, 1);
+        assert_eq!(
+            emails.iter().filter(|e| e.thread.depth == 0).count(),
+            emails.len() - 1,
+            "everything else is a singleton"
+        );
+    }
+
+    #[test]
+    fn apply_nests_by_reference_keeps_root_order_and_folds_collapsed_threads() {
+        let mut emails = vec![
+            email("c", "b", &["a", "b"]),
+            email("solo", "", &[]),
+            email("a", "", &[]),
+            // Parent not loaded: falls back to the nearest loaded reference.
+            email("d", "missing", &["a", "missing"]),
+            email("b", "a", &["a"]),
+            // A loop must not drop either message.
+            email("x", "y", &[]),
+            email("y", "x", &[]),
+        ];
+        apply(&mut emails, &HashSet::new());
+        assert_eq!(
+            ids_and_depths(&emails),
+            [
+                ("solo", 0),
+                ("a", 0),
+                ("d", 1),
+                ("b", 1),
+                ("c", 2),
+                ("y", 0),
+                ("x", 1),
+            ]
+        );
+        assert_eq!(emails[1].thread.replies, 3);
+        assert_eq!(emails[3].thread.replies, 1);
+        assert_eq!(root_row(&emails, 4), 1);
+
+        apply(&mut emails, &HashSet::from(["a".to_string()]));
+        assert!(emails[1].thread.collapsed);
+        let hidden: Vec<bool> = emails.iter().map(|e| e.thread.hidden).collect();
+        assert_eq!(hidden, [false, false, true, true, true, false, false]);
+        assert_eq!(visible_row(&emails, 3), 1);
+        assert_eq!(shown(&emails).collect::<Vec<_>>(), [0, 1, 5, 6]);
+        assert_eq!(visible_row(&emails, 6), 6);
+    }
+}
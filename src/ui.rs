@@ -1,21 +1,34 @@
 use crate::components::{
-    AccountsComponent, Component, ContentComponent, Ctx, DraftComponent, FolderPickerComponent,
-    FoldersComponent, MessagesComponent, SearchComponent,
+    AccountsComponent, CommandLineComponent, Component, ContentComponent, Ctx, DraftComponent,
+    FolderPickerComponent, FoldersComponent, MessagesComponent, SearchComponent, StatusKind,
+    StatusMessage,
 };
 use crate::config::Config;
 use crate::email::{EmailLoadState, EmailStore};
+use crate::keymap::{Action, Keymap};
 use crate::layout::{self, ActivePane, Layout, View};
 use crate::theme::Theme;
+use crate::util::format_file_size;
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout as RLayout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout as RLayout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 
+/// Columns reserved at the right of the status bar for the
+/// [`position_indicator`].
+const POSITION_WIDTH: u16 = 12;
+
+/// Braille spinner frames shown next to "Loading…" while folders or
+/// headers load, one step per drawn frame.
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
 pub struct UI {
     attachment_list_state: ListState,
+    /// Frames drawn so far; drives the loading spinner.
+    pub frame_count: u64,
 }
 
 impl Default for UI {
@@ -28,6 +41,7 @@ impl UI {
     pub fn new() -> Self {
         Self {
             attachment_list_state: ListState::default(),
+            frame_count: 0,
         }
     }
 
@@ -37,9 +51,12 @@ impl UI {
         f: &mut Frame,
         store: &mut EmailStore,
         layout: &Layout,
-        status_message: &Option<String>,
+        status_message: Option<&StatusMessage>,
+        notification: Option<&StatusMessage>,
+        pending_command: Option<&str>,
+        loading: bool,
         images_visible: bool,
-        help_visible: bool,
+        help: Option<&crate::components::help::HelpView>,
         folders: &FoldersComponent,
         messages: &MessagesComponent,
         content: &ContentComponent,
@@ -47,17 +64,18 @@ impl UI {
         draft: &DraftComponent,
         folder_picker: &FolderPickerComponent,
         search: &SearchComponent,
+        command_line: &CommandLineComponent,
         config: &Config,
-        keymap: &crate::keymap::Keymap,
+        keymap: &Keymap,
         theme: &Theme,
     ) {
+        self.frame_count = self.frame_count.wrapping_add(1);
         let size = f.area();
-        if help_visible {
+        if let Some(view) = help {
             self.draw_main_layout(
-                f, store, layout, folders, messages, content, accounts, draft, config, theme,
-                size,
+                f, store, layout, folders, messages, content, accounts, draft, config, theme, size,
             );
-            crate::components::help::render_help_overlay(f, size, keymap, theme);
+            crate::components::help::render_help_overlay(f, size, keymap, view, theme);
             return;
         }
         self.draw_main_layout(
@@ -68,8 +86,13 @@ impl UI {
             store,
             layout,
             content.prefer_plaintext,
+            content.word_wrap,
+            loading,
             images_visible,
+            messages.tagged.len(),
             status_message,
+            pending_command,
+            keymap,
             theme,
             size,
         );
@@ -79,6 +102,10 @@ impl UI {
         // screen, so they never collide.
         folder_picker.render_modal(f, size, theme);
         search.render_modal(f, size, theme);
+        command_line.render_modal(f, size, theme);
+        if let Some(message) = notification {
+            draw_notification_popup(f, size, message, theme);
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -96,12 +123,10 @@ impl UI {
         theme: &Theme,
         area: Rect,
     ) {
+        lay.pane_areas.borrow_mut().clear();
         match lay.current_view {
             View::FolderMessages => {
-                let chunks = RLayout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                    .split(area);
+                let chunks = layout::split_columns(area, lay.folder_split);
 
                 let is_folders_active = matches!(lay.active_pane, ActivePane::Folders);
                 let is_messages_active = matches!(lay.active_pane, ActivePane::Messages);
@@ -112,6 +137,15 @@ impl UI {
                     store,
                 };
                 folders.render(f, chunks[0], is_folders_active, &ctx);
+                let folder_rows =
+                    layout::count_visible_folders(&store.root_folder, &folders.collapsed);
+                Self::record_pane(
+                    lay,
+                    ActivePane::Folders,
+                    chunks[0],
+                    folders.list_offset(),
+                    folder_rows,
+                );
                 Self::draw_messages_pane(
                     f,
                     store,
@@ -121,13 +155,11 @@ impl UI {
                     chunks[1],
                     is_messages_active,
                     theme,
+                    config,
                 );
             }
             View::MessagesContent => {
-                let chunks = RLayout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                    .split(area);
+                let chunks = layout::split_columns(area, lay.message_split);
 
                 let is_messages_active = matches!(lay.active_pane, ActivePane::Messages);
                 let is_content_active = matches!(lay.active_pane, ActivePane::Content);
@@ -141,10 +173,12 @@ impl UI {
                     chunks[0],
                     is_messages_active,
                     theme,
+                    config,
                 );
                 Self::render_content_pane(
                     f,
                     store,
+                    lay,
                     content,
                     chunks[1],
                     is_content_active,
@@ -157,6 +191,7 @@ impl UI {
                 Self::render_content_pane(
                     f,
                     store,
+                    lay,
                     content,
                     area,
                     is_content_active,
@@ -175,13 +210,11 @@ impl UI {
                     area,
                     is_messages_active,
                     theme,
+                    config,
                 );
             }
             View::MessagesAttachments => {
-                let chunks = RLayout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                    .split(area);
+                let chunks = layout::split_columns(area, lay.message_split);
 
                 let is_messages_active = matches!(lay.active_pane, ActivePane::Messages);
                 let is_attachments_active = matches!(lay.active_pane, ActivePane::Attachments);
@@ -195,14 +228,20 @@ impl UI {
                     chunks[0],
                     is_messages_active,
                     theme,
+                    config,
+                );
+                self.draw_attachments_pane(
+                    f,
+                    store,
+                    lay,
+                    chunks[1],
+                    is_attachments_active,
+                    config.render.nerd_fonts,
+                    theme,
                 );
-                self.draw_attachments_pane(f, store, lay, chunks[1], is_attachments_active, theme);
             }
             View::AccountsFolders => {
-                let chunks = RLayout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                    .split(area);
+                let chunks = layout::split_columns(area, lay.folder_split);
 
                 let is_accounts_active = matches!(lay.active_pane, ActivePane::Accounts);
                 let is_folders_active = matches!(lay.active_pane, ActivePane::Folders);
@@ -214,6 +253,79 @@ impl UI {
                 };
                 accounts.render(f, chunks[0], is_accounts_active, &ctx);
                 folders.render(f, chunks[1], is_folders_active, &ctx);
+                let folder_rows =
+                    layout::count_visible_folders(&store.root_folder, &folders.collapsed);
+                Self::record_pane(lay, ActivePane::Accounts, chunks[0], 0, 0);
+                Self::record_pane(
+                    lay,
+                    ActivePane::Folders,
+                    chunks[1],
+                    folders.list_offset(),
+                    folder_rows,
+                );
+            }
+            View::ThreePane => {
+                let [folders_area, rest] = if lay.content_pane_hidden {
+                    layout::split_columns(area, lay.folder_split)
+                } else {
+                    layout::split_columns(area, layout::THREE_PANE_FOLDER_PERCENT)
+                };
+
+                let is_folders_active = matches!(lay.active_pane, ActivePane::Folders);
+                let is_messages_active = matches!(lay.active_pane, ActivePane::Messages);
+                let is_content_active = matches!(lay.active_pane, ActivePane::Content);
+
+                let ctx = Ctx {
+                    theme,
+                    config,
+                    store,
+                };
+                folders.render(f, folders_area, is_folders_active, &ctx);
+                let folder_rows =
+                    layout::count_visible_folders(&store.root_folder, &folders.collapsed);
+                Self::record_pane(
+                    lay,
+                    ActivePane::Folders,
+                    folders_area,
+                    folders.list_offset(),
+                    folder_rows,
+                );
+                if lay.content_pane_hidden {
+                    Self::draw_messages_pane(
+                        f,
+                        store,
+                        lay,
+                        folders,
+                        messages,
+                        rest,
+                        is_messages_active,
+                        theme,
+                        config,
+                    );
+                } else {
+                    let chunks = layout::split_columns(rest, lay.message_split);
+                    Self::draw_messages_pane(
+                        f,
+                        store,
+                        lay,
+                        folders,
+                        messages,
+                        chunks[0],
+                        is_messages_active,
+                        theme,
+                        config,
+                    );
+                    Self::render_content_pane(
+                        f,
+                        store,
+                        lay,
+                        content,
+                        chunks[1],
+                        is_content_active,
+                        config,
+                        theme,
+                    );
+                }
             }
             View::ContentDraft => {
                 let chunks = RLayout::default()
@@ -227,6 +339,7 @@ impl UI {
                 Self::render_content_pane(
                     f,
                     store,
+                    lay,
                     content,
                     chunks[0],
                     is_content_active,
@@ -239,6 +352,7 @@ impl UI {
                     store,
                 };
                 draft.render(f, chunks[1], is_draft_active, &ctx);
+                Self::record_pane(lay, ActivePane::Draft, chunks[1], 0, 0);
             }
         }
     }
@@ -253,6 +367,7 @@ impl UI {
         area: Rect,
         is_active: bool,
         theme: &Theme,
+        config: &Config,
     ) {
         // Search-results virtual folder wins over every per-view
         // selection: when a notmuch search is live, the Messages pane
@@ -269,32 +384,36 @@ impl UI {
                 &breadcrumb,
                 &store.drafts,
                 theme,
+                config,
             );
+            let (offset, len) = (messages.list_offset(), results.emails.len());
+            let height = MessagesComponent::row_height(config);
+            Self::record_list_pane(lay, ActivePane::Messages, area, offset, len, height);
             return;
         }
-        let selected_folder = match lay.current_view {
-            View::FolderMessages => {
-                let root = &store.root_folder;
-                let folder_path =
-                    layout::get_folder_path_from_display_index(root, folders.folder_index);
-                folder_path.and_then(|p| store.get_folder_at_path(&p))
-            }
-            _ => None,
+        // While the folder cursor is live, the list previews the folder
+        // under it rather than the one last entered.
+        let previews_hovered_folder = match lay.current_view {
+            View::FolderMessages => true,
+            View::ThreePane => lay.active_pane == ActivePane::Folders,
+            _ => false,
         };
-        let folder_to_display = selected_folder.unwrap_or_else(|| store.get_current_folder());
-
-        let folder_path_str = match lay.current_view {
-            View::FolderMessages => {
-                let root = &store.root_folder;
-                if let Some(path_indices) =
-                    layout::get_folder_path_from_display_index(root, folders.folder_index)
-                {
-                    store.get_folder_path_for_indices(&path_indices)
-                } else {
-                    store.get_folder_path()
-                }
-            }
-            _ => store.get_folder_path(),
+        let hovered_path = if previews_hovered_folder {
+            layout::get_folder_path_from_display_index(
+                &store.root_folder,
+                folders.folder_index,
+                &folders.collapsed,
+            )
+        } else {
+            None
+        };
+        let folder_to_display = hovered_path
+            .as_ref()
+            .and_then(|p| store.get_folder_at_path(p))
+            .unwrap_or_else(|| store.get_current_folder());
+        let folder_path_str = match &hovered_path {
+            Some(path_indices) => store.get_folder_path_for_indices(path_indices),
+            None => store.get_folder_path(),
         };
 
         messages.render_with_folder(
@@ -305,12 +424,19 @@ impl UI {
             &folder_path_str,
             &store.drafts,
             theme,
+            config,
         );
+        let len = crate::threading::shown(&folder_to_display.emails).count();
+        let (offset, len) = (messages.list_offset(), len);
+        let height = MessagesComponent::row_height(config);
+        Self::record_list_pane(lay, ActivePane::Messages, area, offset, len, height);
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn render_content_pane(
         f: &mut Frame,
         store: &EmailStore,
+        lay: &Layout,
         content: &ContentComponent,
         area: Rect,
         is_active: bool,
@@ -323,8 +449,36 @@ impl UI {
             store,
         };
         content.render(f, area, is_active, &ctx);
+        Self::record_pane(lay, ActivePane::Content, area, 0, 0);
+    }
+
+    /// Note where `pane` was drawn this frame for mouse hit-testing.
+    /// `offset` is the list's first visible row and `len` its row count
+    /// (both 0 for non-list panes).
+    fn record_pane(lay: &Layout, pane: ActivePane, rect: Rect, offset: usize, len: usize) {
+        Self::record_list_pane(lay, pane, rect, offset, len, 1);
     }
 
+    /// [`Self::record_pane`] for a list whose rows are `row_height`
+    /// lines tall.
+    fn record_list_pane(
+        lay: &Layout,
+        pane: ActivePane,
+        rect: Rect,
+        offset: usize,
+        len: usize,
+        row_height: usize,
+    ) {
+        lay.pane_areas.borrow_mut().push(layout::PaneArea {
+            pane,
+            rect,
+            offset,
+            len,
+            row_height,
+        });
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn draw_attachments_pane(
         &mut self,
         f: &mut Frame,
@@ -332,13 +486,10 @@ impl UI {
         lay: &Layout,
         area: Rect,
         is_active: bool,
+        nerd_fonts: bool,
         theme: &Theme,
     ) {
-        let border_style = if is_active {
-            Style::default().fg(theme.accent_light)
-        } else {
-            Style::default()
-        };
+        let border_style = theme.focus(is_active, theme.accent_light);
 
         if let Some(email) = store.get_selected_email() {
             if !email.attachments.is_empty() {
@@ -347,23 +498,24 @@ impl UI {
                     .iter()
                     .enumerate()
                     .map(|(i, attachment)| {
-                        let size_str = if attachment.size < 1024 {
-                            format!("{} B", attachment.size)
-                        } else if attachment.size < 1024 * 1024 {
-                            format!("{:.1} KB", attachment.size as f64 / 1024.0)
-                        } else {
-                            format!("{:.1} MB", attachment.size as f64 / (1024.0 * 1024.0))
-                        };
+                        let size_str = format_file_size(attachment.size);
 
                         let content = format!(
-                            "{:2}. {} ({}) - {}",
+                            " {:2}. {} ({}) - {}",
                             i + 1,
                             attachment.filename,
                             attachment.content_type,
                             size_str
                         );
 
-                        ListItem::new(content)
+                        ListItem::new(Line::from(vec![
+                            Span::styled(
+                                mime_type_icon(&attachment.content_type, nerd_fonts),
+                                Style::default()
+                                    .fg(mime_type_color(&attachment.content_type, theme)),
+                            ),
+                            Span::raw(content),
+                        ]))
                     })
                     .collect();
 
@@ -372,12 +524,9 @@ impl UI {
                     .style(border_style)
                     .title("Attachments");
 
-                let list = List::new(attachment_items).block(block).highlight_style(
-                    Style::default()
-                        .bg(theme.primary)
-                        .fg(Color::White)
-                        .add_modifier(Modifier::BOLD),
-                );
+                let list = List::new(attachment_items)
+                    .block(block)
+                    .highlight_style(theme.selection());
 
                 self.attachment_list_state
                     .select(Some(lay.selection.attachment_index));
@@ -411,16 +560,27 @@ impl UI {
 
             f.render_widget(paragraph, area);
         }
+        let offset = self.attachment_list_state.offset();
+        let len = store
+            .get_selected_email()
+            .map_or(0, |e| e.attachments.len());
+        Self::record_pane(lay, ActivePane::Attachments, area, offset, len);
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn draw_status_bar(
         &mut self,
         f: &mut Frame,
         store: &EmailStore,
         lay: &Layout,
         prefer_plaintext: bool,
+        word_wrap: bool,
+        loading: bool,
         images_visible: bool,
-        status_message: &Option<String>,
+        tagged: usize,
+        status_message: Option<&StatusMessage>,
+        pending_command: Option<&str>,
+        keymap: &Keymap,
         theme: &Theme,
         area: Rect,
     ) {
@@ -433,13 +593,21 @@ impl UI {
 
         let mut status_text = vec![];
 
-        let help_text = build_status_hint(lay.content_pane_hidden);
+        let help_text = build_status_hint(keymap, lay.content_pane_hidden);
 
         status_text.push(Span::styled(
             help_text,
             Style::default().fg(theme.gray_dark),
         ));
 
+        if loading {
+            status_text.push(Span::raw(" | "));
+            status_text.push(Span::styled(
+                format!("{} Loading…", spinner_frame(self.frame_count)),
+                Style::default().fg(theme.cyan),
+            ));
+        }
+
         if let Some(indicator) = body_parts_indicator(store) {
             status_text.push(Span::raw(" | "));
             status_text.push(Span::styled(
@@ -456,29 +624,122 @@ impl UI {
             ));
         }
 
-        if images_visible {
+        if !word_wrap {
             status_text.push(Span::raw(" | "));
             status_text.push(Span::styled(
-                "[img]",
+                "[Wrap: off]",
                 Style::default().fg(theme.accent),
             ));
         }
 
+        if lay.resizing {
+            status_text.push(Span::raw(" | "));
+            status_text.push(Span::styled(
+                "[Resize: h/l, = reset, Esc]",
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        if images_visible {
+            status_text.push(Span::raw(" | "));
+            status_text.push(Span::styled("[img]", Style::default().fg(theme.accent)));
+        }
+
+        if tagged > 0 {
+            status_text.push(Span::raw(" | "));
+            status_text.push(Span::styled(
+                format!("{} tagged", tagged),
+                Style::default().fg(theme.cyan).add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        let corrupt = store.corrupt_email_count();
+        if corrupt > 0 {
+            status_text.push(Span::raw(" | "));
+            status_text.push(Span::styled(
+                format!("! {} unreadable", corrupt),
+                Style::default().fg(theme.error()),
+            ));
+        }
+
         if let Some(message) = status_message {
+            let color = if message.is_error() {
+                theme.error()
+            } else {
+                theme.accent
+            };
             status_text.push(Span::raw(" | "));
             status_text.push(Span::styled(
-                message.clone(),
-                Style::default().fg(theme.accent),
+                message.text.clone(),
+                Style::default().fg(color),
+            ));
+        }
+
+        let [hints_area, position_area] = RLayout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(POSITION_WIDTH)])
+            .areas(status_area);
+        let bar_style = theme.status_bar();
+
+        // Half-typed count / sequence prefix, echoed like vim's showcmd.
+        if let Some(pending) = pending_command {
+            status_text.push(Span::raw(" | "));
+            status_text.push(Span::styled(
+                pending.to_string(),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
             ));
         }
 
         let status_line = Line::from(status_text);
-        let status_paragraph =
-            Paragraph::new(status_line).style(Style::default().bg(theme.dark).fg(Color::White));
+        let status_paragraph = Paragraph::new(status_line).style(bar_style);
+        f.render_widget(status_paragraph, hints_area);
 
-        f.render_widget(status_paragraph, status_area);
+        let position = Paragraph::new(position_indicator(store))
+            .style(bar_style)
+            .alignment(Alignment::Right);
+        f.render_widget(position, position_area);
     }
+}
 
+/// Float `message` in a small box at the top-right of `area`, over the
+/// panes. Cyan for info, the accent colour for warnings, red for errors;
+/// long text is cut to half the screen width.
+fn draw_notification_popup(f: &mut Frame, area: Rect, message: &StatusMessage, theme: &Theme) {
+    let color = match message.kind {
+        StatusKind::Info => theme.cyan,
+        StatusKind::Warning => theme.accent,
+        StatusKind::Error => theme.error(),
+    };
+    let max_width = (area.width / 2).max(20).min(area.width);
+    let width = (message.text.chars().count() as u16)
+        .saturating_add(4)
+        .min(max_width);
+    if area.height < 4 || width < 5 {
+        return;
+    }
+    let rect = Rect {
+        x: area.x + area.width - width,
+        y: area.y + 1,
+        width,
+        height: 3,
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(color));
+    let text = Paragraph::new(format!(" {} ", message.text))
+        .block(block)
+        .style(Style::default().fg(color));
+    f.render_widget(Clear, rect);
+    f.render_widget(text, rect);
+}
+
+/// Spinner glyph for the `frame_count`-th frame.
+pub(crate) fn spinner_frame(frame_count: u64) -> &'static str {
+    SPINNER[(frame_count % SPINNER.len() as u64) as usize]
 }
 
 /// Legacy static help cheatsheet — retained only for the `help_screen_lists_*`
@@ -524,6 +785,30 @@ pub(crate) fn help_screen_lines() -> Vec<&'static str> {
     ]
 }
 
+/// `"5/42"` for the email at `index` (0-based) among `total`; `"?/42"`
+/// when nothing is selected and `"0/0"` for an empty folder.
+pub(crate) fn format_position(index: Option<usize>, total: usize) -> String {
+    match (index, total) {
+        (_, 0) => "0/0".to_string(),
+        (Some(index), _) => format!("{}/{}", index + 1, total),
+        (None, _) => format!("?/{}", total),
+    }
+}
+
+/// Right-hand status-bar text: the selection's [`format_position`] in
+/// the current folder, or the match count while search results are
+/// shown.
+pub(crate) fn position_indicator(store: &EmailStore) -> String {
+    match &store.search_results {
+        Some(results) if results.emails.len() == 1 => "1 match".to_string(),
+        Some(results) => format!("{} matches", results.emails.len()),
+        None => format_position(
+            store.selected_email,
+            store.get_current_folder().emails.len(),
+        ),
+    }
+}
+
 /// Status-bar body-parts indicator. Returns:
 /// - `Some("TXT+HTML")` when the selected email carries both a
 ///   `text/plain` and a `text/html` body (i.e. `multipart/alternative`).
@@ -545,28 +830,341 @@ pub(crate) fn body_parts_indicator(store: &EmailStore) -> Option<String> {
     }
 }
 
+/// Coarse attachment category driving the Attachments-pane icon and
+/// its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MimeCategory {
+    Text,
+    Spreadsheet,
+    Image,
+    Audio,
+    Video,
+    Archive,
+    Pdf,
+    Other,
+}
+
+impl MimeCategory {
+    /// Classify a `type/subtype` string. Parameters (`; charset=…`) and
+    /// case are ignored. Spreadsheets are checked before `text/*` so
+    /// `text/csv` lands with them.
+    fn of(content_type: &str) -> Self {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or("")
+            .trim()
+            .to_ascii_lowercase();
+        let (top, sub) = mime.split_once('/').unwrap_or((mime.as_str(), ""));
+        if sub == "vnd.ms-excel" || sub.contains("spreadsheet") || sub == "csv" {
+            MimeCategory::Spreadsheet
+        } else if top == "text" {
+            MimeCategory::Text
+        } else if top == "image" {
+            MimeCategory::Image
+        } else if top == "audio" {
+            MimeCategory::Audio
+        } else if top == "video" {
+            MimeCategory::Video
+        } else if sub == "pdf" {
+            MimeCategory::Pdf
+        } else if matches!(
+            sub,
+            "zip"
+                | "x-zip-compressed"
+                | "gzip"
+                | "x-gzip"
+                | "x-tar"
+                | "x-bzip2"
+                | "x-xz"
+                | "zstd"
+                | "x-7z-compressed"
+                | "x-rar-compressed"
+                | "vnd.rar"
+        ) {
+            MimeCategory::Archive
+        } else {
+            MimeCategory::Other
+        }
+    }
+}
+
+/// Icon shown before each row of the Attachments pane. Emoji by
+/// default; the matching Nerd Font (Font Awesome) glyph when
+/// `[render].nerd_fonts` is on.
+pub(crate) fn mime_type_icon(content_type: &str, nerd_fonts: bool) -> &'static str {
+    match (MimeCategory::of(content_type), nerd_fonts) {
+        (MimeCategory::Text, false) => "📄",
+        (MimeCategory::Spreadsheet, false) => "📊",
+        (MimeCategory::Image, false) => "🖼️",
+        (MimeCategory::Audio, false) => "🎵",
+        (MimeCategory::Video, false) => "📹",
+        (MimeCategory::Archive, false) => "📦",
+        (MimeCategory::Pdf, false) => "📝",
+        (MimeCategory::Other, false) => "📎",
+        (MimeCategory::Text, true) => "\u{f0f6}",
+        (MimeCategory::Spreadsheet, true) => "\u{f1c3}",
+        (MimeCategory::Image, true) => "\u{f1c5}",
+        (MimeCategory::Audio, true) => "\u{f1c7}",
+        (MimeCategory::Video, true) => "\u{f1c8}",
+        (MimeCategory::Archive, true) => "\u{f1c6}",
+        (MimeCategory::Pdf, true) => "\u{f1c1}",
+        (MimeCategory::Other, true) => "\u{f0c6}",
+    }
+}
+
+/// Palette role for an attachment's icon, grouped by category so a
+/// long list scans by kind at a glance.
+fn mime_type_color(content_type: &str, theme: &Theme) -> Color {
+    match MimeCategory::of(content_type) {
+        MimeCategory::Text => theme.light,
+        MimeCategory::Spreadsheet | MimeCategory::Pdf => theme.accent_light,
+        MimeCategory::Image => theme.cyan_light,
+        MimeCategory::Audio | MimeCategory::Video => theme.cyan,
+        MimeCategory::Archive => theme.accent,
+        MimeCategory::Other => theme.gray_light,
+    }
+}
+
 /// Status-bar hint string. Reflects the keys most worth surfacing
-/// from a non-help screen; full list lives in `help_screen_lines`.
-pub(crate) fn build_status_hint(content_pane_hidden: bool) -> String {
+/// from a non-help screen, as bound in `keymap` so a rebind never
+/// leaves a stale hint; the full list lives in the `?` overlay. An
+/// entry whose actions are all unbound is left out.
+pub(crate) fn build_status_hint(keymap: &Keymap, content_pane_hidden: bool) -> String {
     let toggle = if content_pane_hidden {
-        "Alt+c: Show Content"
+        "Show Content"
     } else {
-        "Alt+c: Hide Content"
+        "Hide Content"
     };
-    format!(
-        "j/k: Navigate | Tab: Pane | h/l: View | a/d/s/m/U: Act | u: Undo | {} | ?: Help | q: Quit",
-        toggle
-    )
+    let entries: [(&[Action], &str); 8] = [
+        (&[Action::MoveDown, Action::MoveUp], "Navigate"),
+        (&[Action::FocusNext], "Pane"),
+        (&[Action::ViewPrev, Action::ViewNext], "View"),
+        (
+            &[
+                Action::Archive,
+                Action::Delete,
+                Action::Star,
+                Action::MoveToFolder,
+                Action::MarkUnread,
+            ],
+            "Act",
+        ),
+        (&[Action::Undo], "Undo"),
+        (&[Action::ToggleContentPane], toggle),
+        (&[Action::ToggleHelp], "Help"),
+        (&[Action::Quit], "Quit"),
+    ];
+    entries
+        .iter()
+        .filter_map(|(actions, label)| {
+            let keys: Vec<&str> = actions.iter().filter_map(|a| keymap.key_for(*a)).collect();
+            (!keys.is_empty()).then(|| format!("{}: {}", keys.join("/"), label))
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn spinner_cycles_through_all_ten_frames() {
+        let frames: Vec<&str> = (0..10).map(spinner_frame).collect();
+        assert_eq!(frames, SPINNER);
+        assert_eq!(spinner_frame(10), spinner_frame(0));
+        assert_eq!(spinner_frame(u64::MAX), SPINNER[5]);
+    }
+
+    #[test]
+    fn draw_advances_the_frame_count_and_the_loading_spinner() {
+        let mut ui = UI::new();
+        let mut store = EmailStore::new(std::path::PathBuf::from("/tmp"));
+        let layout = Layout::new();
+        let (config, theme) = (Config::default(), Theme::default());
+        let keymap = crate::keymap::resolve_keymap(&Default::default()).unwrap();
+        let folders = FoldersComponent::with_index(0);
+        let mut messages = MessagesComponent::new();
+        messages.tagged.insert("/tmp/INBOX/cur/a".into());
+        messages.tagged.insert("/tmp/INBOX/cur/b".into());
+        let content = ContentComponent::new();
+        let accounts = AccountsComponent::new();
+        let draft = DraftComponent::new();
+        let picker = FolderPickerComponent::new();
+        let search = SearchComponent::new();
+        let command_line = CommandLineComponent::new();
+        let backend = ratatui::backend::TestBackend::new(200, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        let mut draw = |ui: &mut UI, notification: Option<&StatusMessage>| {
+            terminal
+                .draw(|f| {
+                    ui.draw(
+                        f,
+                        &mut store,
+                        &layout,
+                        None,
+                        notification,
+                        None,
+                        true,
+                        false,
+                        None,
+                        &folders,
+                        &messages,
+                        &content,
+                        &accounts,
+                        &draft,
+                        &picker,
+                        &search,
+                        &command_line,
+                        &config,
+                        &keymap,
+                        &theme,
+                    )
+                })
+                .unwrap();
+            let buf = terminal.backend().buffer();
+            let row = |y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+            };
+            (row(2), row(buf.area.height - 1))
+        };
+        let mut status_row = |ui: &mut UI| draw(ui, None).1;
+        let first = status_row(&mut ui);
+        assert!(first.contains("⠙ Loading…"));
+        assert!(first.contains("2 tagged"));
+        assert_eq!(ui.frame_count, 1);
+        assert!(status_row(&mut ui).contains("⠹ Loading…"));
+        assert_eq!(ui.frame_count, 2);
+
+        // A notification floats at the top right, over the panes.
+        let popup = StatusMessage::error("Disk full");
+        let (row, _) = draw(&mut ui, Some(&popup));
+        assert!(row.trim_end().ends_with("│ Disk full │"), "{:?}", row);
+    }
+
+    #[test]
+    fn monochrome_theme_draws_without_any_rgb_colour() {
+        let draw_with = |theme: &Theme| {
+            let mut ui = UI::new();
+            let mut store = EmailStore::new(std::path::PathBuf::from("/tmp"));
+            let config = Config::default();
+            let keymap = crate::keymap::resolve_keymap(&Default::default()).unwrap();
+            let backend = ratatui::backend::TestBackend::new(120, 12);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            let popup = StatusMessage::error("Disk full");
+            terminal
+                .draw(|f| {
+                    ui.draw(
+                        f,
+                        &mut store,
+                        &Layout::new(),
+                        Some(&popup),
+                        Some(&popup),
+                        None,
+                        true,
+                        false,
+                        None,
+                        &FoldersComponent::with_index(0),
+                        &MessagesComponent::new(),
+                        &ContentComponent::new(),
+                        &AccountsComponent::new(),
+                        &DraftComponent::new(),
+                        &FolderPickerComponent::new(),
+                        &SearchComponent::new(),
+                        &CommandLineComponent::new(),
+                        &config,
+                        &keymap,
+                        theme,
+                    )
+                })
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let is_rgb = |c: Color| matches!(c, Color::Rgb(..) | Color::Red | Color::White);
+
+        let plain = draw_with(&Theme::monochrome());
+        assert!(
+            plain.content.iter().all(|c| !is_rgb(c.fg) && !is_rgb(c.bg)),
+            "monochrome output carries a colour"
+        );
+        let coloured = draw_with(&Theme::default());
+        assert!(
+            coloured
+                .content
+                .iter()
+                .any(|c| is_rgb(c.fg) || is_rgb(c.bg))
+        );
+    }
+
     fn joined() -> String {
         help_screen_lines().join("\n")
     }
 
+    #[test]
+    fn format_position_covers_empty_unselected_and_last() {
+        assert_eq!(format_position(None, 0), "0/0");
+        assert_eq!(format_position(Some(0), 0), "0/0");
+        assert_eq!(format_position(None, 42), "?/42");
+        assert_eq!(format_position(Some(0), 1), "1/1");
+        assert_eq!(format_position(Some(4), 42), "5/42");
+        assert_eq!(format_position(Some(41), 42), "42/42");
+        assert_eq!(
+            format_position(Some(usize::MAX - 1), usize::MAX),
+            format!("{}/{}", usize::MAX, usize::MAX)
+        );
+    }
+
+    #[test]
+    fn position_indicator_counts_search_matches() {
+        let mut store = EmailStore::new(std::path::PathBuf::from("/tmp/mail"));
+        for i in 0..3 {
+            store.root_folder.emails.push(crate::email::Email::new(
+                format!("/tmp/mail/cur/{i}").into(),
+            ));
+        }
+        assert_eq!(position_indicator(&store), "?/3");
+        store.select_email(1);
+        assert_eq!(position_indicator(&store), "2/3");
+
+        let mut results = crate::email::Folder::new("q".into(), "/tmp/q".into());
+        results.emails = store.root_folder.emails.clone();
+        store.set_search_results(results);
+        assert_eq!(position_indicator(&store), "3 matches");
+    }
+
+    #[test]
+    fn mime_type_icon_picks_category_icon() {
+        let cases = [
+            ("text/plain", "📄"),
+            ("TEXT/HTML; charset=utf-8", "📄"),
+            ("application/vnd.ms-excel", "📊"),
+            (
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+                "📊",
+            ),
+            ("image/png", "🖼️"),
+            ("audio/mpeg", "🎵"),
+            ("video/mp4", "📹"),
+            ("application/zip", "📦"),
+            ("application/pdf", "📝"),
+            ("application/octet-stream", "📎"),
+        ];
+        for (mime, icon) in cases {
+            assert_eq!(mime_type_icon(mime, false), icon, "{mime}");
+        }
+    }
+
+    #[test]
+    fn mime_type_icon_uses_nerd_font_glyphs_when_enabled() {
+        assert_eq!(mime_type_icon("application/pdf", true), "\u{f1c1}");
+        assert_eq!(mime_type_icon("application/x-tar", true), "\u{f1c6}");
+        assert_eq!(mime_type_icon("", true), "\u{f0c6}");
+    }
+
     #[test]
     fn help_screen_lists_wired_action_keys() {
         let text = joined();
@@ -644,17 +1242,36 @@ mod tests {
 
     #[test]
     fn status_hint_swaps_content_toggle_label() {
-        let shown = build_status_hint(false);
+        let keymap = crate::keymap::resolve_keymap(&Default::default()).unwrap();
+        let shown = build_status_hint(&keymap, false);
         assert!(shown.contains("Hide Content"), "{}", shown);
-        let hidden = build_status_hint(true);
+        let hidden = build_status_hint(&keymap, true);
         assert!(hidden.contains("Show Content"), "{}", hidden);
     }
 
     #[test]
     fn status_hint_advertises_core_keys() {
-        let s = build_status_hint(false);
+        let keymap = crate::keymap::resolve_keymap(&Default::default()).unwrap();
+        let s = build_status_hint(&keymap, false);
         for token in ["j/k", "Tab", "h/l", "?", "q", "u:"] {
             assert!(s.contains(token), "status hint missing `{}`: {}", token, s);
         }
     }
+
+    #[test]
+    fn status_hint_follows_the_keymap() {
+        let defaults = crate::keymap::resolve_keymap(&Default::default()).unwrap();
+        assert_eq!(
+            build_status_hint(&defaults, false),
+            "j/k: Navigate | Tab: Pane | h/l: View | a/d/s/m/U: Act | u: Undo | Alt+c: Hide Content | ?: Help | q: Quit"
+        );
+
+        let mut overrides = std::collections::BTreeMap::new();
+        overrides.insert("quit".to_string(), "Q".into());
+        overrides.insert("undo".to_string(), crate::keymap::KeySpec::Many(Vec::new()));
+        let keymap = crate::keymap::resolve_keymap(&overrides).unwrap();
+        let hint = build_status_hint(&keymap, false);
+        assert!(hint.ends_with("?: Help | Q: Quit"), "{}", hint);
+        assert!(!hint.contains("Undo"), "{}", hint);
+    }
 }
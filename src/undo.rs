@@ -3,23 +3,30 @@
 // `Mutation` records each reversible action the user takes (mark-read,
 // archive, delete, move, toggle-star, mark-unread). The action-key
 // handlers push mutations onto `AppRoot.undo_stack` after performing
-// their filesystem op; the `u` key pops the stack and calls
-// `Mutation::reverse`, which either renames the file back to `from` or
-// flips the Maildir `F` flag.
+// their filesystem op; `u` / `Ctrl-z` / `:undo` pop the newest entry
+// and call `Mutation::reverse` on each of its mutations, which either
+// renames the file back to `from` or flips the Maildir `F` flag. A bulk
+// action over tagged emails is one entry, so it undoes as a unit. Only
+// the last `UNDO_LIMIT` entries are kept.
 //
 // `reverse` is best-effort by design (VISION.md "Undo"): if the file
 // has been rewritten by `mbsync` or otherwise vanished from its
-// post-action location, we return `Skipped::FileMoved` and `AppRoot`
-// surfaces "Could not undo: file moved" via the status line. The stack
-// is in-memory only and discarded at quit.
+// post-action location, we return `Reversed::Skipped` and `AppRoot`
+// surfaces "Could not undo: file moved" via the status line. If
+// something else now sits at the original location we return
+// `Reversed::Occupied` rather than rename over it. The stack is
+// in-memory only and discarded at quit.
 
+use std::collections::VecDeque;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 /// One reversible user action. `msg` is the file path at the time the
 /// action completed; `from`/`to` capture the pre- and post-action
 /// locations for path-move mutations. `ToggleStar.prev_flag` is the
-/// `F`-flag state *before* the toggle so undo can restore it directly.
+/// `F`-flag state *before* the toggle so undo can restore it directly;
+/// `ToggleStar.restore_to` is the original `new/` path when starring
+/// also moved the file into `cur/` (Maildir only flags `cur/` files).
 ///
 /// Variants `MarkRead`, `MarkUnread`, and `ToggleStar` are not yet
 /// constructed in production code — the action-key handlers will
@@ -51,6 +58,7 @@ pub enum Mutation {
     ToggleStar {
         msg: PathBuf,
         prev_flag: bool,
+        restore_to: Option<PathBuf>,
     },
     MarkUnread {
         msg: PathBuf,
@@ -72,6 +80,77 @@ pub enum Reversed {
     FlagRestored { old: PathBuf, new: PathBuf },
     /// Could not undo (file moved/deleted by something else).
     Skipped,
+    /// Could not undo: another file already sits at the pre-action
+    /// location, and renaming onto it would clobber it.
+    Occupied,
+}
+
+/// Most actions the undo stack remembers; older ones fall off.
+pub const UNDO_LIMIT: usize = 20;
+
+/// Undo history, newest last. Each entry is one user action: a single
+/// mutation, or every mutation of a bulk action recorded between
+/// `begin_group` and `end_group`.
+#[derive(Debug, Default)]
+pub struct UndoStack {
+    entries: VecDeque<Vec<Mutation>>,
+    /// Mutations of the bulk action in progress, if any.
+    group: Option<Vec<Mutation>>,
+}
+
+impl UndoStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `mutation` as its own entry, or as part of the open group.
+    pub fn push(&mut self, mutation: Mutation) {
+        match &mut self.group {
+            Some(group) => group.push(mutation),
+            None => self.push_entry(vec![mutation]),
+        }
+    }
+
+    /// Collect the following pushes into one entry until `end_group`.
+    pub fn begin_group(&mut self) {
+        self.group.get_or_insert_with(Vec::new);
+    }
+
+    /// Close the open group. A group nothing was pushed to leaves no
+    /// entry behind.
+    pub fn end_group(&mut self) {
+        if let Some(group) = self.group.take() {
+            self.push_entry(group);
+        }
+    }
+
+    fn push_entry(&mut self, entry: Vec<Mutation>) {
+        if entry.is_empty() {
+            return;
+        }
+        if self.entries.len() == UNDO_LIMIT {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Remove the newest entry. Its mutations are in the order they
+    /// were made; reverse them back to front.
+    pub fn pop(&mut self) -> Option<Vec<Mutation>> {
+        self.entries.pop_back()
+    }
+
+    /// Number of entries (actions, not mutations).
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The most recent mutation recorded.
+    #[cfg(test)]
+    pub(crate) fn last(&self) -> Option<&Mutation> {
+        self.entries.back()?.last()
+    }
 }
 
 impl Mutation {
@@ -84,7 +163,53 @@ impl Mutation {
             | Mutation::Delete { to, from, .. }
             | Mutation::Move { to, from, .. }
             | Mutation::MarkUnread { to, from, .. } => move_back(to, from),
-            Mutation::ToggleStar { msg, prev_flag } => flip_flag_f(msg, *prev_flag),
+            Mutation::ToggleStar {
+                msg,
+                restore_to: Some(from),
+                ..
+            } => move_back(msg, from),
+            Mutation::ToggleStar { msg, prev_flag, .. } => flip_flag_f(msg, *prev_flag),
+        }
+    }
+
+    /// Where the message was before the action, for path moves
+    /// (`None` for a star toggle that didn't move the file).
+    pub fn origin(&self) -> Option<&Path> {
+        match self {
+            Mutation::MarkRead { from, .. }
+            | Mutation::Archive { from, .. }
+            | Mutation::Delete { from, .. }
+            | Mutation::Move { from, .. }
+            | Mutation::MarkUnread { from, .. } => Some(from),
+            Mutation::ToggleStar { restore_to, .. } => restore_to.as_deref(),
+        }
+    }
+
+    /// Where the action left the message, which is where undo finds it.
+    pub fn current(&self) -> &Path {
+        match self {
+            Mutation::MarkRead { to, .. }
+            | Mutation::Archive { to, .. }
+            | Mutation::Delete { to, .. }
+            | Mutation::Move { to, .. }
+            | Mutation::MarkUnread { to, .. } => to,
+            Mutation::ToggleStar { msg, .. } => msg,
+        }
+    }
+
+    /// What undoing did, for the status line: "moved 'Lunch' back to
+    /// INBOX". `folder` names the folder a moved message returned to.
+    pub fn undone_summary(&self, subject: &str, folder: &str) -> String {
+        match self {
+            Mutation::Archive { .. } | Mutation::Delete { .. } | Mutation::Move { .. } => {
+                format!("moved '{}' back to {}", subject, folder)
+            }
+            Mutation::MarkRead { .. } => format!("marked '{}' unread", subject),
+            Mutation::MarkUnread { .. } => format!("marked '{}' read", subject),
+            Mutation::ToggleStar {
+                prev_flag: true, ..
+            } => format!("starred '{}' again", subject),
+            Mutation::ToggleStar { .. } => format!("unstarred '{}'", subject),
         }
     }
 }
@@ -93,6 +218,9 @@ fn move_back(to: &Path, from: &Path) -> Reversed {
     if !to.exists() {
         return Reversed::Skipped;
     }
+    if from.exists() {
+        return Reversed::Occupied;
+    }
     if let Some(parent) = from.parent() {
         // Best-effort: `mbsync` will have created the parent already.
         // If creating it fails (permission, ENOSPC) the rename below
@@ -130,16 +258,23 @@ pub(crate) fn set_maildir_flag(path: &Path, flag: char, want: bool) -> std::io::
     let name = path
         .file_name()
         .and_then(|s| s.to_str())
-        .ok_or_else(|| std::io::Error::other("non-utf8 maildir filename"))?
-        .to_string();
-    let (base, flags) = match name.split_once(":2,") {
-        Some((b, f)) => (b.to_string(), f.to_string()),
-        None => (name.clone(), String::new()),
-    };
-    let has = flags.contains(flag);
-    if has == want {
+        .ok_or_else(|| std::io::Error::other("non-utf8 maildir filename"))?;
+    let new_name = maildir_name_with_flag(name, flag, want);
+    if new_name == name {
         return Ok(path.to_path_buf());
     }
+    let new_path = parent.join(new_name);
+    fs::rename(path, &new_path)?;
+    Ok(new_path)
+}
+
+/// [`set_maildir_flag`]'s renaming without the disk: `name` with `flag`
+/// added or removed, unchanged when it's already in the wanted state.
+pub(crate) fn maildir_name_with_flag(name: &str, flag: char, want: bool) -> String {
+    let (base, flags) = name.split_once(":2,").unwrap_or((name, ""));
+    if flags.contains(flag) == want {
+        return name.to_string();
+    }
     let new_flags: String = if want {
         let mut chars: Vec<char> = flags.chars().chain(std::iter::once(flag)).collect();
         chars.sort_unstable();
@@ -148,10 +283,7 @@ pub(crate) fn set_maildir_flag(path: &Path, flag: char, want: bool) -> std::io::
     } else {
         flags.chars().filter(|c| *c != flag).collect()
     };
-    let new_name = format!("{}:2,{}", base, new_flags);
-    let new_path = parent.join(new_name);
-    fs::rename(path, &new_path)?;
-    Ok(new_path)
+    format!("{}:2,{}", base, new_flags)
 }
 
 #[cfg(test)]
@@ -258,6 +390,7 @@ mod tests {
         let m = Mutation::ToggleStar {
             msg: starred.clone(),
             prev_flag: false,
+            restore_to: None,
         };
         match m.reverse() {
             Reversed::FlagRestored { new, .. } => {
@@ -280,6 +413,7 @@ mod tests {
         let m = Mutation::ToggleStar {
             msg: unstarred.clone(),
             prev_flag: true,
+            restore_to: None,
         };
         match m.reverse() {
             Reversed::FlagRestored { new, .. } => {
@@ -292,6 +426,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn toggle_star_round_trip_returns_file_to_new() {
+        let temp = TempDir::new().unwrap();
+        // Post-action: starring moved the file out of new/ to carry F.
+        let orig = temp.path().join("INBOX/new/msg1");
+        let starred = temp.path().join("INBOX/cur/msg1:2,F");
+        write_msg(&starred, "body");
+        let m = Mutation::ToggleStar {
+            msg: starred.clone(),
+            prev_flag: false,
+            restore_to: Some(orig.clone()),
+        };
+        match m.reverse() {
+            Reversed::PathRestored { new, .. } => assert_eq!(new, orig),
+            other => panic!("expected PathRestored, got {:?}", other),
+        }
+        assert!(orig.exists() && !starred.exists());
+    }
+
     #[test]
     fn reverse_of_missing_file_is_skipped() {
         let temp = TempDir::new().unwrap();
@@ -316,6 +469,7 @@ mod tests {
         let m = Mutation::ToggleStar {
             msg: temp.path().join("INBOX/cur/nope:2,F"),
             prev_flag: false,
+            restore_to: None,
         };
         match m.reverse() {
             Reversed::Skipped => {}
@@ -323,6 +477,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reverse_never_renames_onto_an_existing_file() {
+        let temp = TempDir::new().unwrap();
+        let from = temp.path().join("INBOX/cur/msg1");
+        let to = temp.path().join("Trash/cur/msg1");
+        write_msg(&to, "deleted");
+        // Something new arrived under the old name since the delete.
+        write_msg(&from, "newcomer");
+        let m = Mutation::Delete {
+            msg: to.clone(),
+            from: from.clone(),
+            to: to.clone(),
+        };
+        assert!(matches!(m.reverse(), Reversed::Occupied));
+        assert_eq!(fs::read_to_string(&from).unwrap(), "newcomer");
+        assert!(to.exists(), "the deleted copy stays in Trash");
+    }
+
+    fn archive(n: usize) -> Mutation {
+        let path = PathBuf::from(format!("/mail/Archive/cur/{}", n));
+        Mutation::Archive {
+            msg: path.clone(),
+            from: PathBuf::from(format!("/mail/INBOX/cur/{}", n)),
+            to: path,
+        }
+    }
+
+    #[test]
+    fn undo_stack_caps_entries_and_groups_bulk_actions() {
+        let mut stack = UndoStack::new();
+        for n in 0..UNDO_LIMIT + 5 {
+            stack.push(archive(n));
+        }
+        assert_eq!(stack.len(), UNDO_LIMIT);
+
+        stack.begin_group();
+        stack.push(archive(100));
+        stack.push(archive(101));
+        stack.end_group();
+        assert_eq!(stack.len(), UNDO_LIMIT, "the oldest entry made room");
+        assert_eq!(stack.last(), Some(&archive(101)));
+        assert_eq!(stack.pop(), Some(vec![archive(100), archive(101)]));
+
+        // An empty group records nothing.
+        stack.begin_group();
+        stack.end_group();
+        assert_eq!(stack.pop(), Some(vec![archive(UNDO_LIMIT + 4)]));
+
+        let mut oldest = None;
+        while let Some(entry) = stack.pop() {
+            oldest = entry.into_iter().next();
+        }
+        // 0..=4 fell off while filling, 5 when the group went in.
+        assert_eq!(oldest, Some(archive(6)));
+        assert_eq!(stack.len(), 0);
+    }
+
     #[test]
     fn set_maildir_flag_sorts_and_dedups() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,120 @@
+//! Small formatting and parsing helpers shared by the TUI panes and the
+//! web viewer.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+
+/// Format a byte count as `"512 B"`, `"1.5 KB"`, `"12.3 MB"` or
+/// `"1.1 GB"` (binary multiples, one decimal above bytes).
+pub fn format_file_size(bytes: usize) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit_index = 0;
+
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+
+    if unit_index == 0 {
+        format!("{} {}", size as usize, UNITS[unit_index])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit_index])
+    }
+}
+
+/// Parse a `Date` header value, trying harder than the mail parser:
+/// RFC 3339 (what the scanner stores), RFC 2822, then the shapes
+/// malformed mail tends to carry — a trailing `(UTC)` comment, a
+/// missing zone (read as UTC), asctime, or a bare date (midnight UTC).
+pub fn parse_flexible_date(date_str: &str) -> Option<DateTime<FixedOffset>> {
+    const ZONED: &[&str] = &[
+        "%a, %d %b %Y %H:%M:%S %z",
+        "%a, %d %b %Y %H:%M %z",
+        "%d %b %Y %H:%M:%S %z",
+        "%Y-%m-%d %H:%M:%S %z",
+        "%a %b %e %H:%M:%S %z %Y",
+    ];
+    const NAIVE: &[&str] = &[
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M:%S",
+        "%a, %d %b %Y %H:%M:%S",
+        "%d %b %Y %H:%M:%S",
+        "%a %b %e %H:%M:%S %Y",
+    ];
+    const DATES: &[&str] = &["%Y-%m-%d", "%a, %d %b %Y", "%d %b %Y"];
+
+    let trimmed = date_str.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    if let Ok(date) =
+        DateTime::parse_from_rfc3339(trimmed).or_else(|_| DateTime::parse_from_rfc2822(trimmed))
+    {
+        return Some(date);
+    }
+
+    let uncommented = trimmed.split('(').next().unwrap_or(trimmed);
+    let normalized = uncommented.split_whitespace().collect::<Vec<_>>().join(" ");
+    if let Ok(date) = DateTime::parse_from_rfc2822(&normalized) {
+        return Some(date);
+    }
+    ZONED
+        .iter()
+        .find_map(|f| DateTime::parse_from_str(&normalized, f).ok())
+        .or_else(|| {
+            NAIVE
+                .iter()
+                .find_map(|f| NaiveDateTime::parse_from_str(&normalized, f).ok())
+                .or_else(|| {
+                    DATES
+                        .iter()
+                        .find_map(|f| NaiveDate::parse_from_str(&normalized, f).ok())
+                        .and_then(|d| d.and_hms_opt(0, 0, 0))
+                })
+                .map(|naive| naive.and_utc().fixed_offset())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_file_size() {
+        assert_eq!(format_file_size(512), "512 B");
+        assert_eq!(format_file_size(1024), "1.0 KB");
+        assert_eq!(format_file_size(1536), "1.5 KB");
+        assert_eq!(format_file_size(1048576), "1.0 MB");
+        assert_eq!(format_file_size(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+
+    #[test]
+    fn parse_flexible_date_reads_rfc3339_and_rfc2822() {
+        let stored = parse_flexible_date("2024-03-11T09:30:00+01:00").unwrap();
+        let header = parse_flexible_date("Mon, 11 Mar 2024 09:30:00 +0100").unwrap();
+        assert_eq!(stored, header);
+        assert_eq!(stored.offset().local_minus_utc(), 3600);
+    }
+
+    #[test]
+    fn parse_flexible_date_recovers_common_malformed_shapes() {
+        let expected = parse_flexible_date("2024-01-01T00:00:00Z").unwrap();
+        for raw in [
+            "Mon, 01 Jan 2024",
+            "2024-01-01",
+            "Mon,  01 Jan 2024 00:00:00 +0000 (Coordinated Universal Time)",
+            "2024-01-01 00:00:00",
+            "Mon Jan  1 00:00:00 2024",
+        ] {
+            assert_eq!(parse_flexible_date(raw), Some(expected), "{:?}", raw);
+        }
+    }
+
+    #[test]
+    fn parse_flexible_date_rejects_junk() {
+        assert_eq!(parse_flexible_date("not a date"), None);
+        assert_eq!(parse_flexible_date("Mon, 01 Ja"), None);
+        assert_eq!(parse_flexible_date(""), None);
+        assert_eq!(parse_flexible_date("   "), None);
+    }
+}
@@ -1,9 +1,12 @@
 use crate::email::{EmailLoadState, EmailStore};
 use crate::error::Result;
 use crate::layout::ActivePane;
+use crate::maildir::MaildirScanner;
+use crate::util::format_file_size;
 use axum::{
     Router,
-    extract::State,
+    extract::ws::{Message, WebSocket, WebSocketUpgrade},
+    extract::{Path as AxumPath, Query, State},
     http::{HeaderValue, StatusCode},
     middleware::{Next, from_fn, from_fn_with_state},
     response::{Html, IntoResponse, Json, Response, Sse},
@@ -11,16 +14,42 @@ use axum::{
 };
 use futures::stream::{self, Stream};
 use rand::RngCore;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::convert::Infallible;
 use std::net::IpAddr;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use std::sync::mpsc::Sender;
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::net::TcpListener;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::watch;
 use tokio::time::sleep;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, Http, HttpAuthScheme, SecurityScheme};
+use utoipa::{Modify, OpenApi, ToSchema};
+
+/// Buffer depth of the [`WsEvent`] broadcast channel. A `/ws` client
+/// that falls further behind skips the missed events rather than
+/// stalling the sender.
+pub const WS_EVENT_CAPACITY: usize = 64;
+
+/// Real-time event pushed to `/ws` subscribers as a JSON text frame,
+/// tagged by `type` (`{"type":"email_changed","id":"…"}`). Mirrors the
+/// `/events` SSE stream and adds the MailDir watcher's folder refreshes.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WsEvent {
+    /// The focused email or its load state changed. `id` is the same
+    /// selection key the SSE `email-changed` event carries.
+    EmailChanged { id: String },
+    /// The folder's `new/` directory grew — mail was delivered. `folder`
+    /// is relative to the account's MailDir root.
+    NewMail { folder: String },
+    /// The watcher saw the folder change on disk; it is being reloaded.
+    FolderChanged { folder: String },
+}
 
 /// Generate a 128-bit cryptographically random token, hex-encoded.
 ///
@@ -98,13 +127,39 @@ pub struct WebState {
     /// happens off the axum executor.
     pub body_request_tx: Sender<PathBuf>,
     /// Per-launch loopback token. Required on every request (`?t=<token>`
-    /// or `X-Vulthor-Token: <token>`) except `/healthz` and `/health`. The
+    /// or `X-Vulthor-Token: <token>`) except `/healthz`. The
     /// HTML shells embed it into subresource URLs; `app.js` reads it from
     /// `window.location.search` for SSE / fetch.
     pub token: Arc<str>,
+    /// `[web].api_token`. When set, `/api/*` also accepts it (see
+    /// [`api_auth_middleware`]) and answers a missing or wrong token
+    /// with a JSON 401.
+    pub api_token: Option<Arc<str>>,
+    /// Flips to `true` once the server begins a graceful shutdown. The
+    /// SSE poll loop checks it every tick and ends the stream, so open
+    /// event-source connections don't hold `with_graceful_shutdown` open
+    /// forever.
+    pub shutdown: watch::Receiver<bool>,
+    /// Fan-out of [`WsEvent`]s to every `/ws` connection. The TUI holds
+    /// a clone to publish watcher events; the server's selection poller
+    /// publishes `EmailChanged`.
+    pub events: broadcast::Sender<WsEvent>,
+    /// When the server state was built; `/health` reports uptime from it.
+    pub started_at: Instant,
+    /// Pages more headers into a folder when `/api/folder/…` asks for
+    /// emails past the loaded tail. Same configuration as the TUI's.
+    pub scanner: MaildirScanner,
 }
 
 impl WebState {
+    /// [`Self::api_token`] when it has something in it. A blank token
+    /// would match a blank `Authorization: Bearer ` or `?token=`, so it
+    /// counts as unset: `Config::validate` rejects one in the config
+    /// file, and this covers a `WebState` built any other way.
+    fn api_token(&self) -> Option<&str> {
+        self.api_token.as_deref().filter(|t| !t.trim().is_empty())
+    }
+
     fn focused_pane(&self) -> ActivePane {
         ActivePane::from_u8(self.focused_pane.load(Ordering::Relaxed))
     }
@@ -116,9 +171,31 @@ impl WebState {
     fn request_body_load(&self, path: PathBuf) {
         let _ = self.body_request_tx.send(path);
     }
+
+    /// Key identifying the focused email and how much of it is loaded.
+    /// Changes whenever the selection moves or the body-loader fills in
+    /// the body, which is exactly when clients should refetch. `None`
+    /// when the store lock is poisoned.
+    fn selection_key(&self) -> Option<String> {
+        let pane = self.focused_pane();
+        let store = self.email_store.lock().ok()?;
+        let folder_indices = store.current_folder.clone();
+        let email_index = store.selected_email.unwrap_or(usize::MAX);
+        let load_tag = match store.current_email_for_web(pane) {
+            Some(e) => match e.load_state {
+                EmailLoadState::HeadersOnly => "headers",
+                EmailLoadState::FullyLoaded => "full",
+            },
+            None => "none",
+        };
+        Some(format!("{:?}:{}:{}", folder_indices, email_index, load_tag))
+    }
 }
 
-#[derive(Serialize)]
+/// JSON shape of `GET /api/current-email` and `GET /api/email/…`: the
+/// email's headers, rendered body, attachment list and position in its
+/// folder, or `has_email: false` when nothing is focused.
+#[derive(Serialize, ToSchema)]
 struct EmailData {
     has_email: bool,
     subject: String,
@@ -128,9 +205,22 @@ struct EmailData {
     body_html: String,
     attachments: Vec<AttachmentData>,
     email_id: String,
+    /// Message file size in bytes; 0 when no email is focused.
+    size_bytes: usize,
+    /// Maildir `F` flag (starred).
+    flagged: bool,
+    /// Folder path relative to the MailDir root, for `/api/email/…`.
+    folder: String,
+    /// Zero-based position of the email within `folder`.
+    email_index: usize,
+    /// Emails loaded in `folder`; Previous / Next stay within
+    /// `0..email_count`.
+    email_count: usize,
 }
 
-#[derive(Serialize)]
+/// One entry of [`EmailData::attachments`]. `size` is pre-formatted
+/// for display (`"12.3 KB"`).
+#[derive(Serialize, ToSchema)]
 struct AttachmentData {
     filename: String,
     content_type: String,
@@ -146,14 +236,16 @@ pub struct WebServer {
     bind: String,
     port: u16,
     state: WebState,
+    shutdown_tx: watch::Sender<bool>,
 }
 
 impl WebServer {
     /// Construct (but do not start) the server. `email_store` and
     /// `focused_pane` are shared with the TUI; `body_request_tx` is
     /// the request side of the same `BodyLoader` worker the TUI feeds,
-    /// so the web handlers never do `fs::read` themselves. `bind` is
-    /// the IP literal validated by `Config::validate`.
+    /// so the web handlers never do `fs::read` themselves. `events` is
+    /// the TUI's [`WsEvent`] sender, fanned out to `/ws` clients. `bind`
+    /// is the IP literal validated by `Config::validate`.
     pub fn new(
         bind: String,
         port: u16,
@@ -161,8 +253,14 @@ impl WebServer {
         focused_pane: Arc<AtomicU8>,
         images_visible: Arc<AtomicBool>,
         body_request_tx: Sender<PathBuf>,
+        events: broadcast::Sender<WsEvent>,
     ) -> Self {
         let token: Arc<str> = Arc::from(generate_token());
+        let (shutdown_tx, shutdown) = watch::channel(false);
+        let root = email_store
+            .lock()
+            .map(|store| store.root_folder.path.clone())
+            .unwrap_or_default();
         Self {
             bind,
             port,
@@ -172,10 +270,31 @@ impl WebServer {
                 images_visible,
                 body_request_tx,
                 token,
+                api_token: None,
+                shutdown,
+                events,
+                started_at: Instant::now(),
+                scanner: MaildirScanner::new(root),
             },
+            shutdown_tx,
         }
     }
 
+    /// Page folders in with the TUI's `scanner` (spam headers, mailbox
+    /// format) rather than a default one rooted at the store.
+    pub fn with_scanner(mut self, scanner: MaildirScanner) -> Self {
+        self.state.scanner = scanner;
+        self
+    }
+
+    /// Accept `token` on `/api/*` besides the per-launch one
+    /// (`[web].api_token`). `None` leaves the API on the per-launch
+    /// token alone.
+    pub fn with_api_token(mut self, token: Option<String>) -> Self {
+        self.state.api_token = token.filter(|t| !t.trim().is_empty()).map(Arc::from);
+        self
+    }
+
     /// The opaque shared secret a client must present on every non-health
     /// request. Surfaced for the startup banner so the TUI can print a
     /// URL that immediately works in a browser. Not stored to disk.
@@ -189,19 +308,32 @@ impl WebServer {
         format!("http://{}:{}/?t={}", self.bind, self.port, self.token())
     }
 
-    /// Bind to `<bind>:<port>` and serve until the listener errors.
-    /// Returns the underlying I/O error wrapped in [`crate::error::VulthorError`] on
-    /// bind / serve failure. Designed to be spawned onto a tokio
-    /// runtime; the call blocks the current task for the lifetime of
-    /// the server.
-    pub async fn start(&self) -> Result<()> {
+    /// Bind to `<bind>:<port>` and serve until `signal` resolves, then
+    /// shut down gracefully: the listener stops accepting, in-flight
+    /// requests run to completion, and open SSE streams close on their
+    /// next poll tick. Also runs the [`broadcast_selection_changes`]
+    /// poller for `/ws` subscribers until shutdown. Returns the underlying I/O error wrapped in
+    /// [`crate::error::VulthorError`] on bind / serve failure. Designed
+    /// to be spawned onto a tokio runtime; `main.rs` feeds it a oneshot
+    /// fired on TUI quit.
+    pub async fn start_with_shutdown<F>(&self, signal: F) -> Result<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
         let app = build_router(self.state.clone());
 
         let addr = format!("{}:{}", self.bind, self.port);
         println!("Web server starting on http://{}", addr);
 
         let listener = TcpListener::bind(&addr).await?;
-        axum::serve(listener, app).await?;
+        tokio::spawn(broadcast_selection_changes(self.state.clone()));
+        let shutdown_tx = self.shutdown_tx.clone();
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async move {
+                signal.await;
+                shutdown_tx.send_replace(true);
+            })
+            .await?;
 
         Ok(())
     }
@@ -228,13 +360,16 @@ form-action 'none'";
 
 /// Attach the security header set to a response. Pulled out of the
 /// middleware so unit tests can pin the contract without spinning up a
-/// Router.
+/// Router. A handler that already set its own `Content-Security-Policy`
+/// keeps it.
 pub(crate) fn apply_security_headers(mut response: Response) -> Response {
     let h = response.headers_mut();
-    h.insert(
-        "content-security-policy",
-        HeaderValue::from_static(CSP_HEADER),
-    );
+    if !h.contains_key("content-security-policy") {
+        h.insert(
+            "content-security-policy",
+            HeaderValue::from_static(CSP_HEADER),
+        );
+    }
     h.insert("x-frame-options", HeaderValue::from_static("DENY"));
     h.insert(
         "x-content-type-options",
@@ -248,17 +383,21 @@ async fn security_headers_middleware(
     req: axum::http::Request<axum::body::Body>,
     next: Next,
 ) -> Response {
-    apply_security_headers(next.run(req).await)
+    // Path only: the query string carries the access token.
+    let method = req.method().clone();
+    let path = req.uri().path().to_string();
+    let response = apply_security_headers(next.run(req).await);
+    tracing::info!(%method, %path, status = response.status().as_u16(), "web request");
+    response
 }
 
 /// Paths exempt from token auth. Kept tiny on purpose — every additional
 /// exempt path is a route that can be read without holding the per-launch
 /// secret, and the design intent (vu-fi1) is "/healthz only". `/health`
-/// rides along because it has been there pre-token and the phase-4
-/// integration test pokes it from the same shell that doesn't know the
-/// token; both names trivially answer the same liveness check.
+/// reports mail counts and the focused message, so it sits behind the
+/// token like every other data route.
 fn is_auth_exempt(path: &str) -> bool {
-    matches!(path, "/healthz" | "/health")
+    path == "/healthz"
 }
 
 /// Pull the presented token out of an incoming request. The `?t=<token>`
@@ -288,7 +427,10 @@ pub(crate) async fn auth_middleware(
     req: axum::http::Request<axum::body::Body>,
     next: Next,
 ) -> Response {
-    if is_auth_exempt(req.uri().path()) {
+    // With an API token configured, `/api/*` is checked by
+    // `api_auth_middleware` instead, which takes either token.
+    let path = req.uri().path();
+    if is_auth_exempt(path) || (state.api_token().is_some() && path.starts_with("/api/")) {
         return next.run(req).await;
     }
     if let Some(t) = presented_token(&req)
@@ -299,13 +441,77 @@ pub(crate) async fn auth_middleware(
     StatusCode::UNAUTHORIZED.into_response()
 }
 
+/// Token a script presents for `[web].api_token`: the bearer
+/// credential of `Authorization`, else the percent-decoded `?token=`
+/// query parameter (for `EventSource`-style clients that can't set
+/// headers). An empty credential counts as none.
+fn presented_api_token(req: &axum::http::Request<axum::body::Body>) -> Option<String> {
+    let bearer = req
+        .headers()
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| {
+            v.strip_prefix("Bearer ")
+                .or_else(|| v.strip_prefix("bearer "))
+        })
+        .map(|t| t.trim().to_string());
+    bearer
+        .or_else(|| {
+            let Query(mut params) =
+                Query::<HashMap<String, String>>::try_from_uri(req.uri()).ok()?;
+            params.remove("token")
+        })
+        .filter(|t| !t.is_empty())
+}
+
+/// Gate on the `/api` routes when `[web].api_token` is set: the request
+/// must carry that token ([`presented_api_token`]) or the per-launch one
+/// the viewer page uses. Anything else gets a 401 with a JSON error
+/// body. Without an API token the outer [`auth_middleware`] has already
+/// checked the per-launch token, and this passes everything through.
+async fn api_auth_middleware(
+    State(state): State<WebState>,
+    req: axum::http::Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    let Some(api_token) = state.api_token() else {
+        return next.run(req).await;
+    };
+    let api_ok =
+        presented_api_token(&req).is_some_and(|t| ct_eq(t.as_bytes(), api_token.as_bytes()));
+    let launch_ok =
+        presented_token(&req).is_some_and(|t| ct_eq(t.as_bytes(), state.token.as_bytes()));
+    if api_ok || launch_ok {
+        return next.run(req).await;
+    }
+    (
+        StatusCode::UNAUTHORIZED,
+        Json(serde_json::json!({ "error": "invalid or missing token" })),
+    )
+        .into_response()
+}
+
+/// The read-only JSON API, mounted under `/api` by [`build_router`]
+/// behind [`api_auth_middleware`].
+fn api_router(state: WebState) -> Router<WebState> {
+    Router::new()
+        .route("/current-email", get(get_current_email_json))
+        .route("/email/:folder/:index", get(get_email_json))
+        .route("/folders", get(get_folders_json))
+        .route("/folder/:folder", get(get_folder_page_json))
+        .route("/cid/:folder/:index/:content_id", get(get_inline_image))
+        .route("/openapi.json", get(serve_openapi_json))
+        .route("/docs", get(serve_api_docs))
+        .route_layer(from_fn_with_state(state, api_auth_middleware))
+}
+
 /// Build the full axum router with all routes and middleware applied.
-/// Used by both [`WebServer::start`] and by tests so they exercise the
+/// Used by both [`WebServer::start_with_shutdown`] and by tests so they exercise the
 /// same wiring (including the security-headers layer).
 pub(crate) fn build_router(state: WebState) -> Router {
     Router::new()
         .route("/", get(serve_email))
-        .route("/health", get(health_check))
+        .route("/health", get(health_json))
         .route("/healthz", get(health_check))
         .route("/styles.css", get(serve_styles))
         .route("/app.js", get(serve_app_js))
@@ -315,7 +521,8 @@ pub(crate) fn build_router(state: WebState) -> Router {
         .route("/manifest.json", get(serve_manifest))
         .route("/sw.js", get(serve_service_worker))
         .route("/events", get(email_events))
-        .route("/api/current-email", get(get_current_email_json))
+        .route("/ws", get(ws_events))
+        .nest("/api", api_router(state.clone()))
         // Auth runs *before* the handler (so unauthorized clients never reach
         // it) but *after* the security-headers layer is registered — order is
         // last-registered-runs-first, so security headers wrap the 401 too.
@@ -324,6 +531,11 @@ pub(crate) fn build_router(state: WebState) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/",
+    responses((status = 200, description = "Rendered HTML page for the focused email (welcome page when none)", body = String, content_type = "text/html"))
+)]
 pub(crate) async fn serve_email(State(state): State<WebState>) -> Response {
     let pane = state.focused_pane();
     // Hold the lock just long enough to clone what we need; never call
@@ -339,16 +551,21 @@ pub(crate) async fn serve_email(State(state): State<WebState>) -> Response {
                     .into_response();
             }
         };
-        store.current_email_for_web(pane).cloned()
+        store.current_email_for_web(pane).cloned().map(|email| {
+            let index = store.selected_email.unwrap_or(0);
+            let position = EmailPosition::of(&store, store.get_current_folder(), index);
+            (email, position)
+        })
     };
 
     let token = state.token.as_ref();
-    if let Some(email) = snapshot {
+    if let Some((email, position)) = snapshot {
         if matches!(email.load_state, EmailLoadState::HeadersOnly) {
             state.request_body_load(email.file_path.clone());
         }
         Html(generate_email_html(
             &email,
+            &position,
             token,
             state.images_visible.load(Ordering::Relaxed),
         ))
@@ -358,10 +575,77 @@ pub(crate) async fn serve_email(State(state): State<WebState>) -> Response {
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    responses((status = 200, description = "Liveness check; exempt from token auth", body = String, content_type = "text/plain"))
+)]
 async fn health_check() -> &'static str {
     "OK"
 }
 
+/// JSON shape of `GET /health`: build version, server uptime and
+/// counts over the folder tree as currently loaded.
+#[derive(Serialize, ToSchema)]
+struct HealthData {
+    version: String,
+    uptime_secs: u64,
+    /// Folders in the tree, excluding the MailDir root itself.
+    folder_count: usize,
+    /// Emails whose headers are in memory; folders load lazily, so
+    /// this grows as the user browses.
+    total_emails_loaded: usize,
+    unread_emails: usize,
+    /// Message-ID of the focused email, if any.
+    selected_email: Option<String>,
+}
+
+impl HealthData {
+    fn collect(state: &WebState) -> Self {
+        let mut data = HealthData {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            uptime_secs: state.started_at.elapsed().as_secs(),
+            folder_count: 0,
+            total_emails_loaded: 0,
+            unread_emails: 0,
+            selected_email: None,
+        };
+        if let Ok(store) = state.email_store.lock() {
+            data.count_folder(&store.root_folder);
+            // The root is a container, not a folder the user sees.
+            data.folder_count -= 1;
+            data.selected_email = store
+                .current_email_for_web(state.focused_pane())
+                .map(|e| e.headers.message_id.clone())
+                .filter(|id| !id.is_empty());
+        }
+        data
+    }
+
+    fn count_folder(&mut self, folder: &crate::email::Folder) {
+        self.folder_count += 1;
+        self.total_emails_loaded += folder.emails.len();
+        self.unread_emails += folder.emails.iter().filter(|e| e.is_unread).count();
+        for sub in &folder.subfolders {
+            self.count_folder(sub);
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/health",
+    responses((status = 200, description = "Version, uptime and mail statistics", body = HealthData))
+)]
+async fn health_json(State(state): State<WebState>) -> Response {
+    apply_no_cache_headers(Json(HealthData::collect(&state)).into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/styles.css",
+    responses((status = 200, description = "Viewer stylesheet", body = String, content_type = "text/css"))
+)]
 async fn serve_styles() -> Response {
     let css = include_str!("../static/styles.css");
     ([("content-type", "text/css")], css).into_response()
@@ -370,6 +654,11 @@ async fn serve_styles() -> Response {
 /// Serve the front-end JS that was formerly inlined into the HTML shells.
 /// Extracting it is the prerequisite for the strict CSP (`script-src 'self'`,
 /// no `'unsafe-inline'`) applied by [`security_headers_middleware`].
+#[utoipa::path(
+    get,
+    path = "/app.js",
+    responses((status = 200, description = "Viewer front-end script", body = String, content_type = "application/javascript"))
+)]
 pub(crate) async fn serve_app_js() -> Response {
     let js = include_str!("../static/app.js");
     (
@@ -379,16 +668,31 @@ pub(crate) async fn serve_app_js() -> Response {
         .into_response()
 }
 
+#[utoipa::path(
+    get,
+    path = "/vulthor_bird.png",
+    responses((status = 200, description = "Logo image", content_type = "image/png"))
+)]
 async fn serve_bird() -> Response {
     let logo_bytes = include_bytes!("../assets/vulthor_bird.png");
     ([("content-type", "image/png")], logo_bytes).into_response()
 }
 
+#[utoipa::path(
+    get,
+    path = "/vulthor_head.png",
+    responses((status = 200, description = "Logo image", content_type = "image/png"))
+)]
 async fn serve_head() -> Response {
     let logo_bytes = include_bytes!("../assets/vulthor_head.png");
     ([("content-type", "image/png")], logo_bytes).into_response()
 }
 
+#[utoipa::path(
+    get,
+    path = "/vulthor_letters.png",
+    responses((status = 200, description = "Logo image", content_type = "image/png"))
+)]
 async fn serve_letters() -> Response {
     let logo_bytes = include_bytes!("../assets/vulthor_letters.png");
     ([("content-type", "image/png")], logo_bytes).into_response()
@@ -401,6 +705,11 @@ async fn serve_letters() -> Response {
 /// without a pre-rasterized multi-size set. `theme_color` /
 /// `background_color` track [`VulthorTheme::PRIMARY_HEX`] /
 /// [`VulthorTheme::DARK_HEX`] so a palette rotation flows through.
+#[utoipa::path(
+    get,
+    path = "/manifest.json",
+    responses((status = 200, description = "PWA web app manifest", body = Object, content_type = "application/manifest+json"))
+)]
 pub(crate) async fn serve_manifest() -> Response {
     let body = format!(
         r#"{{
@@ -430,6 +739,11 @@ pub(crate) async fn serve_manifest() -> Response {
 /// pass-through. We are not chasing offline mail — the goal is just to
 /// satisfy Chrome/Edge's installability heuristic so the OS-level
 /// "Install Vulthor" entry appears (see VISION.md).
+#[utoipa::path(
+    get,
+    path = "/sw.js",
+    responses((status = 200, description = "Install-only PWA service worker", body = String, content_type = "application/javascript"))
+)]
 pub(crate) async fn serve_service_worker() -> Response {
     // The SW is registered via `/sw.js?t=<token>` so `self.location.search`
     // carries the same per-launch token the auth middleware demands. Without
@@ -472,6 +786,11 @@ self.addEventListener('fetch', (event) => {
         .into_response()
 }
 
+#[utoipa::path(
+    get,
+    path = "/events",
+    responses((status = 200, description = "Server-sent `email-changed` events as the TUI selection moves", body = String, content_type = "text/event-stream"))
+)]
 async fn email_events(
     State(state): State<WebState>,
 ) -> Sse<impl Stream<Item = std::result::Result<axum::response::sse::Event, Infallible>>> {
@@ -480,24 +799,11 @@ async fn email_events(
         async move {
             loop {
                 sleep(Duration::from_millis(200)).await;
+                if *state.shutdown.borrow() {
+                    return None;
+                }
 
-                let current_email_id = {
-                    let pane = state.focused_pane();
-                    let store = state.email_store.lock().ok()?;
-                    let folder_indices = store.current_folder.clone();
-                    let email_index = store.selected_email.unwrap_or(usize::MAX);
-                    // Include load_state in the key so SSE refires when the
-                    // body-loader fills in the body after the initial
-                    // selection event.
-                    let load_tag = match store.current_email_for_web(pane) {
-                        Some(e) => match e.load_state {
-                            EmailLoadState::HeadersOnly => "headers",
-                            EmailLoadState::FullyLoaded => "full",
-                        },
-                        None => "none",
-                    };
-                    format!("{:?}:{}:{}", folder_indices, email_index, load_tag)
-                };
+                let current_email_id = state.selection_key()?;
 
                 if last_email_id.as_ref() != Some(&current_email_id) {
                     let event = axum::response::sse::Event::default()
@@ -516,6 +822,81 @@ async fn email_events(
     )
 }
 
+/// Publish [`WsEvent::EmailChanged`] whenever the selection key moves.
+/// One poller per server, on the same 200 ms tick as the SSE stream,
+/// so `/ws` clients don't each poll the store. Ends on shutdown.
+pub(crate) async fn broadcast_selection_changes(state: WebState) {
+    let mut last = None;
+    loop {
+        sleep(Duration::from_millis(200)).await;
+        if *state.shutdown.borrow() {
+            return;
+        }
+        let Some(key) = state.selection_key() else {
+            return;
+        };
+        if last.as_ref() != Some(&key) {
+            // No subscribers is fine — a client that connects later
+            // gets the current key on connect.
+            let _ = state.events.send(WsEvent::EmailChanged { id: key.clone() });
+            last = Some(key);
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/ws",
+    responses((status = 101, description = "WebSocket streaming JSON `WsEvent` frames, starting with the current `email_changed`", body = WsEvent))
+)]
+async fn ws_events(ws: WebSocketUpgrade, State(state): State<WebState>) -> Response {
+    // Subscribe before the upgrade so nothing published during the
+    // handshake is lost.
+    let rx = state.events.subscribe();
+    ws.on_upgrade(move |socket| forward_ws_events(socket, rx, state))
+}
+
+/// Pump broadcast events into one WebSocket until the client goes away,
+/// the channel closes, or the server shuts down.
+async fn forward_ws_events(
+    mut socket: WebSocket,
+    mut rx: broadcast::Receiver<WsEvent>,
+    state: WebState,
+) {
+    let mut shutdown = state.shutdown.clone();
+    let initial = state.selection_key().map(|id| WsEvent::EmailChanged { id });
+    if let Some(event) = initial
+        && send_ws_event(&mut socket, &event).await.is_err()
+    {
+        return;
+    }
+    loop {
+        tokio::select! {
+            event = rx.recv() => match event {
+                Ok(event) => {
+                    if send_ws_event(&mut socket, &event).await.is_err() {
+                        return;
+                    }
+                }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return,
+            },
+            // Clients don't send anything meaningful; a close frame or
+            // a dropped connection ends the task.
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                Some(Ok(_)) => {}
+            },
+            _ = shutdown.changed() => return,
+        }
+    }
+}
+
+async fn send_ws_event(socket: &mut WebSocket, event: &WsEvent) -> std::result::Result<(), ()> {
+    let text = serde_json::to_string(event).map_err(|_| ())?;
+    socket.send(Message::Text(text)).await.map_err(|_| ())
+}
+
 /// Add `Cache-Control: no-store` and `Vary: Origin` to a response.
 ///
 /// The JSON endpoint surfaces email content keyed to the TUI's focus; a cache
@@ -530,116 +911,657 @@ fn apply_no_cache_headers(mut response: Response) -> Response {
     response
 }
 
+/// Where an email sits, for the viewer's Previous / Next controls.
+/// `count` is the number of emails loaded in the folder — folders load
+/// lazily, so it can trail the on-disk total until the TUI scrolls on.
+struct EmailPosition {
+    folder: String,
+    index: usize,
+    count: usize,
+}
+
+impl EmailPosition {
+    /// Position of the email at `index` in `folder`.
+    fn of(store: &EmailStore, folder: &crate::email::Folder, index: usize) -> Self {
+        Self {
+            folder: store.relative_folder_path(folder),
+            index,
+            count: folder.emails.len(),
+        }
+    }
+}
+
+/// JSON body for an email snapshot taken under the store lock. Kicks
+/// off a body parse for `HeadersOnly` emails so a refetch picks it up.
+fn email_json_response(
+    state: &WebState,
+    email: Option<crate::email::Email>,
+    email_id: String,
+    position: EmailPosition,
+) -> Response {
+    let Some(email) = email else {
+        return apply_no_cache_headers(
+            Json(EmailData {
+                has_email: false,
+                subject: String::new(),
+                from: String::new(),
+                to: String::new(),
+                date: String::new(),
+                body_html: String::new(),
+                attachments: vec![],
+                email_id,
+                size_bytes: 0,
+                flagged: false,
+                folder: position.folder,
+                email_index: position.index,
+                email_count: position.count,
+            })
+            .into_response(),
+        );
+    };
+
+    if matches!(email.load_state, EmailLoadState::HeadersOnly) {
+        state.request_body_load(email.file_path.clone());
+    }
+
+    let body_content = if matches!(email.load_state, EmailLoadState::HeadersOnly) {
+        "<p><em>Loading body…</em></p>".to_string()
+    } else if let Some(html) = &email.body_html {
+        // vu-aoy: when the user hasn't pressed Shift+I for this
+        // selection, strip every <img> but the email's own `cid:`
+        // parts from the sanitized body before handing it to the
+        // browser.
+        let html = if state.images_visible.load(Ordering::Relaxed) {
+            html.clone()
+        } else {
+            crate::sanitizer::strip_images(html)
+        };
+        rewrite_cid_sources(&html, &position, &state.token)
+    } else {
+        markdown_to_html(&email.display_body())
+    };
+
+    let attachments: Vec<AttachmentData> = email
+        .attachments
+        .iter()
+        .map(|attachment| AttachmentData {
+            filename: attachment.filename.clone(),
+            content_type: attachment.content_type.clone(),
+            size: format_file_size(attachment.size),
+        })
+        .collect();
+
+    apply_no_cache_headers(
+        Json(EmailData {
+            has_email: true,
+            subject: email.headers.subject.clone(),
+            from: email.headers.from.clone(),
+            to: email.headers.to.clone(),
+            date: email.headers.date.clone(),
+            body_html: body_content,
+            attachments,
+            email_id,
+            size_bytes: email.size_bytes,
+            flagged: email.is_flagged,
+            folder: position.folder,
+            email_index: position.index,
+            email_count: position.count,
+        })
+        .into_response(),
+    )
+}
+
+/// 500 response for a poisoned store lock, in the [`EmailData`] shape
+/// the client expects.
+fn email_json_lock_error() -> Response {
+    apply_no_cache_headers(
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(EmailData {
+                has_email: false,
+                subject: String::new(),
+                from: String::new(),
+                to: String::new(),
+                date: String::new(),
+                body_html: "Error: Could not access application state".to_string(),
+                attachments: vec![],
+                email_id: "error".to_string(),
+                size_bytes: 0,
+                flagged: false,
+                folder: String::new(),
+                email_index: 0,
+                email_count: 0,
+            }),
+        )
+            .into_response(),
+    )
+}
+
+fn load_tag(email: Option<&crate::email::Email>) -> &'static str {
+    match email {
+        Some(e) => match e.load_state {
+            EmailLoadState::HeadersOnly => "headers",
+            EmailLoadState::FullyLoaded => "full",
+        },
+        None => "none",
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/current-email",
+    responses(
+        (status = 200, description = "The focused email as JSON", body = EmailData),
+        (status = 500, description = "Store lock poisoned", body = EmailData)
+    )
+)]
 async fn get_current_email_json(State(state): State<WebState>) -> Response {
     let pane = state.focused_pane();
     // Snapshot the visible state under the lock, then drop it before doing
     // any HTML/JSON work. The store lock is shared with the TUI render
     // thread, so we must never block on it.
-    let snapshot = {
-        let store = match state.email_store.lock() {
-            Ok(s) => s,
-            Err(_) => {
-                return apply_no_cache_headers(
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(EmailData {
-                            has_email: false,
-                            subject: String::new(),
-                            from: String::new(),
-                            to: String::new(),
-                            date: String::new(),
-                            body_html: "Error: Could not access application state".to_string(),
-                            attachments: vec![],
-                            email_id: "error".to_string(),
-                        }),
-                    )
-                        .into_response(),
-                );
-            }
+    let (email, email_id, position) = {
+        let Ok(store) = state.email_store.lock() else {
+            return email_json_lock_error();
         };
-        let folder_indices = store.current_folder.clone();
         let email_index = store.selected_email.unwrap_or(usize::MAX);
         let email = store.current_email_for_web(pane).cloned();
-        (folder_indices, email_index, email)
+        let email_id = format!(
+            "{:?}:{}:{}",
+            store.current_folder,
+            email_index,
+            load_tag(email.as_ref())
+        );
+        let position = EmailPosition::of(
+            &store,
+            store.get_current_folder(),
+            store.selected_email.unwrap_or(0),
+        );
+        (email, email_id, position)
     };
-    let (folder_indices, email_index, current_email) = snapshot;
+    email_json_response(&state, email, email_id, position)
+}
 
-    let load_tag = match &current_email {
-        Some(e) => match e.load_state {
-            EmailLoadState::HeadersOnly => "headers",
-            EmailLoadState::FullyLoaded => "full",
-        },
-        None => "none",
+#[utoipa::path(
+    get,
+    path = "/api/email/{folder}/{index}",
+    params(
+        ("folder" = String, Path, description = "Folder path relative to the MailDir root, URL-encoded (`INBOX`, `Work%2FProjects`)"),
+        ("index" = usize, Path, description = "Zero-based position among the folder's loaded emails")
+    ),
+    responses(
+        (status = 200, description = "The email at `index` in `folder`, independent of the TUI selection", body = EmailData),
+        (status = 404, description = "Unknown folder or index out of range", body = String),
+        (status = 500, description = "Store lock poisoned", body = EmailData)
+    )
+)]
+async fn get_email_json(
+    State(state): State<WebState>,
+    AxumPath((folder, index)): AxumPath<(String, usize)>,
+) -> Response {
+    let (email, email_id, position) = {
+        let Ok(store) = state.email_store.lock() else {
+            return email_json_lock_error();
+        };
+        let found = store.folder_by_relative_path(&folder).and_then(|f| {
+            let email = f.emails.get(index)?.clone();
+            Some((email, EmailPosition::of(&store, f, index)))
+        });
+        let Some((email, position)) = found else {
+            return apply_no_cache_headers(
+                (StatusCode::NOT_FOUND, "No such email").into_response(),
+            );
+        };
+        let email_id = format!("{}:{}:{}", folder, index, load_tag(Some(&email)));
+        (Some(email), email_id, position)
     };
-    let email_id = format!("{:?}:{}:{}", folder_indices, email_index, load_tag);
+    email_json_response(&state, email, email_id, position)
+}
 
-    if let Some(email) = current_email {
-        if matches!(email.load_state, EmailLoadState::HeadersOnly) {
-            state.request_body_load(email.file_path.clone());
+/// One node of `GET /api/folders`: a folder, its counts and its
+/// children, in the order the Folders pane lists them.
+#[derive(Serialize, ToSchema)]
+struct FolderNode {
+    name: String,
+    /// Folder path relative to the MailDir root, for `/api/folder/…`.
+    path: String,
+    /// Unread emails among those loaded.
+    unread: usize,
+    /// Emails loaded so far; the whole folder once `loaded` is set.
+    total: usize,
+    /// True once every header in the folder has been scanned.
+    loaded: bool,
+    #[schema(no_recursion)]
+    subfolders: Vec<FolderNode>,
+}
+
+impl FolderNode {
+    fn of(store: &EmailStore, folder: &crate::email::Folder) -> Self {
+        FolderNode {
+            name: folder.name.clone(),
+            path: store.relative_folder_path(folder),
+            unread: folder.unread_count,
+            total: folder.total_count,
+            loaded: folder.is_loaded,
+            subfolders: Self::children(store, folder),
         }
+    }
 
-        let body_content = if matches!(email.load_state, EmailLoadState::HeadersOnly) {
-            "<p><em>Loading body…</em></p>".to_string()
-        } else if let Some(html) = &email.body_html {
-            // vu-aoy: when the user hasn't pressed Shift+I for this
-            // selection, strip all <img> tags from the sanitized body
-            // before handing it to the browser.
-            if state.images_visible.load(Ordering::Relaxed) {
-                html.clone()
-            } else {
-                crate::sanitizer::strip_images(html)
-            }
-        } else {
-            markdown_to_html(&email.display_body())
+    /// Saved-search pseudo-folders are left out: they have no
+    /// directory for `/api/folder/…` to page through.
+    fn children(store: &EmailStore, folder: &crate::email::Folder) -> Vec<Self> {
+        folder
+            .get_sorted_subfolders()
+            .into_iter()
+            .filter(|sub| !sub.is_virtual)
+            .map(|sub| Self::of(store, sub))
+            .collect()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/folders",
+    responses(
+        (status = 200, description = "The top-level folders, each with its subfolders", body = Vec<FolderNode>),
+        (status = 500, description = "Store lock poisoned", body = String)
+    )
+)]
+async fn get_folders_json(State(state): State<WebState>) -> Response {
+    let Ok(store) = state.email_store.lock() else {
+        return (StatusCode::INTERNAL_SERVER_ERROR, "Store lock poisoned").into_response();
+    };
+    let folders = FolderNode::children(&store, &store.root_folder);
+    drop(store);
+    apply_no_cache_headers(Json(folders).into_response())
+}
+
+/// Emails per `/api/folder/…` page when the request names no `limit`.
+const FOLDER_PAGE_DEFAULT: usize = 50;
+
+/// Largest `limit` `/api/folder/…` honours; bigger requests are cut
+/// down to it.
+const FOLDER_PAGE_MAX: usize = 200;
+
+/// Query string of `GET /api/folder/{folder}`.
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+struct FolderPageParams {
+    /// Index of the first email to return. Defaults to 0.
+    offset: Option<usize>,
+    /// Emails to return, at most 200. Defaults to 50.
+    limit: Option<usize>,
+}
+
+/// JSON shape of `GET /api/folder/{folder}`: one page of the folder's
+/// email list, in the order `/api/email/{folder}/{index}` indexes it.
+#[derive(Serialize, ToSchema)]
+struct FolderPage {
+    /// Folder path relative to the MailDir root.
+    folder: String,
+    /// Index of the first email in `emails`.
+    offset: usize,
+    /// Emails in the folder on disk, loaded or not.
+    total: usize,
+    emails: Vec<FolderEmailSummary>,
+}
+
+/// One row of [`FolderPage::emails`].
+#[derive(Serialize, ToSchema)]
+struct FolderEmailSummary {
+    /// Position in the folder, for `/api/email/{folder}/{index}`.
+    index: usize,
+    subject: String,
+    from: String,
+    date: String,
+    unread: bool,
+    flagged: bool,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/folder/{folder}",
+    params(
+        ("folder" = String, Path, description = "Folder path relative to the MailDir root, URL-encoded (`INBOX`, `Work%2FProjects`)"),
+        FolderPageParams
+    ),
+    responses(
+        (status = 200, description = "A page of the folder's emails and the folder's total", body = FolderPage),
+        (status = 404, description = "Unknown folder", body = String),
+        (status = 500, description = "Store lock poisoned", body = String)
+    )
+)]
+async fn get_folder_page_json(
+    State(state): State<WebState>,
+    AxumPath(folder): AxumPath<String>,
+    Query(params): Query<FolderPageParams>,
+) -> Response {
+    let offset = params.offset.unwrap_or(0);
+    let limit = params
+        .limit
+        .unwrap_or(FOLDER_PAGE_DEFAULT)
+        .min(FOLDER_PAGE_MAX);
+    // Paging in headers parses files, so it runs on the blocking pool
+    // rather than an executor thread, like a `j` scroll in the TUI. The
+    // parsing and the directory count happen on a detached copy of the
+    // folder with the store lock released: the TUI renders under that
+    // lock, so it must never be held across disk work.
+    tokio::task::spawn_blocking(move || {
+        let mut loaded = {
+            let Ok(store) = state.email_store.lock() else {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Store lock poisoned").into_response();
+            };
+            let Some(f) = store.folder_by_relative_path(&folder) else {
+                return apply_no_cache_headers(
+                    (StatusCode::NOT_FOUND, "No such folder").into_response(),
+                );
+            };
+            f.detached_copy()
         };
+        let end = offset.saturating_add(limit).saturating_sub(1);
+        if let Err(e) = EmailStore::load_more_detached(&mut loaded, &state.scanner, end) {
+            tracing::warn!(folder = %folder, error = %e, "loading folder page failed");
+        }
+        let on_disk = (!loaded.is_loaded).then(|| state.scanner.count_folder_emails(&loaded));
 
-        let attachments: Vec<AttachmentData> = email
-            .attachments
+        let Ok(mut store) = state.email_store.lock() else {
+            return (StatusCode::INTERNAL_SERVER_ERROR, "Store lock poisoned").into_response();
+        };
+        store.merge_loaded_emails(loaded);
+        let Some(f) = store.folder_by_relative_path(&folder) else {
+            return (StatusCode::INTERNAL_SERVER_ERROR, "Folder vanished").into_response();
+        };
+        let emails = f
+            .emails
             .iter()
-            .map(|attachment| AttachmentData {
-                filename: attachment.filename.clone(),
-                content_type: attachment.content_type.clone(),
-                size: format_file_size(attachment.size),
+            .enumerate()
+            .skip(offset)
+            .take(limit)
+            .map(|(index, e)| FolderEmailSummary {
+                index,
+                subject: e.headers.subject.clone(),
+                from: e.headers.from.clone(),
+                date: e.headers.date.clone(),
+                unread: e.is_unread,
+                flagged: e.is_flagged,
             })
             .collect();
-
+        let total = match on_disk {
+            Some(count) if !f.is_loaded => count.max(f.emails.len()),
+            _ => f.emails.len(),
+        };
         apply_no_cache_headers(
-            Json(EmailData {
-                has_email: true,
-                subject: email.headers.subject.clone(),
-                from: email.headers.from.clone(),
-                to: email.headers.to.clone(),
-                date: email.headers.date.clone(),
-                body_html: body_content,
-                attachments,
-                email_id,
+            Json(FolderPage {
+                folder: store.relative_folder_path(f),
+                offset,
+                total,
+                emails,
             })
             .into_response(),
         )
-    } else {
-        apply_no_cache_headers(
-            Json(EmailData {
-                has_email: false,
-                subject: String::new(),
-                from: String::new(),
-                to: String::new(),
-                date: String::new(),
-                body_html: String::new(),
-                attachments: vec![],
-                email_id,
-            })
-            .into_response(),
+    })
+    .await
+    .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/cid/{folder}/{index}/{content_id}",
+    params(
+        ("folder" = String, Path, description = "Folder path relative to the MailDir root, URL-encoded (`INBOX`, `Work%2FProjects`)"),
+        ("index" = usize, Path, description = "Zero-based position among the folder's loaded emails"),
+        ("content_id" = String, Path, description = "The part's `Content-ID` without angle brackets, URL-encoded")
+    ),
+    responses(
+        (status = 200, description = "The inline image's bytes under its own `image/*` Content-Type", content_type = "image/*"),
+        (status = 404, description = "Unknown email, body not loaded yet, or no image part with that Content-ID", body = String),
+        (status = 500, description = "Store lock poisoned", body = String)
+    )
+)]
+async fn get_inline_image(
+    State(state): State<WebState>,
+    AxumPath((folder, index, content_id)): AxumPath<(String, usize, String)>,
+) -> Response {
+    let image = {
+        let Ok(store) = state.email_store.lock() else {
+            return (StatusCode::INTERNAL_SERVER_ERROR, "Store lock poisoned").into_response();
+        };
+        store
+            .folder_by_relative_path(&folder)
+            .and_then(|f| f.emails.get(index))
+            .and_then(|e| e.inline_images.iter().find(|i| i.content_id == content_id))
+            .cloned()
+    };
+    // Only raster images: this route serves sender-controlled bytes from
+    // our own origin, so an inline `text/html` or SVG part must not be
+    // handed back as something the browser would run.
+    match image {
+        Some(image) if is_servable_image(&image.content_type) => (
+            [(axum::http::header::CONTENT_TYPE, image.content_type)],
+            image.raw_bytes,
         )
+            .into_response(),
+        _ => (StatusCode::NOT_FOUND, "No such inline image").into_response(),
+    }
+}
+
+fn is_servable_image(content_type: &str) -> bool {
+    let mime = content_type.to_ascii_lowercase();
+    mime.starts_with("image/") && !mime.starts_with("image/svg")
+}
+
+/// Point every `src="cid:…"` in sanitized `html` at
+/// `/api/cid/{folder}/{index}/{content_id}`, so the email's own inline
+/// parts load from this server. Anything else is left as is.
+fn rewrite_cid_sources(html: &str, position: &EmailPosition, token: &str) -> String {
+    const NEEDLE: &str = "src=\"cid:";
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(at) = rest.find(NEEDLE) {
+        let value_start = at + NEEDLE.len();
+        let Some(len) = rest[value_start..].find('"') else {
+            break;
+        };
+        // The sanitizer's serializer escapes `&` in attribute values.
+        let content_id = rest[value_start..value_start + len].replace("&amp;", "&");
+        out.push_str(&rest[..at]);
+        out.push_str(&format!(
+            "src=\"/api/cid/{}/{}/{}?t={}\"",
+            encode_path_segment(&position.folder),
+            position.index,
+            encode_path_segment(&content_id),
+            token
+        ));
+        rest = &rest[value_start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters, so
+/// `/` and `@` survive a trip through one path segment.
+fn encode_path_segment(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// OpenAPI description of every route [`build_router`] serves. Paths
+/// come from the `#[utoipa::path]` attribute on each handler; the
+/// per-launch token is declared as a query / header API key.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Vulthor HTML viewer"),
+    paths(
+        serve_email,
+        health_check,
+        health_json,
+        serve_styles,
+        serve_app_js,
+        serve_bird,
+        serve_head,
+        serve_letters,
+        serve_manifest,
+        serve_service_worker,
+        email_events,
+        ws_events,
+        get_current_email_json,
+        get_email_json,
+        get_folders_json,
+        get_folder_page_json,
+        get_inline_image,
+        serve_openapi_json,
+        serve_api_docs,
+    ),
+    modifiers(&TokenAuth),
+    security(("token_query" = []), ("token_header" = []))
+)]
+pub struct ApiDoc;
+
+/// Registers the two equivalent ways of presenting the per-launch
+/// token (see [`auth_middleware`]) as security schemes.
+struct TokenAuth;
+
+impl Modify for TokenAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "token_query",
+            SecurityScheme::ApiKey(ApiKey::Query(ApiKeyValue::new("t"))),
+        );
+        components.add_security_scheme(
+            "token_header",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-Vulthor-Token"))),
+        );
+        components.add_security_scheme(
+            "api_token",
+            SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+        );
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/openapi.json",
+    responses((status = 200, description = "This OpenAPI document", body = Object))
+)]
+async fn serve_openapi_json() -> Response {
+    Json(ApiDoc::openapi()).into_response()
+}
+
+/// API reference over [`ApiDoc`], rendered here rather than by Swagger
+/// UI from a CDN: this page is reached with the per-launch token in its
+/// URL, and third-party script running beside that token could read
+/// every mail endpoint. Served under the default CSP, with no script at
+/// all. `/api/openapi.json` stays available for external tools.
+#[utoipa::path(
+    get,
+    path = "/api/docs",
+    responses((status = 200, description = "API reference page", body = String, content_type = "text/html"))
+)]
+async fn serve_api_docs() -> Response {
+    let spec = serde_json::to_value(ApiDoc::openapi()).unwrap_or_default();
+    let text = |v: &serde_json::Value| escape_html(v.as_str().unwrap_or_default());
+    let mut sections = String::new();
+    for (path, item) in spec["paths"].as_object().into_iter().flatten() {
+        for method in ["get", "put", "post", "delete", "patch"] {
+            let Some(op) = item.get(method) else {
+                continue;
+            };
+            sections.push_str(&format!(
+                "<section>\n<h2><code>{} {}</code></h2>\n",
+                method.to_uppercase(),
+                escape_html(path)
+            ));
+            for key in ["summary", "description"] {
+                if op[key].is_string() {
+                    sections.push_str(&format!("<p>{}</p>\n", text(&op[key])));
+                }
+            }
+            let params = op["parameters"].as_array().into_iter().flatten();
+            let params: Vec<String> = params
+                .map(|p| {
+                    format!(
+                        "<li><code>{}</code> ({}) {}</li>",
+                        text(&p["name"]),
+                        text(&p["in"]),
+                        text(&p["description"])
+                    )
+                })
+                .collect();
+            if !params.is_empty() {
+                sections.push_str(&format!("<ul>{}</ul>\n", params.join("")));
+            }
+            let responses = op["responses"].as_object().into_iter().flatten();
+            let responses: Vec<String> = responses
+                .map(|(status, r)| {
+                    format!(
+                        "<li><code>{}</code> {}</li>",
+                        escape_html(status),
+                        text(&r["description"])
+                    )
+                })
+                .collect();
+            sections.push_str(&format!("<ul>{}</ul>\n</section>\n", responses.join("")));
+        }
     }
+    Html(format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <title>Vulthor API</title>
+</head>
+<body>
+    <h1>Vulthor API</h1>
+    <p>Machine-readable description: <code>/api/openapi.json</code>.
+    Requests take the viewer's token as <code>?t=</code> or the
+    <code>X-Vulthor-Token</code> header.</p>
+{sections}</body>
+</html>"#
+    ))
+    .into_response()
+}
+
+/// Previous / Next bar for browsing `position.folder` from the page.
+/// The buttons are wired up by `app.js`; they disable at either end of
+/// the loaded emails rather than wrapping.
+fn email_nav_html(position: &EmailPosition) -> String {
+    let disabled = |off: bool| if off { " disabled" } else { "" };
+    format!(
+        r#"<nav class="email-nav" data-folder="{}" data-index="{}" data-count="{}">
+            <button type="button" class="nav-prev"{}>&larr; Previous</button>
+            <span class="email-position">{} / {}</span>
+            <button type="button" class="nav-next"{}>Next &rarr;</button>
+        </nav>"#,
+        escape_html(&position.folder),
+        position.index,
+        position.count,
+        disabled(position.index == 0),
+        position.index + 1,
+        position.count,
+        disabled(position.index + 1 >= position.count),
+    )
 }
 
-fn generate_email_html(email: &crate::email::Email, token: &str, images_visible: bool) -> String {
+fn generate_email_html(
+    email: &crate::email::Email,
+    position: &EmailPosition,
+    token: &str,
+    images_visible: bool,
+) -> String {
     let body_content = if let Some(html) = &email.body_html {
-        if images_visible {
+        let html = if images_visible {
             html.clone()
         } else {
             crate::sanitizer::strip_images(html)
-        }
+        };
+        rewrite_cid_sources(&html, position, token)
     } else {
         // Convert plain text to HTML
         markdown_to_html(&email.display_body())
@@ -677,10 +1599,27 @@ fn generate_email_html(email: &crate::email::Email, token: &str, images_visible:
         String::new()
     };
 
-    format!(
-        r#"<!DOCTYPE html>
-<html lang="en">
-<head>
+    let badges = email.auth_status.badges();
+    let auth_html = if badges.is_empty() {
+        String::new()
+    } else {
+        let spans: Vec<String> = badges
+            .into_iter()
+            .map(|(label, passed)| {
+                if passed {
+                    format!(r#"<span class="auth-pass">✓ {}</span>"#, label)
+                } else {
+                    format!(r#"<span class="auth-fail">✗ {}</span>"#, label)
+                }
+            })
+            .collect();
+        format!(r#"<div class="auth-badges">{}</div>"#, spans.join(""))
+    };
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
     <title>Vulthor - {}</title>
@@ -695,11 +1634,13 @@ fn generate_email_html(email: &crate::email::Email, token: &str, images_visible:
         <img src="/vulthor_letters.png?t={t}" alt="Vulthor" class="logo-text">
     </div>
     <div class="container">
+        {}
         <header class="email-header">
             <h1 class="email-subject">{}</h1>
             <div class="email-meta">
                 <div class="email-from">
                     <strong>From:</strong> {}
+                    {}
                 </div>
                 <div class="email-to">
                     <strong>To:</strong> {}
@@ -721,8 +1662,10 @@ fn generate_email_html(email: &crate::email::Email, token: &str, images_visible:
 </body>
 </html>"#,
         escape_html(&email.headers.subject),
+        email_nav_html(position),
         escape_html(&email.headers.subject),
         escape_html(&email.headers.from),
+        auth_html,
         escape_html(&email.headers.to),
         escape_html(&email.headers.date),
         body_srcdoc,
@@ -891,26 +1834,36 @@ fn escape_html_attr(text: &str) -> String {
     text.replace('&', "&amp;").replace('"', "&quot;")
 }
 
-fn format_file_size(bytes: usize) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
-    let mut size = bytes as f64;
-    let mut unit_index = 0;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
-        unit_index += 1;
+    fn test_position() -> EmailPosition {
+        EmailPosition {
+            folder: "INBOX".to_string(),
+            index: 0,
+            count: 1,
+        }
     }
 
-    if unit_index == 0 {
-        format!("{} {}", size as usize, UNITS[unit_index])
-    } else {
-        format!("{:.1} {}", size, UNITS[unit_index])
+    #[test]
+    fn email_nav_disables_buttons_at_folder_ends() {
+        let nav = |index, count| {
+            email_nav_html(&EmailPosition {
+                folder: "Work/<x>".to_string(),
+                index,
+                count,
+            })
+        };
+        let first = nav(0, 3);
+        assert!(first.contains(r#"class="nav-prev" disabled"#));
+        assert!(!first.contains(r#"class="nav-next" disabled"#));
+        assert!(first.contains("1 / 3"));
+        assert!(first.contains(r#"data-folder="Work/&lt;x&gt;""#));
+        let last = nav(2, 3);
+        assert!(!last.contains(r#"class="nav-prev" disabled"#));
+        assert!(last.contains(r#"class="nav-next" disabled"#));
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
     #[test]
     fn test_escape_html() {
@@ -918,14 +1871,6 @@ mod tests {
         assert_eq!(escape_html("\"quoted\""), "&quot;quoted&quot;");
     }
 
-    #[test]
-    fn test_format_file_size() {
-        assert_eq!(format_file_size(512), "512 B");
-        assert_eq!(format_file_size(1024), "1.0 KB");
-        assert_eq!(format_file_size(1536), "1.5 KB");
-        assert_eq!(format_file_size(1048576), "1.0 MB");
-    }
-
     // --- PWA install surface (vu-cyj) ---
     //
     // VISION.md §HTML Viewer §PWA bonus: the rendered shell must
@@ -1056,10 +2001,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn email_html_shows_auth_badges_only_when_verdicts_exist() {
+        let mut email = crate::email::Email::new(PathBuf::from("/tmp/fake.eml"));
+        let html = generate_email_html(&email, &test_position(), "tok", false);
+        assert!(!html.contains("auth-badges"));
+
+        email.auth_status.dkim = Some(true);
+        email.auth_status.spf = Some(false);
+        let html = generate_email_html(&email, &test_position(), "tok", false);
+        assert!(html.contains(
+            r#"<div class="auth-badges"><span class="auth-pass">✓ DKIM</span><span class="auth-fail">✗ SPF</span></div>"#
+        ));
+    }
+
     #[test]
     fn email_html_head_advertises_pwa_install_hooks() {
         let email = crate::email::Email::new(PathBuf::from("/tmp/fake.eml"));
-        let html = generate_email_html(&email, "tok", false);
+        let html = generate_email_html(&email, &test_position(), "tok", false);
         let head_end = html.find("</head>").expect("email HTML must have a head");
         let head = &html[..head_end];
         assert!(
@@ -1096,7 +2055,7 @@ mod tests {
     #[test]
     fn email_html_does_not_inline_scripts() {
         let email = Email::new(PathBuf::from("/tmp/fake.eml"));
-        let html = generate_email_html(&email, "tok", false);
+        let html = generate_email_html(&email, &test_position(), "tok", false);
         // The only `<script` permitted is the external app.js reference.
         // Any inline block re-introduces the `unsafe-inline` requirement
         // we explicitly avoid in CSP_HEADER.
@@ -1160,10 +2119,35 @@ mod tests {
         }
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn styles_carry_both_palettes_for_the_theme_toggle() {
+        let response = serve_styles().await;
+        let body_bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        let css = std::str::from_utf8(&body_bytes).unwrap();
+        let palette = |selector: &str| {
+            let start = css
+                .find(&format!("{selector} {{"))
+                .unwrap_or_else(|| panic!("no `{selector}` block in /styles.css"));
+            let block = &css[start..];
+            block[..block.find('}').unwrap()].to_string()
+        };
+        let light = palette(":root");
+        let dark = palette(":root[data-theme=\"dark\"]");
+        for var in ["--bg-primary:", "--bg-secondary:", "--text-primary:"] {
+            assert!(light.contains(var), "light palette missing {var}");
+            assert!(dark.contains(var), "dark palette missing {var}");
+        }
+        assert!(css.contains(".theme-toggle"));
+
+        let js = include_str!("../static/app.js");
+        assert!(js.contains("localStorage.setItem(THEME_KEY"));
+        assert!(js.contains("'(prefers-color-scheme: dark)'"));
+    }
+
     #[test]
     fn email_html_wraps_body_in_sandboxed_iframe() {
         let email = Email::new(PathBuf::from("/tmp/fake.eml"));
-        let html = generate_email_html(&email, "tok", false);
+        let html = generate_email_html(&email, &test_position(), "tok", false);
         let iframe = html
             .find("<iframe")
             .map(|i| &html[i..])
@@ -1200,7 +2184,7 @@ mod tests {
         // Round-trip a body with `&` and `"` to prove escape_html_attr fires.
         let mut email = Email::new(PathBuf::from("/tmp/fake.eml"));
         email.body_html = Some(r#"<p>tom & jerry "say" hi</p>"#.to_string());
-        let html = generate_email_html(&email, "tok", false);
+        let html = generate_email_html(&email, &test_position(), "tok", false);
         // vu-aoy: with images_visible=false, generate_email_html now
         // re-sanitizes the body via `strip_images` (which encodes `&`
         // to `&amp;`). The srcdoc-attribute escape then encodes the `&`
@@ -1232,14 +2216,14 @@ mod tests {
             "<p>before</p><img src=\"data:image/png;base64,abc\" alt=\"x\"><p>after</p>"
                 .to_string(),
         );
-        let hidden = generate_email_html(&email, "tok", false);
+        let hidden = generate_email_html(&email, &test_position(), "tok", false);
         // The body lives inside `srcdoc="…"`, which `escape_html_attr`
         // turns the angle brackets into themselves (only `&` and `"`
         // are escaped). So a literal `<img` substring would indicate
         // strip_images didn't fire. The whole document includes one
         // unrelated `<img` reference in static markup — count occurrences.
         let hidden_img_hits = hidden.matches("<img").count();
-        let shown_img_hits = generate_email_html(&email, "tok", true)
+        let shown_img_hits = generate_email_html(&email, &test_position(), "tok", true)
             .matches("<img")
             .count();
         assert!(
@@ -1342,6 +2326,92 @@ mod tests {
         assert_security_headers_present("/api/current-email").await;
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn openapi_json_route_lists_every_path() {
+        let app = router_for_test();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/openapi.json?t=test-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let doc: serde_json::Value =
+            serde_json::from_slice(&bytes).expect("openapi.json must be valid JSON");
+        assert!(doc["openapi"].as_str().unwrap().starts_with("3."));
+        for path in [
+            "/",
+            "/health",
+            "/healthz",
+            "/events",
+            "/ws",
+            "/api/current-email",
+            "/api/email/{folder}/{index}",
+            "/api/folders",
+            "/api/folder/{folder}",
+            "/api/cid/{folder}/{index}/{content_id}",
+            "/api/openapi.json",
+            "/api/docs",
+        ] {
+            assert!(
+                doc["paths"].get(path).is_some(),
+                "openapi.json must describe {}",
+                path
+            );
+        }
+        assert!(doc["components"]["schemas"].get("EmailData").is_some());
+        assert!(doc["components"]["schemas"].get("FolderNode").is_some());
+        assert!(doc["components"]["schemas"].get("WsEvent").is_some());
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn api_docs_route_serves_the_reference_under_the_default_csp() {
+        let app = router_for_test();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/docs?t=test-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let csp = response.headers()["content-security-policy"]
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert_eq!(csp, CSP_HEADER);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(html.contains("/api/openapi.json"));
+        assert!(html.contains("<code>GET /api/email/{folder}/{index}</code>"));
+        assert!(!html.contains("<script") && !html.contains("https://"));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn api_docs_require_token() {
+        let app = router_for_test();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn app_js_route_is_reachable_through_router() {
         let app = router_for_test();
@@ -1412,6 +2482,11 @@ mod tests {
             images_visible: Arc::new(AtomicBool::new(false)),
             body_request_tx: tx,
             token: Arc::from("test-token"),
+            api_token: None,
+            shutdown: watch::channel(false).1,
+            events: broadcast::channel(WS_EVENT_CAPACITY).0,
+            started_at: Instant::now(),
+            scanner: MaildirScanner::new(PathBuf::from("/nonexistent_root")),
         };
         (state, rx)
     }
@@ -1639,6 +2714,510 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    async fn get_json(app: Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        let response = app
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null),
+        )
+    }
+
+    /// With images revealed, `cid:` sources point at `/api/cid/…`,
+    /// which serves the inline part's bytes; remote images stay on the
+    /// placeholder and a missing Content-ID is a 404.
+    #[tokio::test]
+    async fn cid_images_are_rewritten_and_served_from_the_email() {
+        let fixture = crate::test_fixtures::TestMailDir::new();
+        fixture.add_inline_image_email("INBOX", "inline.eml");
+        let mut store = EmailStore::new(fixture.root_path.clone());
+        let mut inbox = Folder::new("INBOX".to_string(), fixture.get_folder_path("INBOX"));
+        let mut email = Email::new(fixture.get_folder_path("INBOX/cur/inline.eml"));
+        email.parse_from_file().unwrap();
+        let png = email.inline_images[0].raw_bytes.clone();
+        inbox.add_email(email);
+        inbox.is_loaded = true;
+        store.root_folder.add_subfolder(inbox);
+
+        let (state, _rx) = webstate_with_one_headers_only_email();
+        let state = WebState {
+            email_store: Arc::new(Mutex::new(store)),
+            images_visible: Arc::new(AtomicBool::new(true)),
+            ..state
+        };
+        let app = build_router(state);
+
+        let (status, email) = get_json(app.clone(), "/api/email/INBOX/0?t=test-token").await;
+        assert_eq!(status, StatusCode::OK);
+        let body = email["body_html"].as_str().unwrap();
+        let src = "/api/cid/INBOX/0/pixel%40v.test?t=test-token";
+        assert!(body.contains(&format!("src=\"{}\"", src)), "{}", body);
+        assert!(!body.contains("cid:"), "{}", body);
+        assert!(!body.contains("tracker.test"), "{}", body);
+
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri(src).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "image/png");
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(bytes.starts_with(b"\x89PNG"));
+        assert_eq!(bytes.as_ref(), png.as_slice());
+
+        let (status, _) = get_json(app, "/api/cid/INBOX/0/other%40v.test?t=test-token").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    /// Inline `cid:` images never leave this server, so they show
+    /// before Shift+I; only the remote image stays hidden.
+    #[tokio::test]
+    async fn cid_images_are_rewritten_while_images_are_hidden() {
+        let fixture = crate::test_fixtures::TestMailDir::new();
+        fixture.add_inline_image_email("INBOX", "inline.eml");
+        let mut store = EmailStore::new(fixture.root_path.clone());
+        let mut inbox = Folder::new("INBOX".to_string(), fixture.get_folder_path("INBOX"));
+        let mut email = Email::new(fixture.get_folder_path("INBOX/cur/inline.eml"));
+        email.parse_from_file().unwrap();
+        inbox.add_email(email);
+        inbox.is_loaded = true;
+        store.root_folder.add_subfolder(inbox);
+
+        let (state, _rx) = webstate_with_one_headers_only_email();
+        let state = WebState {
+            email_store: Arc::new(Mutex::new(store)),
+            images_visible: Arc::new(AtomicBool::new(false)),
+            ..state
+        };
+        let app = build_router(state);
+
+        let (status, email) = get_json(app, "/api/email/INBOX/0?t=test-token").await;
+        assert_eq!(status, StatusCode::OK);
+        let body = email["body_html"].as_str().unwrap();
+        let src = "/api/cid/INBOX/0/pixel%40v.test?t=test-token";
+        assert!(body.contains(&format!("src=\"{}\"", src)), "{}", body);
+        assert_eq!(body.matches("<img").count(), 1, "{}", body);
+    }
+
+    /// `/api/email/{folder}/{index}` reads any loaded email without
+    /// moving the TUI selection, and reports the bounds the page's
+    /// Previous / Next buttons need.
+    #[tokio::test(flavor = "current_thread")]
+    async fn api_email_by_folder_and_index_reports_position() {
+        let (state, _rx) = webstate_with_one_headers_only_email();
+        {
+            let mut store = state.email_store.lock().unwrap();
+            let mut second = Email::new(PathBuf::from("/nonexistent_root/INBOX/cur/two"));
+            second.headers.subject = "Second".to_string();
+            store.get_current_folder_mut().add_email(second);
+        }
+        let app = build_router(state.clone());
+
+        let (status, current) = get_json(app.clone(), "/api/current-email?t=test-token").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(current["folder"], "INBOX");
+        assert_eq!(current["email_index"], 0);
+        assert_eq!(current["email_count"], 2);
+
+        let (status, next) = get_json(app.clone(), "/api/email/INBOX/1?t=test-token").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(next["has_email"], true);
+        assert_eq!(next["subject"], "Second");
+        assert_eq!(next["email_index"], 1);
+        assert_eq!(next["email_count"], 2);
+        assert_eq!(state.email_store.lock().unwrap().selected_email, Some(0));
+
+        for uri in [
+            "/api/email/INBOX/2?t=test-token",
+            "/api/email/Nope/0?t=test-token",
+        ] {
+            let (status, _) = get_json(app.clone(), uri).await;
+            assert_eq!(status, StatusCode::NOT_FOUND, "{}", uri);
+        }
+    }
+
+    /// `/api/folder/{folder}` pages through a folder, loading headers
+    /// only as deep as the requested page, and reports the on-disk total
+    /// from the start.
+    #[tokio::test(flavor = "current_thread")]
+    async fn api_folder_pages_by_offset_and_limit_and_loads_lazily() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let cur = tmp.path().join("INBOX/cur");
+        std::fs::create_dir_all(&cur).unwrap();
+        std::fs::create_dir_all(tmp.path().join("INBOX/new")).unwrap();
+        for i in 0..230 {
+            std::fs::write(
+                cur.join(format!("msg{:03}:2,S", i)),
+                format!(
+                    "From: a@example.com\r\nSubject: Message {}\r\n\r\nbody\r\n",
+                    i
+                ),
+            )
+            .unwrap();
+        }
+        let scanner = MaildirScanner::new(tmp.path().to_path_buf());
+        let mut store = EmailStore::new(tmp.path().to_path_buf());
+        let mut inbox = Folder::new("INBOX".to_string(), tmp.path().join("INBOX"));
+        scanner
+            .load_folder_emails_with_limit(&mut inbox, Some(10))
+            .unwrap();
+        store.root_folder.add_subfolder(inbox);
+
+        let (state, _rx) = webstate_with_one_headers_only_email();
+        let state = WebState {
+            email_store: Arc::new(Mutex::new(store)),
+            scanner,
+            ..state
+        };
+        let loaded = || {
+            state.email_store.lock().unwrap().root_folder.subfolders[0]
+                .emails
+                .len()
+        };
+        let app = build_router(state.clone());
+
+        let (status, page) = get_json(
+            app.clone(),
+            "/api/folder/INBOX?offset=0&limit=20&t=test-token",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page["folder"], "INBOX");
+        assert_eq!(page["total"], 230);
+        assert_eq!(page["offset"], 0);
+        let emails = page["emails"].as_array().unwrap();
+        assert_eq!(emails.len(), 20);
+        assert_eq!(emails[19]["index"], 19);
+        assert!(
+            emails[0]["subject"]
+                .as_str()
+                .unwrap()
+                .starts_with("Message ")
+        );
+        assert_eq!(emails[0]["unread"], false);
+        assert_eq!(loaded(), 60, "one scroll-sized chunk past the first 10");
+
+        let (_, page) = get_json(
+            app.clone(),
+            "/api/folder/INBOX?offset=200&limit=50&t=test-token",
+        )
+        .await;
+        let indices: Vec<u64> = page["emails"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["index"].as_u64().unwrap())
+            .collect();
+        assert_eq!(indices, (200..230).collect::<Vec<_>>());
+        assert_eq!(page["total"], 230);
+        assert_eq!(loaded(), 230);
+
+        let (_, page) = get_json(app.clone(), "/api/folder/INBOX?limit=1000&t=test-token").await;
+        assert_eq!(page["emails"].as_array().unwrap().len(), FOLDER_PAGE_MAX);
+        let (_, page) = get_json(app.clone(), "/api/folder/INBOX?offset=500&t=test-token").await;
+        assert!(page["emails"].as_array().unwrap().is_empty());
+        let (status, page) = get_json(
+            app.clone(),
+            &format!("/api/folder/INBOX?offset={}&t=test-token", usize::MAX),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "a huge offset must not overflow");
+        assert!(page["emails"].as_array().unwrap().is_empty());
+
+        let (status, _) = get_json(app, "/api/folder/Nope?t=test-token").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    /// With `[web].api_token` set, `/api/*` takes it as a bearer token
+    /// or `?token=`, still takes the viewer's per-launch token, and
+    /// answers anything else with a JSON 401. `/events` is not part of
+    /// the API and keeps to the per-launch token.
+    #[tokio::test(flavor = "current_thread")]
+    async fn api_token_gates_the_api_routes() {
+        let (state, _rx) = webstate_with_one_headers_only_email();
+        let state = WebState {
+            api_token: Some(Arc::from("script-secret")),
+            ..state
+        };
+        let app = build_router(state);
+        let call = |uri: &str, bearer: Option<&str>| {
+            let mut request = Request::builder().uri(uri);
+            if let Some(bearer) = bearer {
+                request = request.header("authorization", format!("Bearer {}", bearer));
+            }
+            app.clone().oneshot(request.body(Body::empty()).unwrap())
+        };
+
+        for (uri, bearer) in [
+            ("/api/folders", Some("script-secret")),
+            ("/api/folders?token=script-secret", None),
+            ("/api/folders?t=test-token", None),
+        ] {
+            let response = call(uri, bearer).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{} {:?}", uri, bearer);
+        }
+
+        for (uri, bearer) in [
+            ("/api/folders", Some("wrong")),
+            ("/api/folders?token=wrong", None),
+            ("/api/current-email", None),
+        ] {
+            let response = call(uri, bearer).await.unwrap();
+            assert_eq!(
+                response.status(),
+                StatusCode::UNAUTHORIZED,
+                "{} {:?}",
+                uri,
+                bearer
+            );
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(
+                body,
+                serde_json::json!({"error": "invalid or missing token"})
+            );
+        }
+
+        let response = call("/events", Some("script-secret")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    /// `?token=` is percent-decoded, so a token with reserved characters
+    /// matches when sent URL-encoded.
+    #[tokio::test(flavor = "current_thread")]
+    async fn api_token_query_parameter_is_percent_decoded() {
+        let (state, _rx) = webstate_with_one_headers_only_email();
+        let state = WebState {
+            api_token: Some(Arc::from("a&b=c/d+e%")),
+            ..state
+        };
+        let app = build_router(state);
+        let status = |uri: &'static str| {
+            let app = app.clone();
+            async move {
+                app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                    .await
+                    .unwrap()
+                    .status()
+            }
+        };
+        assert_eq!(
+            status("/api/folders?token=a%26b%3Dc%2Fd%2Be%25").await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status("/api/folders?token=a&b=c/d+e%").await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    /// A blank `api_token` must not open the API to a blank bearer or
+    /// `?token=`; the per-launch token still gates `/api/*`.
+    #[tokio::test(flavor = "current_thread")]
+    async fn blank_api_token_leaves_the_api_on_the_launch_token() {
+        for blank in ["", "   "] {
+            let (state, _rx) = webstate_with_one_headers_only_email();
+            let state = WebState {
+                api_token: Some(Arc::from(blank)),
+                ..state
+            };
+            let app = build_router(state);
+            for (uri, bearer) in [
+                ("/api/folders", Some("")),
+                ("/api/folders", Some(blank)),
+                ("/api/folders?token=", None),
+                ("/api/folders?token=%20%20%20", None),
+                ("/api/folders", None),
+            ] {
+                let mut request = Request::builder().uri(uri);
+                if let Some(bearer) = bearer {
+                    request = request.header("authorization", format!("Bearer {}", bearer));
+                }
+                let response = app
+                    .clone()
+                    .oneshot(request.body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    response.status(),
+                    StatusCode::UNAUTHORIZED,
+                    "{:?}: {} {:?}",
+                    blank,
+                    uri,
+                    bearer
+                );
+            }
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .uri("/api/folders?t=test-token")
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
+    /// The read-only API against the fixture MailDir: the folder tree
+    /// with counts, a folder's header list, and one fully parsed email
+    /// with its body and attachment metadata.
+    #[tokio::test(flavor = "current_thread")]
+    async fn api_serves_the_fixture_tree_folder_and_email() {
+        let fixture = crate::test_fixtures::TestMailDir::new();
+        let scanner = MaildirScanner::new(fixture.root_path.clone());
+        let mut store = EmailStore::new(fixture.root_path.clone());
+        store.root_folder = scanner.scan().unwrap();
+        let inbox = store
+            .root_folder
+            .subfolders
+            .iter_mut()
+            .find(|f| f.name == "INBOX")
+            .unwrap();
+        scanner.load_folder_emails_with_limit(inbox, None).unwrap();
+        let attachment_index = inbox
+            .emails
+            .iter()
+            .position(|e| e.file_path.ends_with("cur/1234567894.email5"))
+            .unwrap();
+        inbox.emails[attachment_index].parse_from_file().unwrap();
+        let inbox_total = inbox.emails.len();
+        let inbox_unread = inbox.unread_count;
+
+        let (state, _rx) = webstate_with_one_headers_only_email();
+        let state = WebState {
+            email_store: Arc::new(Mutex::new(store)),
+            scanner,
+            ..state
+        };
+        let app = build_router(state);
+
+        let (status, folders) = get_json(app.clone(), "/api/folders?t=test-token").await;
+        assert_eq!(status, StatusCode::OK);
+        let folders = folders.as_array().unwrap();
+        let node = |name: &str| {
+            folders
+                .iter()
+                .find(|f| f["name"] == name)
+                .unwrap_or_else(|| panic!("no {} in {:?}", name, folders))
+        };
+        let inbox = node("INBOX");
+        assert_eq!(inbox["path"], "INBOX");
+        assert_eq!(inbox["total"], inbox_total);
+        assert_eq!(inbox["unread"], inbox_unread);
+        assert!(inbox_unread > 0);
+        assert_eq!(inbox["loaded"], true);
+        let work = node("Work");
+        assert_eq!(work["loaded"], false);
+        let work_children: Vec<&str> = work["subfolders"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["path"].as_str().unwrap())
+            .collect();
+        assert_eq!(work_children, ["Work/Meetings", "Work/Projects"]);
+
+        let (status, page) = get_json(app.clone(), "/api/folder/INBOX?t=test-token").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page["total"], inbox_total);
+        let row = &page["emails"][attachment_index];
+        assert_eq!(row["index"], attachment_index);
+        for field in ["subject", "from", "date"] {
+            assert!(row[field].is_string(), "{} in {}", field, row);
+        }
+        assert!(row["unread"].is_boolean() && row["flagged"].is_boolean());
+
+        let uri = format!("/api/email/INBOX/{}?t=test-token", attachment_index);
+        let (status, email) = get_json(app, &uri).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(email["has_email"], true);
+        assert_eq!(email["email_count"], inbox_total);
+        assert!(
+            email["body_html"]
+                .as_str()
+                .unwrap()
+                .contains("Please find the attached project documents")
+        );
+        let attachments: Vec<(&str, &str)> = email["attachments"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| {
+                assert!(a["size"].is_string());
+                (
+                    a["filename"].as_str().unwrap(),
+                    a["content_type"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            attachments,
+            [
+                ("project_specs.pdf", "application/pdf"),
+                ("prototype_photo.jpg", "image/jpeg"),
+            ]
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn health_reports_version_uptime_and_mail_counts() {
+        let (state, _rx) = webstate_with_one_headers_only_email();
+        state
+            .email_store
+            .lock()
+            .unwrap()
+            .get_current_folder_mut()
+            .emails[0]
+            .is_unread = true;
+        let app = build_router(state);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/health")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/health?t=test-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let health: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(health["version"], env!("CARGO_PKG_VERSION"));
+        assert!(health["uptime_secs"].as_u64().is_some());
+        assert_eq!(health["folder_count"], 1);
+        assert_eq!(health["total_emails_loaded"], 1);
+        assert_eq!(health["unread_emails"], 1);
+        // The fixture email is unparsed, so it has no Message-ID.
+        assert!(health["selected_email"].is_null());
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn api_current_email_sets_no_store_and_vary_origin() {
         let app = router_for_test();
@@ -1701,4 +3280,171 @@ mod tests {
         assert!(!ct_eq(b"", b"x"));
         assert!(ct_eq(b"", b""));
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn start_with_shutdown_resolves_after_signal_with_open_sse_stream() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let port = {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (tx, _rx) = std::sync::mpsc::channel::<PathBuf>();
+        let server = WebServer::new(
+            "127.0.0.1".to_string(),
+            port,
+            Arc::new(Mutex::new(EmailStore::new(tmp.path().to_path_buf()))),
+            Arc::new(AtomicU8::new(ActivePane::Messages.to_u8())),
+            Arc::new(AtomicBool::new(false)),
+            tx,
+            broadcast::channel(WS_EVENT_CAPACITY).0,
+        );
+        let token = server.token().to_string();
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let serve = tokio::spawn(async move {
+            server
+                .start_with_shutdown(async {
+                    let _ = shutdown_rx.await;
+                })
+                .await
+        });
+
+        // Hold an SSE connection open across the shutdown: the stream
+        // never ends on its own, so this proves the server closes it
+        // instead of waiting on it forever.
+        let addr = format!("127.0.0.1:{port}");
+        let mut sse = None;
+        for _ in 0..100 {
+            if let Ok(stream) = tokio::net::TcpStream::connect(&addr).await {
+                sse = Some(stream);
+                break;
+            }
+            sleep(Duration::from_millis(20)).await;
+        }
+        let mut sse = sse.expect("server never accepted a connection");
+        let request = format!("GET /events?t={token} HTTP/1.1\r\nHost: {addr}\r\n\r\n");
+        sse.write_all(request.as_bytes()).await.unwrap();
+        let mut buf = [0u8; 256];
+        let n = sse.read(&mut buf).await.unwrap();
+        assert!(
+            String::from_utf8_lossy(&buf[..n]).starts_with("HTTP/1.1 200"),
+            "SSE handshake failed: {:?}",
+            String::from_utf8_lossy(&buf[..n]),
+        );
+
+        shutdown_tx.send(()).unwrap();
+        let result = tokio::time::timeout(Duration::from_secs(3), serve)
+            .await
+            .expect("serve future must resolve after the shutdown signal")
+            .unwrap();
+        assert!(result.is_ok(), "graceful shutdown errored: {:?}", result);
+    }
+
+    /// `/ws` opens with the current selection, follows a selection
+    /// change via the server's poller, and relays events the TUI
+    /// publishes on the shared sender.
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn websocket_streams_selection_and_watcher_events() {
+        use futures::StreamExt;
+        use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+        let port = {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let mut store = EmailStore::new(PathBuf::from("/nonexistent_root"));
+        let mut inbox = Folder::new(
+            "INBOX".to_string(),
+            PathBuf::from("/nonexistent_root/INBOX"),
+        );
+        for name in ["a.eml", "b.eml"] {
+            inbox.add_email(Email::new(
+                PathBuf::from("/nonexistent_root/INBOX/cur").join(name),
+            ));
+        }
+        inbox.is_loaded = true;
+        store.root_folder.add_subfolder(inbox);
+        store.enter_folder_by_path(&[0]);
+        store.select_email(0);
+        let store = Arc::new(Mutex::new(store));
+
+        let events = broadcast::channel(WS_EVENT_CAPACITY).0;
+        let (tx, _rx) = std::sync::mpsc::channel::<PathBuf>();
+        let server = WebServer::new(
+            "127.0.0.1".to_string(),
+            port,
+            store.clone(),
+            Arc::new(AtomicU8::new(ActivePane::Messages.to_u8())),
+            Arc::new(AtomicBool::new(false)),
+            tx,
+            events.clone(),
+        );
+        let token = server.token().to_string();
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let serve = tokio::spawn(async move {
+            server
+                .start_with_shutdown(async {
+                    let _ = shutdown_rx.await;
+                })
+                .await
+        });
+
+        let url = format!("ws://127.0.0.1:{port}/ws?t={token}");
+        let mut ws = None;
+        for _ in 0..100 {
+            if let Ok((stream, _)) = tokio_tungstenite::connect_async(url.as_str()).await {
+                ws = Some(stream);
+                break;
+            }
+            sleep(Duration::from_millis(20)).await;
+        }
+        let mut ws = ws.expect("server never accepted the WebSocket");
+        let mut next_event = async || -> serde_json::Value {
+            loop {
+                let frame = tokio::time::timeout(Duration::from_secs(3), ws.next())
+                    .await
+                    .expect("no WebSocket frame within 3s")
+                    .expect("WebSocket closed")
+                    .unwrap();
+                if let WsMessage::Text(text) = frame {
+                    return serde_json::from_str(&text).unwrap();
+                }
+            }
+        };
+
+        let initial = next_event().await;
+        assert_eq!(initial["type"], "email_changed");
+        assert!(initial["id"].as_str().unwrap().contains(":0:"));
+
+        store.lock().unwrap().select_email(1);
+        // The poller may publish the pre-change key first if it hadn't
+        // ticked yet; wait for the key that names the new selection.
+        loop {
+            let event = next_event().await;
+            if event["type"] == "email_changed" && event["id"].as_str().unwrap().contains(":1:") {
+                break;
+            }
+        }
+
+        events
+            .send(WsEvent::FolderChanged {
+                folder: "INBOX".to_string(),
+            })
+            .unwrap();
+        loop {
+            let event = next_event().await;
+            if event["type"] == "folder_changed" {
+                assert_eq!(event["folder"], "INBOX");
+                break;
+            }
+        }
+
+        shutdown_tx.send(()).unwrap();
+        let result = tokio::time::timeout(Duration::from_secs(3), serve)
+            .await
+            .expect("serve future must resolve with an open WebSocket")
+            .unwrap();
+        assert!(result.is_ok(), "graceful shutdown errored: {:?}", result);
+    }
 }
@@ -5,8 +5,14 @@
 //
 // Behavior is a union of the two former shells: register the PWA service
 // worker, subscribe to /events for refresh notifications, and re-render the
-// shell on selection changes. The email body is loaded into a sandboxed
-// <iframe srcdoc> so untrusted markup cannot reach the parent origin.
+// shell on selection changes. The Previous / Next bar browses the folder
+// through /api/email/<folder>/<index> without moving the TUI selection; the
+// next TUI selection change takes the page back to the focused email. The
+// folder sidebar lists the folder a page at a time from
+// /api/folder/<folder>, fetching the next page as it is scrolled near its
+// end. The email body is loaded into a sandboxed
+// <iframe srcdoc> so untrusted markup cannot reach the parent origin. The
+// theme toggle switches the page between the light and dark palettes.
 
 (function () {
     // Per-launch token gating every request except /healthz. The HTML shell
@@ -21,6 +27,43 @@
         return path + sep + 't=' + encodeURIComponent(TOKEN);
     }
 
+    // Light / dark palette (see the data-theme rules in styles.css). A
+    // choice made with the toggle is kept in localStorage; until there is
+    // one the page follows prefers-color-scheme, live.
+    const THEME_KEY = 'vulthor-theme';
+    const darkQuery = window.matchMedia('(prefers-color-scheme: dark)');
+    function storedTheme() {
+        try {
+            const theme = localStorage.getItem(THEME_KEY);
+            return theme === 'dark' || theme === 'light' ? theme : null;
+        } catch (err) {
+            return null;
+        }
+    }
+    const themeToggle = document.createElement('button');
+    themeToggle.type = 'button';
+    themeToggle.className = 'theme-toggle';
+    function applyTheme(theme) {
+        document.documentElement.dataset.theme = theme;
+        const other = theme === 'dark' ? 'light' : 'dark';
+        themeToggle.textContent = theme === 'dark' ? 'Light' : 'Dark';
+        themeToggle.title = 'Switch to the ' + other + ' theme';
+    }
+    applyTheme(storedTheme() || (darkQuery.matches ? 'dark' : 'light'));
+    themeToggle.addEventListener('click', function () {
+        const theme = document.documentElement.dataset.theme === 'dark' ? 'light' : 'dark';
+        try {
+            localStorage.setItem(THEME_KEY, theme);
+        } catch (err) {
+            // Storage disabled: the choice lasts until the page reloads.
+        }
+        applyTheme(theme);
+    });
+    darkQuery.addEventListener('change', function (event) {
+        if (!storedTheme()) applyTheme(event.matches ? 'dark' : 'light');
+    });
+    document.body.appendChild(themeToggle);
+
     if ('serviceWorker' in navigator) {
         window.addEventListener('load', function () {
             navigator.serviceWorker.register(withToken('/sw.js')).catch(function (err) {
@@ -81,6 +124,11 @@
         if (!container.querySelector('.email-header')) {
             container.className = 'container email-view';
             container.innerHTML =
+                '<nav class="email-nav">' +
+                '  <button type="button" class="nav-prev">&larr; Previous</button>' +
+                '  <span class="email-position"></span>' +
+                '  <button type="button" class="nav-next">Next &rarr;</button>' +
+                '</nav>' +
                 '<header class="email-header">' +
                 '  <h1 class="email-subject"></h1>' +
                 '  <div class="email-meta">' +
@@ -125,9 +173,173 @@
         }
 
         renderAttachments(emailData.attachments);
+        updateNav(emailData);
+        if (emailData.folder) {
+            showFolder(emailData.folder);
+        }
+        markCurrentRow(emailData.folder, emailData.email_index);
         document.querySelector('.container').className = 'container email-view';
     }
 
+    // Record the email's place in its folder on the nav bar and disable
+    // the buttons that would step past either end.
+    function updateNav(emailData) {
+        const nav = document.querySelector('.email-nav');
+        if (!nav) return;
+        const count = emailData.email_count || 0;
+        const index = emailData.email_index || 0;
+        nav.dataset.folder = emailData.folder || '';
+        nav.dataset.index = String(index);
+        nav.dataset.count = String(count);
+        nav.querySelector('.email-position').textContent =
+            count > 0 ? (index + 1) + ' / ' + count : '';
+        nav.querySelector('.nav-prev').disabled = index <= 0;
+        nav.querySelector('.nav-next').disabled = index + 1 >= count;
+    }
+
+    // Show the email `step` places from the one shown.
+    function browse(step) {
+        const nav = document.querySelector('.email-nav');
+        if (!nav) return;
+        const index = Number(nav.dataset.index) + step;
+        if (index < 0 || index >= Number(nav.dataset.count)) return;
+        openEmail(nav.dataset.folder, index);
+    }
+
+    // Fetch the email at `index` in `folder`. A body the server is still
+    // parsing comes back as a placeholder, so poll a few times until it
+    // lands.
+    async function openEmail(folder, index) {
+        if (isLoading) return;
+        const url = withToken(
+            '/api/email/' + encodeURIComponent(folder) + '/' + index
+        );
+        isLoading = true;
+        try {
+            for (let attempt = 0; attempt < 10; attempt++) {
+                const response = await fetch(url);
+                if (!response.ok) return;
+                const emailData = await response.json();
+                updateEmailDisplay(emailData);
+                if (!emailData.email_id.endsWith(':headers')) return;
+                await new Promise(function (resolve) { setTimeout(resolve, 300); });
+            }
+        } catch (error) {
+            console.error('Error browsing emails:', error);
+        } finally {
+            isLoading = false;
+        }
+    }
+
+    document.addEventListener('click', function (event) {
+        const row = event.target.closest('.folder-row');
+        if (row) {
+            openEmail(sidebar.folder, Number(row.dataset.index));
+        } else if (event.target.closest('.nav-prev')) {
+            browse(-1);
+        } else if (event.target.closest('.nav-next')) {
+            browse(1);
+        }
+    });
+
+    // Rows fetched per /api/folder page.
+    const FOLDER_PAGE_SIZE = 50;
+    const sidebar = { folder: null, loaded: 0, total: 0, loading: false };
+
+    function ensureSidebar() {
+        let aside = document.querySelector('.folder-sidebar');
+        if (!aside) {
+            aside = document.createElement('aside');
+            aside.className = 'folder-sidebar';
+            const title = document.createElement('h2');
+            title.className = 'folder-sidebar-title';
+            const list = document.createElement('ul');
+            list.className = 'folder-sidebar-list';
+            aside.appendChild(title);
+            aside.appendChild(list);
+            aside.addEventListener('scroll', function () {
+                if (aside.scrollTop + aside.clientHeight >= aside.scrollHeight - 200) {
+                    loadFolderPage();
+                }
+            });
+            document.body.insertBefore(aside, document.body.firstChild);
+            document.body.classList.add('has-sidebar');
+        }
+        return aside;
+    }
+
+    // List `folder` in the sidebar, starting over from its first page
+    // when it isn't the folder already listed.
+    function showFolder(folder) {
+        const aside = ensureSidebar();
+        if (sidebar.folder === folder) return;
+        sidebar.folder = folder;
+        sidebar.loaded = 0;
+        sidebar.total = 0;
+        aside.querySelector('.folder-sidebar-title').textContent = folder;
+        aside.querySelector('.folder-sidebar-list').textContent = '';
+        loadFolderPage();
+    }
+
+    // Append the next page of the listed folder. Keeps going while the
+    // list is too short to scroll, so the scroll handler gets a chance
+    // to fire.
+    async function loadFolderPage() {
+        if (sidebar.loading || (sidebar.loaded > 0 && sidebar.loaded >= sidebar.total)) return;
+        const folder = sidebar.folder;
+        sidebar.loading = true;
+        let added = 0;
+        try {
+            const response = await fetch(withToken(
+                '/api/folder/' + encodeURIComponent(folder) +
+                '?offset=' + sidebar.loaded + '&limit=' + FOLDER_PAGE_SIZE
+            ));
+            if (!response.ok || folder !== sidebar.folder) return;
+            const page = await response.json();
+            const list = document.querySelector('.folder-sidebar-list');
+            page.emails.forEach(function (email) {
+                list.appendChild(folderRow(email));
+            });
+            added = page.emails.length;
+            sidebar.loaded += added;
+            sidebar.total = added === 0 ? sidebar.loaded : page.total;
+            const nav = document.querySelector('.email-nav');
+            if (nav) markCurrentRow(nav.dataset.folder, Number(nav.dataset.index));
+        } catch (error) {
+            console.error('Error loading folder:', error);
+        } finally {
+            sidebar.loading = false;
+        }
+        const aside = document.querySelector('.folder-sidebar');
+        if (added > 0 && aside && aside.scrollHeight <= aside.clientHeight) {
+            loadFolderPage();
+        }
+    }
+
+    function folderRow(email) {
+        const row = document.createElement('li');
+        row.className = 'folder-row' + (email.unread ? ' unread' : '');
+        row.dataset.index = String(email.index);
+        const subject = document.createElement('span');
+        subject.className = 'folder-row-subject';
+        subject.textContent = (email.flagged ? '\u2605 ' : '') + (email.subject || '(no subject)');
+        const from = document.createElement('span');
+        from.className = 'folder-row-from';
+        from.textContent = email.from;
+        row.appendChild(subject);
+        row.appendChild(from);
+        return row;
+    }
+
+    function markCurrentRow(folder, index) {
+        document.querySelectorAll('.folder-row.current').forEach(function (row) {
+            row.classList.remove('current');
+        });
+        if (folder !== sidebar.folder) return;
+        const row = document.querySelector('.folder-row[data-index="' + index + '"]');
+        if (row) row.classList.add('current');
+    }
+
     function renderAttachments(attachments) {
         const existing = document.querySelector('.attachments-section');
         if (existing) {
@@ -177,6 +389,12 @@
         if (banner) {
             banner.remove();
         }
+        const aside = document.querySelector('.folder-sidebar');
+        if (aside) {
+            aside.remove();
+            document.body.classList.remove('has-sidebar');
+            sidebar.folder = null;
+        }
         if (!document.querySelector('.welcome-header')) {
             const container = document.querySelector('.container');
             container.className = 'container welcome-view';
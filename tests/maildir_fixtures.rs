@@ -5,7 +5,8 @@
 
 use std::path::PathBuf;
 
-use vulthor::email::Email;
+use vulthor::email::{Email, Folder};
+use vulthor::maildir::MaildirScanner;
 
 fn fixture_dir(rel: &str) -> PathBuf {
     let mut p = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -27,7 +28,10 @@ fn fixture(rel: &str) -> Email {
 fn plain_text_fixture_populates_body_plain_only() {
     let email = fixture("Inbox/cur/01-plain-text.eml:2,S");
     assert!(
-        email.body_plain.as_deref().is_some_and(|b| b.contains("Alice")),
+        email
+            .body_plain
+            .as_deref()
+            .is_some_and(|b| b.contains("Alice")),
         "plain fixture must populate body_plain",
     );
     assert!(
@@ -74,7 +78,10 @@ fn multipart_alternative_fixture_populates_both_bodies() {
 fn attachment_fixture_lists_the_attachment_separately() {
     let email = fixture("Inbox/cur/04-with-attachment.eml:2,S");
     assert!(
-        email.body_plain.as_deref().is_some_and(|b| b.contains("Dave")),
+        email
+            .body_plain
+            .as_deref()
+            .is_some_and(|b| b.contains("Dave")),
         "attachment fixture's text part must populate body_plain",
     );
     assert_eq!(
@@ -94,7 +101,10 @@ fn attachment_fixture_lists_the_attachment_separately() {
 #[test]
 fn phishing_fixture_loads_and_carries_mismatched_link() {
     let email = fixture("Inbox/new/05-phishing-link.eml:2,");
-    let html = email.body_html.as_deref().expect("phishing fixture is HTML");
+    let html = email
+        .body_html
+        .as_deref()
+        .expect("phishing fixture is HTML");
     // The sanitized output retains the visible text "paypal.com" but the
     // href points at attacker.evil.test — vu-6yi's link-check pass is
     // what flags the mismatch downstream.
@@ -131,3 +141,68 @@ fn large_body_fixture_parses_and_keeps_full_text() {
         "large-body fixture must preserve the full repeated body",
     );
 }
+
+#[test]
+fn undeclared_latin1_fixture_is_detected_instead_of_mangled() {
+    let email = fixture("Inbox/cur/08-latin1-undeclared.eml:2,S");
+    let plain = email
+        .body_plain
+        .as_deref()
+        .expect("latin-1 fixture must populate body_plain");
+    assert!(plain.contains("café crème coûte"), "body was {:?}", plain);
+    assert!(!plain.contains('\u{FFFD}'));
+    // WHATWG (and so encoding_rs) treats ISO-8859-1 as windows-1252.
+    assert_eq!(email.decoded_charset, Some("windows-1252"));
+    assert!(
+        email
+            .get_header_display()
+            .contains("(decoded as windows-1252)")
+    );
+}
+
+#[test]
+fn declared_shift_jis_fixture_decodes_without_a_note() {
+    let email = fixture("Inbox/cur/09-shift-jis.eml:2,S");
+    let plain = email
+        .body_plain
+        .as_deref()
+        .expect("shift-jis fixture must populate body_plain");
+    assert!(
+        plain.contains("明日の会議は午後三時からです。"),
+        "body was {:?}",
+        plain
+    );
+    assert_eq!(email.decoded_charset, None);
+    assert!(!email.get_header_display().contains("decoded as"));
+}
+
+/// Scan the fixture Maildir and fully load the top-level folder `name`.
+fn loaded_folder(name: &str) -> Folder {
+    let scanner = MaildirScanner::new(fixture_dir(""));
+    let root = scanner.scan().expect("fixture root scans");
+    let mut folder = root
+        .subfolders
+        .into_iter()
+        .find(|f| f.name == name)
+        .unwrap_or_else(|| panic!("fixture folder `{name}` not found"));
+    scanner
+        .load_folder_emails_with_limit(&mut folder, None)
+        .expect("fixture folder loads");
+    folder
+}
+
+#[test]
+fn cur_only_folder_loads_its_emails() {
+    let folder = loaded_folder("Archive");
+    assert_eq!(folder.emails.len(), 1);
+    assert_eq!(folder.emails[0].headers.subject, "Archived thread");
+    assert!(!folder.emails[0].is_unread);
+}
+
+#[test]
+fn new_only_folder_loads_its_emails_as_unread() {
+    let folder = loaded_folder("Incoming");
+    assert_eq!(folder.emails.len(), 1);
+    assert_eq!(folder.emails[0].headers.subject, "Fresh delivery");
+    assert!(folder.emails[0].is_unread);
+}
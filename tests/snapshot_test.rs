@@ -152,6 +152,7 @@ fn render_messages_snapshot() {
             "Mail > INBOX",
             &drafts,
             &theme,
+            &Config::default(),
         );
     });
     assert_snapshot!(rendered);